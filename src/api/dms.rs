@@ -0,0 +1,53 @@
+use crate::api::request::RequestBuilder;
+use crate::api::types::{DmEvent, ListResponse};
+use crate::api::{ApiClientError, XApiClient};
+
+/// `dm_event.fields` requested with every DM event.
+const DM_EVENT_FIELDS: &str =
+    "id,text,event_type,created_at,dm_conversation_id,sender_id,participant_ids";
+
+/// Senders and participants come back in `includes.users`.
+const DM_EXPANSIONS: &str = "sender_id,participant_ids";
+
+impl XApiClient {
+    /// The authenticated user's recent DM events across every
+    /// conversation, newest first.
+    pub async fn get_dm_events(
+        &self,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<DmEvent>, ApiClientError> {
+        let request = RequestBuilder::new(&["dm_events"]);
+        self.get_dm_page(request, max_results, pagination_token)
+            .await
+    }
+
+    /// Events in the one-to-one conversation with `participant_id`, newest
+    /// first.
+    pub async fn get_dm_conversation_with(
+        &self,
+        participant_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<DmEvent>, ApiClientError> {
+        let request =
+            RequestBuilder::new(&["dm_conversations", "with", participant_id, "dm_events"]);
+        self.get_dm_page(request, max_results, pagination_token)
+            .await
+    }
+
+    async fn get_dm_page(
+        &self,
+        request: RequestBuilder,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<DmEvent>, ApiClientError> {
+        let request = request
+            .max_results(max_results, 1..=100)
+            .param("dm_event.fields", DM_EVENT_FIELDS)
+            .param("expansions", DM_EXPANSIONS)
+            .user_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.oauth_get(&self.url(&request)).await
+    }
+}
@@ -1,32 +1,94 @@
-use crate::api::types::{ListResponse, Tweet};
-use crate::api::{
-    ApiClientError, XApiClient, media_fields, tweet_expansions, tweet_fields, user_fields,
+use crate::api::request::RequestBuilder;
+use crate::api::types::{
+    BookmarkStatus, LikeStatus, ListResponse, RetweetStatus, SingleResponse, Tweet,
 };
+use crate::api::{ApiClientError, XApiClient};
 
 impl XApiClient {
     /// Get the authenticated user's bookmarks.
     pub async fn get_bookmarks(
-        &mut self,
+        &self,
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
         let my_id = self.get_my_user_id().await?;
-        let max_results = max_results.clamp(10, 100);
+        let request = RequestBuilder::new(&["users", &my_id, "bookmarks"])
+            .max_results(max_results, 10..=100)
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.oauth_get(&self.url(&request)).await
+    }
+
+    /// Bookmark a tweet for the authenticated user.
+    pub async fn bookmark_tweet(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<BookmarkStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "bookmarks"]);
+        self.oauth_post(
+            &self.url(&request),
+            &serde_json::json!({ "tweet_id": tweet_id }),
+        )
+        .await
+    }
+
+    /// Remove a tweet from the authenticated user's bookmarks.
+    pub async fn remove_bookmark(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<BookmarkStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "bookmarks", tweet_id]);
+        self.oauth_delete(&self.url(&request)).await
+    }
+
+    /// Like a tweet as the authenticated user.
+    pub async fn like_tweet(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<LikeStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "likes"]);
+        self.oauth_post(
+            &self.url(&request),
+            &serde_json::json!({ "tweet_id": tweet_id }),
+        )
+        .await
+    }
 
-        let mut url = Self::url(&format!(
-            "/users/{my_id}/bookmarks?max_results={max_results}\
-             &tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        ));
+    /// Remove the authenticated user's like from a tweet.
+    pub async fn unlike_tweet(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<LikeStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "likes", tweet_id]);
+        self.oauth_delete(&self.url(&request)).await
+    }
 
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
+    /// Repost a tweet as the authenticated user.
+    pub async fn retweet(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<RetweetStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "retweets"]);
+        self.oauth_post(
+            &self.url(&request),
+            &serde_json::json!({ "tweet_id": tweet_id }),
+        )
+        .await
+    }
 
-        self.oauth_get(&url).await
+    /// Undo the authenticated user's repost of a tweet.
+    pub async fn unretweet(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<RetweetStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "retweets", tweet_id]);
+        self.oauth_delete(&self.url(&request)).await
     }
 
     /// Get tweets liked by a user.
@@ -36,21 +98,10 @@ impl XApiClient {
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
-        let max_results = max_results.clamp(10, 100);
-
-        let mut url = Self::url(&format!(
-            "/users/{user_id}/liked_tweets?max_results={max_results}\
-             &tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        ));
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["users", user_id, "liked_tweets"])
+            .max_results(max_results, 10..=100)
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 }
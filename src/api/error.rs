@@ -0,0 +1,410 @@
+//! Structured errors parsed from X API v2 problem responses.
+//!
+//! Non-2xx responses carry a JSON "problem" body (`title`, `detail`, `type`,
+//! and sometimes `reason`). Classifying it up front lets callers react to
+//! specific failures (suspended account, protected tweets, missing resource)
+//! instead of pattern-matching raw response text.
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::api::types::ApiError;
+
+/// Fields of an X API problem body. Everything is optional because error
+/// shapes differ between endpoints and API generations.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Problem {
+    #[serde(skip)]
+    pub status: u16,
+    /// HTTP method of the failed request (e.g. `GET`).
+    #[serde(skip)]
+    pub method: String,
+    /// Request path of the failed request (e.g. `/2/users/123/bookmarks`).
+    #[serde(skip)]
+    pub endpoint: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub detail: Option<String>,
+    #[serde(rename = "type", default)]
+    pub type_: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub required_enrollment: Option<String>,
+    /// Per-parameter messages on `invalid-request` problems.
+    #[serde(default)]
+    pub errors: Vec<ProblemItem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProblemItem {
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl Problem {
+    /// Last path segment of the problem `type` URI, e.g. `client-forbidden`.
+    pub fn kind(&self) -> Option<&str> {
+        self.type_
+            .as_deref()
+            .and_then(|t| t.rsplit('/').next())
+            .filter(|k| !k.is_empty() && *k != "about:blank")
+    }
+
+    fn message(&self) -> String {
+        let mut msg = match (self.title.as_deref(), self.detail.as_deref()) {
+            (Some(title), Some(detail)) if title != detail => format!("{title}: {detail}"),
+            (_, Some(text)) | (Some(text), None) => text.to_string(),
+            (None, None) => format!("HTTP {}", self.status),
+        };
+        let item_messages: Vec<&str> = self
+            .errors
+            .iter()
+            .filter_map(|e| e.message.as_deref())
+            .collect();
+        if !item_messages.is_empty() {
+            msg.push_str(" (");
+            msg.push_str(&item_messages.join("; "));
+            msg.push(')');
+        }
+        msg
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message())
+    }
+}
+
+/// Why an account's profile or posts can't be shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountState {
+    /// Posts are visible only to followers the account approved.
+    Protected,
+    Suspended,
+    /// No such account: deactivated, renamed, or never existed.
+    Deactivated,
+}
+
+/// A failed X API request, classified by its problem body.
+#[derive(Debug, Clone, Error)]
+pub enum XApiError {
+    #[error("account suspended: {0}")]
+    Suspended(Problem),
+    #[error("protected: {0}")]
+    Protected(Problem),
+    #[error("not found: {0}")]
+    NotFound(Problem),
+    #[error("unauthorized: {0}")]
+    Unauthorized(Problem),
+    #[error("forbidden: {0}")]
+    Forbidden(Problem),
+    #[error("invalid request: {0}")]
+    InvalidRequest(Problem),
+    #[error("API error (status {status}): {0}", status = .0.status)]
+    Other(Problem),
+}
+
+impl XApiError {
+    /// Classify an error response from its status code and raw body. Bodies
+    /// that are not JSON are kept verbatim as the problem detail.
+    pub fn from_response(status: u16, body: &str) -> Self {
+        let mut problem = serde_json::from_str::<Problem>(body).unwrap_or_else(|_| Problem {
+            detail: (!body.trim().is_empty()).then(|| body.trim().to_string()),
+            ..Problem::default()
+        });
+        problem.status = status;
+        Self::classify(problem)
+    }
+
+    /// Classify an entry of a 200 response's `errors` array, which is how
+    /// user lookups report suspended and missing accounts.
+    pub fn from_partial(error: &ApiError) -> Self {
+        Self::classify(Problem {
+            status: error.status.unwrap_or_default(),
+            title: error.title.clone(),
+            detail: error.detail.clone(),
+            type_: error.type_.clone(),
+            ..Problem::default()
+        })
+    }
+
+    /// The account-level reason behind a failed user lookup or timeline.
+    pub fn account_state(&self) -> Option<AccountState> {
+        match self {
+            Self::Protected(_) => Some(AccountState::Protected),
+            Self::Suspended(_) => Some(AccountState::Suspended),
+            Self::NotFound(_) => Some(AccountState::Deactivated),
+            _ => None,
+        }
+    }
+
+    /// Attach the request that failed, used to tailor remediation hints.
+    pub fn with_request(mut self, method: &str, endpoint: &str) -> Self {
+        let problem = self.problem_mut();
+        problem.method = method.to_string();
+        problem.endpoint = endpoint.to_string();
+        self
+    }
+
+    fn classify(problem: Problem) -> Self {
+        let detail = problem.detail.as_deref().unwrap_or_default().to_lowercase();
+        let kind = problem.kind().unwrap_or_default();
+
+        if detail.contains("suspended") {
+            Self::Suspended(problem)
+        } else if kind == "not-authorized-for-resource" || detail.contains("protected") {
+            Self::Protected(problem)
+        } else if kind == "resource-not-found" || problem.status == 404 {
+            Self::NotFound(problem)
+        } else if kind == "invalid-request" || problem.status == 400 {
+            Self::InvalidRequest(problem)
+        } else if problem.status == 401 {
+            Self::Unauthorized(problem)
+        } else if problem.status == 403 {
+            Self::Forbidden(problem)
+        } else {
+            Self::Other(problem)
+        }
+    }
+
+    /// The underlying problem details.
+    pub fn problem(&self) -> &Problem {
+        match self {
+            Self::Suspended(p)
+            | Self::Protected(p)
+            | Self::NotFound(p)
+            | Self::Unauthorized(p)
+            | Self::Forbidden(p)
+            | Self::InvalidRequest(p)
+            | Self::Other(p) => p,
+        }
+    }
+
+    fn problem_mut(&mut self) -> &mut Problem {
+        match self {
+            Self::Suspended(p)
+            | Self::Protected(p)
+            | Self::NotFound(p)
+            | Self::Unauthorized(p)
+            | Self::Forbidden(p)
+            | Self::InvalidRequest(p)
+            | Self::Other(p) => p,
+        }
+    }
+
+    /// HTTP status of the failed response.
+    pub fn status(&self) -> u16 {
+        self.problem().status
+    }
+
+    /// The OAuth 2.0 scope a 403 was most likely missing, going by the
+    /// request. `None` for access-tier and app-only token refusals, which
+    /// signing in again with more scopes wouldn't fix.
+    pub fn missing_scope(&self) -> Option<&'static str> {
+        let Self::Forbidden(p) = self else {
+            return None;
+        };
+        let kind = p.kind().unwrap_or_default();
+        if kind == "client-forbidden"
+            || kind == "unsupported-authentication"
+            || p.reason.as_deref() == Some("client-not-enrolled")
+        {
+            return None;
+        }
+        required_scope(&p.method, &p.endpoint)
+    }
+
+    /// Specific guidance for auth and access failures, suitable for showing
+    /// under the error message. `None` when there is nothing actionable.
+    pub fn remediation(&self) -> Option<String> {
+        let p = self.problem();
+        let kind = p.kind().unwrap_or_default();
+
+        if kind == "client-forbidden" || p.reason.as_deref() == Some("client-not-enrolled") {
+            let tier = p
+                .required_enrollment
+                .as_deref()
+                .map(|e| format!(" (requires: {e})"))
+                .unwrap_or_default();
+            return Some(format!(
+                "This endpoint is not available on your X API access tier{tier}. \
+                 Check your app's plan in the X developer portal."
+            ));
+        }
+        if kind == "unsupported-authentication" {
+            return Some(
+                "This endpoint needs a signed-in user, not an app-only bearer token. \
+                 Set X_CLIENT_ID and run :auth (or `xplorertui auth`)."
+                    .into(),
+            );
+        }
+
+        match self {
+            Self::Unauthorized(_) => Some(
+                "The access token was rejected (expired or revoked). \
+                 Re-run :auth (or `xplorertui auth`) to sign in again."
+                    .into(),
+            ),
+            Self::Forbidden(_) => Some(match required_scope(&p.method, &p.endpoint) {
+                Some(scope) if scope.ends_with(".write") => {
+                    format!("{scope} scope missing, re-run :auth with write scopes.")
+                }
+                Some(scope) => format!("{scope} scope missing, re-run :auth to grant it."),
+                None => "The token is not permitted to do this. Re-run :auth; if it \
+                         persists, check the app's permissions in the X developer portal."
+                    .into(),
+            }),
+            Self::Protected(_) => Some(
+                "This account's posts are protected; only approved followers can see them.".into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// OAuth 2.0 scope an endpoint needs beyond the basic read scopes, used to
+/// explain 403s caused by tokens granted before that scope was requested.
+pub fn required_scope(method: &str, endpoint: &str) -> Option<&'static str> {
+    let read = method.is_empty() || method.eq_ignore_ascii_case("GET");
+    let pick = |r: &'static str, w: &'static str| Some(if read { r } else { w });
+
+    if endpoint.contains("/bookmarks") {
+        pick("bookmark.read", "bookmark.write")
+    } else if endpoint.contains("/likes") || endpoint.contains("/liked_tweets") {
+        pick("like.read", "like.write")
+    } else if endpoint.contains("/muting") {
+        pick("mute.read", "mute.write")
+    } else if endpoint.contains("/blocking") {
+        pick("block.read", "block.write")
+    } else if endpoint.contains("/lists") || endpoint.contains("list_memberships") {
+        pick("list.read", "list.write")
+    } else if endpoint.contains("/dm_") {
+        pick("dm.read", "dm.write")
+    } else if endpoint.contains("/hidden") {
+        Some("tweet.moderate.write")
+    } else if endpoint.contains("/followers") || endpoint.contains("/following") {
+        pick("follows.read", "follows.write")
+    } else if !read && (endpoint.contains("/tweets") || endpoint.contains("/retweets")) {
+        Some("tweet.write")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unauthorized_problem() {
+        let body =
+            r#"{"title":"Unauthorized","type":"about:blank","status":401,"detail":"Unauthorized"}"#;
+        let err = XApiError::from_response(401, body);
+        assert!(matches!(err, XApiError::Unauthorized(_)));
+        assert_eq!(err.to_string(), "unauthorized: Unauthorized");
+    }
+
+    #[test]
+    fn parses_client_forbidden_with_reason() {
+        let body = r#"{"title":"Client Forbidden","detail":"This app is not enrolled.","reason":"client-not-enrolled","type":"https://api.twitter.com/2/problems/client-forbidden","required_enrollment":"Appropriate Level of API Access"}"#;
+        let err = XApiError::from_response(403, body);
+        assert!(matches!(err, XApiError::Forbidden(_)));
+        assert_eq!(err.problem().kind(), Some("client-forbidden"));
+        assert_eq!(err.problem().reason.as_deref(), Some("client-not-enrolled"));
+    }
+
+    #[test]
+    fn classifies_suspended_and_protected() {
+        let suspended = r#"{"title":"Forbidden","detail":"User has been suspended: [someone].","type":"https://api.twitter.com/2/problems/resource-not-found"}"#;
+        assert!(matches!(
+            XApiError::from_response(403, suspended),
+            XApiError::Suspended(_)
+        ));
+
+        let protected = r#"{"title":"Authorization Error","detail":"Sorry, you are not authorized to see the Tweet.","type":"https://api.twitter.com/2/problems/not-authorized-for-resource"}"#;
+        assert!(matches!(
+            XApiError::from_response(403, protected),
+            XApiError::Protected(_)
+        ));
+    }
+
+    #[test]
+    fn partial_errors_map_to_account_states() {
+        let partial = |detail: &str, kind: &str| ApiError {
+            detail: Some(detail.into()),
+            title: None,
+            type_: Some(format!("https://api.twitter.com/2/problems/{kind}")),
+            status: None,
+            resource_type: Some("user".into()),
+            resource_id: None,
+            parameter: Some("username".into()),
+        };
+        let state = |detail, kind| XApiError::from_partial(&partial(detail, kind)).account_state();
+
+        assert_eq!(
+            state("User has been suspended: [spam].", "resource-not-found"),
+            Some(AccountState::Suspended)
+        );
+        assert_eq!(
+            state(
+                "Could not find user with username: [gone].",
+                "resource-not-found"
+            ),
+            Some(AccountState::Deactivated)
+        );
+        assert_eq!(
+            state(
+                "Sorry, you are not authorized to see the user with id: [7].",
+                "not-authorized-for-resource"
+            ),
+            Some(AccountState::Protected)
+        );
+        assert_eq!(state("Something else", "invalid-request"), None);
+    }
+
+    #[test]
+    fn invalid_request_includes_parameter_messages() {
+        let body = r#"{"errors":[{"parameters":{"max_results":["500"]},"message":"The `max_results` query parameter value [500] is not between 5 and 100"}],"title":"Invalid Request","detail":"One or more parameters to your request was invalid.","type":"https://api.twitter.com/2/problems/invalid-request"}"#;
+        let err = XApiError::from_response(400, body);
+        assert!(matches!(err, XApiError::InvalidRequest(_)));
+        assert!(err.to_string().contains("is not between 5 and 100"));
+    }
+
+    #[test]
+    fn forbidden_write_suggests_missing_scope() {
+        let body = r#"{"title":"Forbidden","status":403,"detail":"Forbidden"}"#;
+        let err = XApiError::from_response(403, body).with_request("POST", "/2/users/1/bookmarks");
+        assert_eq!(
+            err.remediation().as_deref(),
+            Some("bookmark.write scope missing, re-run :auth with write scopes.")
+        );
+        assert_eq!(err.missing_scope(), Some("bookmark.write"));
+
+        let tier = r#"{"title":"Client Forbidden","type":"https://api.twitter.com/2/problems/client-forbidden"}"#;
+        let err = XApiError::from_response(403, tier).with_request("POST", "/2/users/1/bookmarks");
+        assert_eq!(err.missing_scope(), None);
+    }
+
+    #[test]
+    fn unauthorized_suggests_reauth() {
+        let err = XApiError::from_response(401, "{}");
+        assert!(err.remediation().unwrap().contains(":auth"));
+    }
+
+    #[test]
+    fn client_forbidden_mentions_access_tier() {
+        let body = r#"{"title":"Client Forbidden","reason":"client-not-enrolled","type":"https://api.twitter.com/2/problems/client-forbidden","required_enrollment":"Appropriate Level of API Access"}"#;
+        let hint = XApiError::from_response(403, body).remediation().unwrap();
+        assert!(hint.contains("access tier (requires: Appropriate Level of API Access)"));
+    }
+
+    #[test]
+    fn non_json_body_is_kept_as_detail() {
+        let err = XApiError::from_response(502, "Bad Gateway");
+        assert!(matches!(err, XApiError::Other(_)));
+        assert_eq!(err.to_string(), "API error (status 502): Bad Gateway");
+    }
+}
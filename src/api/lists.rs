@@ -0,0 +1,114 @@
+use crate::api::request::RequestBuilder;
+use crate::api::types::{
+    List, ListDeleted, ListFields, ListMemberStatus, ListResponse, ListUpdated, SingleResponse,
+    User,
+};
+use crate::api::{ApiClientError, XApiClient};
+
+/// `list.fields` requested with every list.
+const LIST_FIELDS: &str = "description,private,member_count,follower_count,owner_id,created_at";
+
+impl XApiClient {
+    /// Lists the authenticated user owns.
+    pub async fn get_owned_lists(
+        &self,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<List>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        self.get_user_lists(&my_id, "owned_lists", max_results, pagination_token)
+            .await
+    }
+
+    /// Lists the authenticated user follows.
+    pub async fn get_followed_lists(
+        &self,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<List>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        self.get_user_lists(&my_id, "followed_lists", max_results, pagination_token)
+            .await
+    }
+
+    /// Create a List owned by the authenticated user. The response only
+    /// has the new List's id and name.
+    pub async fn create_list(
+        &self,
+        fields: &ListFields,
+    ) -> Result<SingleResponse<List>, ApiClientError> {
+        let request = RequestBuilder::new(&["lists"]);
+        self.oauth_post(&self.url(&request), fields).await
+    }
+
+    /// Change a List's name, description or visibility.
+    pub async fn update_list(
+        &self,
+        list_id: &str,
+        fields: &ListFields,
+    ) -> Result<SingleResponse<ListUpdated>, ApiClientError> {
+        let request = RequestBuilder::new(&["lists", list_id]);
+        self.oauth_put(&self.url(&request), fields).await
+    }
+
+    /// Delete a List the authenticated user owns.
+    pub async fn delete_list(
+        &self,
+        list_id: &str,
+    ) -> Result<SingleResponse<ListDeleted>, ApiClientError> {
+        let request = RequestBuilder::new(&["lists", list_id]);
+        self.oauth_delete(&self.url(&request)).await
+    }
+
+    /// Members of a List.
+    pub async fn get_list_members(
+        &self,
+        list_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<User>, ApiClientError> {
+        let request = RequestBuilder::new(&["lists", list_id, "members"])
+            .max_results(max_results, 1..=100)
+            .user_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.oauth_get(&self.url(&request)).await
+    }
+
+    /// Add a user to a List the authenticated user owns.
+    pub async fn add_list_member(
+        &self,
+        list_id: &str,
+        user_id: &str,
+    ) -> Result<SingleResponse<ListMemberStatus>, ApiClientError> {
+        let request = RequestBuilder::new(&["lists", list_id, "members"]);
+        self.oauth_post(
+            &self.url(&request),
+            &serde_json::json!({ "user_id": user_id }),
+        )
+        .await
+    }
+
+    /// Remove a user from a List the authenticated user owns.
+    pub async fn remove_list_member(
+        &self,
+        list_id: &str,
+        user_id: &str,
+    ) -> Result<SingleResponse<ListMemberStatus>, ApiClientError> {
+        let request = RequestBuilder::new(&["lists", list_id, "members", user_id]);
+        self.oauth_delete(&self.url(&request)).await
+    }
+
+    async fn get_user_lists(
+        &self,
+        user_id: &str,
+        relation: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<List>, ApiClientError> {
+        let request = RequestBuilder::new(&["users", user_id, relation])
+            .max_results(max_results, 1..=100)
+            .param("list.fields", LIST_FIELDS)
+            .pagination_token(pagination_token);
+        self.oauth_get(&self.url(&request)).await
+    }
+}
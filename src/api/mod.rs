@@ -1,15 +1,30 @@
+pub mod dms;
 pub mod engagement;
+pub mod error;
+pub mod lists;
+pub mod pagination;
+pub mod recording;
+pub mod request;
+pub mod stream;
 pub mod tweets;
 pub mod types;
 pub mod users;
 
+use std::path::Path;
+
 use chrono::{DateTime, Utc};
-use reqwest::Response;
+use reqwest::{Method, Response};
+use serde::Serialize;
 use serde::de::DeserializeOwned;
 use thiserror::Error;
+use tokio::sync::{Mutex, OnceCell};
 
 use crate::auth::oauth2_pkce;
 use crate::auth::{AuthError, AuthMethod, AuthProvider};
+use crate::config::{FieldOverrides, FieldsConfig};
+use error::XApiError;
+use recording::{RecordedResponse, Recording};
+use types::{SingleResponse, User};
 
 // ---------------------------------------------------------------------------
 // Error type
@@ -21,12 +36,46 @@ pub enum ApiClientError {
     Http(#[from] reqwest::Error),
     #[error("rate limited until {reset_at}")]
     RateLimited { reset_at: DateTime<Utc> },
-    #[error("API error (status {status}): {detail}")]
-    ApiError { status: u16, detail: String },
+    #[error(transparent)]
+    ApiError(#[from] XApiError),
     #[error("auth error: {0}")]
     Auth(#[from] AuthError),
     #[error("deserialization error: {0}")]
     Deserialize(String),
+    #[error("replay: {0}")]
+    Replay(String),
+    #[error("stream disconnected: {0}")]
+    StreamDisconnected(&'static str),
+}
+
+impl ApiClientError {
+    /// The error message followed by any remediation hint, for display in
+    /// the error popup.
+    pub fn with_remediation(&self) -> String {
+        match self {
+            Self::ApiError(e) => match e.remediation() {
+                Some(hint) => format!("{self}\n\nHint: {hint}"),
+                None => self.to_string(),
+            },
+            Self::Auth(AuthError::NoAuthMethod) => format!(
+                "{self}\n\nHint: no usable credentials or stored tokens. \
+                 Run :auth (or `xplorertui auth`) to sign in."
+            ),
+            Self::Auth(AuthError::UserContextRequired) => format!(
+                "{self}\n\nHint: run :auth (or `xplorertui auth`) to sign in with OAuth 2.0."
+            ),
+            _ => self.to_string(),
+        }
+    }
+
+    /// The OAuth 2.0 scope the request was refused for lacking, if that is
+    /// why it failed.
+    pub fn missing_scope(&self) -> Option<&'static str> {
+        match self {
+            Self::ApiError(e) => e.missing_scope(),
+            _ => None,
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -40,13 +89,32 @@ pub struct RateLimitInfo {
     pub limit: Option<u32>,
 }
 
+impl RateLimitInfo {
+    /// Read the `x-rate-limit-*` headers (best effort).
+    fn from_response(resp: &RecordedResponse) -> Self {
+        Self {
+            remaining: resp
+                .header("x-rate-limit-remaining")
+                .and_then(|v| v.parse().ok()),
+            reset_at: resp
+                .header("x-rate-limit-reset")
+                .and_then(|v| v.parse::<i64>().ok())
+                .and_then(|ts| DateTime::from_timestamp(ts, 0)),
+            limit: resp
+                .header("x-rate-limit-limit")
+                .and_then(|v| v.parse().ok()),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Query parameter helpers
 // ---------------------------------------------------------------------------
 
 pub(crate) fn tweet_fields() -> &'static str {
     "created_at,public_metrics,author_id,conversation_id,in_reply_to_user_id,\
-     referenced_tweets,attachments,entities,lang,note_tweet"
+     referenced_tweets,attachments,entities,lang,note_tweet,source,reply_settings,\
+     withheld,context_annotations"
 }
 
 pub(crate) fn tweet_expansions() -> &'static str {
@@ -54,27 +122,128 @@ pub(crate) fn tweet_expansions() -> &'static str {
 }
 
 pub(crate) fn user_fields() -> &'static str {
-    "name,username,verified,profile_image_url,public_metrics,created_at,\
-     description,url,location,pinned_tweet_id"
+    "name,username,verified,verified_type,protected,profile_image_url,profile_banner_url,\
+     public_metrics,created_at,description,url,location,pinned_tweet_id,withheld"
 }
 
 pub(crate) fn media_fields() -> &'static str {
     "url,preview_image_url,type,width,height,alt_text"
 }
 
+/// The `tweet.fields`, `expansions`, `user.fields` and `media.fields` lists
+/// sent with every request, after applying the `[fields]` config overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSets {
+    pub tweet: String,
+    pub expansions: String,
+    pub user: String,
+    pub media: String,
+}
+
+impl Default for FieldSets {
+    fn default() -> Self {
+        Self {
+            tweet: tweet_fields().to_string(),
+            expansions: tweet_expansions().to_string(),
+            user: user_fields().to_string(),
+            media: media_fields().to_string(),
+        }
+    }
+}
+
+impl FieldSets {
+    /// The default lists with `config`'s removals and additions applied.
+    pub fn from_config(config: &FieldsConfig) -> Self {
+        Self {
+            tweet: config.tweet.apply(tweet_fields()),
+            expansions: config.expansions.apply(tweet_expansions()),
+            user: config.user.apply(user_fields()),
+            media: config.media.apply(media_fields()),
+        }
+    }
+}
+
+impl FieldOverrides {
+    /// `defaults` (a comma-separated list) minus `remove`, plus `add`,
+    /// keeping order and dropping duplicates.
+    fn apply(&self, defaults: &str) -> String {
+        let mut fields: Vec<&str> = Vec::new();
+        let kept = defaults
+            .split(',')
+            .filter(|f| !self.remove.iter().any(|r| r.trim() == *f));
+        for field in kept.chain(self.add.iter().map(|f| f.trim())) {
+            if !field.is_empty() && !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        fields.join(",")
+    }
+}
+
+/// Tweet kinds the timeline endpoints can leave out server-side, so they
+/// don't use up page space (`exclude=replies,retweets`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimelineExclude {
+    pub replies: bool,
+    pub retweets: bool,
+}
+
+impl TimelineExclude {
+    /// The `exclude` parameter value, or `None` to exclude nothing.
+    pub(crate) fn param(self) -> Option<&'static str> {
+        match (self.replies, self.retweets) {
+            (true, true) => Some("replies,retweets"),
+            (true, false) => Some("replies"),
+            (false, true) => Some("retweets"),
+            (false, false) => None,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // API client
 // ---------------------------------------------------------------------------
 
 const BASE_URL: &str = "https://api.x.com/2";
 
+/// Environment variable that overrides [`BASE_URL`] in debug builds, e.g. to
+/// point the client at a local mock server.
+pub const API_BASE_ENV: &str = "XPLORERTUI_API_BASE";
+
+/// The API base URL: `XPLORERTUI_API_BASE` if set in a debug build, otherwise
+/// the real API. Release builds ignore it, so a stray variable can't send
+/// credentials to another host.
+fn api_base() -> String {
+    #[cfg(debug_assertions)]
+    if let Some(base) = std::env::var(API_BASE_ENV)
+        .ok()
+        .map(|base| base.trim_end_matches('/').to_string())
+        .filter(|base| !base.is_empty())
+    {
+        return base;
+    }
+    BASE_URL.to_string()
+}
+
 pub struct XApiClient {
     http_client: reqwest::Client,
     auth: AuthProvider,
-    user_id: Option<String>,
+    /// API root every request path is appended to (see [`api_base`]).
+    base_url: String,
+    /// Authenticated user's ID, resolved lazily on first use. A `OnceCell`
+    /// keeps every request method `&self` so calls can run concurrently.
+    user_id: OnceCell<String>,
+    /// Serializes OAuth 2.0 token refreshes. X refresh tokens are single-use,
+    /// so concurrent requests must not each try to refresh the same token.
+    refresh_lock: Mutex<()>,
     callback_port: u16,
-    #[allow(dead_code)]
-    rate_limit: RateLimitInfo,
+    /// Record or replay responses (`--record` / `--replay`).
+    recording: Option<Recording>,
+    /// Field and expansion lists requested for tweets and users.
+    fields: FieldSets,
+    /// Rate-limit headers of the latest response that had them. Limits are
+    /// per endpoint, so this describes whichever endpoint was hit last.
+    rate_limit: std::sync::Mutex<RateLimitInfo>,
 }
 
 impl XApiClient {
@@ -82,28 +251,52 @@ impl XApiClient {
         Self {
             http_client: reqwest::Client::new(),
             auth,
-            user_id: None,
+            base_url: api_base(),
+            user_id: OnceCell::new(),
+            refresh_lock: Mutex::new(()),
             callback_port,
-            rate_limit: RateLimitInfo::default(),
+            recording: None,
+            fields: FieldSets::default(),
+            rate_limit: std::sync::Mutex::default(),
         }
     }
 
+    /// Record responses to disk, or serve them from a previous recording.
+    pub fn with_recording(mut self, recording: Option<Recording>) -> Self {
+        self.recording = recording;
+        self
+    }
+
+    /// Request these field and expansion lists instead of the defaults.
+    pub fn with_fields(mut self, fields: FieldSets) -> Self {
+        self.fields = fields;
+        self
+    }
+
     /// Return the auth method in use (for diagnostics).
     pub fn auth_method(&self) -> AuthMethod {
         self.auth.method
     }
 
     /// Return the authenticated user's ID, caching after first call.
-    pub async fn get_my_user_id(&mut self) -> Result<String, ApiClientError> {
-        if let Some(ref id) = self.user_id {
-            return Ok(id.clone());
-        }
-        let id = self
-            .auth
-            .get_authenticated_user_id(&self.http_client)
-            .await?;
-        self.user_id = Some(id.clone());
-        Ok(id)
+    pub async fn get_my_user_id(&self) -> Result<String, ApiClientError> {
+        self.user_id
+            .get_or_try_init(|| async {
+                // Go through the regular request path so the lookup is
+                // recorded and replayed like any other response.
+                if self.recording.is_some() {
+                    let me: SingleResponse<User> = self.oauth_get(&self.url("/users/me")).await?;
+                    return me.data.map(|user| user.id).ok_or_else(|| {
+                        ApiClientError::Deserialize("/users/me had no data".into())
+                    });
+                }
+                self.auth
+                    .get_authenticated_user_id(&self.http_client, &self.base_url)
+                    .await
+                    .map_err(ApiClientError::from)
+            })
+            .await
+            .cloned()
     }
 
     /// Load an OAuth 2.0 bearer token, auto-refreshing if expired.
@@ -118,10 +311,16 @@ impl XApiClient {
         let Some(tokens) = oauth2_pkce::load_tokens().map_err(AuthError::OAuth2)? else {
             return Err(ApiClientError::Auth(AuthError::NoAuthMethod));
         };
+        if !needs_refresh(&tokens) {
+            return Ok(format!("Bearer {}", tokens.access_token));
+        }
 
-        // Refresh if within 60 seconds of expiry.
-        if let Some(expires_at) = tokens.expires_at
-            && chrono::Utc::now() + chrono::Duration::seconds(60) >= expires_at
+        // Re-read under the lock: another request may have refreshed already.
+        let _guard = self.refresh_lock.lock().await;
+        let Some(tokens) = oauth2_pkce::load_tokens().map_err(AuthError::OAuth2)? else {
+            return Err(ApiClientError::Auth(AuthError::NoAuthMethod));
+        };
+        if needs_refresh(&tokens)
             && let Some(ref refresh) = tokens.refresh_token
         {
             let refreshed = oauth2_pkce::refresh_token(oauth2_creds, refresh, self.callback_port)
@@ -138,6 +337,10 @@ impl XApiClient {
         &self,
         url: &str,
     ) -> Result<T, ApiClientError> {
+        if let Some(Recording::Replay(ref dir)) = self.recording {
+            return self.replay("GET", url, dir);
+        }
+
         let auth_header = match self.auth.method {
             AuthMethod::OAuth2Pkce => self.get_oauth2_bearer().await?,
             _ => self.auth.get_bearer_header()?,
@@ -150,7 +353,50 @@ impl XApiClient {
             .send()
             .await?;
 
-        self.handle_response(resp).await
+        self.handle_response("GET", url, resp).await
+    }
+
+    /// Issue a GET request with the app-only bearer token, for endpoints
+    /// that reject user-context tokens.
+    pub(crate) async fn app_get<T: DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<T, ApiClientError> {
+        if let Some(Recording::Replay(ref dir)) = self.recording {
+            return self.replay("GET", url, dir);
+        }
+
+        let auth_header = self.auth.get_bearer_header()?;
+        let resp = self
+            .http_client
+            .get(url)
+            .header("Authorization", &auth_header)
+            .send()
+            .await?;
+
+        self.handle_response("GET", url, resp).await
+    }
+
+    /// Issue a POST request with a JSON body and the app-only bearer token.
+    pub(crate) async fn app_post<T: DeserializeOwned, B: Serialize>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> Result<T, ApiClientError> {
+        if let Some(Recording::Replay(ref dir)) = self.recording {
+            return self.replay("POST", url, dir);
+        }
+
+        let auth_header = self.auth.get_bearer_header()?;
+        let resp = self
+            .http_client
+            .post(url)
+            .header("Authorization", &auth_header)
+            .json(body)
+            .send()
+            .await?;
+
+        self.handle_response("POST", url, resp).await
     }
 
     /// Issue a GET request with user-context authorization.
@@ -162,6 +408,10 @@ impl XApiClient {
         &self,
         url: &str,
     ) -> Result<T, ApiClientError> {
+        if let Some(Recording::Replay(ref dir)) = self.recording {
+            return self.replay("GET", url, dir);
+        }
+
         let auth_header = match self.auth.method {
             AuthMethod::OAuth2Pkce => self.get_oauth2_bearer().await?,
             AuthMethod::OAuth1 => self.auth.get_oauth_header("GET", url, None)?,
@@ -175,61 +425,162 @@ impl XApiClient {
             .send()
             .await?;
 
-        self.handle_response(resp).await
+        self.handle_response("GET", url, resp).await
     }
 
-    /// Parse rate-limit headers, check status, and deserialize the body.
-    async fn handle_response<T: DeserializeOwned>(
+    /// Issue a POST request with a JSON body and user-context authorization.
+    pub(crate) async fn oauth_post<T: DeserializeOwned, B: Serialize>(
         &self,
-        resp: Response,
+        url: &str,
+        body: &B,
     ) -> Result<T, ApiClientError> {
-        // Parse rate-limit headers (best effort).
-        let remaining = resp
-            .headers()
-            .get("x-rate-limit-remaining")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse::<u32>().ok());
+        self.user_write(Method::POST, url, Some(body)).await
+    }
 
-        let reset_at = resp
-            .headers()
-            .get("x-rate-limit-reset")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse::<i64>().ok())
-            .and_then(|ts| DateTime::from_timestamp(ts, 0));
+    /// Issue a PUT request with a JSON body and user-context authorization.
+    pub(crate) async fn oauth_put<T: DeserializeOwned, B: Serialize>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> Result<T, ApiClientError> {
+        self.user_write(Method::PUT, url, Some(body)).await
+    }
 
-        let _limit = resp
-            .headers()
-            .get("x-rate-limit-limit")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse::<u32>().ok());
+    /// Issue a DELETE request with user-context authorization.
+    pub(crate) async fn oauth_delete<T: DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<T, ApiClientError> {
+        self.user_write(Method::DELETE, url, None::<&()>).await
+    }
 
-        let status = resp.status();
+    /// Send a write request. Write endpoints need a user token, so unlike
+    /// `oauth_get` there is no bearer-only fallback.
+    async fn user_write<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T, ApiClientError> {
+        if let Some(Recording::Replay(ref dir)) = self.recording {
+            return self.replay(method.as_str(), url, dir);
+        }
 
-        if status.as_u16() == 429 {
-            let reset = reset_at.unwrap_or_else(Utc::now);
-            return Err(ApiClientError::RateLimited { reset_at: reset });
+        let auth_header = match self.auth.method {
+            AuthMethod::OAuth2Pkce => self.get_oauth2_bearer().await?,
+            // JSON bodies are not part of the OAuth 1.0a signature base.
+            AuthMethod::OAuth1 => self.auth.get_oauth_header(method.as_str(), url, None)?,
+            AuthMethod::BearerOnly => return Err(AuthError::UserContextRequired.into()),
+        };
+
+        let mut request = self
+            .http_client
+            .request(method.clone(), url)
+            .header("Authorization", &auth_header);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let resp = request.send().await?;
+
+        self.handle_response(method.as_str(), url, resp).await
+    }
+
+    /// Read the response (saving it when recording) and parse it.
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        url: &str,
+        resp: Response,
+    ) -> Result<T, ApiClientError> {
+        let recorded = RecordedResponse::from_response(method, self.relative(url), resp).await?;
+        if let Some(Recording::Record(ref dir)) = self.recording
+            && let Err(e) = recording::save(dir, &recorded)
+        {
+            tracing::warn!("failed to record {method} {}: {e}", recorded.request);
         }
+        self.parse_response(recorded)
+    }
+
+    /// Answer a request from a recording instead of the network.
+    // Returns the same error type as the async request methods it stands in for.
+    #[allow(clippy::result_large_err)]
+    fn replay<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        url: &str,
+        dir: &Path,
+    ) -> Result<T, ApiClientError> {
+        let recorded =
+            recording::load(dir, method, self.relative(url)).map_err(ApiClientError::Replay)?;
+        self.parse_response(recorded)
+    }
+
+    /// Rate-limit state from the latest response that reported one.
+    pub fn rate_limit(&self) -> RateLimitInfo {
+        self.rate_limit
+            .lock()
+            .map(|info| info.clone())
+            .unwrap_or_default()
+    }
 
-        if !status.is_success() {
-            let body = resp.text().await.unwrap_or_default();
-            return Err(ApiClientError::ApiError {
-                status: status.as_u16(),
-                detail: body,
-            });
+    /// Check status and rate-limit headers, then deserialize the body.
+    #[allow(clippy::result_large_err)]
+    fn parse_response<T: DeserializeOwned>(
+        &self,
+        resp: RecordedResponse,
+    ) -> Result<T, ApiClientError> {
+        let info = RateLimitInfo::from_response(&resp);
+        let reset_at = info.reset_at;
+        if info.remaining.is_some()
+            && let Ok(mut rate_limit) = self.rate_limit.lock()
+        {
+            *rate_limit = info;
         }
+        parse_response(resp, reset_at)
+    }
 
-        // Store rate-limit info (interior mutability is not required since
-        // the fields are purely informational; we skip the update here and
-        // keep the struct simple).
-        let _ = remaining;
+    /// `url` without the API root, as used to key recordings.
+    fn relative<'a>(&self, url: &'a str) -> &'a str {
+        url.strip_prefix(self.base_url.as_str()).unwrap_or(url)
+    }
+
+    /// Build a full API URL from a path (e.g. "/tweets/123") or a
+    /// [`RequestBuilder`](request::RequestBuilder).
+    pub(crate) fn url(&self, path: impl std::fmt::Display) -> String {
+        format!("{}{path}", self.base_url)
+    }
+}
 
-        let body = resp.text().await?;
-        serde_json::from_str::<T>(&body)
-            .map_err(|e| ApiClientError::Deserialize(format!("{e}: {body}")))
+/// Check the status, then deserialize the body. `reset_at` is when a 429
+/// clears, from the rate-limit headers.
+#[allow(clippy::result_large_err)]
+fn parse_response<T: DeserializeOwned>(
+    resp: RecordedResponse,
+    reset_at: Option<DateTime<Utc>>,
+) -> Result<T, ApiClientError> {
+    if resp.status == 429 {
+        let reset = reset_at.unwrap_or_else(Utc::now);
+        return Err(ApiClientError::RateLimited { reset_at: reset });
     }
 
-    /// Build a full API URL from a path (e.g. "/tweets/123").
-    pub(crate) fn url(path: &str) -> String {
-        format!("{BASE_URL}{path}")
+    if !(200..300).contains(&resp.status) {
+        return Err(XApiError::from_response(resp.status, &resp.body)
+            .with_request(&resp.method, &resp.endpoint)
+            .into());
     }
+
+    let body = resp.body;
+    let _span = tracing::debug_span!("deserialize", bytes = body.len()).entered();
+    serde_json::from_str::<T>(&body)
+        .map_err(|e| ApiClientError::Deserialize(format!("{e}: {body}")))
+}
+
+/// Whether stored tokens are within 60 seconds of expiry.
+fn needs_refresh(tokens: &oauth2_pkce::TokenData) -> bool {
+    tokens
+        .expires_at
+        .is_some_and(|expires_at| Utc::now() + chrono::Duration::seconds(60) >= expires_at)
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,256 @@
+//! Cursor handling for list endpoints.
+//!
+//! X API list responses carry a `meta.next_token`; passing it back as
+//! `pagination_token` returns the following page. [`Paginator`] owns that
+//! loop so callers (the TUI's load-next-page, the CLI's `--all`, exports)
+//! don't each track tokens by hand.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use futures::future::BoxFuture;
+
+use super::types::{Includes, ListResponse, Meta};
+use super::{ApiClientError, TimelineExclude, XApiClient};
+use crate::api::types::{Tweet, User};
+
+/// Longest rate-limit reset worth waiting for. X windows are 15 minutes.
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(15 * 60);
+
+/// Times a page is retried after waiting out a rate limit.
+const RATE_LIMIT_RETRIES: usize = 3;
+
+type FetchPage<'a, T> = Box<
+    dyn FnMut(Option<String>) -> BoxFuture<'a, Result<ListResponse<T>, ApiClientError>> + Send + 'a,
+>;
+
+/// Walks a list endpoint page by page, following `next_token`.
+pub struct Paginator<'a, T> {
+    fetch: FetchPage<'a, T>,
+    next_token: Option<String>,
+    /// Set once a page came back without a `next_token`.
+    exhausted: bool,
+    wait_on_rate_limit: bool,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    /// Paginate with `fetch`, which requests the page for a token (`None`
+    /// for the first page).
+    pub fn new<F>(mut fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> BoxFuture<'a, Result<ListResponse<T>, ApiClientError>>
+            + Send
+            + 'a,
+    {
+        Self {
+            fetch: Box::new(move |token| fetch(token)),
+            next_token: None,
+            exhausted: false,
+            wait_on_rate_limit: false,
+        }
+    }
+
+    /// Fetch just the page at `token` (the first page for `None`), e.g. to
+    /// load more of a timeline that is already on screen.
+    pub async fn page_at(
+        mut self,
+        token: Option<String>,
+    ) -> Result<ListResponse<T>, ApiClientError> {
+        self.next_token = token;
+        self.exhausted = false;
+        Ok(self.next_page().await?.unwrap_or_default())
+    }
+
+    /// When rate-limited, sleep until the limit resets (if that is within
+    /// 15 minutes) and retry instead of failing. Off by default: the TUI
+    /// shouldn't stall, but a long CLI pull would rather wait.
+    pub fn wait_on_rate_limit(mut self, wait: bool) -> Self {
+        self.wait_on_rate_limit = wait;
+        self
+    }
+
+    /// Token for the page the next call to [`next_page`](Self::next_page)
+    /// fetches.
+    pub fn next_token(&self) -> Option<&str> {
+        self.next_token.as_deref()
+    }
+
+    /// Whether every page has been fetched.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Fetch the next page, or `None` once every page has been fetched.
+    /// On error the position is unchanged, so the same page can be retried.
+    pub async fn next_page(&mut self) -> Result<Option<ListResponse<T>>, ApiClientError> {
+        if self.exhausted {
+            return Ok(None);
+        }
+        let mut retries = 0;
+        let page = loop {
+            match (self.fetch)(self.next_token.clone()).await {
+                Err(ApiClientError::RateLimited { reset_at })
+                    if self.wait_on_rate_limit && retries < RATE_LIMIT_RETRIES =>
+                {
+                    let wait = (reset_at - Utc::now()).to_std().unwrap_or_default();
+                    if wait > MAX_RATE_LIMIT_WAIT {
+                        return Err(ApiClientError::RateLimited { reset_at });
+                    }
+                    tracing::info!(?wait, "rate limited while paginating; waiting");
+                    tokio::time::sleep(wait + Duration::from_secs(1)).await;
+                    retries += 1;
+                }
+                result => break result?,
+            }
+        };
+        self.next_token = page.meta.as_ref().and_then(|m| m.next_token.clone());
+        self.exhausted = self.next_token.is_none();
+        Ok(Some(page))
+    }
+
+    /// Fetch pages until every page is in or at least `limit` items are,
+    /// merged into one response truncated to `limit`. The returned
+    /// `meta.next_token` is where a later call would continue.
+    pub async fn all_pages(&mut self, limit: usize) -> Result<ListResponse<T>, ApiClientError> {
+        let mut all = ListResponse {
+            data: Some(Vec::new()),
+            ..ListResponse::default()
+        };
+        while all.data.as_ref().map_or(0, Vec::len) < limit {
+            let Some(page) = self.next_page().await? else {
+                break;
+            };
+            append_page(&mut all, page);
+        }
+        if let Some(data) = all.data.as_mut() {
+            data.truncate(limit);
+        }
+        let meta = all.meta.get_or_insert_with(Meta::default);
+        meta.next_token = self.next_token.clone();
+        meta.result_count = all.data.as_ref().map(|d| d.len() as u32);
+        Ok(all)
+    }
+}
+
+/// Add `page` to `all`: items, includes (without duplicates) and errors.
+fn append_page<T>(all: &mut ListResponse<T>, page: ListResponse<T>) {
+    all.data
+        .get_or_insert_with(Vec::new)
+        .extend(page.data.unwrap_or_default());
+    if let Some(includes) = page.includes {
+        let merged = all.includes.get_or_insert_with(Includes::default);
+        merge_unique(&mut merged.users, includes.users, |u| u.id.clone());
+        merge_unique(&mut merged.tweets, includes.tweets, |t| t.id.clone());
+        merge_unique(&mut merged.media, includes.media, |m| m.media_key.clone());
+    }
+    if let Some(errors) = page.errors {
+        all.errors.get_or_insert_with(Vec::new).extend(errors);
+    }
+    if all.meta.is_none() {
+        all.meta = page.meta;
+    }
+}
+
+fn merge_unique<I>(into: &mut Option<Vec<I>>, from: Option<Vec<I>>, key: impl Fn(&I) -> String) {
+    let Some(from) = from else {
+        return;
+    };
+    let into = into.get_or_insert_with(Vec::new);
+    for item in from {
+        let k = key(&item);
+        if !into.iter().any(|existing| key(existing) == k) {
+            into.push(item);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Paginated endpoints
+// ---------------------------------------------------------------------------
+
+impl XApiClient {
+    pub fn home_timeline_pages(
+        &self,
+        max_results: u32,
+        exclude: TimelineExclude,
+    ) -> Paginator<'_, Tweet> {
+        Paginator::new(move |token| {
+            Box::pin(async move {
+                self.get_home_timeline(max_results, exclude, token.as_deref())
+                    .await
+            })
+        })
+    }
+
+    pub fn mentions_pages(&self, max_results: u32) -> Paginator<'_, Tweet> {
+        Paginator::new(move |token| {
+            Box::pin(async move { self.get_mentions(max_results, token.as_deref()).await })
+        })
+    }
+
+    pub fn bookmarks_pages(&self, max_results: u32) -> Paginator<'_, Tweet> {
+        Paginator::new(move |token| {
+            Box::pin(async move { self.get_bookmarks(max_results, token.as_deref()).await })
+        })
+    }
+
+    pub fn search_pages(&self, query: &str, max_results: u32) -> Paginator<'_, Tweet> {
+        let query = query.to_string();
+        Paginator::new(move |token| {
+            let query = query.clone();
+            Box::pin(async move {
+                self.search_tweets(&query, max_results, token.as_deref())
+                    .await
+            })
+        })
+    }
+
+    pub fn timeline_pages(
+        &self,
+        user_id: &str,
+        max_results: u32,
+        exclude: TimelineExclude,
+    ) -> Paginator<'_, Tweet> {
+        let user_id = user_id.to_string();
+        Paginator::new(move |token| {
+            let user_id = user_id.clone();
+            Box::pin(async move {
+                self.get_timeline(&user_id, max_results, exclude, token.as_deref())
+                    .await
+            })
+        })
+    }
+
+    pub fn thread_pages(&self, conversation_id: &str, max_results: u32) -> Paginator<'_, Tweet> {
+        let conversation_id = conversation_id.to_string();
+        Paginator::new(move |token| {
+            let conversation_id = conversation_id.clone();
+            Box::pin(async move {
+                self.get_conversation_thread(&conversation_id, max_results, token.as_deref())
+                    .await
+            })
+        })
+    }
+
+    pub fn followers_pages(&self, user_id: &str, max_results: u32) -> Paginator<'_, User> {
+        let user_id = user_id.to_string();
+        Paginator::new(move |token| {
+            let user_id = user_id.clone();
+            Box::pin(async move {
+                self.get_followers(&user_id, max_results, token.as_deref())
+                    .await
+            })
+        })
+    }
+
+    pub fn following_pages(&self, user_id: &str, max_results: u32) -> Paginator<'_, User> {
+        let user_id = user_id.to_string();
+        Paginator::new(move |token| {
+            let user_id = user_id.clone();
+            Box::pin(async move {
+                self.get_following(&user_id, max_results, token.as_deref())
+                    .await
+            })
+        })
+    }
+}
@@ -0,0 +1,120 @@
+//! Record-and-replay of raw API responses.
+//!
+//! With `--record DIR` every response the client receives is written to
+//! `DIR` as one JSON file per request; `--replay DIR` serves those files back
+//! instead of touching the network. Requests are keyed by method and the URL
+//! relative to the API root, so a recording replays against any base URL and
+//! without credentials.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Response headers worth keeping; everything else is dropped.
+const KEPT_HEADERS: &[&str] = &[
+    "x-rate-limit-limit",
+    "x-rate-limit-remaining",
+    "x-rate-limit-reset",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recording {
+    /// Save every response under this directory.
+    Record(PathBuf),
+    /// Answer requests from responses saved under this directory.
+    Replay(PathBuf),
+}
+
+/// A response as received from the API, before any parsing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub method: String,
+    /// Path and query relative to the API root, e.g. `/tweets/123?...`.
+    pub request: String,
+    pub status: u16,
+    /// Full request path as the server saw it (e.g. `/2/tweets/123`).
+    pub endpoint: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl RecordedResponse {
+    /// Read a live response, keeping its status, rate-limit headers and body.
+    pub async fn from_response(
+        method: &str,
+        request: &str,
+        resp: reqwest::Response,
+    ) -> Result<Self, reqwest::Error> {
+        let headers = KEPT_HEADERS
+            .iter()
+            .filter_map(|&name| {
+                let value = resp.headers().get(name)?.to_str().ok()?;
+                Some((name.to_string(), value.to_string()))
+            })
+            .collect();
+        Ok(Self {
+            method: method.to_string(),
+            request: request.to_string(),
+            status: resp.status().as_u16(),
+            endpoint: resp.url().path().to_string(),
+            headers,
+            body: resp.text().await?,
+        })
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+/// File a request's response is stored in: a readable prefix from the path
+/// plus a hash of the full request, e.g. `GET_users_42_bookmarks-1a2b3c4d5e6f7a8b.json`.
+pub fn file_name(method: &str, request: &str) -> String {
+    let path = request.split('?').next().unwrap_or_default();
+    let mut prefix: String = format!("{method}{path}")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    prefix.truncate(60);
+    let digest = Sha256::digest(format!("{method} {request}").as_bytes());
+    format!("{prefix}-{}.json", &hex::encode(digest)[..16])
+}
+
+/// Write `response` into `dir`, creating it if needed.
+pub fn save(dir: &Path, response: &RecordedResponse) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let json = serde_json::to_string_pretty(response)?;
+    fs::write(
+        dir.join(file_name(&response.method, &response.request)),
+        json,
+    )
+}
+
+/// The response recorded for `method request` in `dir`.
+pub fn load(dir: &Path, method: &str, request: &str) -> Result<RecordedResponse, String> {
+    let path = dir.join(file_name(method, request));
+    let json = fs::read_to_string(&path)
+        .map_err(|_| format!("no recorded response for {method} {request}"))?;
+    serde_json::from_str(&json).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_names_are_readable_and_distinct() {
+        let a = file_name("GET", "/users/42/bookmarks?max_results=20");
+        let b = file_name(
+            "GET",
+            "/users/42/bookmarks?max_results=20&pagination_token=x",
+        );
+        assert!(a.starts_with("GET_users_42_bookmarks-"));
+        assert!(a.ends_with(".json"));
+        assert_ne!(a, b);
+    }
+}
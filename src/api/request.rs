@@ -0,0 +1,119 @@
+//! Typed construction of API request paths and query strings.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+
+use crate::api::{FieldSets, TimelineExclude};
+
+/// Path segments: everything except unreserved characters is encoded.
+const SEGMENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Query values: as for segments, but commas stay readable in field lists.
+const QUERY_ENCODE_SET: &AsciiSet = &SEGMENT_ENCODE_SET.remove(b',');
+
+/// A request path plus query parameters, relative to the API root. Every
+/// segment and value is percent-encoded when formatted, so IDs, search
+/// queries and pagination tokens are passed as-is.
+#[derive(Debug, Clone)]
+pub(crate) struct RequestBuilder {
+    segments: Vec<String>,
+    query: Vec<(&'static str, String)>,
+}
+
+impl RequestBuilder {
+    /// A request for the path made of `segments`, e.g.
+    /// `["users", id, "bookmarks"]` for `/users/{id}/bookmarks`.
+    pub fn new(segments: &[&str]) -> Self {
+        Self {
+            segments: segments.iter().map(|s| s.to_string()).collect(),
+            query: Vec::new(),
+        }
+    }
+
+    pub fn param(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.query.push((key, value.into()));
+        self
+    }
+
+    /// `max_results`, clamped to what the endpoint accepts.
+    pub fn max_results(self, max_results: u32, allowed: RangeInclusive<u32>) -> Self {
+        let max_results = max_results.clamp(*allowed.start(), *allowed.end());
+        self.param("max_results", max_results.to_string())
+    }
+
+    pub fn pagination_token(self, token: Option<&str>) -> Self {
+        match token {
+            Some(token) => self.param("pagination_token", token),
+            None => self,
+        }
+    }
+
+    pub fn exclude(self, exclude: TimelineExclude) -> Self {
+        match exclude.param() {
+            Some(value) => self.param("exclude", value),
+            None => self,
+        }
+    }
+
+    /// Tweet fields plus the expansions (author, referenced tweets, media)
+    /// needed to render a tweet card.
+    pub fn tweet_fields(self, fields: &FieldSets) -> Self {
+        self.param("tweet.fields", &fields.tweet)
+            .param("expansions", &fields.expansions)
+            .user_fields(fields)
+            .param("media.fields", &fields.media)
+    }
+
+    pub fn user_fields(self, fields: &FieldSets) -> Self {
+        self.param("user.fields", &fields.user)
+    }
+}
+
+impl fmt::Display for RequestBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            write!(f, "/{}", utf8_percent_encode(segment, SEGMENT_ENCODE_SET))?;
+        }
+        for (i, (key, value)) in self.query.iter().enumerate() {
+            let sep = if i == 0 { '?' } else { '&' };
+            write!(
+                f,
+                "{sep}{key}={}",
+                utf8_percent_encode(value, QUERY_ENCODE_SET)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_segments_and_values() {
+        let request = RequestBuilder::new(&["tweets", "search", "recent"])
+            .param("query", "rust & \"async\" #tokio")
+            .max_results(5, 10..=100)
+            .pagination_token(Some("b26v89c19zqg8o3f+/x=="));
+        assert_eq!(
+            request.to_string(),
+            "/tweets/search/recent?query=rust%20%26%20%22async%22%20%23tokio\
+             &max_results=10&pagination_token=b26v89c19zqg8o3f%2B%2Fx%3D%3D"
+        );
+
+        let request = RequestBuilder::new(&["users", "by", "username", "a/b?c"])
+            .user_fields(&FieldSets::default());
+        assert!(
+            request
+                .to_string()
+                .starts_with("/users/by/username/a%2Fb%3Fc?user.fields=name,username,")
+        );
+    }
+}
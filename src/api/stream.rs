@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use reqwest::Response;
+use serde::de::IgnoredAny;
+
+use crate::api::recording::{RecordedResponse, Recording};
+use crate::api::request::RequestBuilder;
+use crate::api::types::{ListResponse, NewStreamRule, StreamMessage, StreamRule};
+use crate::api::{ApiClientError, XApiClient};
+
+/// Path of the filtered stream's rules, relative to the API root.
+const RULES: [&str; 4] = ["tweets", "search", "stream", "rules"];
+
+/// How long the stream may go without sending anything before it counts
+/// as stalled. X sends a keep-alive every 20 seconds.
+pub const STALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+impl XApiClient {
+    /// The filtered stream's rules. Like every stream endpoint, this needs
+    /// an app-only bearer token.
+    pub async fn get_stream_rules(&self) -> Result<ListResponse<StreamRule>, ApiClientError> {
+        let request = RequestBuilder::new(&RULES);
+        self.app_get(&self.url(&request)).await
+    }
+
+    /// Add rules to the filtered stream. Rules X refuses (invalid or
+    /// duplicates) are reported in `errors` while the others are created.
+    pub async fn add_stream_rules(
+        &self,
+        rules: &[NewStreamRule],
+    ) -> Result<ListResponse<StreamRule>, ApiClientError> {
+        let request = RequestBuilder::new(&RULES);
+        self.app_post(&self.url(&request), &serde_json::json!({ "add": rules }))
+            .await
+    }
+
+    /// Delete the rules with these IDs.
+    pub async fn delete_stream_rules(
+        &self,
+        ids: &[String],
+    ) -> Result<ListResponse<StreamRule>, ApiClientError> {
+        let request = RequestBuilder::new(&RULES);
+        self.app_post(
+            &self.url(&request),
+            &serde_json::json!({ "delete": { "ids": ids } }),
+        )
+        .await
+    }
+
+    /// Connect to the filtered stream. Tweets matching the rules arrive
+    /// through [`TweetStream::next_message`] until the connection drops.
+    pub async fn connect_stream(&self) -> Result<TweetStream, ApiClientError> {
+        self.connect(&["tweets", "search", "stream"], "filtered stream")
+            .await
+    }
+
+    /// Connect to the sampled stream, a random 1% of all public tweets as
+    /// they are posted.
+    pub async fn connect_sample_stream(&self) -> Result<TweetStream, ApiClientError> {
+        self.connect(&["tweets", "sample", "stream"], "sampled stream")
+            .await
+    }
+
+    async fn connect(&self, path: &[&str], name: &str) -> Result<TweetStream, ApiClientError> {
+        let request = RequestBuilder::new(path).tweet_fields(&self.fields);
+        let url = self.url(&request);
+        if let Some(Recording::Replay(_)) = self.recording {
+            return Err(ApiClientError::Replay(format!(
+                "the {name} can't be replayed"
+            )));
+        }
+
+        let auth_header = self.auth.get_bearer_header()?;
+        let resp = self
+            .http_client
+            .get(&url)
+            .header("Authorization", &auth_header)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let recorded =
+                RecordedResponse::from_response("GET", self.relative(&url), resp).await?;
+            return match self.parse_response::<IgnoredAny>(recorded) {
+                Err(e) => Err(e),
+                Ok(_) => unreachable!("responses other than 2xx are errors"),
+            };
+        }
+        Ok(TweetStream {
+            resp,
+            buf: Vec::new(),
+        })
+    }
+}
+
+/// An open connection to a stream: one JSON message per line,
+/// with blank lines as keep-alives.
+pub struct TweetStream {
+    resp: Response,
+    buf: Vec<u8>,
+}
+
+impl TweetStream {
+    /// The next message, skipping keep-alives. Fails once X closes the
+    /// connection or it stalls for [`STALL_TIMEOUT`].
+    pub async fn next_message(&mut self) -> Result<StreamMessage, ApiClientError> {
+        loop {
+            if let Some(end) = self.buf.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = self.buf.drain(..=end).collect();
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+                return serde_json::from_slice(&line).map_err(|e| {
+                    ApiClientError::Deserialize(format!("{e}: {}", String::from_utf8_lossy(&line)))
+                });
+            }
+            match tokio::time::timeout(STALL_TIMEOUT, self.resp.chunk()).await {
+                Ok(Ok(Some(chunk))) => self.buf.extend_from_slice(&chunk),
+                Ok(Ok(None)) => return Err(ApiClientError::StreamDisconnected("closed by X")),
+                Ok(Err(e)) => return Err(e.into()),
+                Err(_) => return Err(ApiClientError::StreamDisconnected("stalled")),
+            }
+        }
+    }
+}
+
+/// Reconnect delays from X's streaming guidelines: back off linearly
+/// from 250ms up to 16s after network errors, exponentially from 5s up to
+/// 320s after HTTP errors, and exponentially from a minute when rate
+/// limited.
+#[derive(Debug, Default)]
+pub struct Backoff {
+    network: u32,
+    http: u32,
+    rate_limited: u32,
+}
+
+impl Backoff {
+    /// How long to wait before reconnecting after `error`, or `None` if
+    /// reconnecting can't help (bad credentials, a client error).
+    pub fn next_delay(&mut self, error: &ApiClientError) -> Option<Duration> {
+        match error {
+            ApiClientError::RateLimited { .. } => {
+                self.rate_limited += 1;
+                Some(Duration::from_secs(60) * 2u32.pow((self.rate_limited - 1).min(4)))
+            }
+            ApiClientError::ApiError(e) if e.status() >= 500 => {
+                self.http += 1;
+                Some(Duration::from_secs(5) * 2u32.pow((self.http - 1).min(6)))
+            }
+            ApiClientError::Http(_)
+            | ApiClientError::Deserialize(_)
+            | ApiClientError::StreamDisconnected(_) => {
+                self.network += 1;
+                Some(Duration::from_millis(250) * self.network.min(64))
+            }
+            ApiClientError::ApiError(_) | ApiClientError::Auth(_) | ApiClientError::Replay(_) => {
+                None
+            }
+        }
+    }
+
+    /// Start over once a connection succeeds.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
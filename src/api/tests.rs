@@ -0,0 +1,1123 @@
+//! `XApiClient` against a local mock server, using recorded-shape fixtures
+//! from `src/api/fixtures/`. No credentials or network access needed.
+
+use wiremock::matchers::{body_json, header, header_regex, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::*;
+use crate::api::error::XApiError;
+use crate::api::types::ListFields;
+use crate::auth::credentials::{BearerCredentials, CredentialSet, OAuth1Credentials};
+
+const TOKEN: &str = "test-token";
+
+fn fixture(body: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_raw(body, "application/json")
+}
+
+/// A bearer-only client pointed at `server`. The mock API lives under `/2`,
+/// like the real one, so error endpoints match what the client reports.
+fn client(server: &MockServer) -> XApiClient {
+    let auth = AuthProvider::new(CredentialSet {
+        bearer: Some(BearerCredentials {
+            bearer_token: TOKEN.to_string(),
+        }),
+        ..CredentialSet::default()
+    })
+    .unwrap();
+    let mut client = XApiClient::new(auth, 0);
+    client.base_url = format!("{}/2", server.uri());
+    client
+}
+
+/// An OAuth 1.0a client pointed at `server`, for user-context writes.
+fn oauth1_client(server: &MockServer) -> XApiClient {
+    let mut client = XApiClient::new(
+        AuthProvider {
+            method: AuthMethod::OAuth1,
+            credentials: CredentialSet {
+                oauth1: Some(OAuth1Credentials {
+                    api_key: "key".into(),
+                    api_secret: "secret".into(),
+                    access_token: "token".into(),
+                    access_token_secret: "token-secret".into(),
+                    bearer_token: None,
+                }),
+                ..CredentialSet::default()
+            },
+        },
+        0,
+    );
+    client.base_url = format!("{}/2", server.uri());
+    client
+}
+
+#[tokio::test]
+async fn get_tweet_sends_bearer_and_parses_includes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/1001"))
+        .and(header("Authorization", format!("Bearer {TOKEN}").as_str()))
+        .and(query_param("expansions", tweet_expansions()))
+        .respond_with(fixture(include_str!("fixtures/tweet.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resp = client(&server).get_tweet("1001").await.unwrap();
+    let tweet = resp.data.unwrap();
+    assert_eq!(tweet.text, "Hello from the mock API");
+    assert_eq!(tweet.public_metrics.unwrap().like_count, 12);
+    assert_eq!(resp.includes.unwrap().users.unwrap()[0].username, "alice");
+}
+
+#[tokio::test]
+async fn configured_fields_replace_the_defaults() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/tweets/1001"))
+        .and(query_param(
+            "tweet.fields",
+            "created_at,public_metrics,author_id,conversation_id,in_reply_to_user_id,\
+             referenced_tweets,attachments,lang,note_tweet,source,reply_settings,withheld,\
+             context_annotations,geo",
+        ))
+        .and(query_param("expansions", "author_id,referenced_tweets.id"))
+        .respond_with(fixture(include_str!("fixtures/tweet.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut config = FieldsConfig::default();
+    config.tweet.remove = vec!["entities".into()];
+    config.tweet.add = vec!["geo".into(), "lang".into()];
+    config.expansions.remove = vec!["attachments.media_keys".into()];
+    let client = client(&server).with_fields(FieldSets::from_config(&config));
+    client.get_tweet("1001").await.unwrap();
+}
+
+#[tokio::test]
+async fn search_follows_next_token() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/tweets/search/recent"))
+        .and(query_param("pagination_token", "page2"))
+        .respond_with(fixture(include_str!("fixtures/search_page2.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // Mounted second so it only answers requests the page-2 mock didn't.
+    Mock::given(path("/2/tweets/search/recent"))
+        .and(query_param("query", "rust lang"))
+        .and(query_param("max_results", "10"))
+        .respond_with(fixture(include_str!("fixtures/search_page1.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    // max_results below the API minimum is clamped to 10.
+    let first = client.search_tweets("rust lang", 5, None).await.unwrap();
+    assert_eq!(first.data.as_ref().unwrap().len(), 2);
+    let next = first.meta.unwrap().next_token;
+    assert_eq!(next.as_deref(), Some("page2"));
+
+    let second = client
+        .search_tweets("rust lang", 5, next.as_deref())
+        .await
+        .unwrap();
+    assert_eq!(second.data.unwrap()[0].id, "2000");
+    assert_eq!(second.meta.unwrap().next_token, None);
+}
+
+#[tokio::test]
+async fn paginator_follows_tokens_and_merges_pages() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/tweets/search/recent"))
+        .and(query_param("pagination_token", "page2"))
+        .respond_with(fixture(include_str!("fixtures/search_page2.json")))
+        .mount(&server)
+        .await;
+    Mock::given(path("/2/tweets/search/recent"))
+        .respond_with(fixture(include_str!("fixtures/search_page1.json")))
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let mut pages = client.search_pages("rust lang", 10);
+    let all = pages.all_pages(usize::MAX).await.unwrap();
+    let ids: Vec<_> = all.data.unwrap().into_iter().map(|t| t.id).collect();
+    assert_eq!(ids.len(), 3);
+    assert_eq!(ids.last().map(String::as_str), Some("2000"));
+    assert_eq!(all.meta.unwrap().next_token, None);
+    assert!(pages.is_exhausted());
+    assert!(pages.next_page().await.unwrap().is_none());
+
+    // Stopping early leaves the token to resume from.
+    let mut pages = client.search_pages("rust lang", 10);
+    let first = pages.all_pages(1).await.unwrap();
+    assert_eq!(first.data.unwrap().len(), 1);
+    assert_eq!(first.meta.unwrap().next_token.as_deref(), Some("page2"));
+    assert_eq!(pages.next_token(), Some("page2"));
+}
+
+#[tokio::test]
+async fn timeline_exclusions_are_sent_to_the_api() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/42/tweets"))
+        .and(query_param("exclude", "replies,retweets"))
+        .respond_with(fixture(include_str!("fixtures/search_page2.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let exclude = TimelineExclude {
+        replies: true,
+        retweets: true,
+    };
+    let resp = client(&server)
+        .get_timeline("42", 20, exclude, None)
+        .await
+        .unwrap();
+    assert_eq!(resp.data.unwrap().len(), 1);
+    assert_eq!(TimelineExclude::default().param(), None);
+}
+
+#[tokio::test]
+async fn user_id_is_resolved_once() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/2/users/42/bookmarks"))
+        .respond_with(fixture(include_str!("fixtures/search_page2.json")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    for _ in 0..2 {
+        let resp = client.get_bookmarks(20, None).await.unwrap();
+        assert_eq!(resp.data.unwrap().len(), 1);
+    }
+}
+
+#[tokio::test]
+async fn rate_limit_headers_are_parsed_and_kept() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/by/username/alice"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("x-rate-limit-limit", "300")
+                .insert_header("x-rate-limit-remaining", "0")
+                .insert_header("x-rate-limit-reset", "1735787045")
+                .set_body_string(r#"{"title":"Too Many Requests","status":429}"#),
+        )
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let err = client.get_user("alice").await.unwrap_err();
+    let info = client.rate_limit();
+    assert_eq!((info.remaining, info.limit), (Some(0), Some(300)));
+    match err {
+        ApiClientError::RateLimited { reset_at } => {
+            assert_eq!(
+                reset_at,
+                DateTime::from_timestamp(1_735_787_045, 0).unwrap()
+            );
+        }
+        other => panic!("expected RateLimited, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn problem_bodies_are_classified() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/by/username/nobody"))
+        .respond_with(ResponseTemplate::new(404).set_body_raw(
+            include_str!("fixtures/problem_not_found.json"),
+            "application/problem+json",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(path("/2/users/by/username/alice"))
+        .respond_with(ResponseTemplate::new(401).set_body_raw(
+            include_str!("fixtures/problem_unauthorized.json"),
+            "application/problem+json",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(path("/2/tweets/1001"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("upstream unavailable"))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+
+    let ApiClientError::ApiError(XApiError::NotFound(problem)) =
+        client.get_user("nobody").await.unwrap_err()
+    else {
+        panic!("expected NotFound");
+    };
+    assert_eq!(problem.endpoint, "/2/users/by/username/nobody");
+    assert_eq!(problem.kind(), Some("resource-not-found"));
+
+    let err = client.get_user("alice").await.unwrap_err();
+    assert!(matches!(
+        err,
+        ApiClientError::ApiError(XApiError::Unauthorized(_))
+    ));
+
+    let ApiClientError::ApiError(XApiError::Other(problem)) =
+        client.get_tweet("1001").await.unwrap_err()
+    else {
+        panic!("expected Other");
+    };
+    assert_eq!(problem.status, 503);
+    assert_eq!(problem.detail.as_deref(), Some("upstream unavailable"));
+}
+
+#[tokio::test]
+async fn malformed_success_body_is_a_deserialize_error() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/tweets/1001"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{\"data\": [1, 2"))
+        .mount(&server)
+        .await;
+
+    let err = client(&server).get_tweet("1001").await.unwrap_err();
+    assert!(matches!(err, ApiClientError::Deserialize(_)));
+}
+
+#[tokio::test]
+async fn recorded_responses_replay_without_network() {
+    let dir = std::env::temp_dir().join(format!("xplorertui-replay-{}", std::process::id()));
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(path("/2/users/42/mentions"))
+        .respond_with(fixture(include_str!("fixtures/search_page1.json")))
+        .mount(&server)
+        .await;
+    Mock::given(path("/2/users/by/username/nobody"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_string(include_str!("fixtures/problem_not_found.json")),
+        )
+        .mount(&server)
+        .await;
+
+    let recorder = client(&server).with_recording(Some(Recording::Record(dir.clone())));
+    recorder.get_mentions(20, None).await.unwrap();
+    recorder.get_user("nobody").await.unwrap_err();
+    drop(server);
+
+    // No credentials and an unreachable base URL: everything comes from disk.
+    let mut replayer = XApiClient::new(
+        AuthProvider {
+            method: AuthMethod::BearerOnly,
+            credentials: CredentialSet::default(),
+        },
+        0,
+    )
+    .with_recording(Some(Recording::Replay(dir.clone())));
+    replayer.base_url = "http://127.0.0.1:9/2".to_string();
+
+    let mentions = replayer.get_mentions(20, None).await.unwrap();
+    assert_eq!(mentions.data.unwrap().len(), 2);
+    let err = replayer.get_user("nobody").await.unwrap_err();
+    assert!(matches!(
+        err,
+        ApiClientError::ApiError(XApiError::NotFound(_))
+    ));
+    let err = replayer.get_user("someone-else").await.unwrap_err();
+    assert!(matches!(err, ApiClientError::Replay(_)));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn bookmark_writes_use_user_context() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/users/42/bookmarks"))
+        .and(header_regex("Authorization", "^OAuth "))
+        .and(body_json(serde_json::json!({ "tweet_id": "1001" })))
+        .respond_with(fixture(r#"{"data":{"bookmarked":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    let resp = client.bookmark_tweet("1001").await.unwrap();
+    assert!(resp.data.unwrap().bookmarked);
+
+    Mock::given(method("DELETE"))
+        .and(path("/2/users/42/bookmarks/1001"))
+        .and(header_regex("Authorization", "^OAuth "))
+        .respond_with(fixture(r#"{"data":{"bookmarked":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let resp = client.remove_bookmark("1001").await.unwrap();
+    assert!(!resp.data.unwrap().bookmarked);
+
+    // App-only tokens can't write; fail before sending the POST.
+    let err = self::client(&server)
+        .bookmark_tweet("1001")
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ApiClientError::Auth(AuthError::UserContextRequired)
+    ));
+}
+
+#[tokio::test]
+async fn likes_post_and_delete_for_the_signed_in_user() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/users/42/likes"))
+        .and(body_json(serde_json::json!({ "tweet_id": "1001" })))
+        .respond_with(fixture(r#"{"data":{"liked":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/users/42/likes/1001"))
+        .respond_with(fixture(r#"{"data":{"liked":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    assert!(client.like_tweet("1001").await.unwrap().data.unwrap().liked);
+    assert!(
+        !client
+            .unlike_tweet("1001")
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .liked
+    );
+}
+
+#[tokio::test]
+async fn muting_posts_and_deletes_for_the_signed_in_user() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/users/42/muting"))
+        .and(body_json(serde_json::json!({ "target_user_id": "7" })))
+        .respond_with(fixture(r#"{"data":{"muting":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/users/42/muting/7"))
+        .respond_with(fixture(r#"{"data":{"muting":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    assert!(client.mute_user("7").await.unwrap().data.unwrap().muting);
+    assert!(!client.unmute_user("7").await.unwrap().data.unwrap().muting);
+}
+
+#[tokio::test]
+async fn blocking_posts_and_deletes_for_the_signed_in_user() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/users/42/blocking"))
+        .and(body_json(serde_json::json!({ "target_user_id": "7" })))
+        .respond_with(fixture(r#"{"data":{"blocking":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/users/42/blocking/7"))
+        .respond_with(fixture(r#"{"data":{"blocking":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    assert!(client.block_user("7").await.unwrap().data.unwrap().blocking);
+    assert!(
+        !client
+            .unblock_user("7")
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .blocking
+    );
+}
+
+#[tokio::test]
+async fn owned_and_followed_lists_are_read_for_the_signed_in_user() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/2/users/42/owned_lists"))
+        .and(query_param("max_results", "100"))
+        .and(query_param(
+            "list.fields",
+            "description,private,member_count,follower_count,owner_id,created_at",
+        ))
+        .respond_with(fixture(
+            r#"{"data":[{"id":"1","name":"Rustaceans","private":true,"member_count":12}]}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/2/users/42/followed_lists"))
+        .respond_with(fixture(r#"{"data":[{"id":"2","name":"TUI folks"}]}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    let owned = client
+        .get_owned_lists(100, None)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(owned[0].name, "Rustaceans");
+    assert_eq!(owned[0].private, Some(true));
+    assert_eq!(owned[0].member_count, Some(12));
+    let followed = client
+        .get_followed_lists(100, None)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(followed[0].id, "2");
+}
+
+#[tokio::test]
+async fn lists_are_created_updated_and_deleted() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/2/lists"))
+        .and(body_json(
+            serde_json::json!({ "name": "Rust", "private": true }),
+        ))
+        .respond_with(fixture(r#"{"data":{"id":"9","name":"Rust"}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/2/lists/9"))
+        .and(body_json(serde_json::json!({
+            "name": "Rustaceans", "description": "People who write Rust", "private": false,
+        })))
+        .respond_with(fixture(r#"{"data":{"updated":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/lists/9"))
+        .respond_with(fixture(r#"{"data":{"deleted":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    let created = client
+        .create_list(&ListFields {
+            name: "Rust".into(),
+            private: Some(true),
+            ..ListFields::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(created.data.unwrap().id, "9");
+    let fields = ListFields {
+        name: "Rustaceans".into(),
+        description: Some("People who write Rust".into()),
+        private: Some(false),
+    };
+    assert!(
+        client
+            .update_list("9", &fields)
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .updated
+    );
+    assert!(client.delete_list("9").await.unwrap().data.unwrap().deleted);
+}
+
+#[tokio::test]
+async fn list_members_are_read_added_and_removed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/lists/1/members"))
+        .and(query_param("max_results", "100"))
+        .respond_with(fixture(
+            r#"{"data":[{"id":"7","name":"Alice","username":"alice"}]}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/lists/1/members"))
+        .and(body_json(serde_json::json!({ "user_id": "8" })))
+        .respond_with(fixture(r#"{"data":{"is_member":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/lists/1/members/7"))
+        .respond_with(fixture(r#"{"data":{"is_member":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    let members = client
+        .get_list_members("1", 100, None)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(members[0].username, "alice");
+    let added = client.add_list_member("1", "8").await.unwrap();
+    assert!(added.data.unwrap().is_member);
+    let removed = client.remove_list_member("1", "7").await.unwrap();
+    assert!(!removed.data.unwrap().is_member);
+}
+
+#[tokio::test]
+async fn replies_name_the_tweet_they_answer() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets"))
+        .and(body_json(serde_json::json!({
+            "text": "agreed",
+            "reply": { "in_reply_to_tweet_id": "1001" },
+            "reply_settings": "mentionedUsers",
+        })))
+        .respond_with(fixture(r#"{"data":{"id":"2002","text":"agreed"}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resp = oauth1_client(&server)
+        .post_tweet("agreed", Some("1001"), types::ReplySettings::MentionedUsers)
+        .await
+        .unwrap();
+    assert_eq!(resp.data.unwrap().id, "2002");
+}
+
+#[tokio::test]
+async fn thread_posts_chain_and_stop_at_the_first_failure() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets"))
+        .and(body_json(serde_json::json!({
+            "text": "one 1/3",
+            "reply": { "in_reply_to_tweet_id": "1001" },
+        })))
+        .respond_with(fixture(r#"{"data":{"id":"11","text":"one 1/3"}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets"))
+        .and(body_json(serde_json::json!({
+            "text": "two 2/3",
+            "reply": { "in_reply_to_tweet_id": "11" },
+        })))
+        .respond_with(fixture(r#"{"data":{"id":"12","text":"two 2/3"}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets"))
+        .and(body_json(serde_json::json!({
+            "text": "three 3/3",
+            "reply": { "in_reply_to_tweet_id": "12" },
+        })))
+        .respond_with(ResponseTemplate::new(503).set_body_string("upstream unavailable"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let posts = ["one 1/3".to_string(), "two 2/3".into(), "three 3/3".into()];
+    let interrupted = oauth1_client(&server)
+        .post_thread(&posts, Some("1001"), types::ReplySettings::Everyone)
+        .await
+        .unwrap_err();
+    assert_eq!(interrupted.posted, ["11", "12"]);
+    assert!(interrupted.error.to_string().contains("503"));
+}
+
+#[tokio::test]
+async fn deleting_a_post_needs_user_context() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/tweets/11"))
+        .and(header_regex("Authorization", "^OAuth "))
+        .respond_with(fixture(r#"{"data":{"deleted":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let deleted = oauth1_client(&server).delete_tweet("11").await.unwrap();
+    assert!(deleted.data.unwrap().deleted);
+    assert!(matches!(
+        client(&server).delete_tweet("11").await,
+        Err(ApiClientError::Auth(AuthError::UserContextRequired))
+    ));
+}
+
+#[tokio::test]
+async fn stream_rules_are_listed_added_and_deleted() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/search/stream/rules"))
+        .and(header("Authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(fixture(
+            r#"{"data":[{"id":"1","value":"ratatui -is:retweet","tag":"tui"}],"meta":{"sent":"2026-10-16T00:00:00.000Z","result_count":1}}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets/search/stream/rules"))
+        .and(body_json(serde_json::json!({
+            "add": [{ "value": "from:alice" }, { "value": "(", "tag": "broken" }],
+        })))
+        .respond_with(fixture(
+            r#"{"data":[{"id":"2","value":"from:alice"}],"errors":[{"value":"(","title":"UnprocessableEntity","detail":"Rule is invalid","type":"https://api.twitter.com/2/problems/invalid-rules"}]}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets/search/stream/rules"))
+        .and(body_json(serde_json::json!({ "delete": { "ids": ["1"] } })))
+        .respond_with(fixture(
+            r#"{"meta":{"sent":"2026-10-16T00:00:00.000Z","summary":{"deleted":1,"not_deleted":0}}}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let rules = client.get_stream_rules().await.unwrap().data.unwrap();
+    assert_eq!(rules[0].tag.as_deref(), Some("tui"));
+
+    let added = client
+        .add_stream_rules(&[
+            types::NewStreamRule {
+                value: "from:alice".into(),
+                tag: None,
+            },
+            types::NewStreamRule {
+                value: "(".into(),
+                tag: Some("broken".into()),
+            },
+        ])
+        .await
+        .unwrap();
+    assert_eq!(added.data.unwrap()[0].id, "2");
+    assert_eq!(
+        added.errors.unwrap()[0].summary(),
+        "UnprocessableEntity: Rule is invalid"
+    );
+
+    let deleted = client.delete_stream_rules(&["1".into()]).await.unwrap();
+    assert!(deleted.errors.is_none());
+}
+
+#[tokio::test]
+async fn the_filtered_stream_skips_keep_alives_and_reports_disconnects() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/search/stream"))
+        .and(header("Authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(fixture(concat!(
+            "\r\n",
+            r#"{"data":{"id":"1","text":"ratatui 0.30"},"matching_rules":[{"id":"9","tag":"tui"}]}"#,
+            "\r\n\r\n\r\n",
+            r#"{"errors":[{"title":"operational-disconnect","detail":"This stream has been disconnected for operational reasons."}]}"#,
+            "\r\n",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stream = client(&server).connect_stream().await.unwrap();
+    let message = stream.next_message().await.unwrap();
+    assert_eq!(message.data.unwrap().id, "1");
+    assert_eq!(message.matching_rules[0].tag.as_deref(), Some("tui"));
+    let message = stream.next_message().await.unwrap();
+    assert!(message.data.is_none());
+    assert_eq!(
+        message.errors.unwrap()[0].title.as_deref(),
+        Some("operational-disconnect")
+    );
+    assert!(matches!(
+        stream.next_message().await,
+        Err(ApiClientError::StreamDisconnected(_))
+    ));
+}
+
+#[tokio::test]
+async fn the_sampled_stream_carries_authors_in_includes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/sample/stream"))
+        .and(header("Authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(fixture(concat!(
+            r#"{"data":{"id":"1","text":"gm","author_id":"7"},"includes":{"users":[{"id":"7","name":"Ann","username":"ann"}]}}"#,
+            "\r\n",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stream = client(&server).connect_sample_stream().await.unwrap();
+    let message = stream.next_message().await.unwrap();
+    assert_eq!(message.data.unwrap().author_id.as_deref(), Some("7"));
+    assert_eq!(message.includes.unwrap().users.unwrap()[0].username, "ann");
+    assert!(message.matching_rules.is_empty());
+}
+
+#[test]
+fn stream_reconnects_back_off_by_kind_of_failure() {
+    use std::time::Duration;
+
+    use crate::api::stream::Backoff;
+
+    let mut backoff = Backoff::default();
+    let dropped = ApiClientError::StreamDisconnected("stalled");
+    assert_eq!(
+        backoff.next_delay(&dropped),
+        Some(Duration::from_millis(250))
+    );
+    assert_eq!(
+        backoff.next_delay(&dropped),
+        Some(Duration::from_millis(500))
+    );
+
+    let unavailable: ApiClientError = XApiError::from_response(503, "{}").into();
+    assert_eq!(
+        backoff.next_delay(&unavailable),
+        Some(Duration::from_secs(5))
+    );
+    assert_eq!(
+        backoff.next_delay(&unavailable),
+        Some(Duration::from_secs(10))
+    );
+    for _ in 0..10 {
+        backoff.next_delay(&unavailable);
+    }
+    assert_eq!(
+        backoff.next_delay(&unavailable),
+        Some(Duration::from_secs(320))
+    );
+
+    let limited = ApiClientError::RateLimited {
+        reset_at: Utc::now(),
+    };
+    assert_eq!(backoff.next_delay(&limited), Some(Duration::from_secs(60)));
+
+    let forbidden: ApiClientError = XApiError::from_response(403, "{}").into();
+    assert_eq!(backoff.next_delay(&forbidden), None);
+
+    backoff.reset();
+    assert_eq!(
+        backoff.next_delay(&dropped),
+        Some(Duration::from_millis(250))
+    );
+}
+
+#[tokio::test]
+async fn retweeted_by_lists_the_reposting_accounts() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/1001/retweeted_by"))
+        .and(query_param("max_results", "100"))
+        .respond_with(fixture(
+            r#"{"data":[{"id":"7","name":"Alice","username":"alice"}],"meta":{"result_count":1}}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let users = client(&server)
+        .get_retweeted_by("1001", 100, None)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(users[0].username, "alice");
+}
+
+#[tokio::test]
+async fn liking_users_lists_the_accounts_that_liked() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/1001/liking_users"))
+        .and(query_param("max_results", "100"))
+        .respond_with(fixture(
+            r#"{"data":[{"id":"8","name":"Bob","username":"bob"}],"meta":{"result_count":1}}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let users = client(&server)
+        .get_liking_users("1001", 100, None)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(users[0].username, "bob");
+}
+
+#[tokio::test]
+async fn dm_events_come_with_their_senders() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/dm_events"))
+        .and(query_param("expansions", "sender_id,participant_ids"))
+        .respond_with(fixture(
+            r#"{
+                "data": [{"id":"3","event_type":"MessageCreate","text":"hi","sender_id":"7",
+                          "dm_conversation_id":"1-7","created_at":"2024-05-01T10:00:00.000Z"}],
+                "includes": {"users": [{"id":"7","name":"Alice","username":"alice"}]}
+            }"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/2/dm_conversations/with/7/dm_events"))
+        .and(query_param("max_results", "100"))
+        .respond_with(fixture(
+            r#"{"data": [{"id":"2","event_type":"ParticipantsJoin","participant_ids":["7"]}]}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    let inbox = client.get_dm_events(100, None).await.unwrap();
+    let event = &inbox.data.unwrap()[0];
+    assert_eq!(event.text.as_deref(), Some("hi"));
+    assert_eq!(event.dm_conversation_id.as_deref(), Some("1-7"));
+    assert_eq!(inbox.includes.unwrap().users.unwrap()[0].username, "alice");
+    let conversation = client
+        .get_dm_conversation_with("7", 100, None)
+        .await
+        .unwrap();
+    let event = &conversation.data.unwrap()[0];
+    assert_eq!(event.event_type, "ParticipantsJoin");
+    assert!(event.text.is_none());
+}
+
+#[tokio::test]
+async fn hiding_a_reply_puts_the_hidden_flag() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/2/tweets/1001/hidden"))
+        .and(body_json(serde_json::json!({ "hidden": true })))
+        .respond_with(fixture(r#"{"data":{"hidden":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/2/tweets/1001/hidden"))
+        .and(body_json(serde_json::json!({ "hidden": false })))
+        .respond_with(fixture(r#"{"data":{"hidden":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    let hide = client.hide_reply("1001", true).await.unwrap();
+    assert!(hide.data.unwrap().hidden);
+    let unhide = client.hide_reply("1001", false).await.unwrap();
+    assert!(!unhide.data.unwrap().hidden);
+}
+
+#[tokio::test]
+async fn retweets_post_and_delete_for_the_signed_in_user() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/users/me"))
+        .respond_with(fixture(include_str!("fixtures/users_me.json")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/2/users/42/retweets"))
+        .and(body_json(serde_json::json!({ "tweet_id": "1001" })))
+        .respond_with(fixture(r#"{"data":{"retweeted":true}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/2/users/42/retweets/1001"))
+        .respond_with(fixture(r#"{"data":{"retweeted":false}}"#))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = oauth1_client(&server);
+    assert!(
+        client
+            .retweet("1001")
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .retweeted
+    );
+    assert!(
+        !client
+            .unretweet("1001")
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .retweeted
+    );
+}
+
+#[tokio::test]
+async fn post_tweet_sends_the_text_with_user_context() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/2/tweets"))
+        .and(header_regex("Authorization", "^OAuth "))
+        .and(body_json(
+            serde_json::json!({ "text": "first line\nsecond" }),
+        ))
+        .respond_with(fixture(
+            r#"{"data":{"id":"2001","text":"first line\nsecond"}}"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let resp = oauth1_client(&server)
+        .post_tweet("first line\nsecond", None, types::ReplySettings::Everyone)
+        .await
+        .unwrap();
+    assert_eq!(resp.data.unwrap().id, "2001");
+
+    let err = client(&server)
+        .post_tweet("hi", None, types::ReplySettings::Everyone)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ApiClientError::Auth(AuthError::UserContextRequired)
+    ));
+}
+
+#[tokio::test]
+async fn withheld_tweets_and_authors_are_parsed() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/tweets/1002"))
+        .respond_with(fixture(
+            r#"{
+                "data": {"id": "1002", "text": "...", "author_id": "7",
+                         "withheld": {"copyright": false, "country_codes": ["DE", "FR"]}},
+                "includes": {"users": [{"id": "7", "username": "bob", "name": "Bob",
+                             "withheld": {"country_codes": ["XX"], "scope": "user"}}]}
+            }"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let resp = client(&server).get_tweet("1002").await.unwrap();
+    let withheld = resp.data.unwrap().withheld.unwrap();
+    assert_eq!(withheld.describe(), "withheld in DE, FR");
+    let author = &resp.includes.unwrap().users.unwrap()[0];
+    assert_eq!(
+        author.withheld.as_ref().unwrap().describe(),
+        "withheld everywhere"
+    );
+}
+
+#[tokio::test]
+async fn usage_counts_parse_from_strings() {
+    let server = MockServer::start().await;
+    Mock::given(path("/2/usage/tweets"))
+        .and(header("Authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(fixture(
+            r#"{"data":{"project_cap":"10000","project_usage":"2500","cap_reset_day":19}}"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let usage = client(&server).get_usage().await.unwrap().data.unwrap();
+    assert_eq!(usage.project_cap, Some(10_000));
+    assert_eq!(usage.project_usage, Some(2_500));
+    assert_eq!(usage.cap_reset_day, Some(19));
+    assert_eq!(usage.percent_used(), Some(25));
+}
+
+#[test]
+fn the_usage_cap_resets_on_its_day_or_the_end_of_short_months() {
+    use chrono::NaiveDate;
+
+    use crate::api::types::Usage;
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let resetting_on = |day| Usage {
+        cap_reset_day: Some(day),
+        ..Usage::default()
+    };
+    let usage = resetting_on(19);
+    assert_eq!(
+        usage.next_reset(date(2026, 10, 16)),
+        Some(date(2026, 10, 19))
+    );
+    assert_eq!(
+        usage.next_reset(date(2026, 10, 19)),
+        Some(date(2026, 11, 19))
+    );
+    assert_eq!(
+        usage.next_reset(date(2026, 12, 31)),
+        Some(date(2027, 1, 19))
+    );
+    assert_eq!(
+        resetting_on(31).next_reset(date(2027, 1, 31)),
+        Some(date(2027, 2, 28))
+    );
+    assert_eq!(Usage::default().next_reset(date(2026, 10, 16)), None);
+    assert_eq!(Usage::default().percent_used(), None);
+}
@@ -1,32 +1,112 @@
-use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
-
-use crate::api::types::{ListResponse, SingleResponse, Tweet};
-use crate::api::{
-    ApiClientError, XApiClient, media_fields, tweet_expansions, tweet_fields, user_fields,
+use crate::api::request::RequestBuilder;
+use crate::api::types::{
+    DeletedStatus, HiddenStatus, ListResponse, PostedTweet, ReplySettings, SingleResponse, Tweet,
+    User,
 };
-
-/// Percent-encoding set for URL query values (encode everything except unreserved chars).
-const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
-    .remove(b'-')
-    .remove(b'.')
-    .remove(b'_')
-    .remove(b'~');
-
-fn encode_query(s: &str) -> String {
-    utf8_percent_encode(s, QUERY_ENCODE_SET).to_string()
-}
+use crate::api::{ApiClientError, XApiClient};
 
 impl XApiClient {
     /// Fetch a single tweet by ID.
     pub async fn get_tweet(&self, tweet_id: &str) -> Result<SingleResponse<Tweet>, ApiClientError> {
-        let url = Self::url(&format!(
-            "/tweets/{tweet_id}?tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        ));
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["tweets", tweet_id]).tweet_fields(&self.fields);
+        self.bearer_get(&self.url(&request)).await
+    }
+
+    /// Publish a new post as the authenticated user, as a reply to
+    /// `reply_to` if given, limiting who may reply to it.
+    pub async fn post_tweet(
+        &self,
+        text: &str,
+        reply_to: Option<&str>,
+        reply_settings: ReplySettings,
+    ) -> Result<SingleResponse<PostedTweet>, ApiClientError> {
+        let request = RequestBuilder::new(&["tweets"]);
+        let mut body = serde_json::json!({ "text": text });
+        if let Some(tweet_id) = reply_to {
+            body["reply"] = serde_json::json!({ "in_reply_to_tweet_id": tweet_id });
+        }
+        if let Some(setting) = reply_settings.param() {
+            body["reply_settings"] = serde_json::json!(setting);
+        }
+        self.oauth_post(&self.url(&request), &body).await
+    }
+
+    /// Publish `posts` as a thread, each a reply to the one before it and
+    /// the first to `reply_to` if given. Stops at the first failure,
+    /// reporting the IDs posted until then.
+    pub async fn post_thread(
+        &self,
+        posts: &[String],
+        reply_to: Option<&str>,
+        reply_settings: ReplySettings,
+    ) -> Result<Vec<String>, ThreadInterrupted> {
+        let mut posted: Vec<String> = Vec::new();
+        for text in posts {
+            let previous = posted.last().map(String::as_str).or(reply_to);
+            let error = match self.post_tweet(text, previous, reply_settings).await {
+                Ok(resp) => match resp.data {
+                    Some(tweet) => {
+                        posted.push(tweet.id);
+                        continue;
+                    }
+                    None => ApiClientError::Deserialize("no post in the response".into()),
+                },
+                Err(error) => error,
+            };
+            return Err(ThreadInterrupted { posted, error });
+        }
+        Ok(posted)
+    }
+
+    /// Delete one of the authenticated user's posts.
+    pub async fn delete_tweet(
+        &self,
+        tweet_id: &str,
+    ) -> Result<SingleResponse<DeletedStatus>, ApiClientError> {
+        let request = RequestBuilder::new(&["tweets", tweet_id]);
+        self.oauth_delete(&self.url(&request)).await
+    }
+
+    /// Hide (or unhide) a reply to one of the authenticated user's tweets.
+    pub async fn hide_reply(
+        &self,
+        tweet_id: &str,
+        hidden: bool,
+    ) -> Result<SingleResponse<HiddenStatus>, ApiClientError> {
+        let request = RequestBuilder::new(&["tweets", tweet_id, "hidden"]);
+        self.oauth_put(
+            &self.url(&request),
+            &serde_json::json!({ "hidden": hidden }),
+        )
+        .await
+    }
+
+    /// Get the accounts that reposted a tweet.
+    pub async fn get_retweeted_by(
+        &self,
+        tweet_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<User>, ApiClientError> {
+        let request = RequestBuilder::new(&["tweets", tweet_id, "retweeted_by"])
+            .max_results(max_results, 1..=100)
+            .user_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
+    }
+
+    /// Get the accounts that liked a tweet.
+    pub async fn get_liking_users(
+        &self,
+        tweet_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse<User>, ApiClientError> {
+        let request = RequestBuilder::new(&["tweets", tweet_id, "liking_users"])
+            .max_results(max_results, 1..=100)
+            .user_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 
     /// Search recent tweets matching a query.
@@ -36,25 +116,12 @@ impl XApiClient {
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
-        let max_results = max_results.clamp(10, 100);
-        let encoded_query = encode_query(query);
-
-        let mut url = format!(
-            "{}/tweets/search/recent?query={}&max_results={}&tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            Self::url(""),
-            encoded_query,
-            max_results,
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        );
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["tweets", "search", "recent"])
+            .param("query", query)
+            .max_results(max_results, 10..=100)
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 
     /// Get tweets in a conversation thread.
@@ -64,26 +131,20 @@ impl XApiClient {
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
-        let query = format!("conversation_id:{conversation_id}");
-
-        let max_results = max_results.clamp(10, 100);
-        let encoded_query = encode_query(&query);
-
-        let mut url = format!(
-            "{}/tweets/search/recent?query={}&max_results={}&sort_order=recency&tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            Self::url(""),
-            encoded_query,
-            max_results,
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        );
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["tweets", "search", "recent"])
+            .param("query", format!("conversation_id:{conversation_id}"))
+            .max_results(max_results, 10..=100)
+            .param("sort_order", "recency")
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 }
+
+/// A thread that stopped at a failed post.
+#[derive(Debug)]
+pub struct ThreadInterrupted {
+    /// IDs of the posts that went out, in order.
+    pub posted: Vec<String>,
+    pub error: ApiClientError,
+}
@@ -1,11 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
 // Generic API response wrapper
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(bound(deserialize = "T: serde::de::DeserializeOwned"))]
 #[serde(bound(serialize = "T: serde::Serialize"))]
 pub struct ApiResponse<T> {
@@ -25,6 +25,199 @@ pub type SingleResponse<T> = ApiResponse<T>;
 /// Response containing a list of objects (e.g. search, timeline).
 pub type ListResponse<T> = ApiResponse<Vec<T>>;
 
+/// Body of `POST /users/:id/bookmarks`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookmarkStatus {
+    pub bookmarked: bool,
+}
+
+/// Body of `POST /tweets`: the new post's ID and text as stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostedTweet {
+    pub id: String,
+    pub text: String,
+}
+
+/// Who may reply to a new post.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReplySettings {
+    #[default]
+    Everyone,
+    Following,
+    MentionedUsers,
+}
+
+impl ReplySettings {
+    /// The next choice, for the composer's toggle.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Everyone => Self::Following,
+            Self::Following => Self::MentionedUsers,
+            Self::MentionedUsers => Self::Everyone,
+        }
+    }
+
+    /// The `reply_settings` value `POST /tweets` takes; anyone may reply
+    /// when it is left out.
+    pub fn param(self) -> Option<&'static str> {
+        match self {
+            Self::Everyone => None,
+            Self::Following => Some("following"),
+            Self::MentionedUsers => Some("mentionedUsers"),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Everyone => "Everyone",
+            Self::Following => "Following",
+            Self::MentionedUsers => "Mentioned",
+        }
+    }
+}
+
+/// Body of `DELETE /tweets/:id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedStatus {
+    pub deleted: bool,
+}
+
+/// Body of `POST /users/:id/likes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LikeStatus {
+    pub liked: bool,
+}
+
+/// Body of `POST /users/:id/retweets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetweetStatus {
+    pub retweeted: bool,
+}
+
+/// Body of `POST /users/:id/muting`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MuteStatus {
+    pub muting: bool,
+}
+
+/// Body of `PUT /tweets/:id/hidden`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HiddenStatus {
+    pub hidden: bool,
+}
+
+/// Body of `POST /lists/:id/members`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListMemberStatus {
+    pub is_member: bool,
+}
+
+/// Body of `PUT /lists/:id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListUpdated {
+    pub updated: bool,
+}
+
+/// Body of `DELETE /lists/:id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListDeleted {
+    pub deleted: bool,
+}
+
+/// Body of `POST /users/:id/blocking`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockStatus {
+    pub blocking: bool,
+}
+
+/// Body of `GET /usage/tweets`: posts read this billing month against the
+/// project's monthly cap.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Usage {
+    #[serde(default, deserialize_with = "count")]
+    pub project_cap: Option<u64>,
+    #[serde(default, deserialize_with = "count")]
+    pub project_usage: Option<u64>,
+    /// Day of the month the usage resets.
+    #[serde(default)]
+    pub cap_reset_day: Option<u32>,
+}
+
+impl Usage {
+    /// Share of the cap used so far, in percent. `None` without a cap.
+    pub fn percent_used(&self) -> Option<u64> {
+        let cap = self.project_cap.filter(|&cap| cap > 0)?;
+        Some(self.project_usage.unwrap_or(0) * 100 / cap)
+    }
+
+    /// The first reset after `today`. Months too short for the reset day
+    /// reset on their last day.
+    pub fn next_reset(&self, today: NaiveDate) -> Option<NaiveDate> {
+        let day = self.cap_reset_day?;
+        let reset_in = |month: NaiveDate| (1..=day).rev().find_map(|d| month.with_day(d));
+        let this_month = today.with_day(1)?;
+        match reset_in(this_month)? {
+            reset if reset > today => Some(reset),
+            _ => reset_in(this_month.checked_add_months(Months::new(1))?),
+        }
+    }
+}
+
+/// A filtered stream rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamRule {
+    pub id: String,
+    pub value: String,
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// A rule to add to the filtered stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewStreamRule {
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// One line of the filtered stream: a matching tweet with the rules it
+/// matched, or errors X reports before closing the connection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StreamMessage {
+    #[serde(default)]
+    pub data: Option<Tweet>,
+    #[serde(default)]
+    pub includes: Option<Includes>,
+    #[serde(default)]
+    pub matching_rules: Vec<MatchingRule>,
+    #[serde(default)]
+    pub errors: Option<Vec<ApiError>>,
+}
+
+/// A rule a streamed tweet matched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchingRule {
+    pub id: String,
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// Counts the usage endpoint sends as strings (`"2000000"`) or numbers.
+fn count<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Count {
+        Number(u64),
+        Text(String),
+    }
+    Ok(match Option::<Count>::deserialize(deserializer)? {
+        Some(Count::Number(n)) => Some(n),
+        Some(Count::Text(s)) => s.parse().ok(),
+        None => None,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tweet
 // ---------------------------------------------------------------------------
@@ -55,6 +248,75 @@ pub struct Tweet {
     pub attachments: Option<Attachments>,
     #[serde(default)]
     pub note_tweet: Option<NoteTweet>,
+    /// Client the tweet was posted from (e.g. "Twitter for iPhone").
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Who may reply: `everyone`, `mentionedUsers`, `following`,
+    /// `subscribers` or `verified`.
+    #[serde(default)]
+    pub reply_settings: Option<String>,
+    /// Set when the tweet (or its author) is withheld in some countries.
+    #[serde(default)]
+    pub withheld: Option<Withheld>,
+    /// Topics X recognized in the tweet, e.g. a person or product.
+    #[serde(default)]
+    pub context_annotations: Option<Vec<ContextAnnotation>>,
+}
+
+/// A topic X recognized in a tweet: the kind of thing (`domain`) and the
+/// thing itself (`entity`). Both can be searched with `context:`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextAnnotation {
+    pub domain: ContextEntity,
+    pub entity: ContextEntity,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextEntity {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Content X withholds, e.g. in response to a legal demand.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Withheld {
+    /// Withheld over a copyright complaint (tweets only).
+    #[serde(default)]
+    pub copyright: Option<bool>,
+    /// ISO country codes it's withheld in; `XX` means everywhere.
+    #[serde(default)]
+    pub country_codes: Vec<String>,
+    /// `tweet` or `user`: whether the tweet itself or its author is withheld.
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+impl Withheld {
+    /// Most countries listed before the rest are summarized as a count.
+    const MAX_COUNTRIES: usize = 4;
+
+    /// A short description, e.g. "withheld in DE, FR" or "withheld for
+    /// copyright".
+    pub fn describe(&self) -> String {
+        if self.copyright == Some(true) {
+            return "withheld for copyright".to_string();
+        }
+        let codes = &self.country_codes;
+        if codes.iter().any(|c| c == "XX") {
+            return "withheld everywhere".to_string();
+        }
+        match codes.len() {
+            0 => "withheld".to_string(),
+            n if n <= Self::MAX_COUNTRIES => format!("withheld in {}", codes.join(", ")),
+            n => format!(
+                "withheld in {} +{}",
+                codes[..Self::MAX_COUNTRIES].join(", "),
+                n - Self::MAX_COUNTRIES
+            ),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,9 +368,17 @@ pub struct User {
     pub created_at: Option<DateTime<Utc>>,
     #[serde(default)]
     pub verified: Option<bool>,
+    /// `blue`, `business`, `government` or `none`.
+    #[serde(default)]
+    pub verified_type: Option<String>,
+    /// Protected accounts' posts are only visible to approved followers.
+    #[serde(default)]
+    pub protected: Option<bool>,
     #[serde(default)]
     pub profile_image_url: Option<String>,
     #[serde(default)]
+    pub profile_banner_url: Option<String>,
+    #[serde(default)]
     pub url: Option<String>,
     #[serde(default)]
     pub location: Option<String>,
@@ -116,6 +386,47 @@ pub struct User {
     pub pinned_tweet_id: Option<String>,
     #[serde(default)]
     pub public_metrics: Option<UserPublicMetrics>,
+    #[serde(default)]
+    pub withheld: Option<Withheld>,
+}
+
+impl User {
+    /// The verification badge to show, from `verified_type`, falling back
+    /// to the legacy `verified` flag.
+    pub fn verification(&self) -> Option<Verification> {
+        match self.verified_type.as_deref() {
+            Some("blue") => Some(Verification::Blue),
+            Some("business") => Some(Verification::Business),
+            Some("government") => Some(Verification::Government),
+            Some(_) => None,
+            None => self.verified.unwrap_or(false).then_some(Verification::Blue),
+        }
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.protected.unwrap_or(false)
+    }
+}
+
+/// Kinds of verified account, each with its own badge color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// Subscription (blue check).
+    Blue,
+    /// Verified organization (gold check).
+    Business,
+    /// Government or multilateral account (gray check).
+    Government,
+}
+
+impl Verification {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Blue => "verified",
+            Self::Business => "verified organization",
+            Self::Government => "government account",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -147,6 +458,62 @@ pub struct Media {
     pub alt_text: Option<String>,
 }
 
+// ---------------------------------------------------------------------------
+// Direct messages
+// ---------------------------------------------------------------------------
+
+/// One event in a DM conversation: a message (`MessageCreate`), or someone
+/// joining or leaving a group (`ParticipantsJoin`, `ParticipantsLeave`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DmEvent {
+    pub id: String,
+    pub event_type: String,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub sender_id: Option<String>,
+    #[serde(default)]
+    pub dm_conversation_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    /// Who joined or left, for participant events.
+    #[serde(default)]
+    pub participant_ids: Option<Vec<String>>,
+}
+
+// ---------------------------------------------------------------------------
+// List
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct List {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub private: Option<bool>,
+    #[serde(default)]
+    pub member_count: Option<u64>,
+    #[serde(default)]
+    pub follower_count: Option<u64>,
+    #[serde(default)]
+    pub owner_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// What `POST /lists` and `PUT /lists/:id` send. Fields that are `None`
+/// are left out, which keeps their current value when updating.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ListFields {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
+
 // ---------------------------------------------------------------------------
 // Entities
 // ---------------------------------------------------------------------------
@@ -217,7 +584,7 @@ pub struct Annotation {
 // Response metadata
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Includes {
     #[serde(default)]
     pub users: Option<Vec<User>>,
@@ -227,7 +594,7 @@ pub struct Includes {
     pub media: Option<Vec<Media>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Meta {
     #[serde(default)]
     pub result_count: Option<u32>,
@@ -239,8 +606,28 @@ pub struct Meta {
     pub newest_id: Option<String>,
     #[serde(default)]
     pub oldest_id: Option<String>,
+    /// Counts from a stream rules change.
+    #[serde(default)]
+    pub summary: Option<RulesSummary>,
+}
+
+/// `meta.summary` of a stream rules change: how many rules X created or
+/// deleted, and how many it didn't.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RulesSummary {
+    #[serde(default)]
+    pub created: u32,
+    #[serde(default)]
+    pub not_created: u32,
+    #[serde(default)]
+    pub deleted: u32,
+    #[serde(default)]
+    pub not_deleted: u32,
 }
 
+/// An entry in a response's `errors` array. On a successful response these
+/// describe partial failures, e.g. a referenced tweet that was deleted or
+/// withheld while the rest of the page loaded fine.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiError {
     #[serde(default)]
@@ -251,4 +638,24 @@ pub struct ApiError {
     pub type_: Option<String>,
     #[serde(default)]
     pub status: Option<u16>,
+    #[serde(default)]
+    pub resource_type: Option<String>,
+    #[serde(default)]
+    pub resource_id: Option<String>,
+    #[serde(default)]
+    pub parameter: Option<String>,
+}
+
+impl ApiError {
+    /// One-line human-readable description for notices and popups.
+    pub fn summary(&self) -> String {
+        match (self.title.as_deref(), self.detail.as_deref()) {
+            (Some(title), Some(detail)) => format!("{title}: {detail}"),
+            (Some(text), None) | (None, Some(text)) => text.to_string(),
+            (None, None) => match (self.resource_type.as_deref(), self.resource_id.as_deref()) {
+                (Some(kind), Some(id)) => format!("{kind} {id} unavailable"),
+                _ => "Unknown error".to_string(),
+            },
+        }
+    }
 }
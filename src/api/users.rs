@@ -1,16 +1,28 @@
-use crate::api::types::{ListResponse, SingleResponse, Tweet, User};
-use crate::api::{
-    ApiClientError, XApiClient, media_fields, tweet_expansions, tweet_fields, user_fields,
+use crate::api::request::RequestBuilder;
+use crate::api::types::{
+    BlockStatus, ListResponse, MuteStatus, SingleResponse, Tweet, Usage, User,
 };
+use crate::api::{ApiClientError, TimelineExclude, XApiClient};
 
 impl XApiClient {
     /// Look up a user by username.
     pub async fn get_user(&self, username: &str) -> Result<SingleResponse<User>, ApiClientError> {
-        let url = Self::url(&format!(
-            "/users/by/username/{username}?user.fields={}",
-            user_fields(),
-        ));
-        self.bearer_get(&url).await
+        let request =
+            RequestBuilder::new(&["users", "by", "username", username]).user_fields(&self.fields);
+        self.bearer_get(&self.url(&request)).await
+    }
+
+    /// The authenticated user.
+    pub async fn get_me(&self) -> Result<SingleResponse<User>, ApiClientError> {
+        let request = RequestBuilder::new(&["users", "me"]).user_fields(&self.fields);
+        self.oauth_get(&self.url(&request)).await
+    }
+
+    /// This month's post consumption against the project cap. Needs an
+    /// app-only bearer token.
+    pub async fn get_usage(&self) -> Result<SingleResponse<Usage>, ApiClientError> {
+        let request = RequestBuilder::new(&["usage", "tweets"]);
+        self.app_get(&self.url(&request)).await
     }
 
     /// Look up a user by numeric ID.
@@ -18,8 +30,56 @@ impl XApiClient {
         &self,
         user_id: &str,
     ) -> Result<SingleResponse<User>, ApiClientError> {
-        let url = Self::url(&format!("/users/{user_id}?user.fields={}", user_fields(),));
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["users", user_id]).user_fields(&self.fields);
+        self.bearer_get(&self.url(&request)).await
+    }
+
+    /// Mute a user for the authenticated user.
+    pub async fn mute_user(
+        &self,
+        target_user_id: &str,
+    ) -> Result<SingleResponse<MuteStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "muting"]);
+        self.oauth_post(
+            &self.url(&request),
+            &serde_json::json!({ "target_user_id": target_user_id }),
+        )
+        .await
+    }
+
+    /// Unmute a user for the authenticated user.
+    pub async fn unmute_user(
+        &self,
+        target_user_id: &str,
+    ) -> Result<SingleResponse<MuteStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "muting", target_user_id]);
+        self.oauth_delete(&self.url(&request)).await
+    }
+
+    /// Block a user for the authenticated user.
+    pub async fn block_user(
+        &self,
+        target_user_id: &str,
+    ) -> Result<SingleResponse<BlockStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "blocking"]);
+        self.oauth_post(
+            &self.url(&request),
+            &serde_json::json!({ "target_user_id": target_user_id }),
+        )
+        .await
+    }
+
+    /// Unblock a user for the authenticated user.
+    pub async fn unblock_user(
+        &self,
+        target_user_id: &str,
+    ) -> Result<SingleResponse<BlockStatus>, ApiClientError> {
+        let my_id = self.get_my_user_id().await?;
+        let request = RequestBuilder::new(&["users", &my_id, "blocking", target_user_id]);
+        self.oauth_delete(&self.url(&request)).await
     }
 
     /// Get a user's tweet timeline.
@@ -27,49 +87,31 @@ impl XApiClient {
         &self,
         user_id: &str,
         max_results: u32,
+        exclude: TimelineExclude,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
-        let max_results = max_results.clamp(10, 100);
-
-        let mut url = Self::url(&format!(
-            "/users/{user_id}/tweets?max_results={max_results}\
-             &tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        ));
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["users", user_id, "tweets"])
+            .max_results(max_results, 10..=100)
+            .exclude(exclude)
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 
     /// Get the authenticated user's reverse-chronological home timeline.
     pub async fn get_home_timeline(
-        &mut self,
+        &self,
         max_results: u32,
+        exclude: TimelineExclude,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
         let my_id = self.get_my_user_id().await?;
-        let max_results = max_results.clamp(10, 100);
-
-        let mut url = Self::url(&format!(
-            "/users/{my_id}/timelines/reverse_chronological?max_results={max_results}\
-             &tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        ));
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.oauth_get(&url).await
+        let request = RequestBuilder::new(&["users", &my_id, "timelines", "reverse_chronological"])
+            .max_results(max_results, 10..=100)
+            .exclude(exclude)
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.oauth_get(&self.url(&request)).await
     }
 
     /// Get a user's followers.
@@ -79,18 +121,11 @@ impl XApiClient {
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<User>, ApiClientError> {
-        let max_results = max_results.clamp(1, 1000);
-
-        let mut url = Self::url(&format!(
-            "/users/{user_id}/followers?max_results={max_results}&user.fields={}",
-            user_fields(),
-        ));
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["users", user_id, "followers"])
+            .max_results(max_results, 1..=1000)
+            .user_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 
     /// Get users that a user is following.
@@ -100,42 +135,24 @@ impl XApiClient {
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<User>, ApiClientError> {
-        let max_results = max_results.clamp(1, 1000);
-
-        let mut url = Self::url(&format!(
-            "/users/{user_id}/following?max_results={max_results}&user.fields={}",
-            user_fields(),
-        ));
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.bearer_get(&url).await
+        let request = RequestBuilder::new(&["users", user_id, "following"])
+            .max_results(max_results, 1..=1000)
+            .user_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.bearer_get(&self.url(&request)).await
     }
 
     /// Get the authenticated user's mentions.
     pub async fn get_mentions(
-        &mut self,
+        &self,
         max_results: u32,
         pagination_token: Option<&str>,
     ) -> Result<ListResponse<Tweet>, ApiClientError> {
         let my_id = self.get_my_user_id().await?;
-        let max_results = max_results.clamp(10, 100);
-
-        let mut url = Self::url(&format!(
-            "/users/{my_id}/mentions?max_results={max_results}\
-             &tweet.fields={}&expansions={}&user.fields={}&media.fields={}",
-            tweet_fields(),
-            tweet_expansions(),
-            user_fields(),
-            media_fields(),
-        ));
-
-        if let Some(token) = pagination_token {
-            url.push_str(&format!("&pagination_token={token}"));
-        }
-
-        self.oauth_get(&url).await
+        let request = RequestBuilder::new(&["users", &my_id, "mentions"])
+            .max_results(max_results, 10..=100)
+            .tweet_fields(&self.fields)
+            .pagination_token(pagination_token);
+        self.oauth_get(&self.url(&request)).await
     }
 }
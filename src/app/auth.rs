@@ -1,30 +1,52 @@
 use std::sync::Arc;
-use tokio::sync::Mutex;
 
 use ratatui::DefaultTerminal;
 
 use super::App;
-use crate::api::XApiClient;
-use crate::auth::AuthProvider;
-use crate::event::EventHandler;
+use crate::api::{FieldSets, XApiClient};
+use crate::auth::{AuthMethod, AuthProvider};
+use crate::event::{AppEvent, EventHandler};
 
 impl App {
     // -- Auth flow (suspends TUI) ------------------------------------------
 
-    pub(super) async fn run_auth_flow(&mut self, terminal: &mut DefaultTerminal) {
-        let Some(ref oauth2_creds) = self.credentials.oauth2 else {
-            self.status_message =
-                Some("OAuth 2.0 not configured. Set X_CLIENT_ID in your .env file.".into());
-            return;
-        };
-        let oauth2_creds = oauth2_creds.clone();
-
+    /// Sign in through the browser, asking for `extra_scopes` on top of the
+    /// usual ones. Returns whether a new API client is in place.
+    pub(super) async fn run_auth_flow(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        extra_scopes: &[String],
+    ) -> bool {
         // Suspend the TUI so the user can interact with their browser.
         ratatui::restore();
 
+        // No OAuth 2.0 app configured yet: walk through first-run setup.
+        let oauth2_creds = match self.credentials.oauth2.clone() {
+            Some(creds) => creds,
+            None => match crate::auth::credentials::run_setup_wizard() {
+                Ok(Some(creds)) => {
+                    self.credentials.oauth2 = Some(creds.clone());
+                    creds
+                }
+                Ok(None) => {
+                    *terminal = ratatui::init();
+                    self.events = EventHandler::new(&self.config);
+                    self.notify("Setup cancelled.");
+                    return false;
+                }
+                Err(e) => {
+                    *terminal = ratatui::init();
+                    self.events = EventHandler::new(&self.config);
+                    self.set_error(format!("Setup failed: {e}"));
+                    return false;
+                }
+            },
+        };
+
         let result = crate::auth::oauth2_pkce::start_pkce_flow(
             &oauth2_creds,
-            self.config.oauth_callback_port,
+            &self.config.oauth_callback_ports(),
+            extra_scopes,
         )
         .await;
 
@@ -45,25 +67,57 @@ impl App {
 
         // Re-initialize the terminal and event handler.
         *terminal = ratatui::init();
-        self.events = EventHandler::new();
+        self.events = EventHandler::new(&self.config);
 
         // On success, rebuild the API client with the new tokens.
         if result.is_ok() {
             match AuthProvider::new(self.credentials.clone()) {
                 Ok(auth) => {
-                    self.api_client = Some(Arc::new(Mutex::new(XApiClient::new(
-                        auth,
-                        self.config.oauth_callback_port,
-                    ))));
-                    self.status_message = Some("Authenticated successfully!".into());
+                    self.api_client = Some(Arc::new(
+                        XApiClient::new(auth, self.config.oauth_callback_port)
+                            .with_recording(self.config.recording.clone())
+                            .with_fields(FieldSets::from_config(&self.config.fields)),
+                    ));
+                    self.notify("Authenticated successfully!");
+                    self.events.send(AppEvent::RefreshView);
+                    return true;
                 }
                 Err(e) => {
-                    self.status_message = Some(format!("Auth provider error: {e}"));
+                    self.notify(format!("Auth provider error: {e}"));
                 }
             }
         } else if let Err(e) = result {
-            self.status_message = Some(format!("Auth failed: {e}"));
+            self.notify(format!("Auth failed: {e}"));
+        }
+        false
+    }
+
+    /// After `request` was refused for lack of `scope`, offer to sign in
+    /// again with it added and then re-send `request`. Only OAuth 2.0
+    /// tokens have scopes; otherwise the error's hint has to do.
+    pub(super) fn offer_scope_upgrade(&mut self, scope: &'static str, request: AppEvent) {
+        let oauth2 = self
+            .api_client
+            .as_ref()
+            .is_some_and(|c| c.auth_method() == AuthMethod::OAuth2Pkce);
+        if !oauth2 {
+            return;
         }
+        // The prompt explains the failure; the error toast stays up.
+        self.error_detail = None;
+        self.confirm(
+            "scope",
+            format!("Grant {scope}"),
+            format!(
+                "Your sign-in doesn't include the {scope} scope this needs. \
+                 Sign in again in the browser with it added (your other \
+                 scopes are kept) and retry?"
+            ),
+            AppEvent::UpgradeScope {
+                scope,
+                retry: Box::new(request),
+            },
+        );
     }
 
     // -- OpenRouter auth flow (suspends TUI) ---------------------------------
@@ -99,21 +153,21 @@ impl App {
 
         // Re-initialize the terminal and event handler.
         *terminal = ratatui::init();
-        self.events = EventHandler::new();
+        self.events = EventHandler::new(&self.config);
 
         // On success, create the OpenRouter client.
         if result.is_ok() {
             match crate::cli::build_openrouter_client() {
                 Ok(client) => {
                     self.openrouter_client = Some(Arc::new(client));
-                    self.status_message = Some("OpenRouter authenticated successfully!".into());
+                    self.notify("OpenRouter authenticated successfully!");
                 }
                 Err(e) => {
-                    self.status_message = Some(format!("OpenRouter client error: {e}"));
+                    self.notify(format!("OpenRouter client error: {e}"));
                 }
             }
         } else if let Err(e) = result {
-            self.status_message = Some(format!("OpenRouter auth failed: {e}"));
+            self.notify(format!("OpenRouter auth failed: {e}"));
         }
     }
 
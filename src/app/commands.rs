@@ -1,7 +1,10 @@
 use super::App;
-use super::dispatch::ChatProviderKind;
+use super::dispatch::{ChatProviderKind, full_text};
 use crate::command::{self, Command};
+use crate::config::PageKind;
 use crate::event::{AppEvent, ViewKind};
+use crate::ui::text::truncate_for_width;
+use crate::ui::theme::ThemeChoice;
 
 impl App {
     // -- Command execution --------------------------------------------------
@@ -12,7 +15,8 @@ impl App {
             Some(Command::User(username)) => {
                 self.events.send(AppEvent::FetchUser { username });
             }
-            Some(Command::Search(query)) => {
+            Some(Command::Search(query, max_results)) => {
+                self.search_max_results = max_results.map(|n| (query.clone(), n));
                 self.search_query = query.clone();
                 self.events.send(AppEvent::FetchSearch {
                     query,
@@ -20,22 +24,45 @@ impl App {
                 });
                 self.events.send(AppEvent::SwitchView(ViewKind::Search));
             }
+            Some(Command::Local(query)) => {
+                self.search_query = query.clone();
+                self.events.send(AppEvent::SearchLocal { query });
+                self.events.send(AppEvent::SwitchView(ViewKind::Search));
+            }
             Some(Command::Open(url_or_id)) => {
                 if let Some(tweet_id) = command::parse_tweet_url(&url_or_id) {
                     self.events.send(AppEvent::FetchTweet { tweet_id });
                 } else {
-                    self.status_message = Some(format!("Invalid tweet URL or ID: {url_or_id}"));
+                    self.notify(format!("Invalid tweet URL or ID: {url_or_id}"));
+                }
+            }
+            Some(Command::Goto(url_or_id)) => {
+                if let Some(tweet_id) = command::parse_tweet_url(&url_or_id) {
+                    self.goto_tweet(tweet_id);
+                } else {
+                    self.notify(format!("Invalid tweet URL or ID: {url_or_id}"));
                 }
             }
-            Some(Command::Home) => {
-                self.events.send(AppEvent::SwitchView(ViewKind::Home));
+            Some(Command::Pipe(command)) => {
+                self.pipe_selection(command);
+            }
+            Some(Command::Home(max_results)) => {
+                self.switch_list_view(ViewKind::Home, PageKind::Home, max_results);
             }
-            Some(Command::Mentions) => {
-                self.events.send(AppEvent::SwitchView(ViewKind::Mentions));
+            Some(Command::Mentions(max_results)) => {
+                self.switch_list_view(ViewKind::Mentions, PageKind::Mentions, max_results);
             }
-            Some(Command::Bookmarks) => {
-                self.events.send(AppEvent::SwitchView(ViewKind::Bookmarks));
+            Some(Command::Bookmarks(max_results)) => {
+                self.switch_list_view(ViewKind::Bookmarks, PageKind::Bookmarks, max_results);
             }
+            Some(Command::History) => {
+                self.events.send(AppEvent::PushView(ViewKind::History));
+            }
+            Some(Command::Drafts) => {
+                self.events.send(AppEvent::PushView(ViewKind::Drafts));
+            }
+            Some(Command::Domains(all)) => self.show_domains(all),
+            Some(Command::Tags) => self.show_tags(),
             Some(Command::Help) => {
                 self.events.send(AppEvent::PushView(ViewKind::Help));
             }
@@ -77,13 +104,14 @@ impl App {
             Some(Command::Topics) => {
                 self.events.send(AppEvent::GenerateClusterTopics);
             }
+            Some(Command::Similar) => self.find_similar(),
             Some(Command::Provider(arg)) => match arg.as_deref() {
                 Some("mlx") => {
                     self.preferred_chat_provider = Some(ChatProviderKind::Mlx);
                     // Always re-probe — the result arrives asynchronously via
                     // MLXCapabilitiesProbed, which updates flags and shows status.
                     self.events.send(AppEvent::ProbeMLXCapabilities);
-                    self.status_message = Some("Preferred provider: MLX. Probing server...".into());
+                    self.notify("Preferred provider: MLX. Probing server...");
                 }
                 Some("openrouter" | "or") => {
                     self.preferred_chat_provider = Some(ChatProviderKind::OpenRouter);
@@ -91,13 +119,11 @@ impl App {
                         let model = self
                             .resolved_chat_model()
                             .unwrap_or_else(|| "(none selected)".into());
-                        self.status_message =
-                            Some(format!("Chat provider set to OpenRouter: {model}"));
+                        self.notify(format!("Chat provider set to OpenRouter: {model}"));
                     } else {
-                        self.status_message = Some(
+                        self.notify(
                             "OpenRouter chat not available. Use :openrouter-auth \
-                                 and :openrouter-models first."
-                                .into(),
+                                 and :openrouter-models first.",
                         );
                     }
                 }
@@ -105,12 +131,11 @@ impl App {
                     self.preferred_chat_provider = None;
                     // Re-probe in case MLX server started after the TUI.
                     self.events.send(AppEvent::ProbeMLXCapabilities);
-                    self.status_message =
-                        Some("Preferred provider: auto. Probing MLX server...".into());
+                    self.notify("Preferred provider: auto. Probing MLX server...");
                 }
                 _ => {
                     let current = self.resolved_chat_provider_name().unwrap_or("none");
-                    self.status_message = Some(format!(
+                    self.notify(format!(
                         "Active: {current}. Usage: :provider <mlx|openrouter|auto>"
                     ));
                 }
@@ -118,13 +143,238 @@ impl App {
             Some(Command::Refresh) => {
                 self.events.send(AppEvent::RefreshView);
             }
+            Some(Command::Retry) => self.retry_failed_request(),
+            Some(Command::Perf) => {
+                self.perf_overlay = !self.perf_overlay;
+            }
+            Some(Command::CacheStats) => match crate::store::cache_stats() {
+                Ok(stats) => self.notify(format!("Cache: {stats}")),
+                Err(e) => self.set_error(format!("Cache stats failed: {e}")),
+            },
+            Some(Command::Doctor) => {
+                self.doctor_report = Some(crate::doctor::run_checks());
+            }
+            Some(Command::Usage) => self.events.send(AppEvent::FetchUsage),
+            Some(Command::Mute(username)) => self.mute_user(username, true),
+            Some(Command::Unmute(username)) => self.mute_user(username, false),
+            Some(Command::Block(username)) => self.block_user(username, true),
+            Some(Command::Unblock(username)) => self.block_user(username, false),
+            Some(Command::MuteThread) => self.mute_thread(true),
+            Some(Command::UnmuteThread) => self.mute_thread(false),
+            Some(Command::Set(key, value)) => self.set_option(&key, &value),
+            Some(Command::List(command)) => self.run_list_command(command),
+            Some(Command::Rules(command)) => self.run_rules_command(command),
+            Some(Command::Stream(stop)) => self.run_stream_command(stop),
+            Some(Command::Messages) => {
+                self.events.send(AppEvent::SwitchView(ViewKind::Messages));
+            }
+            Some(Command::Dm(username)) => self.run_dm_command(username),
             Some(Command::Quit) => {
                 self.events.send(AppEvent::Quit);
             }
             None => {
-                self.status_message = Some(format!("Unknown command: {input}"));
+                self.notify(format!("Unknown command: {input}"));
             }
         }
         self.command_input.clear();
     }
+
+    /// Switch to a list view. A `--max` page size sticks for the session
+    /// and reloads the view so the pages already loaded match it.
+    fn switch_list_view(&mut self, view: ViewKind, kind: PageKind, max_results: Option<u32>) {
+        if self.refuse_without_user_context(&view) {
+            return;
+        }
+        self.events.send(AppEvent::SwitchView(view));
+        if let Some(max_results) = max_results {
+            self.config.max_results.set(kind, max_results);
+            self.events.send(AppEvent::RefreshView);
+        }
+    }
+
+    /// Apply a `:set <key> <value>` command.
+    fn set_option(&mut self, key: &str, value: &str) {
+        match key {
+            "tick_rate_fps" => match value.parse::<f64>() {
+                Ok(fps) if fps.is_finite() && (1.0..=240.0).contains(&fps) => {
+                    self.config.tick_rate_fps = fps;
+                    self.events.set_tick_rate(fps);
+                    self.notify(format!("tick_rate_fps = {fps}"));
+                }
+                _ => {
+                    self.notify(format!("Invalid tick_rate_fps: {value} (expected 1-240)"));
+                }
+            },
+            "theme" => match ThemeChoice::parse(value) {
+                Some(choice) => {
+                    self.config.theme = choice;
+                    self.palette = choice.resolve(&self.term_caps);
+                    self.notify(format!("theme = {}", self.palette.name()));
+                }
+                None => {
+                    self.notify(format!(
+                        "Unknown theme: {value} (auto, default, basic16, high-contrast)"
+                    ));
+                }
+            },
+            "exclude_replies" | "exclude_retweets" => {
+                let current = if key == "exclude_replies" {
+                    &mut self.config.exclude_replies
+                } else {
+                    &mut self.config.exclude_retweets
+                };
+                let Some(enabled) = parse_switch(value, *current) else {
+                    self.notify(format!("Invalid {key}: {value} (on, off or toggle)"));
+                    return;
+                };
+                *current = enabled;
+                self.notify(format!("{key} = {enabled}"));
+                // Page tokens from before the change would mix the two
+                // filters, so reload the affected timeline from the top.
+                if matches!(
+                    self.current_view(),
+                    Some(ViewKind::Home | ViewKind::UserProfile(_))
+                ) {
+                    self.refresh_current_view();
+                }
+            }
+            _ => {
+                self.notify(format!("Unknown setting: {key}"));
+            }
+        }
+    }
+
+    /// Show the bookmarks and stored tweets most like the selected one in
+    /// the Search view, as `:local` does.
+    fn find_similar(&mut self) {
+        let Some(tweet) = self.selected_tweet().cloned() else {
+            self.notify("Select a tweet to find ones like it");
+            return;
+        };
+        let text = full_text(&tweet);
+        if text.trim().is_empty() {
+            self.notify("This tweet has no text to compare");
+            return;
+        }
+        if !self.has_embed_provider() {
+            self.set_error(
+                "No embedding provider configured. Set mlx_server_url in config \
+                 or use :openrouter-auth + :embeddings."
+                    .into(),
+            );
+            return;
+        }
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let query = format!("like \"{}\"", truncate_for_width(&text, 40));
+        self.search_query = query.clone();
+        self.events.send(AppEvent::FindSimilar {
+            query,
+            tweet: Box::new(tweet),
+        });
+        self.events.send(AppEvent::SwitchView(ViewKind::Search));
+    }
+
+    /// Mute or unmute the open thread's conversation. Muting also drops its
+    /// tweets from the Home and Mentions already loaded.
+    fn mute_thread(&mut self, mute: bool) {
+        let Some(ViewKind::Thread(view_id)) = self.current_view() else {
+            self.notify("Open a thread first");
+            return;
+        };
+        let conversation_id = self
+            .thread_root
+            .as_ref()
+            .and_then(|t| t.conversation_id.clone())
+            .unwrap_or_else(|| view_id.clone());
+
+        match self.muted_conversations.set(conversation_id, mute) {
+            Ok(true) => {}
+            Ok(false) => {
+                self.notify(if mute {
+                    "Thread is already muted"
+                } else {
+                    "Thread isn't muted"
+                });
+                return;
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to save muted threads: {e}"));
+                return;
+            }
+        }
+        if !mute {
+            self.notify("Unmuted thread");
+            return;
+        }
+
+        let muted = &self.muted_conversations;
+        for tl in [&mut self.home_timeline, &mut self.mentions] {
+            tl.tweets.retain(|t| !muted.hides(t));
+            let last = tl.tweets.len().saturating_sub(1);
+            tl.selected_index = tl.selected_index.min(last);
+            tl.scroll_offset = tl.scroll_offset.min(last);
+        }
+        for vs in &mut self.view_stack {
+            let len = match vs.kind {
+                ViewKind::Home => self.home_timeline.tweets.len(),
+                ViewKind::Mentions => self.mentions.tweets.len(),
+                _ => continue,
+            };
+            vs.selected_index = vs.selected_index.min(len.saturating_sub(1));
+            vs.scroll_offset = vs.scroll_offset.min(len.saturating_sub(1));
+        }
+        self.notify("Muted thread: hidden from Home and Mentions");
+    }
+
+    /// Mute or unmute `username` on X. The open profile's ID is reused so
+    /// muting from it needs no lookup.
+    pub(super) fn mute_user(&mut self, username: String, mute: bool) {
+        let user_id = self.viewed_user_id(&username);
+        self.events.send(AppEvent::MuteUser {
+            username,
+            user_id,
+            mute,
+        });
+    }
+
+    /// Block or unblock `username` on X. Blocking also unfollows in both
+    /// directions, so it asks first; unblocking doesn't.
+    pub(super) fn block_user(&mut self, username: String, block: bool) {
+        let user_id = self.viewed_user_id(&username);
+        let title = format!("Block @{username}");
+        let event = AppEvent::BlockUser {
+            username,
+            user_id,
+            block,
+        };
+        if !block {
+            self.events.send(event);
+            return;
+        }
+        self.confirm(
+            "block",
+            title,
+            "They will be unfollowed and won't be able to follow you or see your posts.",
+            event,
+        );
+    }
+
+    /// The open profile's ID, if it is `username`'s.
+    fn viewed_user_id(&self, username: &str) -> Option<String> {
+        self.viewed_user
+            .as_ref()
+            .filter(|u| u.username.eq_ignore_ascii_case(username))
+            .map(|u| u.id.clone())
+    }
+}
+
+/// A boolean `:set` value: `on`/`off` (or `true`/`false`), or `toggle` to
+/// flip `current`.
+fn parse_switch(value: &str, current: bool) -> Option<bool> {
+    match value {
+        "on" | "true" => Some(true),
+        "off" | "false" => Some(false),
+        "toggle" => Some(!current),
+        _ => None,
+    }
 }
@@ -0,0 +1,89 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{App, AppMode};
+use crate::event::AppEvent;
+
+/// A pending yes/no question in front of a destructive action. Confirming
+/// sends `on_confirm`; cancelling drops it.
+#[derive(Debug, Clone)]
+pub struct ConfirmDialog {
+    /// Identifies the action for the don't-ask-again list (`skip_confirm`).
+    pub kind: &'static str,
+    pub title: String,
+    pub message: String,
+    pub on_confirm: AppEvent,
+    /// Which button has focus. Starts on No.
+    pub yes_selected: bool,
+    /// Skip this kind of prompt for the rest of the session once confirmed.
+    pub dont_ask_again: bool,
+    /// Mode to return to once answered.
+    return_mode: AppMode,
+}
+
+impl App {
+    /// Ask before sending `on_confirm`, unless `kind` is in the
+    /// don't-ask-again list, in which case it is sent right away.
+    pub(super) fn confirm(
+        &mut self,
+        kind: &'static str,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        on_confirm: AppEvent,
+    ) {
+        if self.config.skip_confirm.iter().any(|k| k == kind) {
+            self.events.send(on_confirm);
+            return;
+        }
+        let (title, message) = (title.into(), message.into());
+        if self.config.accessible {
+            // Screen readers pick up the status line, not the popup.
+            self.notify(format!("{title}? {message} (y/n)"));
+        }
+        self.confirm = Some(ConfirmDialog {
+            kind,
+            title,
+            message,
+            on_confirm,
+            yes_selected: false,
+            dont_ask_again: false,
+            return_mode: self.mode.clone(),
+        });
+        self.mode = AppMode::Confirm;
+    }
+
+    pub(super) fn handle_confirm_key(&mut self, key: KeyEvent) {
+        let Some(dialog) = self.confirm.as_mut() else {
+            self.mode = AppMode::Normal;
+            return;
+        };
+        match key.code {
+            KeyCode::Char('y' | 'Y') => self.answer_confirm(true),
+            KeyCode::Char('n' | 'N' | 'q') | KeyCode::Esc => self.answer_confirm(false),
+            KeyCode::Enter => {
+                let yes = dialog.yes_selected;
+                self.answer_confirm(yes);
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::Char('h' | 'l') => {
+                dialog.yes_selected = !dialog.yes_selected;
+            }
+            KeyCode::Char('d' | ' ') => {
+                dialog.dont_ask_again = !dialog.dont_ask_again;
+            }
+            _ => {}
+        }
+    }
+
+    fn answer_confirm(&mut self, accepted: bool) {
+        let Some(dialog) = self.confirm.take() else {
+            return;
+        };
+        self.mode = dialog.return_mode;
+        if !accepted {
+            return;
+        }
+        if dialog.dont_ask_again {
+            self.config.skip_confirm.push(dialog.kind.to_string());
+        }
+        self.events.send(dialog.on_confirm);
+    }
+}
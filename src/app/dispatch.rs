@@ -1,16 +1,47 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-
-use super::{App, ClusterSource};
-use crate::api::types::{Includes, Tweet, User};
-use crate::event::{ApiResult, AppEvent, Event, ViewKind};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
+
+use super::{App, BulkRun, ClusterSource};
+use crate::api::error::{AccountState, XApiError};
+use crate::api::stream::Backoff;
+use crate::api::types::{ApiResponse, Includes, List, ListFields, NewStreamRule, Tweet, User};
+use crate::api::{ApiClientError, XApiClient};
+use crate::config::PageKind;
+use crate::domains::DomainReport;
+use crate::event::{
+    ApiResult, AppEvent, BulkAction, Event, PendingPost, StreamStatus, TweetUsersKind, ViewKind,
+};
 use crate::mlx::client::MlxClient;
 use crate::openrouter;
 use crate::openrouter::client::OpenRouterClient;
 use crate::openrouter::types::{EmbeddingResponse, Model};
+use crate::perf::{self, PerfStats, RequestRecorder};
+use crate::store::{self, WatchDelivery};
 
 const DEFAULT_MLX_EMBEDDING_MODEL: &str = "mlx-community/Qwen3-Embedding-0.6B-mxfp8";
 const DEFAULT_MLX_CHAT_MODEL: &str = "mlx-community/Qwen3.5-0.8B-OptiQ-4bit";
 
+/// Pause between writes in a bulk action, so a long sweep doesn't burst
+/// through the (small) per-user write limits.
+const BULK_PACING: Duration = Duration::from_secs(1);
+
+/// Times a single write is retried after waiting out a rate limit.
+const BULK_RATE_LIMIT_RETRIES: usize = 3;
+
+/// Posts fetched for the thread view's author preview (the endpoint minimum).
+const AUTHOR_PREVIEW_SIZE: u32 = 10;
+
+/// Pages of a user's timeline fetched for the posting heatmap, at the
+/// largest page size: up to 300 tweets, enough to show a pattern.
+const HEATMAP_PAGES: usize = 3;
+const HEATMAP_PAGE_SIZE: u32 = 100;
+
 /// Identifies which chat provider the user prefers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChatProviderKind {
@@ -97,6 +128,25 @@ impl App {
         });
     }
 
+    /// Rank the bookmarks and stored tweets by how close their embeddings
+    /// are to `tweet`'s. Vectors are kept in the local store, so only
+    /// tweets not seen before are sent to the provider.
+    pub(super) fn dispatch_similar(&self, query: String, tweet: Tweet) {
+        let Some((provider, model)) = self.resolve_embed_provider() else {
+            return;
+        };
+        let bookmarks = self.bookmarks.tweets.clone();
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let result = find_similar(&provider, &model, tweet, bookmarks).await;
+            let _ = sender.send(Event::App(Box::new(AppEvent::SimilarLoaded {
+                query,
+                result,
+            })));
+        });
+    }
+
     pub(super) fn dispatch_cluster_timeline(&self) {
         // Try to resolve an embed provider now; if none is available but
         // an MLX client exists, pass it along so the async task can
@@ -257,6 +307,384 @@ impl App {
         });
     }
 
+    pub(super) fn dispatch_profile_banner(&self, url: String) {
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = crate::media::fetch_image(&crate::media::banner_url(&url))
+                .await
+                .map_err(Arc::new);
+            let _ = sender.send(Event::App(Box::new(AppEvent::ProfileBannerLoaded {
+                url,
+                result,
+            })));
+        });
+    }
+
+    pub(super) fn dispatch_author_preview(&self, user_id: String) {
+        let Some(ref client) = self.api_client else {
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        let exclude = self.config.timeline_exclude();
+        tokio::spawn(async move {
+            let result = client
+                .get_timeline(&user_id, AUTHOR_PREVIEW_SIZE, exclude, None)
+                .await
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::AuthorPreviewLoaded {
+                user_id,
+                result,
+            })));
+        });
+    }
+
+    /// Search the local store for `query` off the UI thread, since it
+    /// decompresses every snapshot and spill file.
+    pub(super) fn dispatch_local_search(&self, query: String) {
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let terms = query.clone();
+            let (tweets, includes) = tokio::task::spawn_blocking(move || {
+                let tweets = store::search_local(&terms);
+                let includes = store::archived_includes(&tweets);
+                (tweets, includes)
+            })
+            .await
+            .unwrap_or_default();
+            let _ = sender.send(Event::App(Box::new(AppEvent::LocalSearchLoaded {
+                query,
+                tweets,
+                includes,
+            })));
+        });
+    }
+
+    /// Count the domains linked from every stored tweet (snapshots, spilled
+    /// and archived) on a blocking thread.
+    pub(super) fn dispatch_store_domains(&self) {
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let report = tokio::task::spawn_blocking(|| {
+                let tweets = store::archived_tweets(usize::MAX);
+                DomainReport::new("the local store".into(), &tweets)
+            })
+            .await
+            .unwrap_or_default();
+            let _ = sender.send(Event::App(Box::new(AppEvent::DomainsCounted(report))));
+        });
+    }
+
+    /// Page through `user_id`'s timeline and report when each tweet was
+    /// posted. A failure after the first page keeps what was fetched.
+    pub(super) fn dispatch_posting_activity(&self, user_id: String) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::PostingActivityLoaded {
+                user_id,
+                result: Err(Arc::new(
+                    "No API client configured. Use :auth first.".into(),
+                )),
+            });
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        let exclude = self.config.timeline_exclude();
+        tokio::spawn(async move {
+            let mut times = Vec::new();
+            let mut token: Option<String> = None;
+            let mut result = Ok(());
+            for _ in 0..HEATMAP_PAGES {
+                match client
+                    .get_timeline(&user_id, HEATMAP_PAGE_SIZE, exclude, token.as_deref())
+                    .await
+                {
+                    Ok(resp) => {
+                        times.extend(resp.data.iter().flatten().filter_map(|t| t.created_at));
+                        token = resp.meta.and_then(|m| m.next_token);
+                        if token.is_none() {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        result = Err(Arc::new(e.with_remediation()));
+                        break;
+                    }
+                }
+            }
+            let result = match result {
+                Err(e) if times.is_empty() => Err(e),
+                _ => Ok(times),
+            };
+            let _ = sender.send(Event::App(Box::new(AppEvent::PostingActivityLoaded {
+                user_id,
+                result,
+            })));
+        });
+    }
+
+    pub(super) fn dispatch_account_summary(&self) {
+        let Some(ref client) = self.api_client else {
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let (me, usage) = tokio::join!(client.get_me(), client.get_usage());
+            let _ = sender.send(Event::App(Box::new(AppEvent::AccountSummaryLoaded {
+                me: me.map_err(|e| Arc::new(e.to_string())),
+                usage: usage.map_err(|e| Arc::new(e.to_string())),
+            })));
+        });
+    }
+
+    pub(super) fn dispatch_link_preview(&self, url: String) {
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = crate::link_preview::fetch(&url).await.map_err(Arc::new);
+            let _ = sender.send(Event::App(Box::new(AppEvent::LinkPreviewLoaded {
+                url,
+                result,
+            })));
+        });
+    }
+
+    /// Send the tweets of a freshly loaded page that match a
+    /// `[[watch.rules]]` entry to the rules' sinks, each tweet to each sink
+    /// only once, across restarts too (see [`store::WatchDelivery`]). A
+    /// delivery counts as made while it's in flight; a sink that fails is
+    /// tried again on the next load, without the ones that took the tweet.
+    pub(super) fn dispatch_watch_sinks(&mut self, tweets: &[Tweet], includes: &Option<Includes>) {
+        let mut pending = Vec::new();
+        for tweet in tweets {
+            let author = tweet
+                .author_id
+                .as_deref()
+                .and_then(|id| self.lookup_user(id));
+            for sink in crate::sinks::matching_sinks(&self.config.watch, tweet, author) {
+                let delivery = WatchDelivery {
+                    tweet_id: tweet.id.clone(),
+                    sink: sink.key(),
+                };
+                if self.delivered_watched.insert(delivery) {
+                    pending.push((tweet.clone(), sink));
+                }
+            }
+        }
+        if pending.is_empty() {
+            return;
+        }
+        let includes = includes.clone();
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let failures = crate::sinks::deliver(&pending, &includes).await;
+            let delivered: Vec<WatchDelivery> = pending
+                .iter()
+                .filter(|(tweet, sink)| {
+                    !failures
+                        .iter()
+                        .any(|f| f.tweet_id == tweet.id && &f.sink == sink)
+                })
+                .map(|(tweet, sink)| WatchDelivery {
+                    tweet_id: tweet.id.clone(),
+                    sink: sink.key(),
+                })
+                .collect();
+            if !delivered.is_empty() {
+                let recorded =
+                    tokio::task::spawn_blocking(move || store::record_watch_deliveries(&delivered))
+                        .await;
+                if let Ok(Err(e)) = recorded {
+                    tracing::warn!("couldn't record watch rule deliveries: {e}");
+                }
+            }
+            if !failures.is_empty() {
+                let _ = sender.send(Event::App(Box::new(AppEvent::WatchSinksFailed(failures))));
+            }
+        });
+    }
+
+    pub(super) fn dispatch_lists(&self) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::ListsLoaded(Err(Arc::new(
+                "No API client configured. Use :auth first.".into(),
+            ))));
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let result = tokio::try_join!(
+                client.get_owned_lists(100, None),
+                client.get_followed_lists(100, None),
+            )
+            .map(|(owned, followed)| {
+                (
+                    owned.data.unwrap_or_default(),
+                    followed.data.unwrap_or_default(),
+                )
+            })
+            .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::ListsLoaded(result))));
+        });
+    }
+
+    pub(super) fn dispatch_list_members(&self, list_id: String) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::ListMembersLoaded {
+                list_id,
+                result: Err(Arc::new(
+                    "No API client configured. Use :auth first.".into(),
+                )),
+            });
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let result = client
+                .get_list_members(&list_id, 100, None)
+                .await
+                .map(|resp| resp.data.unwrap_or_default())
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::ListMembersLoaded {
+                list_id,
+                result,
+            })));
+        });
+    }
+
+    pub(super) fn dispatch_tweet_users(&self, tweet_id: String, kind: TweetUsersKind) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::TweetUsersLoaded {
+                tweet_id,
+                kind,
+                result: Err(Arc::new(
+                    "No API client configured. Use :auth first.".into(),
+                )),
+            });
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let resp = match kind {
+                TweetUsersKind::RetweetedBy => client.get_retweeted_by(&tweet_id, 100, None).await,
+                TweetUsersKind::LikingUsers => client.get_liking_users(&tweet_id, 100, None).await,
+            };
+            let result = resp
+                .map(|resp| resp.data.unwrap_or_default())
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::TweetUsersLoaded {
+                tweet_id,
+                kind,
+                result,
+            })));
+        });
+    }
+
+    pub(super) fn dispatch_stream_rules(&self) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::StreamRulesLoaded(Err(Arc::new(
+                "No API client configured. Use :auth first.".into(),
+            ))));
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let result = client
+                .get_stream_rules()
+                .await
+                .map(|resp| resp.data.unwrap_or_default())
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::StreamRulesLoaded(result))));
+        });
+    }
+
+    pub(super) fn dispatch_usage(&self) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::UsageLoaded(Err(Arc::new(
+                "No API client configured. Use :auth first.".into(),
+            ))));
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let result = client
+                .get_usage()
+                .await
+                .map(|resp| resp.data.unwrap_or_default())
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::UsageLoaded(result))));
+        });
+    }
+
+    pub(super) fn dispatch_add_stream_rules(&mut self, rules: Vec<NewStreamRule>) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = client
+                .add_stream_rules(&rules)
+                .await
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::StreamRulesAdded(result))));
+        });
+    }
+
+    pub(super) fn dispatch_delete_stream_rules(&mut self, ids: Vec<String>) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = client
+                .delete_stream_rules(&ids)
+                .await
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let _ = sender.send(Event::App(Box::new(AppEvent::StreamRulesDeleted {
+                ids,
+                result,
+            })));
+        });
+    }
+
+    /// Connect to the filtered stream in a task that keeps reconnecting
+    /// until [`App::stop_stream`] cancels it.
+    pub(super) fn dispatch_filtered_stream(&mut self) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        let cancel = CancellationToken::new();
+        if let Some(previous) = self.stream_cancel.replace(cancel.clone()) {
+            previous.cancel();
+        }
+        self.stream_generation += 1;
+        let generation = self.stream_generation;
+        self.stream_status = StreamStatus::Connecting;
+        tokio::spawn(async move {
+            cancel
+                .run_until_cancelled(run_filtered_stream(&client, &sender, generation))
+                .await;
+        });
+    }
+
     pub(super) fn dispatch_hf_models(&self) {
         let sender = self.events.sender();
         let search = self.hf_search.clone();
@@ -348,189 +776,1042 @@ impl App {
                     )
                     .await?;
 
-                let choice = resp
-                    .choices
-                    .first()
-                    .ok_or_else(|| Arc::new("Chat model returned no choices".to_string()))?;
+                let content = completion_content(&resp)?;
+                let labels = parse_cluster_topic_labels(&content, num_clusters);
 
-                // Only use `content` -- reasoning/reasoning_content are
-                // chain-of-thought fields and must NOT be treated as output.
-                let raw = choice.message.content.clone().ok_or_else(|| {
-                    let reason = choice.finish_reason.as_deref().unwrap_or("unknown");
-                    if reason == "length" {
-                        Arc::new(
-                            "Model exhausted token budget on reasoning \
-                             before producing content (finish_reason: length)"
-                                .to_string(),
-                        )
-                    } else {
-                        Arc::new(format!(
-                            "Chat model returned null content \
-                             (finish_reason: {reason})"
-                        ))
-                    }
-                })?;
-
-                // Strip <think>...</think> blocks that reasoning models
-                // may embed in content.
-                let content = openrouter::strip_think_tags(&raw);
-
-                if content.trim().is_empty() {
-                    return Err(Arc::new(
-                        "Chat model returned empty content \
-                         (after stripping reasoning tags)"
-                            .to_string(),
-                    ));
+                if labels.iter().all(|l| l.is_empty()) {
+                    return Err(Arc::new(format!(
+                        "No labels parsed from response: {content}"
+                    )));
+                }
+
+                Ok(labels)
+            }
+            .await;
+
+            let _ = sender.send(Event::App(Box::new(AppEvent::ClusterTopicsGenerated(
+                generation, result,
+            ))));
+        });
+    }
+
+    /// Ask the chat model for reply drafts to `tweet_id`. Nothing is
+    /// posted; the drafts are only offered in the composer.
+    pub(super) fn dispatch_reply_drafts(
+        &self,
+        tweet_id: String,
+        messages: Vec<openrouter::types::ChatMessage>,
+    ) {
+        let Some((provider, model)) = self.resolve_chat_provider() else {
+            self.events.send(AppEvent::ReplyDraftsGenerated {
+                tweet_id,
+                result: Err(Arc::new(
+                    "No chat provider configured. Set mlx_server_url in config \
+                     or use :openrouter-auth + :openrouter-models."
+                        .into(),
+                )),
+            });
+            return;
+        };
+        let sender = self.events.sender();
+
+        tokio::spawn(async move {
+            let result = async {
+                use crate::openrouter::types::ReasoningConfig;
+                let resp = provider
+                    .chat_completion(
+                        &model,
+                        messages,
+                        Some(600),
+                        Some(0.8),
+                        Some(ReasoningConfig { exclude: true }),
+                    )
+                    .await?;
+                let content = completion_content(&resp)?;
+                let drafts = super::reply_drafts::parse_reply_drafts(&content);
+                if drafts.is_empty() {
+                    return Err(Arc::new(format!(
+                        "No drafts parsed from response: {content}"
+                    )));
                 }
+                Ok(drafts)
+            }
+            .await;
+
+            let _ = sender.send(Event::App(Box::new(AppEvent::ReplyDraftsGenerated {
+                tweet_id,
+                result,
+            })));
+        });
+    }
+
+    // -- API dispatch -------------------------------------------------------
+
+    pub(super) fn dispatch_api_request(&self, event: AppEvent) {
+        let Some(ref client) = self.api_client else {
+            // No API client -- emit the matching *Loaded(Err) so loading
+            // flags get cleared through the normal response path.
+            let err: Arc<String> = Arc::new("No API client configured. Use :auth first.".into());
+            if let Some(response) = event.into_error_response(err) {
+                self.events.send(response);
+            }
+            return;
+        };
+
+        // Coalesce identical in-flight requests: the pending response updates
+        // the same state, so a second network call would only duplicate it.
+        let requested_from = self.current_view().cloned();
+        let Some(guard) =
+            InFlightGuard::acquire(&self.in_flight, &event, requested_from.clone(), &self.perf)
+        else {
+            tracing::debug!(?event, "coalesced duplicate in-flight request");
+            return;
+        };
+
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        let max_results = match event {
+            AppEvent::FetchHomeTimeline { .. } => self.config.max_results_for(PageKind::Home),
+            AppEvent::FetchMentions { .. } => self.config.max_results_for(PageKind::Mentions),
+            AppEvent::FetchBookmarks { .. } => self.config.max_results_for(PageKind::Bookmarks),
+            AppEvent::FetchSearch { ref query, .. } => match &self.search_max_results {
+                Some((for_query, n)) if for_query == query => *n,
+                _ => self.config.max_results_for(PageKind::Search),
+            },
+            AppEvent::FetchUserTimeline { .. } => {
+                self.config.max_results_for(PageKind::UserTimeline)
+            }
+            AppEvent::FetchThread { .. } => self.config.max_results_for(PageKind::Thread),
+            AppEvent::FetchFollowers { .. } | AppEvent::FetchFollowing { .. } => {
+                self.config.max_results_for(PageKind::Follows)
+            }
+            _ => self.config.default_max_results,
+        };
+        let exclude = self.config.timeline_exclude();
+
+        let span = tracing::info_span!("fetch", request = %guard.key);
+        let cancel = guard.cancel.clone();
+
+        let task = async move {
+            /// Map an API result to an `AppEvent` and send it through the channel.
+            /// Failures travel with the `request` that caused them so it can
+            /// be retried.
+            ///
+            /// The in-flight guard is released first so a handler reacting to
+            /// this response can immediately issue the same request again.
+            fn send_result<T: Send + 'static>(
+                guard: InFlightGuard,
+                sender: &tokio::sync::mpsc::UnboundedSender<Event>,
+                request: AppEvent,
+                result: Result<T, ApiClientError>,
+                wrap: impl FnOnce(ApiResult<T>) -> AppEvent,
+            ) {
+                drop(guard);
+                let failed = result.is_err();
+                let scope = result
+                    .as_ref()
+                    .err()
+                    .and_then(ApiClientError::missing_scope);
+                let mapped = result.map_err(|e| Arc::new(e.with_remediation()));
+                let mut response = wrap(mapped);
+                if failed {
+                    response = offer_scope(
+                        scope,
+                        request.clone(),
+                        AppEvent::RequestFailed {
+                            request: Box::new(request),
+                            response: Box::new(response),
+                        },
+                    );
+                }
+                let _ = sender.send(Event::App(Box::new(response)));
+            }
+
+            /// `send_result` for user lookups and timelines, except that a
+            /// protected, suspended or missing account is reported as
+            /// `AccountUnavailable` so `view` can explain it.
+            fn send_account_result<T: Send + 'static>(
+                guard: InFlightGuard,
+                sender: &tokio::sync::mpsc::UnboundedSender<Event>,
+                request: AppEvent,
+                view: ViewKind,
+                requested_from: Option<ViewKind>,
+                result: Result<ApiResponse<T>, ApiClientError>,
+                wrap: impl FnOnce(ApiResult<ApiResponse<T>>) -> AppEvent,
+            ) {
+                match account_unavailable(&result) {
+                    Some((state, detail)) => {
+                        drop(guard);
+                        let event = AppEvent::AccountUnavailable {
+                            view,
+                            state,
+                            detail,
+                            requested_from,
+                        };
+                        let _ = sender.send(Event::App(Box::new(event)));
+                    }
+                    None => send_result(guard, sender, request, result, wrap),
+                }
+            }
+
+            let request = event.clone();
+            match event {
+                AppEvent::FetchHomeTimeline { pagination_token } => {
+                    let result = client
+                        .home_timeline_pages(max_results, exclude)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(
+                        guard,
+                        &sender,
+                        request,
+                        result,
+                        AppEvent::HomeTimelineLoaded,
+                    );
+                }
+                AppEvent::FetchUserTimeline {
+                    user_id,
+                    pagination_token,
+                } => {
+                    let result = client
+                        .timeline_pages(&user_id, max_results, exclude)
+                        .page_at(pagination_token)
+                        .await;
+                    let view = ViewKind::UserTimeline(user_id.clone());
+                    send_account_result(
+                        guard,
+                        &sender,
+                        request,
+                        view,
+                        requested_from,
+                        result,
+                        |r| AppEvent::UserTimelineLoaded { user_id, result: r },
+                    );
+                }
+                AppEvent::FetchTweet { tweet_id } => {
+                    let result = client.get_tweet(&tweet_id).await;
+                    send_result(guard, &sender, request, result, |r| {
+                        AppEvent::TweetLoaded(Box::new(r))
+                    });
+                }
+                AppEvent::FetchThread {
+                    conversation_id,
+                    pagination_token,
+                } => {
+                    let result = client
+                        .thread_pages(&conversation_id, max_results)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(guard, &sender, request, result, |r| {
+                        AppEvent::ThreadLoaded {
+                            conversation_id,
+                            result: r,
+                        }
+                    });
+                }
+                AppEvent::FetchUser { username } => {
+                    let result = client.get_user(&username).await;
+                    let view = ViewKind::UserProfile(username.clone());
+                    send_account_result(
+                        guard,
+                        &sender,
+                        request,
+                        view,
+                        requested_from,
+                        result,
+                        |result| AppEvent::UserLoaded {
+                            username: Some(username),
+                            result,
+                        },
+                    );
+                }
+                AppEvent::FetchUserById { user_id } => {
+                    let result = client.get_user_by_id(&user_id).await;
+                    let view = ViewKind::UserProfile(user_id);
+                    send_account_result(
+                        guard,
+                        &sender,
+                        request,
+                        view,
+                        requested_from,
+                        result,
+                        |result| AppEvent::UserLoaded {
+                            username: None,
+                            result,
+                        },
+                    );
+                }
+                AppEvent::FetchSearch {
+                    query,
+                    pagination_token,
+                } => {
+                    let result = client
+                        .search_pages(&query, max_results)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(guard, &sender, request, result, |r| {
+                        AppEvent::SearchLoaded { query, result: r }
+                    });
+                }
+                AppEvent::FetchMentions { pagination_token } => {
+                    let result = client
+                        .mentions_pages(max_results)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(guard, &sender, request, result, AppEvent::MentionsLoaded);
+                }
+                AppEvent::FetchBookmarks { pagination_token } => {
+                    let result = client
+                        .bookmarks_pages(max_results)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(guard, &sender, request, result, AppEvent::BookmarksLoaded);
+                }
+                AppEvent::FetchFollowers {
+                    user_id,
+                    pagination_token,
+                } => {
+                    let result = client
+                        .followers_pages(&user_id, max_results)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(guard, &sender, request, result, |r| {
+                        AppEvent::FollowersLoaded { user_id, result: r }
+                    });
+                }
+                AppEvent::FetchFollowing {
+                    user_id,
+                    pagination_token,
+                } => {
+                    let result = client
+                        .following_pages(&user_id, max_results)
+                        .page_at(pagination_token)
+                        .await;
+                    send_result(guard, &sender, request, result, |r| {
+                        AppEvent::FollowingLoaded { user_id, result: r }
+                    });
+                }
+                _ => unreachable!("dispatch_api_request called with non-Fetch variant"),
+            }
+        };
+        // Cancelling drops the request (and its guard) without a response;
+        // whoever cancelled has already cleared the loading state.
+        let task = async move {
+            if cancel.run_until_cancelled(task).await.is_none() {
+                tracing::debug!("request cancelled");
+            }
+        };
+        tokio::spawn(task.instrument(span));
+    }
+
+    /// Whether a fetch started from the current view is still pending.
+    pub(super) fn has_view_requests(&self) -> bool {
+        let view = self.current_view();
+        let pending = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        pending
+            .values()
+            .any(|r| r.view.as_ref() == view && !r.cancel.is_cancelled())
+    }
+
+    /// Cancel the fetches started from the current view and clear the
+    /// loading flags their responses would have cleared. Returns how many
+    /// were cancelled.
+    pub(super) fn cancel_view_requests(&mut self) -> usize {
+        let view = self.current_view().cloned();
+        self.cancel_requests(|r| r.view == view).len()
+    }
+
+    /// Watchdog, run on every tick. Abandons fetches that have gone
+    /// `request_timeout_secs` without a response, and resets loading flags
+    /// left behind by a task that died without reporting back. Returns
+    /// whether anything changed.
+    pub(super) fn check_stuck_requests(&mut self) -> bool {
+        let timeout = Duration::from_secs(self.config.request_timeout_secs);
+        if timeout.is_zero() {
+            return false;
+        }
+
+        let timed_out = self.cancel_requests(|r| r.started.elapsed() >= timeout);
+        if let Some(request) = timed_out.last() {
+            self.toasts.error(format!(
+                "Request timed out after {}s (:retry to try again)",
+                timeout.as_secs()
+            ));
+            self.retry_request = Some(request.clone());
+            return true;
+        }
+
+        let api_loading = self.loading
+            || self.home_timeline.loading
+            || self.mentions.loading
+            || self.bookmarks.loading
+            || self.search_results.loading
+            || self.viewed_user_timeline.loading;
+        let stalled = self
+            .loading_started_at
+            .is_some_and(|started| started.elapsed() >= timeout);
+        let idle = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_empty();
+        if !(api_loading && stalled && idle) || self.embedding_job {
+            return false;
+        }
+        self.loading = false;
+        self.home_timeline.loading = false;
+        self.mentions.loading = false;
+        self.bookmarks.loading = false;
+        self.search_results.loading = false;
+        self.viewed_user_timeline.loading = false;
+        self.revalidating_profiles.clear();
+        self.check_loading_finished();
+        self.toasts.error(format!(
+            "No response after {}s; stopped waiting",
+            timeout.as_secs()
+        ));
+        true
+    }
+
+    /// Cancel the pending fetches matching `filter` and clear the loading
+    /// flags their responses would have cleared. Returns the cancelled
+    /// requests.
+    fn cancel_requests(&mut self, filter: impl Fn(&InFlightRequest) -> bool) -> Vec<AppEvent> {
+        let cancelled: Vec<AppEvent> = {
+            let pending = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            pending
+                .values()
+                .filter(|r| !r.cancel.is_cancelled() && filter(r))
+                .map(|r| {
+                    r.cancel.cancel();
+                    r.request.clone()
+                })
+                .collect()
+        };
+        if cancelled.is_empty() {
+            return cancelled;
+        }
+        for request in &cancelled {
+            match request {
+                AppEvent::FetchHomeTimeline { .. } => self.home_timeline.loading = false,
+                AppEvent::FetchMentions { .. } => self.mentions.loading = false,
+                AppEvent::FetchBookmarks { .. } => self.bookmarks.loading = false,
+                AppEvent::FetchSearch { .. } => self.search_results.loading = false,
+                AppEvent::FetchUserTimeline { .. } => self.viewed_user_timeline.loading = false,
+                AppEvent::FetchUser { username } => {
+                    if let Some((user, _)) = self.profile_cache.get(username) {
+                        let id = user.id.clone();
+                        self.revalidating_profiles.remove(&id);
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.loading = false;
+        self.check_loading_finished();
+        cancelled
+    }
+
+    /// Apply `action` to `ids` one at a time in the background, reporting
+    /// progress after each one and waiting out rate limits.
+    pub(super) fn dispatch_bulk(&mut self, action: BulkAction, ids: Vec<String>) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        if self.bulk_run.is_some() {
+            self.notify("A bulk action is already running");
+            return;
+        }
+        if ids.is_empty() {
+            self.notify("Nothing selected");
+            return;
+        }
+
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        let total = ids.len();
+        self.bulk_run = Some(BulkRun {
+            action,
+            done: 0,
+            total,
+            rate_limited_until: None,
+        });
+
+        tokio::spawn(async move {
+            let send = |event: AppEvent| {
+                let _ = sender.send(Event::App(Box::new(event)));
+            };
+            let mut succeeded = 0;
+            let mut failed = Vec::new();
+
+            'items: for (i, id) in ids.iter().enumerate() {
+                if i > 0 {
+                    tokio::time::sleep(BULK_PACING).await;
+                }
+                let mut retries = 0;
+                loop {
+                    let result = match action {
+                        BulkAction::Bookmark => client.bookmark_tweet(id).await.map(drop),
+                        BulkAction::Unbookmark => client.remove_bookmark(id).await.map(drop),
+                        BulkAction::Mute => client.mute_user(id).await.map(drop),
+                    };
+                    match result {
+                        Ok(()) => succeeded += 1,
+                        Err(ApiClientError::RateLimited { reset_at })
+                            if retries < BULK_RATE_LIMIT_RETRIES =>
+                        {
+                            retries += 1;
+                            send(AppEvent::BulkProgressed {
+                                done: i,
+                                total,
+                                rate_limited_until: Some(reset_at),
+                            });
+                            let wait = (reset_at - chrono::Utc::now()).to_std().unwrap_or_default();
+                            tokio::time::sleep(wait + BULK_PACING).await;
+                            continue;
+                        }
+                        Err(e) => {
+                            // Credential and scope problems fail every
+                            // remaining request the same way.
+                            let fatal = matches!(
+                                e,
+                                ApiClientError::Auth(_)
+                                    | ApiClientError::ApiError(
+                                        XApiError::Unauthorized(_) | XApiError::Forbidden(_)
+                                    )
+                            );
+                            failed.push((id.clone(), Arc::new(e.with_remediation())));
+                            if fatal {
+                                break 'items;
+                            }
+                        }
+                    }
+                    break;
+                }
+                send(AppEvent::BulkProgressed {
+                    done: i + 1,
+                    total,
+                    rate_limited_until: None,
+                });
+            }
+
+            send(AppEvent::BulkFinished {
+                action,
+                succeeded,
+                total,
+                failed,
+            });
+        });
+    }
+
+    /// Mute or unmute `username` in the background, looking up its ID if
+    /// it isn't known.
+    pub(super) fn dispatch_mute(&mut self, username: String, user_id: Option<String>, mute: bool) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let request = AppEvent::MuteUser {
+                username: username.clone(),
+                user_id: user_id.clone(),
+                mute,
+            };
+            let mut scope = None;
+            let result = async {
+                let user_id = resolve_user_id(&client, &username, user_id).await?;
+                let result = if mute {
+                    client.mute_user(&user_id).await
+                } else {
+                    client.unmute_user(&user_id).await
+                };
+                result.map(|_| user_id).map_err(|e| {
+                    scope = e.missing_scope();
+                    Arc::new(e.with_remediation())
+                })
+            }
+            .await;
+            let response = AppEvent::MuteFinished {
+                username,
+                mute,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    /// Block or unblock `username` in the background, looking up its ID if
+    /// it isn't known.
+    pub(super) fn dispatch_block(
+        &mut self,
+        username: String,
+        user_id: Option<String>,
+        block: bool,
+    ) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let request = AppEvent::BlockUser {
+                username: username.clone(),
+                user_id: user_id.clone(),
+                block,
+            };
+            let mut scope = None;
+            let result = async {
+                let user_id = resolve_user_id(&client, &username, user_id).await?;
+                let result = if block {
+                    client.block_user(&user_id).await
+                } else {
+                    client.unblock_user(&user_id).await
+                };
+                result.map(|_| user_id).map_err(|e| {
+                    scope = e.missing_scope();
+                    Arc::new(e.with_remediation())
+                })
+            }
+            .await;
+            let response = AppEvent::BlockFinished {
+                username,
+                block,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    /// Add `tweet` to bookmarks, or remove it, in the background.
+    pub(super) fn dispatch_bookmark(&mut self, tweet: Tweet, bookmark: bool) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = if bookmark {
+                client.bookmark_tweet(&tweet.id).await
+            } else {
+                client.remove_bookmark(&tweet.id).await
+            };
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::BookmarkTweet {
+                tweet: tweet.clone(),
+                bookmark,
+            };
+            let response = AppEvent::BookmarkFinished {
+                tweet,
+                bookmark,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    /// Like or unlike `tweet_id` in the background.
+    pub(super) fn dispatch_like(&mut self, tweet_id: String, like: bool) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = if like {
+                client.like_tweet(&tweet_id).await
+            } else {
+                client.unlike_tweet(&tweet_id).await
+            };
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::LikeTweet {
+                tweet_id: tweet_id.clone(),
+                like,
+            };
+            let response = AppEvent::LikeFinished {
+                tweet_id,
+                like,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    /// Repost or undo the repost of `tweet_id` in the background.
+    pub(super) fn dispatch_retweet(&mut self, tweet_id: String, retweet: bool) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = if retweet {
+                client.retweet(&tweet_id).await
+            } else {
+                client.unretweet(&tweet_id).await
+            };
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::RetweetTweet {
+                tweet_id: tweet_id.clone(),
+                retweet,
+            };
+            let response = AppEvent::RetweetFinished {
+                tweet_id,
+                retweet,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    /// Hide or unhide a reply in the background.
+    pub(super) fn dispatch_hide_reply(&mut self, tweet_id: String, hide: bool) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = client.hide_reply(&tweet_id, hide).await;
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::HideReply {
+                tweet_id: tweet_id.clone(),
+                hide,
+            };
+            let response = AppEvent::HideReplyFinished {
+                tweet_id,
+                hide,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    /// Publish `text` as a new post in the background.
+    pub(super) fn dispatch_post_tweet(&mut self, post: PendingPost) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::TweetPosted {
+                post,
+                result: Err(Arc::new(
+                    "No API client configured. Use :auth first.".into(),
+                )),
+            });
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let reply_to = post.reply_to.as_ref().map(|r| r.tweet_id.as_str());
+            let result = client
+                .post_tweet(post.text.trim(), reply_to, post.reply_settings)
+                .await;
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::PostTweet(post.clone());
+            let response = AppEvent::TweetPosted { post, result };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
+
+    pub(super) fn dispatch_post_thread(
+        &mut self,
+        posts: Vec<String>,
+        reply_to: Option<String>,
+        mut posted: Vec<String>,
+        post: PendingPost,
+    ) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::ThreadPosted {
+                posted,
+                post,
+                result: Err(Arc::new(
+                    "No API client configured. Use :auth first.".into(),
+                )),
+            });
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let event = match client
+                .post_thread(&posts, reply_to.as_deref(), post.reply_settings)
+                .await
+            {
+                Ok(ids) => {
+                    posted.extend(ids);
+                    AppEvent::ThreadPosted {
+                        posted,
+                        post,
+                        result: Ok(()),
+                    }
+                }
+                Err(interrupted) => {
+                    let sent = interrupted.posted.len();
+                    let scope = interrupted.error.missing_scope();
+                    posted.extend(interrupted.posted);
+                    // Retrying posts the rest, under the last post that went out.
+                    let request = AppEvent::PostThread {
+                        posts: posts[sent..].to_vec(),
+                        reply_to: posted.last().cloned().or(reply_to),
+                        posted: posted.clone(),
+                        post: post.clone(),
+                    };
+                    let response = AppEvent::ThreadPosted {
+                        posted,
+                        post,
+                        result: Err(Arc::new(interrupted.error.with_remediation())),
+                    };
+                    offer_scope(
+                        scope,
+                        request.clone(),
+                        AppEvent::RequestFailed {
+                            request: Box::new(request),
+                            response: Box::new(response),
+                        },
+                    )
+                }
+            };
+            let _ = sender.send(Event::App(Box::new(event)));
+        });
+    }
+
+    /// Delete just-published posts, newest first so a thread never hangs
+    /// off a deleted post. Stops at the first failure.
+    pub(super) fn dispatch_delete_posts(&mut self, tweet_ids: Vec<String>) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let mut deleted = Vec::new();
+            let mut result = Ok(());
+            for tweet_id in tweet_ids.iter().rev() {
+                match client.delete_tweet(tweet_id).await {
+                    Ok(_) => deleted.push(tweet_id.clone()),
+                    Err(e) => {
+                        result = Err(Arc::new(e.with_remediation()));
+                        break;
+                    }
+                }
+            }
+            let _ = sender.send(Event::App(Box::new(AppEvent::PostsDeleted {
+                tweet_ids,
+                deleted,
+                result,
+            })));
+        });
+    }
+
+    /// Add `user` to `list`, or remove them, in the background.
+    pub(super) fn dispatch_list_member(&mut self, list: List, user: User, member: bool) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = if member {
+                client.add_list_member(&list.id, &user.id).await
+            } else {
+                client.remove_list_member(&list.id, &user.id).await
+            };
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result
+                .map(|_| ())
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::SetListMember {
+                list: list.clone(),
+                user: user.clone(),
+                member,
+            };
+            let response = AppEvent::ListMemberFinished {
+                list,
+                user,
+                member,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
 
-                let labels = parse_cluster_topic_labels(&content, num_clusters);
+    /// Create a List, or update the one with `list_id`.
+    pub(super) fn dispatch_save_list(&mut self, list_id: Option<String>, fields: ListFields) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = match &list_id {
+                Some(id) => client
+                    .update_list(id, &fields)
+                    .await
+                    .map(|_| Some(id.clone())),
+                None => client
+                    .create_list(&fields)
+                    .await
+                    .map(|resp| resp.data.map(|list| list.id)),
+            };
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result
+                .map_err(|e| Arc::new(e.with_remediation()))
+                .and_then(|id| id.ok_or_else(|| Arc::new("X returned no List".to_string())));
+            let request = AppEvent::SaveList {
+                list_id: list_id.clone(),
+                fields: fields.clone(),
+            };
+            let response = AppEvent::ListSaved {
+                list_id,
+                fields,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
 
-                if labels.iter().all(|l| l.is_empty()) {
-                    return Err(Arc::new(format!(
-                        "No labels parsed from response: {content}"
-                    )));
-                }
+    pub(super) fn dispatch_delete_list(&mut self, list: List) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = client.delete_list(&list.id).await;
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result
+                .map(|_| ())
+                .map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::DeleteList { list: list.clone() };
+            let response = AppEvent::ListDeleted { list, result };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
 
-                Ok(labels)
-            }
-            .await;
+    pub(super) fn dispatch_dm_events(&self) {
+        let Some(ref client) = self.api_client else {
+            self.events.send(AppEvent::DmEventsLoaded(Err(Arc::new(
+                "No API client configured. Use :auth first.".into(),
+            ))));
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
 
-            let _ = sender.send(Event::App(Box::new(AppEvent::ClusterTopicsGenerated(
-                generation, result,
+        tokio::spawn(async move {
+            let result = tokio::try_join!(client.get_my_user_id(), client.get_dm_events(100, None));
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let response = AppEvent::DmEventsLoaded(result);
+            let _ = sender.send(Event::App(Box::new(offer_scope(
+                scope,
+                AppEvent::FetchDmEvents,
+                response,
             ))));
         });
     }
 
-    // -- API dispatch -------------------------------------------------------
+    pub(super) fn dispatch_open_dm(&mut self, username: String) {
+        let Some(ref client) = self.api_client else {
+            self.set_error("No API client configured. Use :auth first.".into());
+            return;
+        };
+        let client = Arc::clone(client);
+        let sender = self.events.sender();
 
-    pub(super) fn dispatch_api_request(&self, event: AppEvent) {
+        tokio::spawn(async move {
+            let result = resolve_user_id(&client, &username, None).await;
+            let _ = sender.send(Event::App(Box::new(AppEvent::DmParticipantResolved {
+                username,
+                result,
+            })));
+        });
+    }
+
+    pub(super) fn dispatch_dm_conversation(&self, participant_id: String) {
         let Some(ref client) = self.api_client else {
-            // No API client -- emit the matching *Loaded(Err) so loading
-            // flags get cleared through the normal response path.
-            let err: Arc<String> = Arc::new("No API client configured. Use :auth first.".into());
-            if let Some(response) = event.into_error_response(err) {
-                self.events.send(response);
-            }
+            self.events.send(AppEvent::DmConversationLoaded {
+                participant_id,
+                result: Err(Arc::new(
+                    "No API client configured. Use :auth first.".into(),
+                )),
+            });
             return;
         };
         let client = Arc::clone(client);
         let sender = self.events.sender();
-        let max_results = self.config.default_max_results;
 
         tokio::spawn(async move {
-            /// Map an API result to an `AppEvent` and send it through the channel.
-            fn send_result<T: Send + 'static>(
-                sender: &tokio::sync::mpsc::UnboundedSender<Event>,
-                result: Result<T, impl std::fmt::Display>,
-                wrap: impl FnOnce(ApiResult<T>) -> AppEvent,
-            ) {
-                let mapped = result.map_err(|e| Arc::new(e.to_string()));
-                let _ = sender.send(Event::App(Box::new(wrap(mapped))));
-            }
+            let result = client
+                .get_dm_conversation_with(&participant_id, 100, None)
+                .await;
+            let scope = result
+                .as_ref()
+                .err()
+                .and_then(ApiClientError::missing_scope);
+            let result = result.map_err(|e| Arc::new(e.with_remediation()));
+            let request = AppEvent::FetchDmConversation {
+                participant_id: participant_id.clone(),
+            };
+            let response = AppEvent::DmConversationLoaded {
+                participant_id,
+                result,
+            };
+            let _ = sender.send(Event::App(Box::new(offer_scope(scope, request, response))));
+        });
+    }
 
-            match event {
-                AppEvent::FetchHomeTimeline { pagination_token } => {
-                    let mut api = client.lock().await;
-                    let result = api
-                        .get_home_timeline(max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, AppEvent::HomeTimelineLoaded);
-                }
-                AppEvent::FetchUserTimeline {
-                    user_id,
-                    pagination_token,
-                } => {
-                    let api = client.lock().await;
-                    let result = api
-                        .get_timeline(&user_id, max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, |r| AppEvent::UserTimelineLoaded {
-                        user_id,
-                        result: r,
-                    });
-                }
-                AppEvent::FetchTweet { tweet_id } => {
-                    let api = client.lock().await;
-                    let result = api.get_tweet(&tweet_id).await;
-                    send_result(&sender, result, |r| AppEvent::TweetLoaded(Box::new(r)));
-                }
-                AppEvent::FetchThread {
-                    conversation_id,
-                    pagination_token,
-                } => {
-                    let api = client.lock().await;
-                    let result = api
-                        .get_conversation_thread(
-                            &conversation_id,
-                            max_results,
-                            pagination_token.as_deref(),
-                        )
-                        .await;
-                    send_result(&sender, result, |r| AppEvent::ThreadLoaded {
-                        conversation_id,
-                        result: r,
-                    });
-                }
-                AppEvent::FetchUser { username } => {
-                    let api = client.lock().await;
-                    let result = api.get_user(&username).await;
-                    send_result(&sender, result, AppEvent::UserLoaded);
-                }
-                AppEvent::FetchSearch {
-                    query,
-                    pagination_token,
-                } => {
-                    let api = client.lock().await;
-                    let result = api
-                        .search_tweets(&query, max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, |r| AppEvent::SearchLoaded {
-                        query,
-                        result: r,
-                    });
-                }
-                AppEvent::FetchMentions { pagination_token } => {
-                    let mut api = client.lock().await;
-                    let result = api
-                        .get_mentions(max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, AppEvent::MentionsLoaded);
-                }
-                AppEvent::FetchBookmarks { pagination_token } => {
-                    let mut api = client.lock().await;
-                    let result = api
-                        .get_bookmarks(max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, AppEvent::BookmarksLoaded);
-                }
-                AppEvent::FetchFollowers {
-                    user_id,
-                    pagination_token,
-                } => {
-                    let api = client.lock().await;
-                    let result = api
-                        .get_followers(&user_id, max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, |r| AppEvent::FollowersLoaded {
-                        user_id,
-                        result: r,
-                    });
-                }
-                AppEvent::FetchFollowing {
-                    user_id,
-                    pagination_token,
-                } => {
-                    let api = client.lock().await;
-                    let result = api
-                        .get_following(&user_id, max_results, pagination_token.as_deref())
-                        .await;
-                    send_result(&sender, result, |r| AppEvent::FollowingLoaded {
-                        user_id,
-                        result: r,
-                    });
-                }
-                _ => unreachable!("dispatch_api_request called with non-Fetch variant"),
-            }
+    /// Run `command` through `sh -c` with `input` on stdin, off the UI thread.
+    pub(super) fn dispatch_pipe(&self, command: String, input: String) {
+        let sender = self.events.sender();
+        tokio::spawn(async move {
+            let result = run_piped(&command, input).await;
+            let _ = sender.send(Event::App(Box::new(AppEvent::PipeFinished {
+                command,
+                result,
+            })));
         });
     }
 
@@ -553,18 +1834,54 @@ impl App {
                     pagination_token: None,
                 });
             }
+            ViewKind::Lists
+                if self.owned_lists.is_empty()
+                    && self.followed_lists.is_empty()
+                    && !self.lists_loading =>
+            {
+                self.events.send(AppEvent::FetchLists);
+            }
+            ViewKind::ListMembers(list_id)
+                if self.list_members.is_empty() && !self.list_members_loading =>
+            {
+                self.events.send(AppEvent::FetchListMembers {
+                    list_id: list_id.clone(),
+                });
+            }
+            ViewKind::TweetUsers { tweet_id, kind }
+                if self.tweet_users.is_empty() && !self.tweet_users_loading =>
+            {
+                self.events.send(AppEvent::FetchTweetUsers {
+                    tweet_id: tweet_id.clone(),
+                    kind: *kind,
+                });
+            }
+            ViewKind::StreamRules if self.stream_rules.is_empty() && !self.stream_rules_loading => {
+                self.events.send(AppEvent::FetchStreamRules);
+            }
+            ViewKind::Messages if self.dm_events.is_empty() && !self.dm_loading => {
+                self.events.send(AppEvent::FetchDmEvents);
+            }
+            ViewKind::DmConversation(participant_id)
+                if self.dm_conversation.is_empty() && !self.dm_conversation_loading =>
+            {
+                self.events.send(AppEvent::FetchDmConversation {
+                    participant_id: participant_id.clone(),
+                });
+            }
             _ => {}
         }
     }
 
-    pub(super) fn cache_users_from_includes(&mut self, includes: &Option<Includes>) {
-        if let Some(inc) = includes
-            && let Some(users) = &inc.users
-        {
-            for user in users {
-                self.users_cache.insert(user.id.clone(), user.clone());
-            }
+    /// Remember the users, media and tweets of a response's `includes`.
+    pub(super) fn cache_includes(&mut self, includes: &Option<Includes>) {
+        let Some(inc) = includes else {
+            return;
+        };
+        for user in inc.users.iter().flatten() {
+            self.users_cache.insert(user.clone());
         }
+        self.included.add(inc);
     }
 
     /// Look up a user by their ID from the includes cache.
@@ -671,6 +1988,193 @@ impl App {
     }
 }
 
+/// `response` to a failed `request`, wrapped in `ScopeMissing` when it was
+/// refused for lack of an OAuth 2.0 scope so signing in again can be offered.
+fn offer_scope(scope: Option<&'static str>, request: AppEvent, response: AppEvent) -> AppEvent {
+    match scope {
+        Some(scope) => AppEvent::ScopeMissing {
+            scope,
+            request: Box::new(request),
+            response: Box::new(response),
+        },
+        None => response,
+    }
+}
+
+/// `user_id` if known, otherwise `username`'s ID from a lookup.
+async fn resolve_user_id(
+    client: &XApiClient,
+    username: &str,
+    user_id: Option<String>,
+) -> ApiResult<String> {
+    if let Some(id) = user_id {
+        return Ok(id);
+    }
+    let resp = client
+        .get_user(username)
+        .await
+        .map_err(|e| Arc::new(e.with_remediation()))?;
+    match resp.data {
+        Some(user) => Ok(user.id),
+        None => Err(Arc::new(format!("@{username} not found"))),
+    }
+}
+
+/// The account state behind a failed user lookup or timeline, with X's
+/// message. Lookups report it in a 200's `errors` array rather than the status.
+fn account_unavailable<T>(
+    result: &Result<ApiResponse<T>, ApiClientError>,
+) -> Option<(AccountState, String)> {
+    let error = match result {
+        Err(ApiClientError::ApiError(e)) => e.clone(),
+        Ok(resp) if resp.data.is_none() => XApiError::from_partial(resp.errors.as_ref()?.first()?),
+        _ => return None,
+    };
+    let detail = error.problem().to_string();
+    Some((error.account_state()?, detail))
+}
+
+/// Spawn `sh -c command`, feed it `input`, and collect its output.
+async fn run_piped(command: &str, input: String) -> Result<String, String> {
+    use std::process::Stdio;
+    use tokio::io::AsyncWriteExt;
+
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run `{command}`: {e}"))?;
+
+    // Write from a separate task so a command that doesn't read all of its
+    // stdin before producing output can't deadlock us.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = tokio::spawn(async move {
+        let _ = stdin.write_all(input.as_bytes()).await;
+    });
+    let output = child
+        .wait_with_output()
+        .await
+        .map_err(|e| format!("`{command}` failed: {e}"))?;
+    let _ = writer.await;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(format!(
+            "`{command}` exited with {}\n\n{}",
+            output.status,
+            stderr.trim_end()
+        ))
+    }
+}
+
+/// Stored tweets `:similar` compares against, newest first.
+const SIMILAR_CANDIDATES: usize = 2000;
+
+/// Most results `:similar` shows.
+const SIMILAR_LIMIT: usize = 50;
+
+/// Texts sent to the embedding provider per request.
+const EMBED_BATCH: usize = 100;
+
+/// The bookmarks and stored tweets nearest `tweet`, most alike first.
+async fn find_similar(
+    provider: &EmbedProvider,
+    model: &str,
+    tweet: Tweet,
+    bookmarks: Vec<Tweet>,
+) -> ApiResult<(Vec<Tweet>, Includes)> {
+    let load_model = model.to_string();
+    let (stored, mut vectors) = tokio::task::spawn_blocking(move || {
+        (
+            store::archived_tweets(SIMILAR_CANDIDATES),
+            store::EmbeddingStore::load(&load_model),
+        )
+    })
+    .await
+    .map_err(|e| Arc::new(e.to_string()))?;
+
+    let mut seen = HashSet::from([tweet.id.clone()]);
+    let candidates: Vec<Tweet> = bookmarks
+        .into_iter()
+        .chain(stored)
+        .filter(|t| !full_text(t).trim().is_empty() && seen.insert(t.id.clone()))
+        .collect();
+    if candidates.is_empty() {
+        return Ok((Vec::new(), Includes::default()));
+    }
+    let similar = rank_similar(provider, model, &tweet, &candidates, &mut vectors).await?;
+
+    vectors.retain(|id| seen.contains(id));
+    let found = similar.clone();
+    let (saved, includes) =
+        tokio::task::spawn_blocking(move || (vectors.save(), store::archived_includes(&found)))
+            .await
+            .map_err(|e| Arc::new(e.to_string()))?;
+    if let Err(e) = saved {
+        tracing::warn!("failed to save embeddings: {e}");
+    }
+    Ok((similar, includes))
+}
+
+/// The tweet's text, in full for a long post, as `:local` matches it.
+pub(super) fn full_text(tweet: &Tweet) -> &str {
+    tweet
+        .note_tweet
+        .as_ref()
+        .map_or(&tweet.text, |note| &note.text)
+}
+
+/// `candidates` ordered by how close they are to `tweet`, embedding the
+/// ones `vectors` doesn't have yet and adding them to it.
+async fn rank_similar(
+    provider: &EmbedProvider,
+    model: &str,
+    tweet: &Tweet,
+    candidates: &[Tweet],
+    vectors: &mut store::EmbeddingStore,
+) -> ApiResult<Vec<Tweet>> {
+    let missing: Vec<&Tweet> = std::iter::once(tweet)
+        .chain(candidates)
+        .filter(|t| vectors.get(&t.id).is_none())
+        .collect();
+    for batch in missing.chunks(EMBED_BATCH) {
+        let texts: Vec<String> = batch.iter().map(|t| full_text(t).to_string()).collect();
+        let mut data = provider.embed(model, &texts).await?.data;
+        if data.len() != texts.len() {
+            return Err(Arc::new(format!(
+                "Expected {} embeddings, got {}",
+                texts.len(),
+                data.len()
+            )));
+        }
+        data.sort_by_key(|d| d.index);
+        for (t, d) in batch.iter().zip(data) {
+            vectors.insert(t.id.clone(), &d.embedding);
+        }
+    }
+
+    let query = vectors
+        .get(&tweet.id)
+        .ok_or_else(|| Arc::new("The tweet could not be embedded".to_string()))?;
+    let items: Vec<(usize, Vec<f64>)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, t)| Some((i, vectors.get(&t.id)?)))
+        .collect();
+    let ranked = crate::embeddings::similarity::rank_by_similarity(&query, &items);
+
+    Ok(ranked
+        .into_iter()
+        .take(SIMILAR_LIMIT)
+        .map(|(i, _)| candidates[i].clone())
+        .collect())
+}
+
 fn cluster_topic_max_tokens(num_clusters: usize) -> u32 {
     // Labels are only 3-5 words, but leave room for punctuation, occasional
     // extra tokens per word, and a little drift before we cut the model off.
@@ -741,6 +2245,45 @@ impl ChatProvider {
     }
 }
 
+/// The text of a chat completion's first choice, without reasoning.
+fn completion_content(resp: &openrouter::types::ChatCompletionResponse) -> ApiResult<String> {
+    let choice = resp
+        .choices
+        .first()
+        .ok_or_else(|| Arc::new("Chat model returned no choices".to_string()))?;
+
+    // Only use `content` -- reasoning/reasoning_content are
+    // chain-of-thought fields and must NOT be treated as output.
+    let raw = choice.message.content.clone().ok_or_else(|| {
+        let reason = choice.finish_reason.as_deref().unwrap_or("unknown");
+        if reason == "length" {
+            Arc::new(
+                "Model exhausted token budget on reasoning \
+                 before producing content (finish_reason: length)"
+                    .to_string(),
+            )
+        } else {
+            Arc::new(format!(
+                "Chat model returned null content \
+                 (finish_reason: {reason})"
+            ))
+        }
+    })?;
+
+    // Strip <think>...</think> blocks that reasoning models
+    // may embed in content.
+    let content = openrouter::strip_think_tags(&raw);
+
+    if content.trim().is_empty() {
+        return Err(Arc::new(
+            "Chat model returned empty content \
+             (after stripping reasoning tags)"
+                .to_string(),
+        ));
+    }
+    Ok(content)
+}
+
 /// Clean a raw label line: strip bullet/numbering prefixes and trim.
 /// Returns `None` for empty strings or lines that are too long to be a
 /// 3-5 word topic label (reasoning leakage, prose explanations).
@@ -828,10 +2371,281 @@ pub(crate) fn parse_cluster_topic_labels(content: &str, num_clusters: usize) ->
     labels
 }
 
+/// Keep the filtered stream connected, waiting between attempts as
+/// [`Backoff`] says, until a failure reconnecting can't fix.
+async fn run_filtered_stream(
+    client: &XApiClient,
+    sender: &mpsc::UnboundedSender<Event>,
+    generation: u64,
+) {
+    let status = |status| {
+        let _ = sender.send(Event::App(Box::new(AppEvent::StreamStatusChanged {
+            generation,
+            status,
+        })));
+    };
+    let mut backoff = Backoff::default();
+    loop {
+        let error = match client.connect_stream().await {
+            Ok(mut stream) => {
+                backoff.reset();
+                status(StreamStatus::Connected);
+                loop {
+                    match stream.next_message().await {
+                        Ok(message) => {
+                            let _ = sender.send(Event::App(Box::new(
+                                AppEvent::StreamMessageReceived {
+                                    generation,
+                                    message: Box::new(message),
+                                },
+                            )));
+                        }
+                        Err(ApiClientError::Deserialize(e)) => {
+                            tracing::warn!("skipping unreadable stream message: {e}");
+                        }
+                        Err(e) => break e,
+                    }
+                }
+            }
+            Err(e) => e,
+        };
+        let Some(delay) = backoff.next_delay(&error) else {
+            status(StreamStatus::Failed(error.with_remediation()));
+            return;
+        };
+        tracing::info!(?delay, "filtered stream disconnected: {error}");
+        status(StreamStatus::Reconnecting {
+            delay,
+            reason: error.to_string(),
+        });
+        tokio::time::sleep(delay).await;
+        status(StreamStatus::Connecting);
+    }
+}
+
+/// Requests currently being fetched, by request key (see
+/// [`AppEvent::request_key`]).
+pub type InFlightRequests = Arc<std::sync::Mutex<HashMap<String, InFlightRequest>>>;
+
+/// A pending fetch and how to stop it.
+#[derive(Debug)]
+pub struct InFlightRequest {
+    request: AppEvent,
+    /// View that was showing when the request went out.
+    view: Option<ViewKind>,
+    started: Instant,
+    cancel: CancellationToken,
+    /// Tells this request apart from a later one with the same key.
+    id: u64,
+}
+
+/// Marks a request as in flight until dropped, at which point its latency
+/// is reported to the perf overlay.
+struct InFlightGuard {
+    set: InFlightRequests,
+    key: String,
+    id: u64,
+    cancel: CancellationToken,
+    started: Instant,
+    recorder: RequestRecorder,
+}
+
+impl InFlightGuard {
+    /// Register `event` as in flight on behalf of `view`. Returns `None` if
+    /// an identical request is already pending; one that was cancelled but
+    /// hasn't finished unwinding is replaced.
+    fn acquire(
+        set: &InFlightRequests,
+        event: &AppEvent,
+        view: Option<ViewKind>,
+        perf: &PerfStats,
+    ) -> Option<Self> {
+        let key = event.request_key()?;
+        let mut pending = set.lock().unwrap_or_else(|e| e.into_inner());
+        if pending
+            .get(&key)
+            .is_some_and(|request| !request.cancel.is_cancelled())
+        {
+            return None;
+        }
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let cancel = CancellationToken::new();
+        pending.insert(
+            key.clone(),
+            InFlightRequest {
+                request: event.clone(),
+                view,
+                started: Instant::now(),
+                cancel: cancel.clone(),
+                id,
+            },
+        );
+        Some(Self {
+            set: Arc::clone(set),
+            key,
+            id,
+            cancel,
+            started: Instant::now(),
+            recorder: perf.request_recorder(),
+        })
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        let mut pending = self.set.lock().unwrap_or_else(|e| e.into_inner());
+        // A cancelled request may have been replaced by a newer one.
+        if pending
+            .get(&self.key)
+            .is_some_and(|request| request.id == self.id)
+        {
+            pending.remove(&self.key);
+        }
+        drop(pending);
+
+        let latency = self.started.elapsed();
+        tracing::debug!(request = %self.key, ?latency, "request finished");
+        perf::record_request(&self.recorder, std::mem::take(&mut self.key), latency);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn a_cancelled_request_makes_way_for_the_same_one_again() {
+        let set = InFlightRequests::default();
+        let perf = PerfStats::default();
+        let search = AppEvent::FetchSearch {
+            query: "rust".into(),
+            pagination_token: None,
+        };
+        let old = InFlightGuard::acquire(&set, &search, None, &perf).unwrap();
+        assert!(InFlightGuard::acquire(&set, &search, None, &perf).is_none());
+
+        old.cancel.cancel();
+        let new = InFlightGuard::acquire(&set, &search, None, &perf).unwrap();
+        // The old task finishing doesn't unregister the new request.
+        drop(old);
+        assert_eq!(set.lock().unwrap().len(), 1);
+        drop(new);
+        assert!(set.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn account_state_comes_from_errors_or_partial_errors() {
+        let suspended: ApiResponse<User> = serde_json::from_value(serde_json::json!({
+            "errors": [{
+                "detail": "User has been suspended: [spam].",
+                "title": "Forbidden",
+                "type": "https://api.twitter.com/2/problems/resource-not-found",
+            }],
+        }))
+        .unwrap();
+        let (state, detail) = account_unavailable(&Ok(suspended)).unwrap();
+        assert_eq!(state, AccountState::Suspended);
+        assert_eq!(detail, "Forbidden: User has been suspended: [spam].");
+
+        let protected = XApiError::from_response(
+            403,
+            r#"{"detail":"Sorry, you are not authorized to see the user with id: [7].","type":"https://api.twitter.com/2/problems/not-authorized-for-resource"}"#,
+        );
+        let result: Result<ApiResponse<Vec<Tweet>>, _> = Err(protected.into());
+        assert_eq!(
+            account_unavailable(&result).map(|(state, _)| state),
+            Some(AccountState::Protected)
+        );
+
+        let empty: ApiResponse<Vec<Tweet>> = ApiResponse::default();
+        assert!(account_unavailable(&Ok(empty)).is_none());
+    }
+
+    #[tokio::test]
+    async fn piped_command_reads_stdin_and_reports_failures() {
+        let out = run_piped("wc -l", "a\nb\nc\n".into()).await.unwrap();
+        assert_eq!(out.trim(), "3");
+
+        let err = run_piped("echo oops >&2; exit 3", String::new())
+            .await
+            .unwrap_err();
+        assert!(err.contains("oops"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn similar_tweets_rank_by_embedding_and_reuse_stored_vectors() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+        // Tweets about Rust point one way, about cooking the other.
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(|req: &Request| {
+                let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+                let data: Vec<_> = body["input"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, text)| {
+                        let rust = text.as_str().unwrap().contains("Rust");
+                        let embedding = if rust { [1.0, 0.1] } else { [0.1, 1.0] };
+                        serde_json::json!({ "index": index, "embedding": embedding })
+                    })
+                    .collect();
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "data": data, "model": "stub" }))
+            })
+            .mount(&server)
+            .await;
+        let provider = EmbedProvider::Mlx(Arc::new(MlxClient::new(server.uri())));
+
+        let tweet = |id: &str, text: &str| -> Tweet {
+            serde_json::from_value(serde_json::json!({ "id": id, "text": text })).unwrap()
+        };
+        // A long post is compared by its full text.
+        let mut long = tweet("3", "Big news today…");
+        long.note_tweet = serde_json::from_value(
+            serde_json::json!({ "text": "Big news today: Rust 2024 is out" }),
+        )
+        .unwrap();
+        let candidates = [
+            tweet("2", "A pasta recipe"),
+            long,
+            tweet("4", "Lunch ideas"),
+        ];
+        let mut vectors = store::EmbeddingStore::new("stub");
+        // Already embedded, so its text isn't sent again.
+        vectors.insert("4".into(), &[0.9, 0.2]);
+
+        let ranked = rank_similar(
+            &provider,
+            "stub",
+            &tweet("1", "Learning Rust"),
+            &candidates,
+            &mut vectors,
+        )
+        .await
+        .unwrap();
+        let ids: Vec<_> = ranked.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["3", "4", "2"]);
+        assert!(vectors.get("2").is_some());
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let sent: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            sent["input"],
+            serde_json::json!([
+                "Learning Rust",
+                "A pasta recipe",
+                "Big news today: Rust 2024 is out"
+            ])
+        );
+    }
+
     #[test]
     fn parses_well_formed_prefix_output() {
         let content = "Cluster 0: AI news\nCluster 1: Rust tooling\nCluster 2: Market updates";
@@ -957,4 +2771,110 @@ mod tests {
         assert_eq!(labels[0], "lower");
         assert_eq!(labels[1], "upper");
     }
+
+    #[test]
+    fn in_flight_guard_coalesces_identical_requests_until_dropped() {
+        let set = InFlightRequests::default();
+        let perf = PerfStats::default();
+        let page_one = AppEvent::FetchHomeTimeline {
+            pagination_token: None,
+        };
+        let page_two = AppEvent::FetchHomeTimeline {
+            pagination_token: Some("abc".into()),
+        };
+
+        let guard =
+            InFlightGuard::acquire(&set, &page_one, None, &perf).expect("first request dispatches");
+        assert!(InFlightGuard::acquire(&set, &page_one, None, &perf).is_none());
+        let other =
+            InFlightGuard::acquire(&set, &page_two, None, &perf).expect("other page is distinct");
+
+        drop(guard);
+        assert!(InFlightGuard::acquire(&set, &page_one, None, &perf).is_some());
+        drop(other);
+        assert!(InFlightGuard::acquire(&set, &AppEvent::Quit, None, &perf).is_none());
+    }
+
+    #[test]
+    fn cancelling_only_touches_the_current_views_requests() {
+        let mut app = App::headless(crate::config::AppConfig::default());
+        let perf = PerfStats::default();
+        let home = AppEvent::FetchHomeTimeline {
+            pagination_token: None,
+        };
+        let mentions = AppEvent::FetchMentions {
+            pagination_token: None,
+        };
+        let home = InFlightGuard::acquire(&app.in_flight, &home, Some(ViewKind::Home), &perf)
+            .expect("home dispatches");
+        let mentions =
+            InFlightGuard::acquire(&app.in_flight, &mentions, Some(ViewKind::Mentions), &perf)
+                .expect("mentions dispatches");
+        app.home_timeline.loading = true;
+        app.mentions.loading = true;
+
+        assert!(app.has_view_requests());
+        assert_eq!(app.cancel_view_requests(), 1);
+        assert!(home.cancel.is_cancelled());
+        assert!(!mentions.cancel.is_cancelled());
+        assert!(!app.home_timeline.loading);
+        assert!(app.mentions.loading);
+        assert!(!app.has_view_requests());
+    }
+
+    #[test]
+    fn watchdog_abandons_slow_requests_and_stalled_loading() {
+        let mut app = App::headless(crate::config::AppConfig::default());
+        let perf = PerfStats::default();
+        let search = AppEvent::FetchSearch {
+            query: "rust".into(),
+            pagination_token: Some("page2".into()),
+        };
+        let guard = InFlightGuard::acquire(&app.in_flight, &search, None, &perf).unwrap();
+        app.search_results.loading = true;
+        assert!(!app.check_stuck_requests());
+
+        let long_ago = Instant::now() - Duration::from_secs(60);
+        app.in_flight
+            .lock()
+            .unwrap()
+            .get_mut(&guard.key)
+            .unwrap()
+            .started = long_ago;
+        assert!(app.check_stuck_requests());
+        assert!(guard.cancel.is_cancelled());
+        assert!(!app.search_results.loading);
+        assert!(app.toasts.has_errors());
+        assert_eq!(
+            app.retry_request.as_ref().and_then(AppEvent::request_key),
+            search.request_key()
+        );
+        drop(guard);
+
+        // A task that died without a response leaves its flag set.
+        app.toasts.dismiss_errors();
+        app.home_timeline.loading = true;
+        app.loading_started_at = Some(long_ago);
+        assert!(app.check_stuck_requests());
+        assert!(!app.home_timeline.loading);
+        assert!(app.loading_started_at.is_none());
+        assert!(app.toasts.has_errors());
+    }
+
+    #[test]
+    fn watchdog_leaves_a_long_embedding_job_running() {
+        let mut app = App::headless(crate::config::AppConfig::default());
+        app.handle_app_event(AppEvent::EmbedAndRankSearch {
+            query: "rust".into(),
+            tweets: Vec::new(),
+        });
+        assert!(app.loading);
+        assert!(app.loading_started_at.is_none());
+
+        // A fetch that started long ago and finished leaves the clock running.
+        app.loading_started_at = Some(Instant::now() - Duration::from_secs(60));
+        assert!(!app.check_stuck_requests());
+        assert!(app.loading);
+        assert!(!app.toasts.has_errors());
+    }
 }
@@ -0,0 +1,151 @@
+use chrono::Utc;
+
+use super::{App, AppMode, ReplyTarget};
+use crate::event::{AppEvent, PendingPost, ViewKind};
+use crate::store::Draft;
+
+impl App {
+    /// Keep the composer's text in the Drafts view. An emptied draft is
+    /// deleted instead.
+    pub(super) fn save_compose_draft(&mut self) {
+        if self.compose_input.trim().is_empty() {
+            if let Some(id) = self.compose_draft_id.take()
+                && let Err(e) = self.drafts.remove(&id)
+            {
+                self.set_error(format!("Failed to delete the draft: {e}"));
+            }
+            return;
+        }
+        let id = self
+            .compose_draft_id
+            .get_or_insert_with(|| Utc::now().timestamp_millis().to_string())
+            .clone();
+        let draft = Draft {
+            id,
+            text: self.compose_input.clone(),
+            reply_to: self.compose_reply_to.as_ref().map(|r| r.tweet_id.clone()),
+            reply_to_username: self
+                .compose_reply_to
+                .as_ref()
+                .and_then(|r| r.username.clone()),
+            thread: self.compose_thread,
+            reply_settings: self.compose_reply_settings,
+            saved_at: Utc::now(),
+        };
+        match self.drafts.save(draft) {
+            Ok(()) => self.notify("Draft saved (:drafts)"),
+            Err(e) => self.set_error(format!("Failed to save the draft: {e}")),
+        }
+    }
+
+    /// Take what the composer holds to post it, leaving it empty for the
+    /// next one.
+    pub(super) fn take_pending_post(&mut self) -> PendingPost {
+        self.compose_thread_posted = None;
+        PendingPost {
+            text: std::mem::take(&mut self.compose_input),
+            reply_to: self.compose_reply_to.take(),
+            thread: std::mem::take(&mut self.compose_thread),
+            reply_settings: std::mem::take(&mut self.compose_reply_settings),
+            draft_id: self.compose_draft_id.take(),
+        }
+    }
+
+    /// Once a post went out, forget the draft it was resumed from, and
+    /// clear the composer if it was put back there after a failure.
+    pub(super) fn finish_post(&mut self, post: &PendingPost) {
+        if let Some(id) = &post.draft_id
+            && let Err(e) = self.drafts.remove(id)
+        {
+            tracing::warn!("failed to delete posted draft: {e}");
+        }
+        if self.compose_input == post.text {
+            self.take_pending_post();
+        }
+    }
+
+    /// Put a post that failed back in the composer, or into the Drafts
+    /// view when something else was written since. Returns where it went.
+    pub(super) fn restore_failed_post(&mut self, post: PendingPost) -> &'static str {
+        if self.compose_input.trim().is_empty() || self.compose_input == post.text {
+            self.compose_input = post.text;
+            self.compose_reply_to = post.reply_to;
+            self.compose_thread = post.thread;
+            self.compose_reply_settings = post.reply_settings;
+            self.compose_draft_id = post.draft_id;
+            return "draft kept, press c";
+        }
+        let draft = Draft {
+            id: post
+                .draft_id
+                .unwrap_or_else(|| Utc::now().timestamp_millis().to_string()),
+            reply_to: post.reply_to.as_ref().map(|r| r.tweet_id.clone()),
+            reply_to_username: post.reply_to.and_then(|r| r.username),
+            text: post.text,
+            thread: post.thread,
+            reply_settings: post.reply_settings,
+            saved_at: Utc::now(),
+        };
+        match self.drafts.save(draft) {
+            Ok(()) => "draft kept in :drafts",
+            Err(e) => {
+                tracing::warn!("failed to save the unsent post as a draft: {e}");
+                "saving it as a draft failed too"
+            }
+        }
+    }
+
+    /// Open the selected draft in the composer, saving what was there.
+    pub(super) fn resume_selected_draft(&mut self) {
+        let Some(draft) = self.drafts.entries().get(self.selected_index()).cloned() else {
+            return;
+        };
+        if self.compose_draft_id.as_deref() != Some(draft.id.as_str()) {
+            self.save_compose_draft();
+        }
+        self.compose_input = draft.text;
+        self.compose_reply_to = draft.reply_to.map(|tweet_id| ReplyTarget {
+            tweet_id,
+            username: draft.reply_to_username,
+        });
+        self.compose_thread = draft.thread;
+        self.compose_reply_settings = draft.reply_settings;
+        self.compose_draft_id = Some(draft.id);
+        self.reply_drafts = None;
+        self.mode = AppMode::Compose;
+    }
+
+    /// `d` in the Drafts view: delete the selected draft after a
+    /// confirmation.
+    pub(super) fn delete_selected_draft(&mut self) {
+        let Some(draft) = self.drafts.entries().get(self.selected_index()) else {
+            return;
+        };
+        let preview: String = draft.text.chars().take(40).collect();
+        self.confirm(
+            "delete_draft",
+            "Delete draft",
+            format!("\"{}\" will be gone for good.", preview.trim()),
+            AppEvent::DeleteDraft {
+                id: draft.id.clone(),
+            },
+        );
+    }
+
+    pub(super) fn delete_draft(&mut self, id: &str) {
+        if let Err(e) = self.drafts.remove(id) {
+            self.set_error(format!("Failed to delete the draft: {e}"));
+            return;
+        }
+        if self.compose_draft_id.as_deref() == Some(id) {
+            self.compose_draft_id = None;
+        }
+        if self.current_view() == Some(&ViewKind::Drafts) {
+            let len = self.drafts.entries().len();
+            if let Some(vs) = self.view_stack.last_mut() {
+                vs.selected_index = vs.selected_index.min(len.saturating_sub(1));
+            }
+        }
+        self.notify("Draft deleted");
+    }
+}
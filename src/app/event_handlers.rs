@@ -1,6 +1,9 @@
-use super::{App, ClusterSource, TimelineState};
-use crate::api::types::Tweet;
-use crate::event::{AppEvent, ViewKind};
+use std::sync::Arc;
+
+use super::{App, AppMode, BulkRun, ClusterSource, TimelineState, UnavailableAccount, UsageReport};
+use crate::api::types::{Includes, Meta, SingleResponse, Tweet, User};
+use crate::event::{ApiResult, AppEvent, ViewKind};
+use crate::store::{self, TimelineKey, WatchDelivery};
 
 impl App {
     // -- App event handling -------------------------------------------------
@@ -14,6 +17,10 @@ impl App {
         match event {
             // Navigation
             AppEvent::Quit => {
+                // Quitting from the composer closes it without posting.
+                if self.mode == AppMode::Compose {
+                    self.save_compose_draft();
+                }
                 self.running = false;
             }
             AppEvent::PushView(kind) => {
@@ -25,8 +32,12 @@ impl App {
             AppEvent::RefreshView => {
                 self.refresh_current_view();
             }
+            AppEvent::PlayMacroKey => self.play_macro_key(),
 
             AppEvent::SwitchView(kind) => {
+                if self.refuse_without_user_context(&kind) {
+                    return;
+                }
                 // Replace the root view or push if stack is deeper.
                 if self.view_stack.len() <= 1 {
                     self.view_stack.clear();
@@ -43,7 +54,7 @@ impl App {
             | AppEvent::FetchUserTimeline { .. }
             | AppEvent::FetchTweet { .. }
             | AppEvent::FetchThread { .. }
-            | AppEvent::FetchUser { .. }
+            | AppEvent::FetchUserById { .. }
             | AppEvent::FetchSearch { .. }
             | AppEvent::FetchMentions { .. }
             | AppEvent::FetchBookmarks { .. }
@@ -54,34 +65,92 @@ impl App {
                 // Set per-timeline loading flags so UI widgets know to show
                 // skeleton / loading indicators.
                 match evt {
-                    AppEvent::FetchHomeTimeline { .. } => self.home_timeline.loading = true,
-                    AppEvent::FetchMentions { .. } => self.mentions.loading = true,
-                    AppEvent::FetchBookmarks { .. } => self.bookmarks.loading = true,
-                    AppEvent::FetchSearch { .. } => self.search_results.loading = true,
-                    AppEvent::FetchUserTimeline { .. } => {
-                        self.viewed_user_timeline.loading = true;
+                    AppEvent::FetchHomeTimeline { pagination_token } => {
+                        self.home_timeline
+                            .start_loading(pagination_token.as_deref());
+                    }
+                    AppEvent::FetchMentions { pagination_token } => {
+                        self.mentions.start_loading(pagination_token.as_deref());
+                    }
+                    AppEvent::FetchBookmarks { pagination_token } => {
+                        self.bookmarks.start_loading(pagination_token.as_deref());
+                    }
+                    AppEvent::FetchSearch {
+                        pagination_token, ..
+                    } => {
+                        self.search_local = false;
+                        self.search_results
+                            .start_loading(pagination_token.as_deref());
+                    }
+                    AppEvent::FetchUserTimeline {
+                        pagination_token, ..
+                    } => {
+                        self.viewed_user_timeline
+                            .start_loading(pagination_token.as_deref());
                     }
                     _ => {}
                 }
                 self.dispatch_api_request(evt.clone());
             }
 
+            // Profile lookups are served from the profile cache when
+            // possible and only hit the network when missing or stale.
+            AppEvent::FetchUser { username } => {
+                if let Some((user, fresh)) = self.profile_cache.get(&username) {
+                    let user = user.clone();
+                    let id = user.id.clone();
+                    self.show_profile(user);
+                    if fresh {
+                        return;
+                    }
+                    self.revalidating_profiles.insert(id);
+                } else {
+                    self.loading = true;
+                    self.mark_loading_started();
+                }
+                self.dispatch_api_request(AppEvent::FetchUser { username });
+            }
+
             // API response events
+            AppEvent::RequestFailed { request, response } => {
+                self.handle_app_event_inner(*response);
+                self.retry_request = Some(*request);
+            }
+            AppEvent::ScopeMissing {
+                scope,
+                request,
+                response,
+            } => {
+                self.handle_app_event_inner(*response);
+                self.offer_scope_upgrade(scope, *request);
+            }
             AppEvent::HomeTimelineLoaded(result) => {
                 self.loading = false;
                 self.home_timeline.loading = false;
+                self.splash = None;
                 match result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
-                        self.home_timeline.next_token =
-                            resp.meta.as_ref().and_then(|m| m.next_token.clone());
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(
+                            resp.data.as_deref().unwrap_or_default(),
+                            &resp.includes,
+                        );
                         self.home_timeline.includes = resp.includes;
                         self.home_timeline
-                            .tweets
-                            .extend(resp.data.unwrap_or_default());
+                            .partial_errors
+                            .extend(resp.errors.unwrap_or_default());
+                        if self.home_timeline.cached_at.take().is_some() {
+                            self.home_timeline.clear_tweets();
+                        }
+                        self.add_timeline_page(
+                            TimelineKey::Home,
+                            resp.meta,
+                            resp.data.unwrap_or_default(),
+                        );
+                        self.save_timeline_snapshot(TimelineKey::Home);
                     }
                     Err(e) => {
-                        self.set_error(format!("Error loading timeline: {e}"));
+                        self.timeline_fetch_failed(TimelineKey::Home, "timeline", e);
                     }
                 }
                 if self.refresh_then_cluster && self.cluster_source == Some(ClusterSource::Home) {
@@ -94,13 +163,21 @@ impl App {
                 self.viewed_user_timeline.loading = false;
                 match result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
-                        self.viewed_user_timeline.next_token =
-                            resp.meta.as_ref().and_then(|m| m.next_token.clone());
+                        self.unavailable_account = None;
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(
+                            resp.data.as_deref().unwrap_or_default(),
+                            &resp.includes,
+                        );
                         self.viewed_user_timeline.includes = resp.includes;
                         self.viewed_user_timeline
-                            .tweets
-                            .extend(resp.data.unwrap_or_default());
+                            .partial_errors
+                            .extend(resp.errors.unwrap_or_default());
+                        self.add_timeline_page(
+                            TimelineKey::UserTimeline,
+                            resp.meta,
+                            resp.data.unwrap_or_default(),
+                        );
                     }
                     Err(e) => {
                         self.set_error(format!("Error loading user timeline: {e}"));
@@ -111,20 +188,24 @@ impl App {
                 self.loading = false;
                 match *result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(resp.data.as_slice(), &resp.includes);
                         if let Some(tweet) = resp.data {
                             let conv_id = tweet
                                 .conversation_id
                                 .clone()
                                 .unwrap_or_else(|| tweet.id.clone());
                             self.thread_root = Some(tweet);
+                            // The previous thread's replies aren't this one's.
+                            self.thread_tweets.clear();
+                            self.thread_partial_errors.clear();
                             self.events.send(AppEvent::FetchThread {
                                 conversation_id: conv_id.clone(),
                                 pagination_token: None,
                             });
                             self.push_view(ViewKind::Thread(conv_id));
                         } else {
-                            self.status_message = Some("Tweet not found".to_string());
+                            self.notify("Tweet not found");
                         }
                     }
                     Err(e) => {
@@ -139,8 +220,15 @@ impl App {
                 self.loading = false;
                 match result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(
+                            resp.data.as_deref().unwrap_or_default(),
+                            &resp.includes,
+                        );
                         self.thread_tweets = resp.data.unwrap_or_default();
+                        self.metrics_history
+                            .record(&self.thread_tweets, chrono::Utc::now());
+                        self.thread_partial_errors = resp.errors.unwrap_or_default();
                         // Push the thread view if not already on it.
                         if self.current_view() != Some(&ViewKind::Thread(conversation_id.clone())) {
                             self.push_view(ViewKind::Thread(conversation_id));
@@ -151,17 +239,30 @@ impl App {
                     }
                 }
             }
-            AppEvent::UserLoaded(result) => {
+            AppEvent::UserLoaded { username, result } => {
+                let revalidating = username
+                    .as_deref()
+                    .and_then(|name| self.profile_cache.get(name))
+                    .map(|(user, _)| user.id.clone())
+                    .filter(|id| self.revalidating_profiles.remove(id));
+                if revalidating.is_some() {
+                    self.apply_revalidated_profile(username.as_deref(), result);
+                    return;
+                }
                 self.loading = false;
                 match result {
                     Ok(resp) => {
                         if let Some(user) = resp.data {
-                            let username = user.username.clone();
-                            self.viewed_user = Some(user);
-                            self.viewed_user_timeline = TimelineState::default();
-                            self.push_view(ViewKind::UserProfile(username));
+                            self.unavailable_account = None;
+                            self.profile_cache.insert(user.clone());
+                            if self.instance_lock.is_some()
+                                && let Err(e) = self.profile_cache.save()
+                            {
+                                tracing::warn!("failed to save profile cache: {e}");
+                            }
+                            self.show_profile(user);
                         } else {
-                            self.status_message = Some("User not found".to_string());
+                            self.notify("User not found");
                         }
                     }
                     Err(e) => {
@@ -169,17 +270,51 @@ impl App {
                     }
                 }
             }
+            AppEvent::AccountUnavailable {
+                view,
+                state,
+                detail,
+                requested_from,
+            } => {
+                self.loading = false;
+                if matches!(view, ViewKind::UserTimeline(_)) {
+                    self.viewed_user_timeline.loading = false;
+                }
+                // Only open the profile from where it was asked for; the
+                // state screen shows if it's opened later.
+                let open = matches!(view, ViewKind::UserProfile(_))
+                    && self.current_view() != Some(&view)
+                    && self.current_view() == requested_from.as_ref();
+                self.unavailable_account = Some(UnavailableAccount {
+                    view: view.clone(),
+                    state,
+                    detail,
+                });
+                if open {
+                    self.push_view(view);
+                }
+            }
             AppEvent::SearchLoaded { query, result } => {
                 self.loading = false;
                 self.search_results.loading = false;
                 match result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(
+                            resp.data.as_deref().unwrap_or_default(),
+                            &resp.includes,
+                        );
                         self.search_results.next_token =
                             resp.meta.as_ref().and_then(|m| m.next_token.clone());
+                        self.search_results.previous_token =
+                            resp.meta.as_ref().and_then(|m| m.previous_token.clone());
                         self.search_results.includes = resp.includes;
+                        self.search_results.partial_errors = resp.errors.unwrap_or_default();
                         let tweets = resp.data.unwrap_or_default();
                         self.search_results.tweets = tweets.clone();
+                        if self.search_by_engagement {
+                            self.sort_search_results();
+                        }
 
                         // If any embedding provider is available, trigger semantic re-ranking.
                         if self.has_embed_provider() && !tweets.is_empty() {
@@ -196,19 +331,292 @@ impl App {
                     self.start_cluster(ClusterSource::Search);
                 }
             }
+            AppEvent::SearchLocal { query } => {
+                self.search_local = true;
+                self.search_results.start_loading(None);
+                self.dispatch_local_search(query);
+            }
+            AppEvent::LocalSearchLoaded {
+                query,
+                tweets,
+                includes,
+            } => {
+                // A newer search, local or not, has replaced this one.
+                if !self.search_local || self.search_query != query {
+                    return;
+                }
+                self.show_local_results(tweets, Some(includes));
+                if self.search_results.tweets.is_empty() {
+                    self.notify(format!("No stored tweets match \"{query}\""));
+                }
+            }
+            AppEvent::DomainsCounted(report) => self.show_domain_report(report),
+            AppEvent::StartBulk { action, ids } => {
+                self.clear_batch_selection();
+                self.dispatch_bulk(action, ids);
+            }
+            AppEvent::BulkProgressed {
+                done,
+                total,
+                rate_limited_until,
+            } => {
+                // A late progress event must not resurrect a finished run.
+                if let Some(run) = self.bulk_run.as_mut() {
+                    *run = BulkRun {
+                        done,
+                        total,
+                        rate_limited_until,
+                        ..*run
+                    };
+                }
+            }
+            AppEvent::BulkFinished {
+                action,
+                succeeded,
+                total,
+                failed,
+            } => {
+                self.bulk_run = None;
+                let (verb, noun) = action.summary();
+                match failed.first() {
+                    None => {
+                        self.notify(format!("{verb} {succeeded} {noun}"));
+                    }
+                    Some((id, e)) => {
+                        self.set_error(format!(
+                            "{verb} {succeeded} of {total} {noun}, {} failed ({id}: {e})",
+                            failed.len()
+                        ));
+                    }
+                }
+            }
+            AppEvent::MuteUser {
+                username,
+                user_id,
+                mute,
+            } => {
+                self.dispatch_mute(username, user_id, mute);
+            }
+            AppEvent::MuteFinished {
+                username,
+                mute,
+                result,
+            } => match result {
+                Ok(user_id) if mute => {
+                    self.muted_users.insert(user_id);
+                    self.notify(format!("Muted @{username}"));
+                }
+                Ok(user_id) => {
+                    self.muted_users.remove(&user_id);
+                    self.notify(format!("Unmuted @{username}"));
+                }
+                Err(e) => {
+                    let verb = if mute { "mute" } else { "unmute" };
+                    self.set_error(format!("Failed to {verb} @{username}: {e}"));
+                }
+            },
+            AppEvent::BlockUser {
+                username,
+                user_id,
+                block,
+            } => {
+                self.dispatch_block(username, user_id, block);
+            }
+            AppEvent::BlockFinished {
+                username,
+                block,
+                result,
+            } => match result {
+                Ok(user_id) if block => {
+                    self.blocked_users.insert(user_id);
+                    self.notify(format!("Blocked @{username}"));
+                }
+                Ok(user_id) => {
+                    self.blocked_users.remove(&user_id);
+                    self.notify(format!("Unblocked @{username}"));
+                }
+                Err(e) => {
+                    let verb = if block { "block" } else { "unblock" };
+                    self.set_error(format!("Failed to {verb} @{username}: {e}"));
+                }
+            },
+            AppEvent::BookmarkTweet { tweet, bookmark } => {
+                self.dispatch_bookmark(tweet, bookmark);
+            }
+            AppEvent::BookmarkFinished {
+                tweet,
+                bookmark,
+                result,
+            } => match result {
+                Ok(_) if bookmark => {
+                    self.add_to_bookmarks(tweet);
+                    self.notify("Bookmarked");
+                }
+                Ok(_) => {
+                    self.remove_from_bookmarks(&tweet.id);
+                    self.notify("Removed from bookmarks");
+                }
+                Err(e) => {
+                    let verb = if bookmark {
+                        "bookmark"
+                    } else {
+                        "remove bookmark of"
+                    };
+                    self.set_error(format!("Failed to {verb} tweet: {e}"));
+                }
+            },
+            AppEvent::LikeTweet { tweet_id, like } => {
+                // Already applied by the key handler; this is for retries.
+                self.set_liked(&tweet_id, like);
+                self.dispatch_like(tweet_id, like);
+            }
+            AppEvent::LikeFinished {
+                tweet_id,
+                like,
+                result,
+            } => {
+                if let Err(e) = result {
+                    // Undo the optimistic update.
+                    self.set_liked(&tweet_id, !like);
+                    let verb = if like { "like" } else { "unlike" };
+                    self.set_error(format!("Failed to {verb} tweet: {e}"));
+                }
+            }
+            AppEvent::RetweetTweet { tweet_id, retweet } => {
+                self.set_retweeted(&tweet_id, retweet);
+                self.dispatch_retweet(tweet_id, retweet);
+            }
+            AppEvent::RetweetFinished {
+                tweet_id,
+                retweet,
+                result,
+            } => {
+                if let Err(e) = result {
+                    self.set_retweeted(&tweet_id, !retweet);
+                    let verb = if retweet { "repost" } else { "undo repost of" };
+                    self.set_error(format!("Failed to {verb} tweet: {e}"));
+                }
+            }
+            AppEvent::HideReply { tweet_id, hide } => {
+                self.set_reply_hidden(&tweet_id, hide);
+                self.dispatch_hide_reply(tweet_id, hide);
+            }
+            AppEvent::HideReplyFinished {
+                tweet_id,
+                hide,
+                result,
+            } => {
+                if let Err(e) = result {
+                    self.set_reply_hidden(&tweet_id, !hide);
+                    let verb = if hide { "hide" } else { "unhide" };
+                    self.set_error(format!("Failed to {verb} reply: {e}"));
+                }
+            }
+            AppEvent::PostTweet(post) => {
+                self.posting = true;
+                self.dispatch_post_tweet(post);
+            }
+            AppEvent::TweetPosted { post, result } => {
+                self.posting = false;
+                match result {
+                    Ok(resp) => {
+                        let reply = post.reply_to.is_some();
+                        let tweet_ids = resp.data.into_iter().map(|t| t.id).collect();
+                        self.finish_post(&post);
+                        self.offer_undo_post(
+                            if reply { "Replied" } else { "Posted" },
+                            tweet_ids,
+                            post,
+                        );
+                    }
+                    Err(e) => {
+                        let kept = self.restore_failed_post(post);
+                        self.set_error(format!("Failed to post ({kept}): {e}"));
+                    }
+                }
+            }
+            AppEvent::PostThread {
+                posts,
+                reply_to,
+                posted,
+                post,
+            } => {
+                self.posting = true;
+                self.dispatch_post_thread(posts, reply_to, posted, post);
+            }
+            AppEvent::ThreadPosted {
+                posted,
+                post,
+                result,
+            } => {
+                self.posting = false;
+                match result {
+                    Ok(()) => {
+                        self.finish_post(&post);
+                        self.offer_undo_post("Thread posted", posted, post);
+                    }
+                    Err(e) => {
+                        if !posted.is_empty() {
+                            self.compose_thread_posted = Some((post.text.clone(), posted.clone()));
+                        }
+                        let kept = self.restore_failed_post(post);
+                        self.set_error(format!(
+                            "Thread stopped after {} posts ({kept}; r, or posting it again, \
+                             posts the rest): {e}",
+                            posted.len()
+                        ));
+                    }
+                }
+            }
+            AppEvent::DeletePosts { tweet_ids } => self.dispatch_delete_posts(tweet_ids),
+            AppEvent::PostsDeleted {
+                tweet_ids,
+                deleted,
+                result,
+            } => {
+                self.apply_posts_deleted(&tweet_ids, &deleted, result);
+            }
+            AppEvent::DeleteDraft { id } => self.delete_draft(&id),
+            AppEvent::ReplyDraftsGenerated { tweet_id, result } => {
+                self.apply_reply_drafts(&tweet_id, result);
+            }
+            AppEvent::PipeFinished { command, result } => match result {
+                Ok(stdout) => {
+                    let first = stdout.lines().find(|l| !l.trim().is_empty());
+                    self.notify(match first {
+                        Some(line) => format!("{command}: {line}"),
+                        None => format!("{command}: done"),
+                    });
+                }
+                Err(e) => self.set_error(e),
+            },
             AppEvent::MentionsLoaded(result) => {
                 self.loading = false;
                 self.mentions.loading = false;
+                self.splash = None;
                 match result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
-                        self.mentions.next_token =
-                            resp.meta.as_ref().and_then(|m| m.next_token.clone());
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(
+                            resp.data.as_deref().unwrap_or_default(),
+                            &resp.includes,
+                        );
                         self.mentions.includes = resp.includes;
-                        self.mentions.tweets.extend(resp.data.unwrap_or_default());
+                        self.mentions
+                            .partial_errors
+                            .extend(resp.errors.unwrap_or_default());
+                        if self.mentions.cached_at.take().is_some() {
+                            self.mentions.clear_tweets();
+                        }
+                        self.add_timeline_page(
+                            TimelineKey::Mentions,
+                            resp.meta,
+                            resp.data.unwrap_or_default(),
+                        );
+                        self.save_timeline_snapshot(TimelineKey::Mentions);
                     }
                     Err(e) => {
-                        self.set_error(format!("Error loading mentions: {e}"));
+                        self.timeline_fetch_failed(TimelineKey::Mentions, "mentions", e);
                     }
                 }
                 if self.refresh_then_cluster && self.cluster_source == Some(ClusterSource::Mentions)
@@ -220,16 +628,30 @@ impl App {
             AppEvent::BookmarksLoaded(result) => {
                 self.loading = false;
                 self.bookmarks.loading = false;
+                self.splash = None;
                 match result {
                     Ok(resp) => {
-                        self.cache_users_from_includes(&resp.includes);
-                        self.bookmarks.next_token =
-                            resp.meta.as_ref().and_then(|m| m.next_token.clone());
+                        self.cache_includes(&resp.includes);
+                        self.archive_fetched(
+                            resp.data.as_deref().unwrap_or_default(),
+                            &resp.includes,
+                        );
                         self.bookmarks.includes = resp.includes;
-                        self.bookmarks.tweets.extend(resp.data.unwrap_or_default());
+                        self.bookmarks
+                            .partial_errors
+                            .extend(resp.errors.unwrap_or_default());
+                        if self.bookmarks.cached_at.take().is_some() {
+                            self.bookmarks.clear_tweets();
+                        }
+                        self.add_timeline_page(
+                            TimelineKey::Bookmarks,
+                            resp.meta,
+                            resp.data.unwrap_or_default(),
+                        );
+                        self.save_timeline_snapshot(TimelineKey::Bookmarks);
                     }
                     Err(e) => {
-                        self.set_error(format!("Error loading bookmarks: {e}"));
+                        self.timeline_fetch_failed(TimelineKey::Bookmarks, "bookmarks", e);
                     }
                 }
                 if self.refresh_then_cluster
@@ -262,11 +684,12 @@ impl App {
                 }
             }
 
-            // Auth (StartAuth is handled in run() before reaching here)
+            // Auth (StartAuth and UpgradeScope are handled in run() before reaching here)
             AppEvent::StartAuth => unreachable!("StartAuth intercepted in run()"),
+            AppEvent::UpgradeScope { .. } => unreachable!("UpgradeScope intercepted in run()"),
             AppEvent::AuthCompleted(result) => match result {
                 Ok(user_id) => {
-                    self.status_message = Some(format!("Authenticated as {user_id}"));
+                    self.notify(format!("Authenticated as {user_id}"));
                 }
                 Err(e) => {
                     self.set_error(format!("Auth failed: {e}"));
@@ -289,7 +712,7 @@ impl App {
                 match result {
                     Ok(models) => {
                         self.openrouter_models = models;
-                        self.status_message = Some(format!(
+                        self.notify(format!(
                             "Loaded {} embedding models",
                             self.openrouter_models.len()
                         ));
@@ -301,14 +724,14 @@ impl App {
             }
             AppEvent::SelectEmbeddingModel { model_id } => {
                 self.selected_embedding_model = Some(model_id.clone());
-                self.status_message = Some(format!("Selected model: {model_id}"));
+                self.notify(format!("Selected model: {model_id}"));
                 self.pop_view();
             }
 
             // Embeddings: semantic search re-ranking
             AppEvent::EmbedAndRankSearch { query, tweets } => {
                 self.loading = true;
-                self.mark_loading_started();
+                self.embedding_job = true;
                 self.dispatch_embed_and_rank(query, tweets);
             }
             AppEvent::SearchRanked {
@@ -317,21 +740,23 @@ impl App {
                 result,
             } => {
                 self.loading = false;
+                self.embedding_job = false;
                 // Guard: only apply if the query and model still match current state.
                 let query_matches = self.search_query == query;
                 let model_matches =
                     self.resolved_embed_model().as_deref() == Some(model_id.as_str());
                 if !query_matches || !model_matches {
-                    self.status_message =
-                        Some("Stale ranking result discarded (query or model changed)".into());
+                    self.notify("Stale ranking result discarded (query or model changed)");
                     return;
                 }
                 match result {
                     Ok(ranked) => {
                         let tweets: Vec<Tweet> = ranked.into_iter().map(|(t, _)| t).collect();
                         self.search_results.tweets = tweets;
-                        self.status_message =
-                            Some("Search results re-ranked by semantic similarity".into());
+                        if self.search_by_engagement {
+                            self.sort_search_results();
+                        }
+                        self.notify("Search results re-ranked by semantic similarity");
                     }
                     Err(e) => {
                         self.set_error(format!("Ranking error: {e}"));
@@ -339,6 +764,31 @@ impl App {
                 }
             }
 
+            AppEvent::FindSimilar { query, tweet } => {
+                self.search_local = true;
+                self.search_results.start_loading(None);
+                self.embedding_job = true;
+                self.dispatch_similar(query, *tweet);
+            }
+            AppEvent::SimilarLoaded { query, result } => {
+                self.embedding_job = false;
+                if !self.search_local || self.search_query != query {
+                    return;
+                }
+                match result {
+                    Ok((tweets, includes)) => {
+                        self.show_local_results(tweets, Some(includes));
+                        if self.search_results.tweets.is_empty() {
+                            self.notify("No bookmarks or stored tweets to compare with");
+                        }
+                    }
+                    Err(e) => {
+                        self.search_results.loading = false;
+                        self.set_error(format!("Finding similar tweets failed: {e}"));
+                    }
+                }
+            }
+
             // Clustering
             AppEvent::ClusterTimeline => {
                 // Resolve source from the current view. If invoked from within
@@ -350,9 +800,8 @@ impl App {
                     None => None,
                 };
                 let Some(source) = source else {
-                    self.status_message = Some(
-                        "Cluster only supported from Home, Mentions, Search, or Bookmarks views."
-                            .into(),
+                    self.notify(
+                        "Cluster only supported from Home, Mentions, Search, or Bookmarks views.",
                     );
                     return;
                 };
@@ -364,7 +813,7 @@ impl App {
                     Ok(cluster_result) => {
                         self.cluster_generation += 1;
                         self.cluster_result = Some(cluster_result);
-                        self.status_message = Some("Clustering complete!".into());
+                        self.notify("Clustering complete!");
                         // Auto-trigger LLM topic generation if a chat provider is available.
                         if self.has_chat_provider() {
                             self.cluster_topics_loading = true;
@@ -387,7 +836,7 @@ impl App {
                 self.text_models_loading = false;
                 match result {
                     Ok(models) => {
-                        self.status_message = Some(format!("Loaded {} text models", models.len()));
+                        self.notify(format!("Loaded {} text models", models.len()));
                         self.text_models = models;
                     }
                     Err(e) => {
@@ -397,7 +846,7 @@ impl App {
             }
             AppEvent::SelectChatModel { model_id } => {
                 self.selected_chat_model = Some(model_id.clone());
-                self.status_message = Some(format!("Selected chat model: {model_id}"));
+                self.notify(format!("Selected chat model: {model_id}"));
                 self.pop_view();
             }
 
@@ -417,9 +866,309 @@ impl App {
                     if chat {
                         caps.push("chat");
                     }
-                    self.status_message = Some(format!("MLX server detected: {}", caps.join(", ")));
+                    self.notify(format!("MLX server detected: {}", caps.join(", ")));
                 } else {
-                    self.status_message = Some("MLX server not reachable.".into());
+                    self.notify("MLX server not reachable.");
+                }
+            }
+
+            // Author previews and posting heatmaps show a failure in their
+            // own panel rather than raising an error popup.
+            AppEvent::FetchAuthorPreview { user_id } => {
+                self.dispatch_author_preview(user_id);
+            }
+            AppEvent::AuthorPreviewLoaded { user_id, result } => {
+                if let Ok(resp) = &result {
+                    self.cache_includes(&resp.includes);
+                }
+                let Some(preview) = self
+                    .author_preview
+                    .as_mut()
+                    .filter(|p| p.user_id == user_id)
+                else {
+                    return;
+                };
+                preview.loading = false;
+                match result {
+                    Ok(resp) => preview.tweets = resp.data.unwrap_or_default(),
+                    Err(e) => preview.error = Some(e.lines().next().unwrap_or_default().into()),
+                }
+            }
+            AppEvent::FetchPostingActivity { user_id } => {
+                self.dispatch_posting_activity(user_id);
+            }
+            AppEvent::PostingActivityLoaded { user_id, result } => {
+                let Some(heatmap) = self
+                    .posting_heatmap
+                    .as_mut()
+                    .filter(|h| h.user_id == user_id)
+                else {
+                    return;
+                };
+                heatmap.loading = false;
+                match result {
+                    Ok(times) => heatmap.count(&times, &chrono::Local),
+                    Err(e) => heatmap.error = Some(e.lines().next().unwrap_or_default().into()),
+                }
+            }
+
+            // Profile banners (best effort: failures just leave the strip
+            // empty rather than raising an error popup).
+            AppEvent::FetchProfileBanner { url } => {
+                self.dispatch_profile_banner(url);
+            }
+            AppEvent::ProfileBannerLoaded { url, result } => {
+                let current = self
+                    .viewed_user
+                    .as_ref()
+                    .and_then(|u| u.profile_banner_url.as_deref());
+                if current != Some(url.as_str()) {
+                    return;
+                }
+                match result {
+                    Ok(image) => self.viewed_user_banner = Some((url, image)),
+                    Err(e) => tracing::debug!("profile banner unavailable: {e}"),
+                }
+            }
+
+            // Startup splash details (best effort, like banners).
+            AppEvent::FetchAccountSummary => {
+                self.dispatch_account_summary();
+            }
+            AppEvent::AccountSummaryLoaded { me, usage } => {
+                match me {
+                    Ok(resp) => self.current_user = resp.data,
+                    Err(e) => tracing::debug!("account lookup failed: {e}"),
+                }
+                if let Some(splash) = self.splash.as_mut() {
+                    splash.account_loaded = true;
+                    match usage {
+                        Ok(resp) => splash.usage = resp.data,
+                        Err(e) => tracing::debug!("usage lookup failed: {e}"),
+                    }
+                }
+            }
+
+            AppEvent::FetchLinkPreview { url } => {
+                self.dispatch_link_preview(url);
+            }
+            AppEvent::LinkPreviewLoaded { url, result } => match result {
+                Ok(preview) => {
+                    self.link_previews.insert(url, Some(preview));
+                }
+                Err(e) => tracing::debug!("link preview unavailable: {e}"),
+            },
+
+            AppEvent::WatchSinksFailed(failures) => {
+                for failure in &failures {
+                    self.delivered_watched.remove(&WatchDelivery {
+                        tweet_id: failure.tweet_id.clone(),
+                        sink: failure.sink.key(),
+                    });
+                }
+                let [first, rest @ ..] = failures.as_slice() else {
+                    return;
+                };
+                match rest.len() {
+                    0 => self.notify(format!("Watch rule sink failed: {first}")),
+                    n => self.notify(format!("Watch rule sink failed: {first} (and {n} more)")),
+                }
+            }
+
+            // Lists
+            AppEvent::FetchLists => {
+                self.lists_loading = true;
+                self.mark_loading_started();
+                self.dispatch_lists();
+            }
+            AppEvent::ListsLoaded(result) => {
+                self.lists_loading = false;
+                match result {
+                    Ok((owned, followed)) => {
+                        self.owned_lists = owned;
+                        self.followed_lists = followed;
+                    }
+                    Err(e) => {
+                        self.set_error(format!("Error loading lists: {e}"));
+                    }
+                }
+            }
+            AppEvent::FetchListMembers { list_id } => {
+                self.list_members_loading = true;
+                self.dispatch_list_members(list_id);
+            }
+            AppEvent::ListMembersLoaded { list_id, result } => {
+                self.list_members_loading = false;
+                // Members of a List that is no longer open are dropped.
+                if self.current_view() != Some(&ViewKind::ListMembers(list_id)) {
+                    return;
+                }
+                match result {
+                    Ok(users) => self.list_members = users,
+                    Err(e) => self.set_error(format!("Error loading List members: {e}")),
+                }
+            }
+            AppEvent::FetchTweetUsers { tweet_id, kind } => {
+                self.tweet_users_loading = true;
+                self.mark_loading_started();
+                self.dispatch_tweet_users(tweet_id, kind);
+            }
+            AppEvent::TweetUsersLoaded {
+                tweet_id,
+                kind,
+                result,
+            } => {
+                self.tweet_users_loading = false;
+                if self.current_view() != Some(&ViewKind::TweetUsers { tweet_id, kind }) {
+                    return;
+                }
+                match result {
+                    Ok(users) => self.tweet_users = users,
+                    Err(e) => self.set_error(format!("Error loading {}: {e}", kind.noun())),
+                }
+            }
+            AppEvent::FetchStreamRules => {
+                self.stream_rules_loading = true;
+                self.mark_loading_started();
+                self.dispatch_stream_rules();
+            }
+            AppEvent::StreamRulesLoaded(result) => {
+                self.stream_rules_loading = false;
+                match result {
+                    Ok(rules) => self.stream_rules = rules,
+                    Err(e) => self.set_error(format!("Error loading stream rules: {e}")),
+                }
+            }
+            AppEvent::AddStreamRules { rules } => self.dispatch_add_stream_rules(rules),
+            AppEvent::FetchUsage => {
+                self.usage_loading = true;
+                self.mark_loading_started();
+                self.dispatch_usage();
+            }
+            AppEvent::UsageLoaded(result) => {
+                self.usage_loading = false;
+                match result {
+                    Ok(usage) => {
+                        self.usage_report = Some(UsageReport {
+                            usage,
+                            checked_on: chrono::Local::now().date_naive(),
+                        });
+                    }
+                    Err(e) => self.set_error(format!("Error loading usage: {e}")),
+                }
+            }
+            AppEvent::StreamRulesAdded(result) => self.apply_stream_rules_added(result),
+            AppEvent::DeleteStreamRules { ids } => self.dispatch_delete_stream_rules(ids),
+            AppEvent::StreamRulesDeleted { ids, result } => {
+                self.apply_stream_rules_deleted(&ids, result);
+            }
+            AppEvent::ConnectStream => self.dispatch_filtered_stream(),
+            AppEvent::StreamStatusChanged { generation, status } => {
+                self.apply_stream_status(generation, status);
+            }
+            AppEvent::StreamMessageReceived {
+                generation,
+                message,
+            } => self.apply_stream_message(generation, *message),
+            AppEvent::SetListMember { list, user, member } => {
+                self.dispatch_list_member(list, user, member);
+            }
+            AppEvent::ListMemberFinished {
+                list,
+                user,
+                member,
+                result,
+            } => match result {
+                Ok(()) => {
+                    let (verb, prep) = if member {
+                        ("Added", "to")
+                    } else {
+                        ("Removed", "from")
+                    };
+                    self.notify(format!("{verb} @{} {prep} {}", user.username, list.name));
+                    self.apply_list_member(&list.id, user, member);
+                }
+                Err(e) => {
+                    let (verb, prep) = if member {
+                        ("add", "to")
+                    } else {
+                        ("remove", "from")
+                    };
+                    self.set_error(format!(
+                        "Failed to {verb} @{} {prep} {}: {e}",
+                        user.username, list.name
+                    ));
+                }
+            },
+            AppEvent::SaveList { list_id, fields } => {
+                self.dispatch_save_list(list_id, fields);
+            }
+            AppEvent::ListSaved {
+                list_id,
+                fields,
+                result,
+            } => match result {
+                Ok(id) => {
+                    let verb = if list_id.is_some() {
+                        "Saved"
+                    } else {
+                        "Created"
+                    };
+                    self.notify(format!("{verb} List {}", fields.name));
+                    self.apply_list_saved(id, fields);
+                }
+                Err(e) => {
+                    let verb = if list_id.is_some() { "save" } else { "create" };
+                    self.set_error(format!("Failed to {verb} List {}: {e}", fields.name));
+                }
+            },
+            AppEvent::DeleteList { list } => self.dispatch_delete_list(list),
+            AppEvent::ListDeleted { list, result } => match result {
+                Ok(()) => {
+                    self.notify(format!("Deleted List {}", list.name));
+                    self.apply_list_deleted(&list.id);
+                }
+                Err(e) => self.set_error(format!("Failed to delete List {}: {e}", list.name)),
+            },
+
+            // Direct messages
+            AppEvent::FetchDmEvents => {
+                self.dm_loading = true;
+                self.mark_loading_started();
+                self.dispatch_dm_events();
+            }
+            AppEvent::DmEventsLoaded(result) => {
+                self.dm_loading = false;
+                match result {
+                    Ok((user_id, resp)) => {
+                        self.cache_includes(&resp.includes);
+                        self.dm_user_id = Some(user_id);
+                        self.dm_events = resp.data.unwrap_or_default();
+                    }
+                    Err(e) => self.set_error(format!("Error loading messages: {e}")),
+                }
+            }
+            AppEvent::OpenDm { username } => self.dispatch_open_dm(username),
+            AppEvent::DmParticipantResolved { username, result } => match result {
+                Ok(participant_id) => self.open_dm_conversation(participant_id),
+                Err(e) => self.set_error(format!("Can't message @{username}: {e}")),
+            },
+            AppEvent::FetchDmConversation { participant_id } => {
+                self.dm_conversation_loading = true;
+                self.dispatch_dm_conversation(participant_id);
+            }
+            AppEvent::DmConversationLoaded {
+                participant_id,
+                result,
+            } => {
+                self.dm_conversation_loading = false;
+                // A conversation that is no longer open is dropped.
+                if self.current_view() != Some(&ViewKind::DmConversation(participant_id)) {
+                    return;
+                }
+                match result {
+                    Ok(resp) => self.apply_dm_conversation(resp),
+                    Err(e) => self.set_error(format!("Error loading conversation: {e}")),
                 }
             }
 
@@ -437,8 +1186,7 @@ impl App {
                 self.hf_models_loading = false;
                 match result {
                     Ok(models) => {
-                        self.status_message =
-                            Some(format!("Loaded {} HuggingFace models", models.len()));
+                        self.notify(format!("Loaded {} HuggingFace models", models.len()));
                         self.hf_models = models;
                     }
                     Err(e) => {
@@ -450,14 +1198,13 @@ impl App {
             // LLM cluster topic generation
             AppEvent::GenerateClusterTopics => {
                 if self.cluster_result.is_none() {
-                    self.status_message = Some("No cluster result. Use :cluster first.".into());
+                    self.notify("No cluster result. Use :cluster first.");
                     return;
                 }
                 if !self.has_chat_provider() {
-                    self.status_message = Some(
+                    self.notify(
                         "No chat provider configured. Set mlx_server_url in config \
-                         or use :openrouter-auth + :openrouter-models."
-                            .into(),
+                         or use :openrouter-auth + :openrouter-models.",
                     );
                     return;
                 }
@@ -483,7 +1230,7 @@ impl App {
                                 }
                             }
                             let provider = self.resolved_chat_provider_name().unwrap_or("LLM");
-                            self.status_message = Some(format!(
+                            self.notify(format!(
                                 "{provider} generated {applied}/{cluster_count} topic labels"
                             ));
                         }
@@ -517,7 +1264,7 @@ impl App {
             ClusterSource::Bookmarks => self.bookmarks.tweets.is_empty(),
         };
         if tweets_empty {
-            self.status_message = Some(format!("No tweets to cluster in {source}. Load it first."));
+            self.notify(format!("No tweets to cluster in {source}. Load it first."));
             return;
         }
         self.cluster_source = Some(source);
@@ -529,4 +1276,286 @@ impl App {
         }
         self.dispatch_cluster_timeline();
     }
+
+    /// Replace the search results with tweets found locally, which come
+    /// as a single page, along with their stored includes.
+    fn show_local_results(&mut self, tweets: Vec<Tweet>, includes: Option<Includes>) {
+        self.cache_includes(&includes);
+        self.search_results.loading = false;
+        self.search_results.next_token = None;
+        self.search_results.previous_token = None;
+        self.search_results.includes = includes;
+        self.search_results.partial_errors.clear();
+        self.search_results.tweets = tweets;
+        if self.search_by_engagement {
+            self.sort_search_results();
+        }
+    }
+
+    /// A background refresh of a cached profile answered. It only updates
+    /// the cache, and the profile view if that profile is still on screen;
+    /// the user may have moved on since.
+    fn apply_revalidated_profile(
+        &mut self,
+        username: Option<&str>,
+        result: ApiResult<SingleResponse<User>>,
+    ) {
+        let on_profile = |app: &App| {
+            matches!(
+                (app.current_view(), username),
+                (Some(ViewKind::UserProfile(name)), Some(username))
+                    if name.eq_ignore_ascii_case(username)
+            )
+        };
+        match result {
+            Ok(resp) => {
+                let Some(user) = resp.data else { return };
+                self.profile_cache.insert(user.clone());
+                if self.instance_lock.is_some()
+                    && let Err(e) = self.profile_cache.save()
+                {
+                    tracing::warn!("failed to save profile cache: {e}");
+                }
+                if on_profile(self) {
+                    self.unavailable_account = None;
+                    self.show_profile(user);
+                }
+            }
+            Err(e) if on_profile(self) => {
+                let first_line = e.lines().next().unwrap_or_default().to_string();
+                self.notify(format!(
+                    "Showing cached profile; refresh failed: {first_line}"
+                ));
+            }
+            Err(e) => tracing::debug!("profile refresh failed: {e}"),
+        }
+    }
+
+    /// Show `user` in the profile view. If that profile is already on
+    /// screen (a background revalidation finished), update it in place.
+    fn show_profile(&mut self, user: User) {
+        let on_profile = matches!(
+            self.current_view(),
+            Some(ViewKind::UserProfile(name)) if name.eq_ignore_ascii_case(&user.username)
+        );
+        let username = user.username.clone();
+        let banner_loaded = match (&self.viewed_user_banner, &user.profile_banner_url) {
+            (Some((loaded, _)), Some(url)) => loaded == url,
+            _ => false,
+        };
+        if !banner_loaded {
+            self.viewed_user_banner = None;
+            if let Some(url) = &user.profile_banner_url {
+                self.events
+                    .send(AppEvent::FetchProfileBanner { url: url.clone() });
+            }
+        }
+        self.viewed_user = Some(user);
+        if !on_profile {
+            self.viewed_user_timeline = TimelineState::default();
+            self.push_view(ViewKind::UserProfile(username));
+        }
+    }
+
+    // -- Offline snapshots --------------------------------------------------
+
+    pub(super) fn timeline_mut(&mut self, key: TimelineKey) -> &mut TimelineState {
+        match key {
+            TimelineKey::Home => &mut self.home_timeline,
+            TimelineKey::Mentions => &mut self.mentions,
+            TimelineKey::Bookmarks => &mut self.bookmarks,
+            TimelineKey::UserTimeline => &mut self.viewed_user_timeline,
+        }
+    }
+
+    /// Add a fetched page to a timeline. Pages fetched with `next_token` go
+    /// after the loaded tweets; a page fetched with `previous_token` goes
+    /// before them, with the selection moved up to its first tweet.
+    fn add_timeline_page(&mut self, key: TimelineKey, meta: Option<Meta>, mut tweets: Vec<Tweet>) {
+        if matches!(key, TimelineKey::Home | TimelineKey::Mentions) {
+            tweets.retain(|t| !self.muted_conversations.hides(t));
+        }
+        // Before folding, so a watched tweet folded away still counts.
+        if self.config.watch.has_sinks() {
+            let includes = self.timeline_mut(key).includes.clone();
+            self.dispatch_watch_sinks(&tweets, &includes);
+        }
+        if self.config.watch.notify {
+            let watched = tweets.iter().filter(|t| self.is_watched(t)).count();
+            match watched {
+                0 => {}
+                1 => self.notify("1 new tweet matches your watch rules"),
+                n => self.notify(format!("{n} new tweets match your watch rules")),
+            }
+        }
+        if self.config.collapse_duplicates && key != TimelineKey::Bookmarks {
+            tweets = self.timeline_mut(key).collapse_duplicates(tweets);
+        }
+        let min_run = self.config.collapse_author_runs;
+        if min_run > 0 && key != TimelineKey::Bookmarks {
+            tweets = self.timeline_mut(key).collapse_author_runs(tweets, min_run);
+        }
+        let tl = self.timeline_mut(key);
+        let previous_token = meta.as_ref().and_then(|m| m.previous_token.clone());
+        if !std::mem::take(&mut tl.loading_previous) {
+            // Only the first page knows what comes before the timeline.
+            if tl.tweets.is_empty() {
+                tl.previous_token = previous_token;
+            }
+            tl.next_token = meta.and_then(|m| m.next_token);
+            tl.tweets.extend(tweets);
+            self.enforce_timeline_cap(key);
+            return;
+        }
+
+        // Not capped: the cap evicts from the front, where this page went.
+        tl.previous_token = previous_token;
+        tl.tweets.splice(0..0, tweets);
+        tl.selected_index = 0;
+        tl.scroll_offset = 0;
+        for vs in &mut self.view_stack {
+            if shows_timeline(&vs.kind, key) {
+                vs.selected_index = 0;
+                vs.scroll_offset = 0;
+            }
+        }
+    }
+
+    /// Keep a timeline within `max_timeline_tweets` by dropping the earliest
+    /// loaded tweets (the ones scrolled past while paginating) and spilling
+    /// them to the local store (only from the window holding the instance
+    /// lock, like the other writes). Selection and scroll positions are
+    /// shifted so the same tweet stays selected; a selected tweet is never
+    /// evicted, so the timeline may stay over the cap until the cursor
+    /// moves on.
+    fn enforce_timeline_cap(&mut self, key: TimelineKey) {
+        let cap = self.config.max_timeline_tweets;
+        let selected = self
+            .view_stack
+            .iter()
+            .filter(|vs| shows_timeline(&vs.kind, key))
+            .map(|vs| vs.selected_index)
+            .min();
+        let tl = self.timeline_mut(key);
+        let selected = selected.unwrap_or(tl.selected_index);
+        let excess = tl.tweets.len().saturating_sub(cap).min(selected);
+        if cap == 0 || excess == 0 {
+            return;
+        }
+
+        let evicted: Vec<Tweet> = tl.tweets.drain(..excess).collect();
+        tl.forget_groups(&evicted);
+        tl.selected_index = tl.selected_index.saturating_sub(excess);
+        tl.scroll_offset = tl.scroll_offset.saturating_sub(excess);
+        for vs in &mut self.view_stack {
+            if shows_timeline(&vs.kind, key) {
+                vs.selected_index = vs.selected_index.saturating_sub(excess);
+                vs.scroll_offset = vs.scroll_offset.saturating_sub(excess);
+            }
+        }
+
+        if self.instance_lock.is_some()
+            && let Err(e) = store::spill_tweets(key, &evicted)
+        {
+            tracing::warn!(?key, "failed to spill evicted tweets: {e}");
+        }
+    }
+
+    /// Persist a top-level timeline after a successful fetch so it can be
+    /// shown again in degraded mode, and sample its tweets' metrics.
+    fn save_timeline_snapshot(&mut self, key: TimelineKey) {
+        let tl = match key {
+            TimelineKey::Home => &self.home_timeline,
+            TimelineKey::Mentions => &self.mentions,
+            TimelineKey::Bookmarks => &self.bookmarks,
+            TimelineKey::UserTimeline => &self.viewed_user_timeline,
+        };
+        self.metrics_history.record(&tl.tweets, chrono::Utc::now());
+        if self.instance_lock.is_none() {
+            return;
+        }
+        if let Err(e) = store::save_timeline(key, &tl.tweets, tl.includes.as_ref()) {
+            tracing::warn!(?key, "failed to save timeline snapshot: {e}");
+        }
+    }
+
+    /// Add fetched tweets, and those in their includes, to the archive
+    /// `:local` searches, each once a session, written on a blocking
+    /// thread. Like the other caches, only the window holding the instance
+    /// lock writes it.
+    pub(super) fn archive_fetched(&mut self, tweets: &[Tweet], includes: &Option<Includes>) {
+        if self.instance_lock.is_none() {
+            return;
+        }
+        let included = includes.iter().flat_map(|inc| inc.tweets.iter().flatten());
+        let tweets: Vec<Tweet> = tweets
+            .iter()
+            .chain(included)
+            .filter(|t| self.archived_ids.insert(t.id.clone()))
+            .cloned()
+            .collect();
+        if tweets.is_empty() {
+            return;
+        }
+        let includes = includes.clone();
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = store::archive_tweets(&tweets, &includes) {
+                tracing::warn!("failed to archive fetched tweets: {e}");
+            }
+        });
+    }
+
+    /// Fill an empty timeline from its local snapshot. Returns `true` if
+    /// cached tweets were restored.
+    pub(super) fn restore_timeline_snapshot(&mut self, key: TimelineKey) -> bool {
+        if !self.timeline_mut(key).tweets.is_empty() {
+            return false;
+        }
+        let Some(snapshot) = store::load_timeline(key) else {
+            return false;
+        };
+        self.cache_includes(&snapshot.includes);
+        let tl = self.timeline_mut(key);
+        tl.tweets = snapshot.tweets;
+        tl.includes = snapshot.includes;
+        tl.next_token = None;
+        tl.previous_token = None;
+        tl.cached_at = Some(snapshot.saved_at);
+        true
+    }
+
+    /// Report a failed timeline fetch, falling back to the local snapshot
+    /// when the timeline has nothing to show.
+    ///
+    /// Without an API client the offline banner already explains what is
+    /// wrong, so only the status bar is updated instead of raising the
+    /// error popup on every view switch.
+    fn timeline_fetch_failed(&mut self, key: TimelineKey, label: &str, err: Arc<String>) {
+        let restored =
+            self.restore_timeline_snapshot(key) || self.timeline_mut(key).cached_at.is_some();
+        if self.api_client.is_none() {
+            self.notify(if restored {
+                format!("Offline: showing cached {label}")
+            } else {
+                format!("Offline: no cached {label} available")
+            });
+        } else if restored {
+            self.set_error(format!(
+                "Error loading {label}: {err} (showing cached copy)"
+            ));
+        } else {
+            self.set_error(format!("Error loading {label}: {err}"));
+        }
+    }
+}
+
+/// Whether `kind` is a view of the timeline stored under `key`.
+fn shows_timeline(kind: &ViewKind, key: TimelineKey) -> bool {
+    matches!(
+        (kind, key),
+        (ViewKind::Home, TimelineKey::Home)
+            | (ViewKind::Mentions, TimelineKey::Mentions)
+            | (ViewKind::Bookmarks, TimelineKey::Bookmarks)
+            | (ViewKind::UserTimeline(_), TimelineKey::UserTimeline)
+    )
 }
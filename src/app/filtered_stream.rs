@@ -0,0 +1,96 @@
+use super::App;
+use crate::api::types::StreamMessage;
+use crate::event::{AppEvent, StreamStatus, ViewKind};
+
+/// Streamed tweets kept in the view; older ones drop off the bottom.
+const MAX_STREAMED: usize = 500;
+
+impl App {
+    /// `:stream` opens the live view and connects; `:stream stop`
+    /// disconnects, keeping the tweets that arrived.
+    pub(super) fn run_stream_command(&mut self, stop: bool) {
+        if stop {
+            if self.stream_cancel.is_some() {
+                self.stop_stream();
+                self.notify("Stream stopped");
+            }
+            return;
+        }
+        if self.current_view() != Some(&ViewKind::FilteredStream) {
+            self.push_view(ViewKind::FilteredStream);
+        }
+        if self.stream_cancel.is_none() {
+            self.events.send(AppEvent::ConnectStream);
+        }
+    }
+
+    pub(super) fn stop_stream(&mut self) {
+        if let Some(cancel) = self.stream_cancel.take() {
+            cancel.cancel();
+        }
+        self.stream_status = StreamStatus::Stopped;
+    }
+
+    /// The stream only runs while its view is on top, so a view opened
+    /// over it or replacing it disconnects it.
+    pub(super) fn stop_stream_unless_shown(&mut self) {
+        if self.current_view() != Some(&ViewKind::FilteredStream) {
+            self.stop_stream();
+        }
+    }
+
+    /// Whether updates from the stream task started as `generation` still
+    /// count. A stopped or replaced task may have had some queued.
+    fn is_current_stream(&self, generation: u64) -> bool {
+        self.stream_cancel.is_some() && generation == self.stream_generation
+    }
+
+    pub(super) fn apply_stream_status(&mut self, generation: u64, status: StreamStatus) {
+        if !self.is_current_stream(generation) {
+            return;
+        }
+        if let StreamStatus::Failed(reason) = &status {
+            self.set_error(format!("The stream stopped: {reason}"));
+            self.stream_cancel = None;
+        }
+        self.stream_status = status;
+    }
+
+    /// Put a streamed tweet at the top, keeping the selection on the tweet
+    /// it was on unless it was following the newest one.
+    pub(super) fn apply_stream_message(&mut self, generation: u64, message: StreamMessage) {
+        if !self.is_current_stream(generation) {
+            return;
+        }
+        if let Some(errors) = &message.errors
+            && message.data.is_none()
+        {
+            // X explains why it is about to close the connection; the
+            // reconnect follows on its own.
+            if let Some(error) = errors.first() {
+                self.notify(format!("Stream: {}", error.summary()));
+            }
+            return;
+        }
+        let Some(tweet) = message.data else {
+            return;
+        };
+        self.cache_includes(&message.includes);
+        if self.filtered_stream.tweets.iter().any(|t| t.id == tweet.id) {
+            return;
+        }
+        self.archive_fetched(std::slice::from_ref(&tweet), &message.includes);
+        if self.config.watch.has_sinks() {
+            self.dispatch_watch_sinks(std::slice::from_ref(&tweet), &message.includes);
+        }
+        let tweets = &mut self.filtered_stream.tweets;
+        tweets.insert(0, tweet);
+        tweets.truncate(MAX_STREAMED);
+        for vs in &mut self.view_stack {
+            if vs.kind == ViewKind::FilteredStream && vs.selected_index > 0 {
+                vs.selected_index = (vs.selected_index + 1).min(MAX_STREAMED - 1);
+                vs.scroll_offset += 1;
+            }
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::{App, AppMode};
-use crate::event::{AppEvent, ViewKind};
+use crate::api::types::ReplySettings;
+use crate::event::{AppEvent, TweetUsersKind, ViewKind};
+use crate::thread_split::{POST_LIMIT, post_len, split_thread};
 
 impl App {
     // -- Key event routing --------------------------------------------------
 
-    pub(super) fn handle_key_event(&mut self, key: KeyEvent) {
+    pub(crate) fn handle_key_event(&mut self, key: KeyEvent) {
         // Ctrl-C always quits.
         if key.modifiers.contains(KeyModifiers::CONTROL)
             && matches!(key.code, KeyCode::Char('c' | 'C'))
@@ -15,17 +17,43 @@ impl App {
             return;
         }
 
+        if self.handle_macro_prefix(key) {
+            return;
+        }
+        let was_recording = self.recording_macro().is_some() && self.mode_records_macro_keys();
+        self.route_key_event(key);
+        self.record_macro_key(key, was_recording);
+    }
+
+    fn route_key_event(&mut self, key: KeyEvent) {
         // Dismiss error popup if open (swallow all other keys).
         if self.error_detail.is_some() {
             match key.code {
                 KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
                     self.error_detail = None;
                 }
+                KeyCode::Char('r') if self.retry_request.is_some() => {
+                    self.retry_failed_request();
+                }
                 _ => {}
             }
             return;
         }
 
+        if self.doctor_report.is_some() {
+            if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                self.doctor_report = None;
+            }
+            return;
+        }
+
+        if self.usage_report.is_some() {
+            if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                self.usage_report = None;
+            }
+            return;
+        }
+
         // Handle model filter popup if open (swallow all keys).
         if self.model_filter_open {
             self.handle_filter_popup_key(key);
@@ -54,6 +82,10 @@ impl App {
             AppMode::Normal => self.handle_normal_key(key),
             AppMode::Command => self.handle_command_key(key),
             AppMode::Search => self.handle_search_key(key),
+            AppMode::Visual => self.handle_visual_key(key),
+            AppMode::Confirm => self.handle_confirm_key(key),
+            AppMode::Compose => self.handle_compose_key(key),
+            AppMode::ListForm => self.handle_list_form_key(key),
         }
     }
 
@@ -105,18 +137,14 @@ impl App {
                 KeyCode::Char('/') => {
                     self.model_filter_search_active = true;
                 }
-                KeyCode::Char('j') if is_ctrl => {
-                    if self.model_filter_index + 1 < item_count {
-                        self.model_filter_index += 1;
-                    }
+                KeyCode::Char('j') if is_ctrl && self.model_filter_index + 1 < item_count => {
+                    self.model_filter_index += 1;
                 }
                 KeyCode::Char('k') if is_ctrl => {
                     self.model_filter_index = self.model_filter_index.saturating_sub(1);
                 }
-                KeyCode::Down => {
-                    if self.model_filter_index + 1 < item_count {
-                        self.model_filter_index += 1;
-                    }
+                KeyCode::Down if self.model_filter_index + 1 < item_count => {
+                    self.model_filter_index += 1;
                 }
                 KeyCode::Up => {
                     self.model_filter_index = self.model_filter_index.saturating_sub(1);
@@ -190,10 +218,8 @@ impl App {
         let item_count = 1 + orgs.len(); // "All" + orgs
 
         match key.code {
-            KeyCode::Char('j') | KeyCode::Down => {
-                if self.hf_org_filter_index + 1 < item_count {
-                    self.hf_org_filter_index += 1;
-                }
+            KeyCode::Char('j') | KeyCode::Down if self.hf_org_filter_index + 1 < item_count => {
+                self.hf_org_filter_index += 1;
             }
             KeyCode::Char('k') | KeyCode::Up => {
                 self.hf_org_filter_index = self.hf_org_filter_index.saturating_sub(1);
@@ -246,12 +272,25 @@ impl App {
     }
 
     fn handle_normal_key(&mut self, key: KeyEvent) {
+        if self.refuse_account_write(key.code) {
+            return;
+        }
         let is_model_view = matches!(
             self.current_view(),
             Some(ViewKind::OpenRouterModels | ViewKind::TextModels)
         );
 
         match key.code {
+            KeyCode::Esc if !self.marked_tweets.is_empty() => {
+                self.clear_batch_selection();
+            }
+            KeyCode::Esc if self.toasts.has_errors() => {
+                self.toasts.dismiss_errors();
+            }
+            KeyCode::Esc if self.has_view_requests() => {
+                self.cancel_view_requests();
+                self.notify("Cancelled loading");
+            }
             KeyCode::Char('q') | KeyCode::Esc => {
                 // In cluster tweet list mode, go back to cluster list first
                 if self.current_view() == Some(&ViewKind::Cluster)
@@ -307,6 +346,9 @@ impl App {
             KeyCode::Char('k') | KeyCode::Up => {
                 self.move_selection_up();
             }
+            KeyCode::PageDown | KeyCode::PageUp if self.current_view() == Some(&ViewKind::Help) => {
+                self.page_help(key.code == KeyCode::PageDown);
+            }
             KeyCode::Enter => {
                 self.open_selected();
             }
@@ -341,13 +383,93 @@ impl App {
             KeyCode::Char('4') => {
                 self.events.send(AppEvent::SwitchView(ViewKind::Search));
             }
+            KeyCode::Char('5') => {
+                self.events.send(AppEvent::SwitchView(ViewKind::Lists));
+            }
+            KeyCode::Char('6') => {
+                self.events.send(AppEvent::SwitchView(ViewKind::Messages));
+            }
             KeyCode::Char('@') => {
                 self.mode = AppMode::Command;
                 self.command_input = "user ".to_string();
             }
+            KeyCode::Char('c') => {
+                // An empty composer starts a new post, not a reply with the
+                // settings of one closed before.
+                if self.compose_input.is_empty() {
+                    self.compose_reply_to = None;
+                    self.compose_reply_settings = ReplySettings::Everyone;
+                }
+                self.mode = AppMode::Compose;
+            }
+            KeyCode::Char('A') if self.api_client.is_none() => {
+                self.events.send(AppEvent::StartAuth);
+            }
+            KeyCode::Char('e') => {
+                self.partial_errors_expanded = !self.partial_errors_expanded;
+            }
             KeyCode::Char('n') => {
                 self.load_next_page();
             }
+            KeyCode::Char('p') => {
+                self.load_previous_page();
+            }
+            KeyCode::Char('u') => {
+                self.open_author_profile();
+            }
+            KeyCode::Char('a') => {
+                self.toggle_author_preview();
+            }
+            KeyCode::Char('s') => {
+                self.toggle_search_sort();
+            }
+            KeyCode::Char('z') => {
+                self.toggle_folded();
+            }
+            KeyCode::Char('Q') => {
+                self.toggle_macro_recording();
+            }
+            KeyCode::Char('&') => {
+                self.start_macro_playback();
+            }
+            KeyCode::Char('Z') => {
+                self.undo_post();
+            }
+            KeyCode::Char('H') => {
+                self.toggle_posting_heatmap();
+            }
+            KeyCode::Char('m') => {
+                self.toggle_profile_mute();
+            }
+            KeyCode::Char('X') => {
+                self.toggle_profile_block();
+            }
+            KeyCode::Char('L') => {
+                self.add_profile_to_list();
+            }
+            KeyCode::Char('d') => match self.current_view() {
+                Some(ViewKind::Drafts) => self.delete_selected_draft(),
+                Some(ViewKind::StreamRules) => self.delete_selected_rule(),
+                _ => self.remove_list_member(),
+            },
+            KeyCode::Char('h') => {
+                self.toggle_hide_reply();
+            }
+            KeyCode::Char('b') => {
+                self.toggle_bookmark();
+            }
+            KeyCode::Char('l') => {
+                self.toggle_like();
+            }
+            KeyCode::Char('t') => {
+                self.toggle_retweet();
+            }
+            KeyCode::Char('R') => {
+                self.show_tweet_users(TweetUsersKind::RetweetedBy);
+            }
+            KeyCode::Char('F') => {
+                self.show_tweet_users(TweetUsersKind::LikingUsers);
+            }
             KeyCode::Char('y') => {
                 self.copy_tweet_url();
             }
@@ -357,6 +479,15 @@ impl App {
             KeyCode::Char('r') => {
                 self.events.send(AppEvent::RefreshView);
             }
+            KeyCode::Char(' ') => {
+                self.toggle_mark();
+            }
+            KeyCode::Char('v') => {
+                self.enter_visual_mode();
+            }
+            KeyCode::Char('B' | 'U' | 'M' | 'x' | '|') => {
+                self.handle_batch_key(key.code);
+            }
             KeyCode::Char('f') => {
                 if is_model_view {
                     self.model_search.clear();
@@ -372,6 +503,67 @@ impl App {
         }
     }
 
+    fn handle_visual_key(&mut self, key: KeyEvent) {
+        if self.refuse_account_write(key.code) {
+            return;
+        }
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection_down();
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection_up();
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_mark();
+            }
+            KeyCode::Char('v' | 'q') | KeyCode::Esc => {
+                self.clear_batch_selection();
+            }
+            KeyCode::Char('B' | 'U' | 'M' | 'x' | '|') => {
+                self.handle_batch_key(key.code);
+            }
+            _ => {}
+        }
+    }
+
+    /// In read-only mode, explain why a key that would change the account
+    /// does nothing instead of letting its request fail with a 403.
+    /// Returns whether it was refused.
+    fn refuse_account_write(&mut self, code: KeyCode) -> bool {
+        if !self.read_only() {
+            return false;
+        }
+        let action = match code {
+            KeyCode::Char('c') => "Posting",
+            KeyCode::Char('l') => "Liking",
+            KeyCode::Char('t') => "Retweeting",
+            KeyCode::Char('b' | 'B' | 'U') => "Bookmarking",
+            KeyCode::Char('m' | 'M') => "Muting",
+            KeyCode::Char('X') => "Blocking",
+            KeyCode::Char('h') => "Hiding replies",
+            KeyCode::Char('L') => "Adding to a list",
+            _ => return false,
+        };
+        self.notify(format!(
+            "{action} needs a signed-in user; the app-only bearer token is read-only. \
+             Run :auth to sign in."
+        ));
+        true
+    }
+
+    /// Batch actions over the marked tweets and the visual range.
+    fn handle_batch_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('B') => self.bookmark_selection(),
+            KeyCode::Char('U') => self.unbookmark_selection(),
+            KeyCode::Char('M') => self.mute_selection_authors(),
+            KeyCode::Char('x') => self.export_selection(),
+            KeyCode::Char('|') => self.start_pipe(),
+            _ => {}
+        }
+    }
+
     fn handle_command_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
@@ -379,8 +571,9 @@ impl App {
                 self.command_input.clear();
             }
             KeyCode::Enter => {
-                self.execute_command();
+                // Before running it, so a command can open a dialog or form.
                 self.mode = AppMode::Normal;
+                self.execute_command();
             }
             KeyCode::Backspace => {
                 self.command_input.pop();
@@ -420,6 +613,90 @@ impl App {
         }
     }
 
+    fn handle_compose_key(&mut self, key: KeyEvent) {
+        if self.reply_drafts.is_some() {
+            self.handle_reply_drafts_key(key);
+            return;
+        }
+        // Most terminals report Ctrl-Enter as a plain Enter, so Ctrl-S and
+        // Alt-Enter submit too.
+        let submit = match key.code {
+            KeyCode::Enter => key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT),
+            KeyCode::Char('s') => key.modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+        if submit {
+            if self.posting {
+                self.notify("Still sending the last post");
+                return;
+            }
+            let text = self.compose_input.trim();
+            if text.is_empty() {
+                self.notify("Nothing to post");
+                return;
+            }
+            if self.compose_thread && post_len(text) > POST_LIMIT {
+                let mut posts = split_thread(text, POST_LIMIT);
+                let mut reply_to = self.compose_reply_to.as_ref().map(|r| r.tweet_id.clone());
+                // A thread that stopped part way goes on under its last post.
+                let posted = match self.compose_thread_posted.take() {
+                    Some((sent, posted))
+                        if sent == self.compose_input && posted.len() < posts.len() =>
+                    {
+                        posts.drain(..posted.len());
+                        reply_to = posted.last().cloned();
+                        posted
+                    }
+                    _ => Vec::new(),
+                };
+                let post = self.take_pending_post();
+                self.events.send(AppEvent::PostThread {
+                    posts,
+                    reply_to,
+                    posted,
+                    post,
+                });
+            } else {
+                let post = self.take_pending_post();
+                self.events.send(AppEvent::PostTweet(post));
+            }
+            self.mode = AppMode::Normal;
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                self.save_compose_draft();
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Enter => {
+                self.compose_input.push('\n');
+            }
+            KeyCode::Backspace => {
+                self.compose_input.pop();
+                // An emptied draft starts over as a new post.
+                if self.compose_input.is_empty() {
+                    self.compose_reply_to = None;
+                }
+            }
+            KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.request_reply_drafts();
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.compose_thread = !self.compose_thread;
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.compose_reply_settings = self.compose_reply_settings.next();
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.compose_input.push(c);
+            }
+            _ => {}
+        }
+    }
+
     /// Clear all model search state (used when leaving model views).
     fn clear_model_search_state(&mut self) {
         self.model_search.clear();
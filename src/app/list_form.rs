@@ -0,0 +1,246 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::{App, AppMode};
+use crate::api::types::{List, ListFields};
+use crate::command::ListCommand;
+use crate::event::{AppEvent, ViewKind};
+
+/// X's limits on a List's name and description, in characters.
+const NAME_LIMIT: usize = 25;
+const DESCRIPTION_LIMIT: usize = 100;
+
+/// The List form's fields, in Tab order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormField {
+    #[default]
+    Name,
+    Description,
+    Private,
+}
+
+impl ListFormField {
+    fn next(self) -> Self {
+        match self {
+            Self::Name => Self::Description,
+            Self::Description => Self::Private,
+            Self::Private => Self::Name,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            Self::Name => Self::Private,
+            Self::Description => Self::Name,
+            Self::Private => Self::Description,
+        }
+    }
+}
+
+/// The form for a new List, or for editing one you own.
+#[derive(Debug, Clone)]
+pub struct ListForm {
+    /// The List being edited; `None` creates one.
+    pub list_id: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub private: bool,
+    pub focus: ListFormField,
+}
+
+impl ListForm {
+    fn fields(&self) -> ListFields {
+        ListFields {
+            name: self.name.trim().to_string(),
+            // An empty description is sent when editing, to clear it.
+            description: Some(self.description.trim().to_string())
+                .filter(|d| self.list_id.is_some() || !d.is_empty()),
+            private: Some(self.private),
+        }
+    }
+}
+
+/// Why X would reject these fields, if it would.
+fn invalid(fields: &ListFields) -> Option<String> {
+    if fields.name.is_empty() {
+        Some("A List needs a name".into())
+    } else if fields.name.chars().count() > NAME_LIMIT {
+        Some(format!("List names are at most {NAME_LIMIT} characters"))
+    } else if fields
+        .description
+        .as_ref()
+        .is_some_and(|d| d.chars().count() > DESCRIPTION_LIMIT)
+    {
+        Some(format!(
+            "List descriptions are at most {DESCRIPTION_LIMIT} characters"
+        ))
+    } else {
+        None
+    }
+}
+
+impl App {
+    /// `:list create|edit|rename|delete`. All but `create` act on the
+    /// List selected in the Lists view, or open in the members view.
+    pub(super) fn run_list_command(&mut self, command: ListCommand) {
+        if self.refuse_without_user_context(&ViewKind::Lists) {
+            return;
+        }
+        if let ListCommand::Create(name) = command {
+            self.open_list_form(None, name, String::new(), false);
+            return;
+        }
+        let Some(list) = self.selected_owned_list() else {
+            self.notify("Select one of your own Lists first (5 opens them)");
+            return;
+        };
+        match command {
+            ListCommand::Create(_) => {}
+            ListCommand::Edit => self.open_list_form(
+                Some(list.id),
+                list.name,
+                list.description.unwrap_or_default(),
+                list.private.unwrap_or_default(),
+            ),
+            ListCommand::Rename(name) => {
+                // Only the name is sent, so the rest stays as it is.
+                let fields = ListFields {
+                    name,
+                    ..ListFields::default()
+                };
+                if let Some(reason) = invalid(&fields) {
+                    self.notify(reason);
+                    return;
+                }
+                self.events.send(AppEvent::SaveList {
+                    list_id: Some(list.id),
+                    fields,
+                });
+            }
+            ListCommand::Delete => self.confirm(
+                "list_delete",
+                format!("Delete {}", list.name),
+                "Its members stay on X, but the List and its followers are gone for good.",
+                AppEvent::DeleteList { list },
+            ),
+        }
+    }
+
+    /// The List `:list` acts on: the selected row of the Lists view or the
+    /// List open in the members view, if you own it.
+    fn selected_owned_list(&self) -> Option<List> {
+        let list = match self.current_view()? {
+            ViewKind::Lists => self.lists().nth(self.selected_index())?,
+            ViewKind::ListMembers(_) => self.members_list()?,
+            _ => return None,
+        };
+        self.owned_lists
+            .iter()
+            .any(|l| l.id == list.id)
+            .then(|| list.clone())
+    }
+
+    fn open_list_form(
+        &mut self,
+        list_id: Option<String>,
+        name: String,
+        description: String,
+        private: bool,
+    ) {
+        self.list_form = Some(ListForm {
+            list_id,
+            name,
+            description,
+            private,
+            focus: ListFormField::Name,
+        });
+        self.mode = AppMode::ListForm;
+    }
+
+    /// Record a created or updated List in `owned_lists`. Fields left out
+    /// of an update keep their value.
+    pub(super) fn apply_list_saved(&mut self, id: String, fields: ListFields) {
+        let Some(list) = self.owned_lists.iter_mut().find(|l| l.id == id) else {
+            self.owned_lists.insert(
+                0,
+                List {
+                    id,
+                    name: fields.name,
+                    description: fields.description,
+                    private: fields.private,
+                    member_count: Some(0),
+                    follower_count: Some(0),
+                    owner_id: self.current_user.as_ref().map(|u| u.id.clone()),
+                    created_at: Some(chrono::Utc::now()),
+                },
+            );
+            return;
+        };
+        list.name = fields.name;
+        if let Some(description) = fields.description {
+            list.description = Some(description).filter(|d| !d.is_empty());
+        }
+        if fields.private.is_some() {
+            list.private = fields.private;
+        }
+    }
+
+    /// Drop a deleted List, leaving its members view if that is open.
+    pub(super) fn apply_list_deleted(&mut self, list_id: &str) {
+        self.owned_lists.retain(|l| l.id != list_id);
+        if self.current_view() == Some(&ViewKind::ListMembers(list_id.to_string())) {
+            self.pop_view();
+        }
+        if self.current_view() == Some(&ViewKind::Lists) {
+            let len = self.lists().count();
+            if let Some(vs) = self.view_stack.last_mut() {
+                vs.selected_index = vs.selected_index.min(len.saturating_sub(1));
+            }
+        }
+    }
+
+    pub(super) fn handle_list_form_key(&mut self, key: KeyEvent) {
+        let Some(form) = self.list_form.as_mut() else {
+            self.mode = AppMode::Normal;
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.list_form = None;
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Enter => {
+                let fields = form.fields();
+                if let Some(reason) = invalid(&fields) {
+                    self.notify(reason);
+                    return;
+                }
+                let list_id = form.list_id.clone();
+                self.list_form = None;
+                self.mode = AppMode::Normal;
+                self.events.send(AppEvent::SaveList { list_id, fields });
+            }
+            KeyCode::Tab | KeyCode::Down => form.focus = form.focus.next(),
+            KeyCode::BackTab | KeyCode::Up => form.focus = form.focus.previous(),
+            KeyCode::Char(' ') if form.focus == ListFormField::Private => {
+                form.private = !form.private;
+            }
+            KeyCode::Backspace => match form.focus {
+                ListFormField::Name => {
+                    form.name.pop();
+                }
+                ListFormField::Description => {
+                    form.description.pop();
+                }
+                ListFormField::Private => {}
+            },
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                match form.focus {
+                    ListFormField::Name => form.name.push(c),
+                    ListFormField::Description => form.description.push(c),
+                    ListFormField::Private => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::{HashMap, VecDeque};
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{App, AppMode};
+use crate::event::AppEvent;
+
+/// Keyboard macros: `Q<reg>` records keys into a register until the next
+/// `Q`, and `&<reg>` plays them back. Registers last until the app quits.
+///
+/// Vim's `q` and `@` are taken here: `q` goes back (or quits) and `@` opens
+/// `:user `, so recording and playing use `Q` and `&`.
+///
+/// Only keys handled in Normal or Confirm mode are recorded. Text typed
+/// into the command line, search or composer, and the keys that open or
+/// leave them, are left out, so playback never stops inside an input.
+#[derive(Debug, Default)]
+pub(super) struct Macros {
+    registers: HashMap<char, Vec<KeyEvent>>,
+    /// The register being recorded and the keys so far.
+    recording: Option<(char, Vec<KeyEvent>)>,
+    /// `Q` or `&` was pressed and the register key comes next.
+    pending: Option<MacroPrefix>,
+    /// The register being played and the keys it has left.
+    playing: Option<(char, VecDeque<KeyEvent>)>,
+    /// Playback is held until loading finishes; the next tick resumes it.
+    waiting: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum MacroPrefix {
+    Record,
+    Play,
+}
+
+impl App {
+    /// The register being recorded into, for the status bar.
+    pub fn recording_macro(&self) -> Option<char> {
+        self.macros
+            .recording
+            .as_ref()
+            .map(|(register, _)| *register)
+    }
+
+    /// Handle a key before it's routed. Returns `true` when it was the
+    /// register after `Q` or `&`, which goes no further.
+    pub(super) fn handle_macro_prefix(&mut self, key: KeyEvent) -> bool {
+        let Some(prefix) = self.macros.pending.take() else {
+            return false;
+        };
+        let register = match key.code {
+            KeyCode::Char(c) if c.is_ascii_lowercase() || c.is_ascii_digit() => c,
+            KeyCode::Esc => return true,
+            _ => {
+                self.notify("Macro registers are a-z and 0-9");
+                return true;
+            }
+        };
+        match prefix {
+            MacroPrefix::Record => {
+                self.macros.recording = Some((register, Vec::new()));
+            }
+            MacroPrefix::Play => match self.recording_macro() {
+                Some(recording) => {
+                    self.notify(format!("Stop recording @{recording} with Q first"));
+                }
+                None => self.play_macro(register),
+            },
+        }
+        true
+    }
+
+    /// Whether a recording takes keys handled in the current mode.
+    pub(super) fn mode_records_macro_keys(&self) -> bool {
+        matches!(self.mode, AppMode::Normal | AppMode::Confirm)
+    }
+
+    /// Add a key that was just handled to the recording, unless it
+    /// started or stopped it or it moved to a mode that isn't recorded.
+    /// `was_recording` is whether a recording took keys before it.
+    pub(super) fn record_macro_key(&mut self, key: KeyEvent, was_recording: bool) {
+        if !was_recording || self.macros.pending.is_some() || !self.mode_records_macro_keys() {
+            return;
+        }
+        if let Some((_, keys)) = &mut self.macros.recording {
+            keys.push(key);
+        }
+    }
+
+    /// `Q`: stop recording, or ask for the register to record into.
+    pub(super) fn toggle_macro_recording(&mut self) {
+        match self.macros.recording.take() {
+            Some((register, keys)) if keys.is_empty() => {
+                self.macros.registers.remove(&register);
+                self.notify(format!("Nothing recorded in @{register}"));
+            }
+            Some((register, keys)) => {
+                let count = keys.len();
+                self.macros.registers.insert(register, keys);
+                self.notify(format!(
+                    "Recorded @{register} ({count} key{}) · &{register} to play",
+                    if count == 1 { "" } else { "s" }
+                ));
+            }
+            None if self.macros.playing.is_some() => {}
+            None => self.macros.pending = Some(MacroPrefix::Record),
+        }
+    }
+
+    /// `&`: ask for the register to play.
+    pub(super) fn start_macro_playback(&mut self) {
+        if self.macros.playing.is_some() {
+            // A macro can't play another one (or itself).
+            return;
+        }
+        self.macros.pending = Some(MacroPrefix::Play);
+    }
+
+    fn play_macro(&mut self, register: char) {
+        match self.macros.registers.get(&register) {
+            Some(keys) => {
+                self.macros.playing = Some((register, keys.iter().copied().collect()));
+                self.events.send(AppEvent::PlayMacroKey);
+            }
+            None => self.notify(format!("Nothing recorded in @{register}")),
+        }
+    }
+
+    /// Play the macro's next key. Each key goes through the event queue
+    /// behind whatever the previous one sent, and waits while anything is
+    /// still loading, so a key that opens a view is followed by keys acting
+    /// on what the view fetched.
+    pub(super) fn play_macro_key(&mut self) {
+        if self.macros.playing.is_none() {
+            return;
+        }
+        if self.any_loading() || self.requests_in_flight() {
+            self.macros.waiting = true;
+            return;
+        }
+        self.macros.waiting = false;
+        let Some((register, keys)) = &mut self.macros.playing else {
+            return;
+        };
+        let register = *register;
+        let Some(key) = keys.pop_front() else {
+            self.macros.playing = None;
+            return;
+        };
+        if self.error_detail.is_some() {
+            self.macros.playing = None;
+            self.notify(format!("Stopped @{register} after an error"));
+            return;
+        }
+        self.handle_key_event(key);
+        self.events.send(AppEvent::PlayMacroKey);
+    }
+
+    /// On a tick, pick playback up again once nothing is loading.
+    pub(super) fn resume_macro_playback(&mut self) {
+        if self.macros.waiting {
+            self.play_macro_key();
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use super::App;
+use crate::api::types::{DmEvent, ListResponse};
+use crate::event::{AppEvent, ViewKind};
+
+impl App {
+    /// The Messages view's rows: the latest event of each conversation,
+    /// most recent conversation first.
+    pub fn dm_inbox(&self) -> Vec<&DmEvent> {
+        let mut seen = HashSet::new();
+        self.dm_events
+            .iter()
+            .filter(|e| {
+                e.dm_conversation_id
+                    .as_deref()
+                    .is_some_and(|id| seen.insert(id))
+            })
+            .collect()
+    }
+
+    /// The other participant of a one-to-one conversation. Their IDs make
+    /// up its ID (`1-7`); group conversations have an ID of their own and
+    /// give `None`.
+    pub fn dm_participant<'a>(&self, conversation_id: &'a str) -> Option<&'a str> {
+        let me = self.dm_user_id.as_deref()?;
+        match conversation_id.split_once('-')? {
+            (a, b) if a == me => Some(b),
+            (a, b) if b == me => Some(a),
+            _ => None,
+        }
+    }
+
+    /// `:dm [user]`. Without a user it opens the conversation with the
+    /// profile being viewed, or else the Messages view.
+    pub(super) fn run_dm_command(&mut self, username: Option<String>) {
+        if self.refuse_without_user_context(&ViewKind::Messages) {
+            return;
+        }
+        if let Some(username) = username {
+            self.events.send(AppEvent::OpenDm { username });
+            return;
+        }
+        let profile = match (self.current_view(), &self.viewed_user) {
+            (Some(ViewKind::UserProfile(_)), Some(user)) => Some(user.id.clone()),
+            _ => None,
+        };
+        match profile {
+            Some(participant_id) => self.open_dm_conversation(participant_id),
+            None => self.events.send(AppEvent::SwitchView(ViewKind::Messages)),
+        }
+    }
+
+    /// Enter in the Messages view: open the selected conversation.
+    pub(super) fn open_selected_dm(&mut self) {
+        let Some(conversation_id) = self
+            .dm_inbox()
+            .get(self.selected_index())
+            .and_then(|e| e.dm_conversation_id.clone())
+        else {
+            return;
+        };
+        match self.dm_participant(&conversation_id) {
+            Some(participant_id) => self.open_dm_conversation(participant_id.to_string()),
+            None => self.notify("Group conversations can only be opened on x.com (o)"),
+        }
+    }
+
+    pub(super) fn open_dm_conversation(&mut self, participant_id: String) {
+        if self.refuse_without_user_context(&ViewKind::Messages) {
+            return;
+        }
+        let kind = ViewKind::DmConversation(participant_id);
+        self.dm_conversation.clear();
+        self.push_view(kind.clone());
+        self.fetch_for_view(&kind);
+    }
+
+    /// Show a loaded conversation oldest first, with the newest message
+    /// selected.
+    pub(super) fn apply_dm_conversation(&mut self, resp: ListResponse<DmEvent>) {
+        self.cache_includes(&resp.includes);
+        self.dm_conversation = resp.data.unwrap_or_default();
+        self.dm_conversation.reverse();
+        let last = self.dm_conversation.len().saturating_sub(1);
+        if let Some(vs) = self.view_stack.last_mut() {
+            vs.selected_index = last;
+        }
+    }
+
+    /// The x.com page of the selected conversation, or of the open one.
+    pub(super) fn selected_dm_url(&self) -> Option<String> {
+        let conversation_id = match self.current_view()? {
+            ViewKind::Messages => self
+                .dm_inbox()
+                .get(self.selected_index())?
+                .dm_conversation_id
+                .clone()?,
+            ViewKind::DmConversation(_) => self
+                .dm_conversation
+                .iter()
+                .find_map(|e| e.dm_conversation_id.clone())?,
+            _ => return None,
+        };
+        Some(format!("https://x.com/messages/{conversation_id}"))
+    }
+}
@@ -1,27 +1,72 @@
 mod auth;
 mod commands;
+mod confirm;
 mod dispatch;
+mod drafts;
 mod event_handlers;
+mod filtered_stream;
 mod key_handlers;
+mod list_form;
+mod macros;
+mod messages;
 mod navigation;
+mod reply_drafts;
+mod stream_rules;
+mod toast;
+mod undo_post;
 
-use std::collections::HashMap;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
 
 use ratatui::DefaultTerminal;
+use tokio_util::sync::CancellationToken;
 
 use crate::api::XApiClient;
-use crate::api::types::{Includes, Tweet, User};
+use crate::api::error::AccountState;
+use crate::api::types::{
+    ApiError, DmEvent, Includes, List, Media, ReplySettings, StreamRule, Tweet, Usage, User,
+};
+use crate::auth::AuthMethod;
 use crate::auth::credentials::CredentialSet;
-use crate::config::AppConfig;
+use crate::config::{AppConfig, StatusSegment};
+use crate::domains::{self, DomainReport};
 use crate::embeddings::cluster::ClusterResult;
-use crate::event::{AppEvent, Event, EventHandler, ViewKind};
+use crate::event::{AppEvent, BulkAction, Event, EventHandler, StreamStatus, ViewKind};
+
+use crate::link_preview::{self, LinkPreview};
 use crate::mlx::client::MlxClient;
 use crate::openrouter::client::OpenRouterClient;
 use crate::openrouter::types::Model;
+use crate::perf::PerfStats;
+use crate::store::{
+    Drafts, History, InstanceLock, MetricsHistory, MutedConversations, ProfileCache, TimelineKey,
+    UserCache, WatchDelivery,
+};
+use crate::tags::TagReport;
+use crate::terminal::TerminalCaps;
 use crate::ui;
+use crate::ui::halfblock::RgbImage;
+use crate::ui::theme::Palette;
+pub use confirm::ConfirmDialog;
+pub use list_form::{ListForm, ListFormField};
+use macros::Macros;
+pub use reply_drafts::{ReplyDrafts, ReplyTarget};
+pub use toast::{Toast, ToastLevel, Toasts};
+use undo_post::UndoPost;
+
+/// Upper bound on queued events handled between two frames, so a flood of
+/// input can't starve rendering entirely.
+const MAX_COALESCED_EVENTS: usize = 256;
+
+/// Link previews kept in memory before the cache is reset.
+const MAX_LINK_PREVIEWS: usize = 500;
+
+/// Included media and tweets kept in memory; past this the oldest go first.
+const MAX_INCLUDED: usize = 5_000;
 
 // ---------------------------------------------------------------------------
 // Timeline state
@@ -33,8 +78,345 @@ pub struct TimelineState {
     pub selected_index: usize,
     pub scroll_offset: usize,
     pub next_token: Option<String>,
+    /// Token for the page before the first one loaded (`p`).
+    pub previous_token: Option<String>,
     pub loading: bool,
+    /// Set while the request in flight is for `previous_token`, so its
+    /// tweets go before the loaded ones instead of after.
+    pub loading_previous: bool,
     pub includes: Option<Includes>,
+    /// When the tweets were restored from the local store instead of the
+    /// API, the time that snapshot was saved.
+    pub cached_at: Option<DateTime<Utc>>,
+    /// Entries from the `errors` array of otherwise successful responses.
+    pub partial_errors: Vec<ApiError>,
+    /// Newest tweet that was on screen, for the unread count.
+    pub seen_id: Option<String>,
+    /// Near-duplicates folded into the tweet they repeat, by that tweet's ID.
+    pub duplicates: HashMap<String, DuplicateGroup>,
+    /// Runs of tweets by one author folded into the run's first tweet, by
+    /// that tweet's ID.
+    pub author_runs: HashMap<String, DuplicateGroup>,
+}
+
+/// Tweets folded into an earlier one: copies sharing its link or text, or
+/// the rest of a run by its author.
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateGroup {
+    pub tweets: Vec<Tweet>,
+    /// Whether the copies are currently shown after the original.
+    pub expanded: bool,
+}
+
+impl TimelineState {
+    /// Mark a fetch for `token` as started.
+    pub fn start_loading(&mut self, token: Option<&str>) {
+        self.loading = true;
+        self.loading_previous = token.is_some() && token == self.previous_token.as_deref();
+    }
+
+    /// Count everything loaded so far as seen.
+    pub fn mark_seen(&mut self) {
+        if let Some(newest) = self
+            .tweets
+            .iter()
+            .map(|t| &t.id)
+            .max_by(|a, b| compare_ids(a, b))
+        {
+            self.seen_id = Some(newest.clone());
+        }
+    }
+
+    /// Tweets newer than the newest one seen; all of them if none was.
+    pub fn unread(&self) -> usize {
+        match &self.seen_id {
+            Some(seen) => self
+                .tweets
+                .iter()
+                .filter(|t| compare_ids(&t.id, seen).is_gt())
+                .count(),
+            None => self.tweets.len(),
+        }
+    }
+
+    /// Drop the loaded tweets and the groups folded into them.
+    pub fn clear_tweets(&mut self) {
+        self.tweets.clear();
+        self.duplicates.clear();
+        self.author_runs.clear();
+    }
+
+    /// Forget the groups folded into `evicted`, which are no longer loaded.
+    pub fn forget_groups(&mut self, evicted: &[Tweet]) {
+        for tweet in evicted {
+            self.duplicates.remove(&tweet.id);
+            self.author_runs.remove(&tweet.id);
+        }
+    }
+
+    /// Fold tweets in `page` that repeat a loaded tweet, or an earlier one
+    /// in the page, into that tweet's duplicate group. Returns the rest.
+    pub fn collapse_duplicates(&mut self, page: Vec<Tweet>) -> Vec<Tweet> {
+        let mut seen: HashMap<String, String> = HashMap::new();
+        for tweet in &self.tweets {
+            for key in duplicate_keys(tweet) {
+                seen.entry(key).or_insert_with(|| tweet.id.clone());
+            }
+        }
+        let mut kept = Vec::with_capacity(page.len());
+        for tweet in page {
+            let keys = duplicate_keys(&tweet);
+            match keys.iter().find_map(|k| seen.get(k)) {
+                Some(original) if *original != tweet.id => {
+                    let group = self.duplicates.entry(original.clone()).or_default();
+                    if !group.tweets.iter().any(|t| t.id == tweet.id) {
+                        group.tweets.push(tweet);
+                    }
+                }
+                _ => {
+                    for key in keys {
+                        seen.entry(key).or_insert_with(|| tweet.id.clone());
+                    }
+                    kept.push(tweet);
+                }
+            }
+        }
+        kept
+    }
+
+    /// Fold each run of at least `min_run` consecutive tweets by one author
+    /// in `page` into the run's first tweet. Returns the rest.
+    ///
+    /// A run folded at the end of the loaded tweets carries on into a page
+    /// loaded after them. A shorter one left unfolded there doesn't count
+    /// toward the page's first run.
+    pub fn collapse_author_runs(&mut self, page: Vec<Tweet>, min_run: usize) -> Vec<Tweet> {
+        let mut kept = Vec::with_capacity(page.len());
+        let mut page = page.into_iter().peekable();
+        if !self.loading_previous
+            && let Some(last) = self.tweets.last()
+            && last.author_id.is_some()
+            && let Some(group) = self.author_runs.iter_mut().find_map(|(head, group)| {
+                let tail = match group.tweets.last() {
+                    Some(tail) if group.expanded => &tail.id,
+                    _ => head,
+                };
+                (*tail == last.id).then_some(group)
+            })
+        {
+            while let Some(tweet) = page.next_if(|t| t.author_id == last.author_id) {
+                if group.expanded {
+                    kept.push(tweet.clone());
+                }
+                group.tweets.push(tweet);
+            }
+        }
+        let mut run: Vec<Tweet> = Vec::new();
+        for tweet in page {
+            let same_author = run
+                .first()
+                .is_some_and(|head| head.author_id.is_some() && head.author_id == tweet.author_id);
+            if !same_author {
+                self.fold_author_run(std::mem::take(&mut run), min_run, &mut kept);
+            }
+            run.push(tweet);
+        }
+        self.fold_author_run(run, min_run, &mut kept);
+        kept
+    }
+
+    fn fold_author_run(&mut self, mut run: Vec<Tweet>, min_run: usize, kept: &mut Vec<Tweet>) {
+        if run.len() < min_run.max(2) {
+            kept.extend(run);
+            return;
+        }
+        let rest = run.split_off(1);
+        let head = run.remove(0);
+        self.author_runs.insert(
+            head.id.clone(),
+            DuplicateGroup {
+                tweets: rest,
+                expanded: false,
+            },
+        );
+        kept.push(head);
+    }
+}
+
+/// Normalized text shorter than this never counts as a duplicate, so short
+/// replies like "thanks!" aren't folded together.
+const MIN_DUPLICATE_TEXT: usize = 24;
+
+/// What makes two tweets copies of each other: a shared link (other than to
+/// X itself), or the same text once reposts, links, mentions, case and
+/// punctuation are set aside.
+fn duplicate_keys(tweet: &Tweet) -> Vec<String> {
+    let mut keys: Vec<String> = tweet
+        .entities
+        .iter()
+        .flat_map(|e| e.urls.iter().flatten())
+        .filter_map(|u| u.expanded_url.as_deref())
+        .filter_map(|link| {
+            let url = url::Url::parse(link).ok()?;
+            let host = domains::link_host(&url)?;
+            (!domains::is_x_host(host))
+                .then(|| format!("url:{host}{}", url.path().trim_end_matches('/')))
+        })
+        .collect();
+
+    let text = match tweet.text.strip_prefix("RT @") {
+        Some(rest) => rest.split_once(": ").map_or(rest, |(_, body)| body),
+        None => &tweet.text,
+    };
+    let normalized = text
+        .split_whitespace()
+        .filter(|word| !word.starts_with("http") && !word.starts_with('@'))
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if normalized.chars().count() >= MIN_DUPLICATE_TEXT {
+        keys.push(format!("text:{normalized}"));
+    }
+    keys
+}
+
+/// Order tweet IDs by age. IDs are increasing integers, so a longer ID is
+/// newer.
+fn compare_ids(a: &str, b: &str) -> std::cmp::Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Media and quoted/replied-to tweets from the `includes` of every response,
+/// by key, so cards can tell attached content the API didn't return apart
+/// from no content at all.
+#[derive(Debug, Default)]
+pub struct IncludedContent {
+    pub media: HashMap<String, Media>,
+    pub tweets: HashMap<String, Tweet>,
+    /// Keys in the order they were first added, for evicting the oldest.
+    order: VecDeque<IncludedKey>,
+}
+
+#[derive(Debug)]
+enum IncludedKey {
+    Media(String),
+    Tweet(String),
+}
+
+impl IncludedContent {
+    pub fn add(&mut self, includes: &Includes) {
+        for m in includes.media.iter().flatten() {
+            if self.media.insert(m.media_key.clone(), m.clone()).is_none() {
+                self.order
+                    .push_back(IncludedKey::Media(m.media_key.clone()));
+            }
+        }
+        for t in includes.tweets.iter().flatten() {
+            if self.tweets.insert(t.id.clone(), t.clone()).is_none() {
+                self.order.push_back(IncludedKey::Tweet(t.id.clone()));
+            }
+        }
+        while self.order.len() > MAX_INCLUDED {
+            match self.order.pop_front() {
+                Some(IncludedKey::Media(key)) => {
+                    self.media.remove(&key);
+                }
+                Some(IncludedKey::Tweet(id)) => {
+                    self.tweets.remove(&id);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// Account details on the splash shown at launch, until the first timeline
+/// arrives. The handle comes from `App::current_user`.
+#[derive(Debug, Default)]
+pub struct StartupSplash {
+    pub auth_method: Option<AuthMethod>,
+    /// OAuth 2.0 access token expiry; other methods don't expire.
+    pub token_expires_at: Option<DateTime<Utc>>,
+    /// Set once the account and usage lookups have answered.
+    pub account_loaded: bool,
+    pub usage: Option<Usage>,
+}
+
+/// This month's post consumption, as of the day it was looked up.
+#[derive(Debug, Clone)]
+pub struct UsageReport {
+    pub usage: Usage,
+    pub checked_on: NaiveDate,
+}
+
+/// Recent posts by a reply's author, shown beside the thread they replied in.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorPreview {
+    /// The thread the preview was opened from; other threads don't show it.
+    pub conversation_id: String,
+    pub user_id: String,
+    pub tweets: Vec<Tweet>,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+/// When a user posts: their fetched tweets counted by weekday and hour, in
+/// local time. Shown under their profile.
+#[derive(Debug, Clone, Default)]
+pub struct PostingHeatmap {
+    pub user_id: String,
+    /// `counts[weekday][hour]`, Monday first.
+    pub counts: [[u32; 24]; 7],
+    /// Tweets counted, and the oldest one's time.
+    pub tweets: usize,
+    pub since: Option<DateTime<Utc>>,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl PostingHeatmap {
+    /// Bucket `times` by weekday and hour in `tz`.
+    pub fn count<Tz: chrono::TimeZone>(&mut self, times: &[DateTime<Utc>], tz: &Tz) {
+        for time in times {
+            let local = time.with_timezone(tz);
+            let day = local.weekday().num_days_from_monday() as usize;
+            self.counts[day][local.hour() as usize] += 1;
+        }
+        self.tweets = times.len();
+        self.since = times.iter().min().copied();
+    }
+
+    /// The busiest hour's count.
+    pub fn max(&self) -> u32 {
+        self.counts.iter().flatten().copied().max().unwrap_or(0)
+    }
+}
+
+/// A profile or timeline X refused to show, kept so its view explains why
+/// instead of staying empty.
+#[derive(Debug, Clone)]
+pub struct UnavailableAccount {
+    /// The view the failed request was for.
+    pub view: ViewKind,
+    pub state: AccountState,
+    /// X's own wording, which names the account.
+    pub detail: String,
+}
+
+/// Progress of a bulk action that works through items one request at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkRun {
+    pub action: BulkAction,
+    pub done: usize,
+    pub total: usize,
+    /// Set while paused for a rate limit.
+    pub rate_limited_until: Option<DateTime<Utc>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -46,6 +428,14 @@ pub enum AppMode {
     Normal,
     Command,
     Search,
+    /// Selecting a range of tweets for a batch action.
+    Visual,
+    /// Answering a confirmation dialog.
+    Confirm,
+    /// Writing a new post in the composer.
+    Compose,
+    /// Filling in the form for a new or edited List.
+    ListForm,
 }
 
 // ---------------------------------------------------------------------------
@@ -111,6 +501,10 @@ pub struct App {
     // View system
     pub view_stack: Vec<ViewState>,
     pub mode: AppMode,
+    /// How far the help overlay can scroll and how many lines it shows,
+    /// as of the last frame. Rendering only has `&App`.
+    pub help_scroll_max: Cell<usize>,
+    pub help_page: Cell<usize>,
 
     // Data state
     pub home_timeline: TimelineState,
@@ -118,26 +512,152 @@ pub struct App {
     pub bookmarks: TimelineState,
     pub search_results: TimelineState,
     pub search_query: String,
+    /// Page size given with `:search --max`, for that query's pages only.
+    pub search_max_results: Option<(String, u32)>,
+    /// Loaded search results are ordered by likes and reposts instead of
+    /// recency (`s`).
+    pub search_by_engagement: bool,
+    /// Search results came from the local store (`:local`), not the API.
+    pub search_local: bool,
     pub current_user: Option<User>,
     pub viewed_user: Option<User>,
     pub viewed_user_timeline: TimelineState,
+    /// OpenGraph previews by URL. `None` marks a fetch that is in flight or
+    /// found nothing, so it isn't retried.
+    pub link_previews: HashMap<String, Option<LinkPreview>>,
+    /// Media and tweets from response `includes`, for cards to resolve.
+    pub included: IncludedContent,
+    /// Decoded banner of the viewed profile, keyed by its URL so a late
+    /// response for a previous profile is ignored.
+    pub viewed_user_banner: Option<(String, RgbImage)>,
+    /// Shown instead of an empty timeline until the first one arrives.
+    pub splash: Option<StartupSplash>,
+    pub unavailable_account: Option<UnavailableAccount>,
     pub thread_tweets: Vec<Tweet>,
+    pub author_preview: Option<AuthorPreview>,
     pub thread_root: Option<Tweet>,
+    pub thread_partial_errors: Vec<ApiError>,
     pub followers: Vec<User>,
     pub following: Vec<User>,
 
+    // Batch selection
+    /// Tweets in the current view marked with Space.
+    pub marked_tweets: HashSet<String>,
+    /// Start of the visual-mode range; the range ends at the cursor.
+    pub visual_anchor: Option<usize>,
+    /// Tweets liked with `l` this session. The API doesn't say which tweets
+    /// the user liked earlier, so those start out unliked here.
+    pub liked_tweets: HashSet<String>,
+    /// Tweets reposted with `t` this session, marked `[RT'd]`.
+    pub retweeted_tweets: HashSet<String>,
+    /// Replies hidden with `h` this session, marked `[hidden]`. Tweets
+    /// don't say whether they are hidden, so earlier ones aren't marked.
+    pub hidden_replies: HashSet<String>,
+    /// IDs of accounts muted this session, from a profile or `:mute`.
+    pub muted_users: HashSet<String>,
+    /// IDs of accounts blocked this session, from a profile or `:block`.
+    pub blocked_users: HashSet<String>,
+    /// The open profile's posting times (`H`).
+    pub posting_heatmap: Option<PostingHeatmap>,
+
     // Input state
     pub command_input: String,
     pub search_input: String,
+    /// Composer draft; kept when the composer is closed with Esc or a post fails.
+    pub compose_input: String,
+    /// The tweet the draft answers, once a suggested reply was picked.
+    pub compose_reply_to: Option<ReplyTarget>,
+    /// The draft is split into a numbered thread when posted (Ctrl-T).
+    pub compose_thread: bool,
+    /// Who may reply to the post (Ctrl-R).
+    pub compose_reply_settings: ReplySettings,
+    /// The saved draft the composer holds, so saving again replaces it.
+    pub compose_draft_id: Option<String>,
+    /// The text of a thread that stopped part way and the posts of it that
+    /// went out, so posting it again sends only the rest.
+    pub compose_thread_posted: Option<(String, Vec<String>)>,
+    /// A post or thread is on its way; Ctrl-S waits for it.
+    pub posting: bool,
+    /// The last post, while `Z` can still delete it.
+    undo_post: Option<UndoPost>,
+    /// Recorded keyboard macros (`Q<reg>`, `&<reg>`).
+    macros: Macros,
+    /// Drafts closed without posting (`:drafts`).
+    pub drafts: Drafts,
+    /// Suggested replies offered in the composer (Ctrl-G).
+    pub reply_drafts: Option<ReplyDrafts>,
 
     // Credentials (needed for runtime auth flows)
     pub credentials: CredentialSet,
 
     // API client (wrapped for sharing with spawned tasks)
-    pub api_client: Option<Arc<Mutex<XApiClient>>>,
+    pub api_client: Option<Arc<XApiClient>>,
+    /// Keys of API requests currently in flight, used to coalesce duplicates.
+    pub in_flight: dispatch::InFlightRequests,
+
+    // Includes cache (users from API responses for author lookup), bounded
+    // and persisted across sessions.
+    pub users_cache: UserCache,
 
-    // Includes cache (users from API responses for author lookup)
-    pub users_cache: HashMap<String, User>,
+    /// Profiles from user lookups, served stale-while-revalidate.
+    pub profile_cache: ProfileCache,
+    /// Conversations hidden from Home and Mentions (`:mute-thread`).
+    pub muted_conversations: MutedConversations,
+    /// Threads and profiles opened, for the History view.
+    pub history: History,
+    /// Tweet metrics seen on each fetch, charted under a thread's root.
+    pub metrics_history: MetricsHistory,
+    /// Held while this is the only TUI on the profile. Without it the caches
+    /// above are read but never saved, leaving them to the TUI that has it.
+    pub instance_lock: Option<InstanceLock>,
+    /// Linked domains shown by the Domains view (`:domains`).
+    pub domain_report: Option<DomainReport>,
+    /// Hashtags and topics shown by the Tags view (`:tags`).
+    pub tag_report: Option<TagReport>,
+    /// Tweets sent to `[[watch.rules]]` sinks, by this run and earlier ones.
+    pub delivered_watched: HashSet<WatchDelivery>,
+    /// Tweets added to the `:local` archive this session.
+    archived_ids: HashSet<String>,
+    /// Lists you own and follow, for the Lists view.
+    pub owned_lists: Vec<List>,
+    pub followed_lists: Vec<List>,
+    pub lists_loading: bool,
+    /// Members of the List open in the members view.
+    pub list_members: Vec<User>,
+    pub list_members_loading: bool,
+    /// Accounts that reposted or liked the tweet open in its users view.
+    pub tweet_users: Vec<User>,
+    pub tweet_users_loading: bool,
+    /// The filtered stream's rules (`:rules`).
+    pub stream_rules: Vec<StreamRule>,
+    pub stream_rules_loading: bool,
+    pub usage_loading: bool,
+    /// Tweets from the filtered stream (`:stream`), newest first.
+    pub filtered_stream: TimelineState,
+    pub stream_status: StreamStatus,
+    /// Stops the stream task; set while it runs.
+    stream_cancel: Option<CancellationToken>,
+    /// Counts stream tasks started, to drop updates from stopped ones.
+    stream_generation: u64,
+    /// The user being added to a List, while the Lists view is open to
+    /// pick one (`L` in a profile).
+    pub list_pick_user: Option<User>,
+    /// The List form, while `mode` is `ListForm`.
+    pub list_form: Option<ListForm>,
+    /// Recent DM events across conversations, newest first, for the
+    /// Messages view; `dm_user_id` is yours, as they were fetched with.
+    pub dm_events: Vec<DmEvent>,
+    pub dm_user_id: Option<String>,
+    pub dm_loading: bool,
+    /// The open DM conversation's events, oldest first.
+    pub dm_conversation: Vec<DmEvent>,
+    pub dm_conversation_loading: bool,
+    /// IDs of stale cached profiles being refreshed in the background.
+    pub revalidating_profiles: HashSet<String>,
+    /// An embedding job (re-ranking search results or finding similar
+    /// tweets) is running. It isn't a tracked request and can take longer
+    /// than `request_timeout_secs`, so the watchdog leaves it alone.
+    pub embedding_job: bool,
 
     // OpenRouter client
     pub openrouter_client: Option<Arc<OpenRouterClient>>,
@@ -201,9 +721,37 @@ pub struct App {
     pub refresh_then_cluster: bool,
 
     // Status
-    pub status_message: Option<String>,
+    /// Notifications shown in the corner (the status line in accessible mode).
+    pub toasts: Toasts,
     pub error_detail: Option<String>,
+    /// `:doctor` results, shown in a popup until dismissed.
+    pub doctor_report: Option<Vec<crate::doctor::Check>>,
+    /// `:usage` results, shown in a popup until dismissed.
+    pub usage_report: Option<UsageReport>,
+    /// The request behind the most recent API error, for `:retry`.
+    pub retry_request: Option<AppEvent>,
+    /// Open confirmation dialog (`AppMode::Confirm`).
+    pub confirm: Option<ConfirmDialog>,
     pub loading: bool,
+    /// Progress of a running bulk action, shown in the status bar.
+    pub bulk_run: Option<BulkRun>,
+    /// Whether partial-error notices list every error or just a count.
+    pub partial_errors_expanded: bool,
+
+    /// What the terminal can render, probed once at startup.
+    pub term_caps: TerminalCaps,
+    /// Color palette applied to each frame (`theme` config, `:set theme`).
+    pub palette: Palette,
+
+    /// Set when state changed since the last frame; the event loop skips
+    /// `terminal.draw` while this is false.
+    pub needs_redraw: bool,
+    /// Minute shown by the status bar clock, to redraw when it changes.
+    clock_minute: u32,
+
+    // Performance instrumentation (`:perf`)
+    pub perf: PerfStats,
+    pub perf_overlay: bool,
 
     // Skeleton loading animation
     /// App-creation timestamp used to compute `elapsed_ms` for skeleton widgets.
@@ -218,6 +766,27 @@ impl App {
         config: AppConfig,
         api_client: Option<XApiClient>,
         credentials: CredentialSet,
+    ) -> Self {
+        let events = EventHandler::new(&config);
+        Self::with_events(events, config, api_client, credentials)
+    }
+
+    /// An app that is only drawn, never run: no terminal input is read, so
+    /// it works with stdin redirected (e.g. `xplorertui render`).
+    pub fn headless(config: AppConfig) -> Self {
+        Self::with_events(
+            EventHandler::headless(),
+            config,
+            None,
+            CredentialSet::default(),
+        )
+    }
+
+    fn with_events(
+        events: EventHandler,
+        config: AppConfig,
+        api_client: Option<XApiClient>,
+        credentials: CredentialSet,
     ) -> Self {
         let default_view = match config.default_view {
             crate::config::DefaultView::Home => ViewKind::Home,
@@ -226,6 +795,16 @@ impl App {
             crate::config::DefaultView::Search => ViewKind::Search,
         };
 
+        // An app-only token can't load the personal timelines.
+        let read_only = api_client
+            .as_ref()
+            .is_some_and(|c| c.auth_method() == AuthMethod::BearerOnly);
+        let default_view = if read_only && default_view.needs_user_context() {
+            ViewKind::Search
+        } else {
+            default_view
+        };
+
         let initial_view = ViewState {
             kind: default_view,
             scroll_offset: 0,
@@ -236,30 +815,97 @@ impl App {
             .mlx_server_url
             .as_ref()
             .map(|url| Arc::new(MlxClient::new(url.clone())));
+        let users_cache = UserCache::new(config.users_cache_capacity);
+        let term_caps = TerminalCaps::detect();
+        let palette = config.theme.resolve(&term_caps);
 
         Self {
             running: true,
-            events: EventHandler::new(),
+            events,
             config,
             view_stack: vec![initial_view],
             mode: AppMode::Normal,
+            help_scroll_max: Cell::new(0),
+            help_page: Cell::new(0),
             home_timeline: TimelineState::default(),
             mentions: TimelineState::default(),
             bookmarks: TimelineState::default(),
             search_results: TimelineState::default(),
             search_query: String::new(),
+            search_max_results: None,
+            search_by_engagement: false,
+            search_local: false,
             current_user: None,
             viewed_user: None,
             viewed_user_timeline: TimelineState::default(),
+            viewed_user_banner: None,
+            link_previews: HashMap::new(),
+            included: IncludedContent::default(),
+            splash: None,
+            unavailable_account: None,
+            author_preview: None,
             thread_tweets: Vec::new(),
             thread_root: None,
+            thread_partial_errors: Vec::new(),
             followers: Vec::new(),
             following: Vec::new(),
+            marked_tweets: HashSet::new(),
+            liked_tweets: HashSet::new(),
+            retweeted_tweets: HashSet::new(),
+            hidden_replies: HashSet::new(),
+            muted_users: HashSet::new(),
+            blocked_users: HashSet::new(),
+            posting_heatmap: None,
+            visual_anchor: None,
             command_input: String::new(),
             search_input: String::new(),
+            compose_input: String::new(),
+            compose_reply_to: None,
+            compose_thread: false,
+            compose_reply_settings: ReplySettings::Everyone,
+            compose_draft_id: None,
+            compose_thread_posted: None,
+            posting: false,
+            undo_post: None,
+            macros: Macros::default(),
+            drafts: Drafts::default(),
+            reply_drafts: None,
             credentials,
-            api_client: api_client.map(|c| Arc::new(Mutex::new(c))),
-            users_cache: HashMap::new(),
+            api_client: api_client.map(Arc::new),
+            in_flight: Default::default(),
+            users_cache,
+            profile_cache: ProfileCache::default(),
+            muted_conversations: MutedConversations::default(),
+            history: History::default(),
+            metrics_history: MetricsHistory::default(),
+            instance_lock: None,
+            domain_report: None,
+            tag_report: None,
+            delivered_watched: HashSet::new(),
+            archived_ids: HashSet::new(),
+            owned_lists: Vec::new(),
+            followed_lists: Vec::new(),
+            lists_loading: false,
+            list_members: Vec::new(),
+            list_members_loading: false,
+            tweet_users: Vec::new(),
+            tweet_users_loading: false,
+            stream_rules: Vec::new(),
+            stream_rules_loading: false,
+            usage_loading: false,
+            filtered_stream: TimelineState::default(),
+            stream_status: StreamStatus::default(),
+            stream_cancel: None,
+            stream_generation: 0,
+            list_pick_user: None,
+            list_form: None,
+            dm_events: Vec::new(),
+            dm_user_id: None,
+            dm_loading: false,
+            dm_conversation: Vec::new(),
+            dm_conversation_loading: false,
+            revalidating_profiles: HashSet::new(),
+            embedding_job: false,
             mlx_client,
             mlx_embed_supported: false,
             mlx_chat_supported: false,
@@ -293,9 +939,21 @@ impl App {
             selected_cluster: None,
             cluster_source: None,
             refresh_then_cluster: false,
-            status_message: None,
+            toasts: Toasts::default(),
             error_detail: None,
+            doctor_report: None,
+            usage_report: None,
+            retry_request: None,
+            confirm: None,
             loading: false,
+            bulk_run: None,
+            partial_errors_expanded: false,
+            term_caps,
+            palette,
+            needs_redraw: true,
+            clock_minute: 0,
+            perf: PerfStats::default(),
+            perf_overlay: false,
             epoch: Instant::now(),
             loading_started_at: None,
         }
@@ -334,7 +992,15 @@ impl App {
     /// Check whether any loading flag is still active and clear
     /// `loading_started_at` if everything has finished.
     pub fn check_loading_finished(&mut self) {
-        let any_loading = self.loading
+        if !self.any_loading() {
+            self.loading_started_at = None;
+        }
+    }
+
+    /// Whether any loading flag is active (and so skeleton/spinner
+    /// animations need fresh frames).
+    fn any_loading(&self) -> bool {
+        self.loading
             || self.home_timeline.loading
             || self.mentions.loading
             || self.bookmarks.loading
@@ -344,10 +1010,23 @@ impl App {
             || self.cluster_topics_loading
             || self.models_loading
             || self.text_models_loading
-            || self.hf_models_loading;
-        if !any_loading {
-            self.loading_started_at = None;
-        }
+            || self.hf_models_loading
+            || self.lists_loading
+            || self.list_members_loading
+            || self.tweet_users_loading
+            || self.stream_rules_loading
+            || self.usage_loading
+            || self.dm_loading
+            || self.dm_conversation_loading
+    }
+
+    /// Whether any fetch is still pending.
+    fn requests_in_flight(&self) -> bool {
+        !self
+            .in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_empty()
     }
 
     // -- Main event loop ----------------------------------------------------
@@ -356,6 +1035,49 @@ impl App {
         // Try to initialize OpenRouter client from stored credentials.
         self.init_openrouter_client();
 
+        match InstanceLock::try_acquire() {
+            Ok(lock) => self.instance_lock = Some(lock),
+            Err(e) => {
+                tracing::warn!("not saving caches: {e}");
+                self.notify(format!(
+                    "{e}; this window reads the cache but won't save it"
+                ));
+            }
+        }
+        if !self.config.problems.is_empty() {
+            let count = self.config.problems.len();
+            self.set_error(format!(
+                "config.toml: {count} problem{}\n\n{}\n\nHint: fix the file, then check it with `xplorertui config check`.",
+                if count == 1 { "" } else { "s" },
+                self.config.problems.join("\n")
+            ));
+        }
+        self.profile_cache = ProfileCache::load(self.config.profile_cache_ttl_secs);
+        // Pre-warm author names so cached and fresh timelines render with
+        // names before their includes arrive.
+        self.users_cache = UserCache::load(self.config.users_cache_capacity);
+        let (muted_conversations, muted_error) = MutedConversations::load();
+        let (drafts, drafts_error) = Drafts::load();
+        self.muted_conversations = muted_conversations;
+        self.drafts = drafts;
+        let unreadable: Vec<String> = [muted_error, drafts_error]
+            .into_iter()
+            .flatten()
+            .map(|e| e.to_string())
+            .collect();
+        if !unreadable.is_empty() {
+            // Alongside any config problems, not instead of them.
+            let earlier = self.error_detail.take().map(|e| format!("{e}\n\n"));
+            self.set_error(format!(
+                "{}{}",
+                earlier.unwrap_or_default(),
+                unreadable.join("\n\n")
+            ));
+        }
+        self.history = History::load();
+        self.metrics_history = MetricsHistory::load();
+        self.delivered_watched = crate::store::load_watch_deliveries();
+
         // Probe MLX server capabilities (non-blocking, fast health check).
         if let Some(ref mlx) = self.mlx_client {
             let caps = mlx.capabilities().await;
@@ -363,6 +1085,43 @@ impl App {
             self.mlx_chat_supported = caps.iter().any(|c| c == "chat");
         }
 
+        // Without an API client, show whatever the local store has instead
+        // of leaving every view empty.
+        if self.api_client.is_none() {
+            for key in [
+                TimelineKey::Home,
+                TimelineKey::Mentions,
+                TimelineKey::Bookmarks,
+            ] {
+                self.restore_timeline_snapshot(key);
+            }
+        }
+
+        // Until the first timeline arrives, show who is signed in and how
+        // much of the monthly cap is left instead of an empty panel.
+        let fetches_timeline = matches!(
+            self.current_view(),
+            Some(ViewKind::Home | ViewKind::Mentions | ViewKind::Bookmarks)
+        );
+        if let Some(client) = &self.api_client
+            && fetches_timeline
+        {
+            let auth_method = client.auth_method();
+            let token_expires_at = match auth_method {
+                AuthMethod::OAuth2Pkce => crate::auth::oauth2_pkce::load_tokens()
+                    .ok()
+                    .flatten()
+                    .and_then(|tokens| tokens.expires_at),
+                _ => None,
+            };
+            self.splash = Some(StartupSplash {
+                auth_method: Some(auth_method),
+                token_expires_at,
+                ..StartupSplash::default()
+            });
+            self.events.send(AppEvent::FetchAccountSummary);
+        }
+
         // Trigger initial data fetch based on default view.
         match self.current_view() {
             Some(ViewKind::Home) => {
@@ -384,35 +1143,150 @@ impl App {
         }
 
         while self.running {
-            terminal.draw(|frame| self.draw(frame))?;
-            match self.events.next().await? {
-                Event::Tick => self.tick(),
-                Event::Crossterm(event) => {
-                    if let crossterm::event::Event::Key(key) = event
-                        && key.kind == crossterm::event::KeyEventKind::Press
-                    {
-                        self.handle_key_event(key);
-                    }
+            if self.needs_redraw {
+                self.mark_timeline_seen();
+                self.prefetch_link_preview();
+                let frame_started = Instant::now();
+                terminal.draw(|frame| self.draw(frame))?;
+                self.perf.record_frame(frame_started.elapsed());
+                self.needs_redraw = false;
+            }
+            let event = self.events.next().await?;
+            self.handle_event(event, &mut terminal).await;
+            // Drain whatever queued up while the last frame was drawing (e.g.
+            // auto-repeated j/k) so the next frame reflects all of it rather
+            // than lagging one frame per keypress.
+            for _ in 0..MAX_COALESCED_EVENTS {
+                let Some(event) = self.events.try_next() else {
+                    break;
+                };
+                if !self.running {
+                    break;
+                }
+                self.handle_event(event, &mut terminal).await;
+            }
+        }
+
+        if self.instance_lock.is_some() {
+            if let Err(e) = self.users_cache.save() {
+                tracing::warn!("failed to save users cache: {e}");
+            }
+            if let Err(e) = self.history.save() {
+                tracing::warn!("failed to save history: {e}");
+            }
+            if let Err(e) = self.metrics_history.save() {
+                tracing::warn!("failed to save metrics history: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_event(&mut self, event: Event, terminal: &mut DefaultTerminal) {
+        match event {
+            Event::Tick => self.tick(),
+            Event::Crossterm(event) => {
+                // Any terminal input (keys, resize, focus) may change
+                // what is on screen.
+                self.needs_redraw = true;
+                if let crossterm::event::Event::Key(key) = event
+                    && key.kind == crossterm::event::KeyEventKind::Press
+                {
+                    self.handle_key_event(key);
                 }
-                Event::App(app_event) => {
-                    if matches!(*app_event, AppEvent::StartAuth) {
-                        self.run_auth_flow(&mut terminal).await;
-                    } else if matches!(*app_event, AppEvent::StartOpenRouterAuth) {
-                        self.run_openrouter_auth_flow(&mut terminal).await;
-                    } else {
-                        self.handle_app_event(*app_event);
+            }
+            Event::App(app_event) => {
+                self.needs_redraw = true;
+                match *app_event {
+                    AppEvent::StartAuth => {
+                        self.run_auth_flow(terminal, &[]).await;
+                    }
+                    AppEvent::UpgradeScope { scope, retry } => {
+                        if self.run_auth_flow(terminal, &[scope.to_string()]).await {
+                            self.events.send(*retry);
+                        }
                     }
+                    AppEvent::StartOpenRouterAuth => {
+                        self.run_openrouter_auth_flow(terminal).await;
+                    }
+                    app_event => self.handle_app_event(app_event),
                 }
             }
         }
-        Ok(())
     }
 
     fn draw(&self, frame: &mut ratatui::Frame) {
+        let _span = tracing::debug_span!("render").entered();
         ui::draw(frame, self);
     }
 
-    fn tick(&self) {}
+    /// Preview card for `tweet`, when link previews are enabled and one is
+    /// available (from the API's URL metadata or a completed fetch).
+    pub fn link_preview(&self, tweet: &Tweet) -> Option<LinkPreview> {
+        if !self.config.link_previews {
+            return None;
+        }
+        let entity = link_preview::preview_entity(tweet)?;
+        link_preview::from_entity(entity).or_else(|| {
+            let url = entity.expanded_url.as_ref().unwrap_or(&entity.url);
+            self.link_previews.get(url)?.clone()
+        })
+    }
+
+    /// Start fetching the selected tweet's link preview if the API didn't
+    /// supply one and it hasn't been fetched yet.
+    fn prefetch_link_preview(&mut self) {
+        if !self.config.link_previews {
+            return;
+        }
+        let Some(entity) = self.selected_tweet().and_then(link_preview::preview_entity) else {
+            return;
+        };
+        if entity.title.is_some() {
+            return;
+        }
+        let url = entity
+            .expanded_url
+            .clone()
+            .unwrap_or_else(|| entity.url.clone());
+        if self.link_previews.contains_key(&url) {
+            return;
+        }
+        if self.link_previews.len() >= MAX_LINK_PREVIEWS {
+            self.link_previews.clear();
+        }
+        self.link_previews.insert(url.clone(), None);
+        self.events.send(AppEvent::FetchLinkPreview { url });
+    }
+
+    /// Ticks expire toasts, run the stuck-request watchdog and resume a
+    /// macro held for loading, and only trigger a redraw while something is animating or has changed.
+    fn tick(&mut self) {
+        let now = Instant::now();
+        let expired = self.toasts.expire(now);
+        self.expire_undo_post(now);
+        let unstuck = self.check_stuck_requests();
+        self.resume_macro_playback();
+        if expired || unstuck || self.any_loading() {
+            self.needs_redraw = true;
+        }
+        if self.config.status_bar.shows(StatusSegment::Clock) {
+            let minute = chrono::Local::now().minute();
+            if minute != self.clock_minute {
+                self.clock_minute = minute;
+                self.needs_redraw = true;
+            }
+        }
+    }
+
+    /// Whatever Following or Mentions has on screen no longer counts as
+    /// unread.
+    fn mark_timeline_seen(&mut self) {
+        match self.current_view() {
+            Some(ViewKind::Home) => self.home_timeline.mark_seen(),
+            Some(ViewKind::Mentions) => self.mentions.mark_seen(),
+            _ => {}
+        }
+    }
 
     // -- View stack ---------------------------------------------------------
 
@@ -420,23 +1294,83 @@ impl App {
         self.view_stack.last().map(|vs| &vs.kind)
     }
 
+    /// Whether the API client only has an app-only bearer token, which can
+    /// read public data but nothing tied to an account.
+    pub fn read_only(&self) -> bool {
+        self.api_client
+            .as_ref()
+            .is_some_and(|c| c.auth_method() == AuthMethod::BearerOnly)
+    }
+
+    /// In read-only mode, explain why `kind` can't be opened instead of
+    /// letting its fetch fail with a 403. Returns whether it was refused.
+    pub(super) fn refuse_without_user_context(&mut self, kind: &ViewKind) -> bool {
+        if !(self.read_only() && kind.needs_user_context()) {
+            return false;
+        }
+        let name = match kind {
+            ViewKind::Home => "Following",
+            ViewKind::Mentions => "Mentions",
+            ViewKind::Bookmarks => "Bookmarks",
+            ViewKind::Messages | ViewKind::DmConversation(_) => "Messages",
+            _ => "Lists",
+        };
+        self.notify(format!(
+            "{name} needs a signed-in user; the app-only bearer token is read-only. \
+             Run :auth to sign in."
+        ));
+        true
+    }
+
     pub fn push_view(&mut self, kind: ViewKind) {
+        self.clear_batch_selection();
+        // Moving on from the Lists view ends picking a List for `L`.
+        self.list_pick_user = None;
+        self.record_history(&kind);
         self.view_stack.push(ViewState {
             kind,
             scroll_offset: 0,
             selected_index: 0,
         });
+        self.stop_stream_unless_shown();
+        self.announce_view();
     }
 
     pub fn pop_view(&mut self) {
         if self.view_stack.len() > 1 {
+            self.clear_batch_selection();
+            // Nothing will show the data the view was waiting for.
+            self.cancel_view_requests();
+            self.list_pick_user = None;
             self.view_stack.pop();
+            self.stop_stream_unless_shown();
+            self.announce_view();
+        }
+    }
+
+    /// In accessible mode, name the new view in the status line so screen
+    /// readers pick up the change.
+    fn announce_view(&mut self) {
+        if self.config.accessible {
+            self.notify(format!("Now viewing {}", ui::status_bar::view_name(self)));
         }
     }
 
+    /// Show `msg` as a toast that expires after `toast_duration_secs`.
+    fn notify(&mut self, msg: impl Into<String>) {
+        let ttl = Duration::from_secs(self.config.toast_duration_secs);
+        self.toasts.info(msg, ttl);
+    }
+
     fn set_error(&mut self, msg: String) {
-        self.status_message = Some(msg.clone());
+        // Only the first line fits in a toast; the popup shows the rest
+        // (e.g. remediation hints).
+        if let Some(first) = msg.lines().next() {
+            self.toasts.error(first);
+        }
         self.error_detail = Some(msg);
+        // A failed request sets this again once its error is reported.
+        self.retry_request = None;
     }
 }
 
@@ -450,7 +1384,2397 @@ fn tweet_url(tweet_id: &str, username: Option<&str>) -> String {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use serde_json::json;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{App, AppMode, IncludedContent, MAX_INCLUDED, ReplyDrafts, ReplyTarget};
+    use crate::api::XApiClient;
+    use crate::api::error::AccountState;
+    use crate::api::types::{
+        DmEvent, Includes, List, ListFields, ListResponse, ReplySettings, Tweet, User,
+    };
+    use crate::auth::credentials::CredentialSet;
+    use crate::auth::{AuthMethod, AuthProvider};
+    use crate::config::AppConfig;
+    use crate::domains::DomainReport;
+    use crate::event::{AppEvent, BulkAction, Event, StreamStatus, TweetUsersKind, ViewKind};
     use crate::openrouter;
+    use crate::store::WatchDelivery;
+
+    fn tweet(id: &str) -> Tweet {
+        serde_json::from_value(serde_json::json!({ "id": id, "text": "hi" })).unwrap()
+    }
+
+    #[test]
+    fn search_max_applies_to_that_search_only() {
+        let mut app = App::headless(AppConfig::default());
+        app.command_input = "search rust --max 50".into();
+        app.execute_command();
+        assert_eq!(app.search_max_results, Some(("rust".into(), 50)));
+        assert_eq!(app.config.max_results.search, None);
+
+        app.command_input = "search go".into();
+        app.execute_command();
+        assert_eq!(app.search_max_results, None);
+    }
+
+    #[test]
+    fn included_content_evicts_the_oldest_entries_past_the_cap() {
+        let mut included = IncludedContent::default();
+        let page = |ids: std::ops::Range<usize>| Includes {
+            tweets: Some(ids.map(|id| tweet(&id.to_string())).collect()),
+            ..Includes::default()
+        };
+        included.add(&page(0..MAX_INCLUDED));
+        included.add(&page(0..10));
+        assert_eq!(included.tweets.len(), MAX_INCLUDED);
+
+        included.add(&page(MAX_INCLUDED..MAX_INCLUDED + 2));
+        assert_eq!(included.tweets.len(), MAX_INCLUDED);
+        assert!(!included.tweets.contains_key("0"));
+        assert!(!included.tweets.contains_key("1"));
+        assert!(included.tweets.contains_key("2"));
+        assert!(included.tweets.contains_key(&MAX_INCLUDED.to_string()));
+    }
+
+    #[test]
+    fn goto_selects_tweet_loaded_in_current_view() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets = vec![tweet("1"), tweet("2"), tweet("3")];
+        app.command_input = "goto https://x.com/alice/status/3".into();
+        app.execute_command();
+        assert_eq!(app.selected_index(), 2);
+        assert_eq!(app.toasts.latest().unwrap().text, "Jumped to tweet 3");
+    }
+
+    #[test]
+    fn a_recorded_macro_replays_its_keys_in_order() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets = vec![tweet("1"), tweet("2"), tweet("3")];
+        let press = |app: &mut App, keys: &str| {
+            for c in keys.chars() {
+                app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+                while let Some(Event::App(e)) = app.events.try_next() {
+                    app.handle_app_event(*e);
+                }
+            }
+        };
+
+        // Open help, go back, move down: `q` only goes back if help opened
+        // first, instead of quitting.
+        press(&mut app, "Qa");
+        assert_eq!(app.recording_macro(), Some('a'));
+        press(&mut app, "&a");
+        assert_eq!(
+            app.toasts.latest().unwrap().text,
+            "Stop recording @a with Q first"
+        );
+        press(&mut app, "?qjQ");
+        assert_eq!(app.recording_macro(), None);
+        assert_eq!(
+            app.toasts.latest().unwrap().text,
+            "Recorded @a (3 keys) · &a to play"
+        );
+        assert_eq!(app.selected_index(), 1);
+
+        press(&mut app, "&a");
+        assert!(app.running);
+        assert_eq!(app.current_view(), Some(&ViewKind::Home));
+        assert_eq!(app.selected_index(), 2);
+
+        press(&mut app, "&b");
+        assert_eq!(app.toasts.latest().unwrap().text, "Nothing recorded in @b");
+    }
+
+    #[test]
+    fn a_macro_leaves_out_keys_typed_into_the_command_line() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets = vec![tweet("1"), tweet("2"), tweet("3")];
+        let press = |app: &mut App, key: KeyCode| {
+            app.handle_key_event(KeyEvent::from(key));
+            while let Some(Event::App(e)) = app.events.try_next() {
+                app.handle_app_event(*e);
+            }
+        };
+
+        press(&mut app, KeyCode::Char('Q'));
+        press(&mut app, KeyCode::Char('a'));
+        press(&mut app, KeyCode::Char(':'));
+        assert_eq!(app.mode, AppMode::Command);
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Esc);
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('Q'));
+        assert_eq!(
+            app.toasts.latest().unwrap().text,
+            "Recorded @a (1 key) · &a to play"
+        );
+        assert_eq!(app.selected_index(), 1);
+
+        press(&mut app, KeyCode::Char('&'));
+        press(&mut app, KeyCode::Char('a'));
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(app.selected_index(), 2);
+    }
+
+    #[test]
+    fn a_macro_waits_for_loading_to_finish() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets = vec![tweet("1"), tweet("2"), tweet("3")];
+        let press = |app: &mut App, keys: &str| {
+            for c in keys.chars() {
+                app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+                while let Some(Event::App(e)) = app.events.try_next() {
+                    app.handle_app_event(*e);
+                }
+            }
+        };
+        press(&mut app, "QajjQ");
+        assert_eq!(app.selected_index(), 2);
+        app.view_stack.last_mut().unwrap().selected_index = 0;
+
+        app.home_timeline.loading = true;
+        press(&mut app, "&a");
+        assert_eq!(app.selected_index(), 0);
+        app.tick();
+        assert_eq!(app.selected_index(), 0);
+
+        app.home_timeline.loading = false;
+        app.tick();
+        while let Some(Event::App(e)) = app.events.try_next() {
+            app.handle_app_event(*e);
+        }
+        assert_eq!(app.selected_index(), 2);
+    }
+
+    #[test]
+    fn toggling_an_exclusion_reloads_the_home_timeline() {
+        let mut app = App::headless(AppConfig::default());
+        app.push_view(ViewKind::Home);
+        app.home_timeline.tweets = vec![tweet("1")];
+        app.command_input = "set exclude_replies on".into();
+        app.execute_command();
+        assert!(app.config.timeline_exclude().replies);
+        assert!(app.home_timeline.tweets.is_empty());
+        let fetched = std::iter::from_fn(|| app.events.try_next()).any(|event| {
+            matches!(event, Event::App(e) if matches!(*e, AppEvent::FetchHomeTimeline { .. }))
+        });
+        assert!(fetched);
+
+        app.command_input = "set exclude_replies maybe".into();
+        app.execute_command();
+        assert!(app.config.exclude_replies);
+    }
+
+    #[test]
+    fn batch_selection_combines_marks_and_visual_range() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets = (1..=5).map(|i| tweet(&i.to_string())).collect();
+
+        app.toggle_mark(); // marks "1", cursor to "2"
+        app.move_selection_down();
+        app.move_selection_down();
+        app.enter_visual_mode(); // anchor at "4"
+        app.move_selection_down();
+        let ids: Vec<_> = app
+            .batch_selection()
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(ids, ["1", "4", "5"]);
+
+        app.clear_batch_selection();
+        assert!(app.batch_selection().is_empty());
+        assert_eq!(app.mode, AppMode::Normal);
+    }
+
+    #[test]
+    fn muting_targets_each_author_once() {
+        let mut app = App::headless(AppConfig::default());
+        let by = |id: &str, author: &str| {
+            let mut t = tweet(id);
+            t.author_id = Some(author.into());
+            t
+        };
+        app.home_timeline.tweets = vec![by("1", "a"), by("2", "b"), by("3", "a")];
+        app.enter_visual_mode();
+        app.move_selection_down();
+        app.move_selection_down();
+        app.mute_selection_authors();
+        assert_eq!(app.mode, AppMode::Confirm);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+
+        assert_eq!(app.mode, AppMode::Visual);
+        let Some(Event::App(event)) = app.events.try_next() else {
+            panic!("expected an app event");
+        };
+        let AppEvent::StartBulk { action, ids } = *event else {
+            panic!("expected StartBulk, got {event:?}");
+        };
+        assert_eq!(action, BulkAction::Mute);
+        assert_eq!(ids, ["a", "b"]);
+    }
+
+    #[test]
+    fn confirm_cancel_keeps_selection_and_dont_ask_again_sticks() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets = vec![tweet("1"), tweet("2")];
+        app.toggle_mark();
+
+        app.unbookmark_selection();
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(app.confirm.is_none());
+        assert!(app.events.try_next().is_none());
+        assert_eq!(app.batch_selection().len(), 1);
+
+        app.unbookmark_selection();
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(app.config.skip_confirm, ["unbookmark"]);
+        assert!(app.events.try_next().is_some());
+
+        // Asked not to, so the next run starts straight away.
+        app.unbookmark_selection();
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(event)) if matches!(*event, AppEvent::StartBulk { .. })
+        ));
+    }
+
+    #[test]
+    fn previous_page_goes_before_the_loaded_tweets() {
+        let page = |ids: &[&str], meta: serde_json::Value| {
+            let data: Vec<_> = ids
+                .iter()
+                .map(|id| json!({ "id": id, "text": "hi" }))
+                .collect();
+            AppEvent::UserTimelineLoaded {
+                user_id: "7".into(),
+                result: Ok(serde_json::from_value(json!({ "data": data, "meta": meta })).unwrap()),
+            }
+        };
+        let mut app = App::headless(AppConfig::default());
+        app.push_view(ViewKind::UserTimeline("7".into()));
+        app.handle_app_event(page(
+            &["3", "4"],
+            json!({ "next_token": "older", "previous_token": "newer" }),
+        ));
+        app.viewed_user_timeline.selected_index = 1;
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('p')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::FetchUserTimeline { pagination_token: Some(t), .. } if t == "newer"
+            )
+        ));
+        app.viewed_user_timeline.start_loading(Some("newer"));
+        app.handle_app_event(page(&["1", "2"], json!({ "next_token": "newer-next" })));
+
+        let tl = &app.viewed_user_timeline;
+        let ids: Vec<_> = tl.tweets.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3", "4"]);
+        assert_eq!(tl.selected_index, 0);
+        assert_eq!(tl.next_token.as_deref(), Some("older"));
+        assert_eq!(tl.previous_token, None);
+    }
+
+    #[test]
+    fn history_lists_opened_threads_and_profiles() {
+        let mut app = App::headless(AppConfig::default());
+        app.thread_tweets =
+            vec![serde_json::from_value(json!({ "id": "100", "text": "first\npost" })).unwrap()];
+        app.push_view(ViewKind::Thread("100".into()));
+        app.push_view(ViewKind::UserProfile("bob".into()));
+        app.push_view(ViewKind::History);
+        let labels: Vec<_> = app
+            .history
+            .entries()
+            .iter()
+            .map(|e| e.label.as_str())
+            .collect();
+        assert_eq!(labels, ["@bob", "first post"]);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::FetchThread { conversation_id, .. } if conversation_id == "100"
+            )
+        ));
+    }
+
+    #[test]
+    fn u_opens_the_selected_authors_profile() {
+        let mut app = App::headless(AppConfig::default());
+        app.push_view(ViewKind::UserTimeline("7".into()));
+        app.handle_app_event(AppEvent::UserTimelineLoaded {
+            user_id: "7".into(),
+            result: Ok(serde_json::from_value(json!({
+                "data": [
+                    { "id": "1", "text": "hi", "author_id": "7" },
+                    { "id": "2", "text": "hi", "author_id": "8" },
+                ],
+                "includes": { "users": [{ "id": "7", "name": "Bob", "username": "bob" }] },
+            }))
+            .unwrap()),
+        });
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('u')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchUser { username } if username == "bob")
+        ));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('u')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchUserById { user_id } if user_id == "8")
+        ));
+    }
+
+    #[test]
+    fn duplicates_fold_into_the_first_copy_and_z_toggles_them() {
+        let link = |id: &str, url: &str| {
+            json!({
+                "id": id,
+                "text": format!("look https://t.co/{id}"),
+                "entities": { "urls": [{ "start": 5, "end": 28, "url": "https://t.co/x", "expanded_url": url }] },
+            })
+        };
+        let mut app = App::headless(AppConfig::default());
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [
+                    link("1", "https://example.com/story/"),
+                    { "id": "2", "text": "Big news: the new release is out today!" },
+                    link("3", "https://www.example.com/story"),
+                    { "id": "4", "text": "RT @alice: Big news — the new release is out today" },
+                    { "id": "5", "text": "thanks!" },
+                    { "id": "6", "text": "Thanks!" },
+                    // Links to X itself are per-tweet, not shared content.
+                    link("7", "https://x.com/alice/status/1/photo/1"),
+                    link("8", "https://x.com/alice/status/1/photo/1"),
+                ],
+            }),
+        )
+        .unwrap())));
+
+        let ids = |app: &App| -> Vec<String> {
+            app.home_timeline
+                .tweets
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&app), ["1", "2", "5", "6", "7", "8"]);
+        assert_eq!(app.duplicate_count("1"), 1);
+        assert_eq!(app.duplicate_count("2"), 1);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1", "2", "4", "5", "6", "7", "8"]);
+
+        // Folding again from the copy selects the original.
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1", "2", "5", "6", "7", "8"]);
+        assert_eq!(app.selected_index(), 1);
+
+        // Tweets scrolled out by the cap take their groups with them.
+        app.config.max_timeline_tweets = 5;
+        app.view_stack.last_mut().unwrap().selected_index = 5;
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({ "data": [{ "id": "9", "text": "a new one" }] }),
+        )
+        .unwrap())));
+        assert_eq!(ids(&app), ["5", "6", "7", "8", "9"]);
+        assert_eq!(app.duplicate_count("1"), 0);
+        assert_eq!(app.duplicate_count("2"), 0);
+
+        app.home_timeline
+            .duplicates
+            .insert("9".into(), super::DuplicateGroup::default());
+        app.refresh_current_view();
+        assert!(app.home_timeline.duplicates.is_empty());
+    }
+
+    #[test]
+    fn author_runs_fold_into_their_first_tweet_and_z_shows_them() {
+        let mut app = App::headless(AppConfig {
+            collapse_author_runs: 3,
+            ..AppConfig::default()
+        });
+        let by = |id: &str, author: &str| json!({ "id": id, "text": format!("update {id}"), "author_id": author });
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [
+                    by("1", "bot"), by("2", "bot"), by("3", "bot"),
+                    by("4", "alice"), by("5", "alice"),
+                    by("6", "bot"),
+                ],
+            }),
+        )
+        .unwrap())));
+
+        let ids = |app: &App| -> Vec<String> {
+            app.home_timeline
+                .tweets
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+        // Two in a row is short of the run length.
+        assert_eq!(ids(&app), ["1", "4", "5", "6"]);
+        assert_eq!(app.author_run_count("1"), 2);
+        assert_eq!(app.author_run_count("4"), 0);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1", "2", "3", "4", "5", "6"]);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1", "4", "5", "6"]);
+        assert_eq!(app.selected_index(), 0);
+
+        // A run folded at the end of a page goes on into the next one.
+        for data in [
+            json!([by("7", "carol"), by("8", "carol"), by("9", "carol")]),
+            json!([by("10", "carol"), by("11", "dave")]),
+        ] {
+            app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+                json!({ "data": data }),
+            )
+            .unwrap())));
+        }
+        assert_eq!(ids(&app), ["1", "4", "5", "6", "7", "11"]);
+        assert_eq!(app.author_run_count("7"), 3);
+
+        app.home_timeline.clear_tweets();
+        assert!(app.home_timeline.author_runs.is_empty());
+    }
+
+    #[test]
+    fn a_tweet_with_copies_and_a_run_opens_each_in_turn() {
+        let mut app = App::headless(AppConfig {
+            collapse_author_runs: 3,
+            ..AppConfig::default()
+        });
+        let text = "The nightly build finished without errors";
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [
+                    { "id": "1", "text": text, "author_id": "bot" },
+                    { "id": "2", "text": "update 2", "author_id": "bot" },
+                    { "id": "3", "text": "update 3", "author_id": "bot" },
+                    { "id": "4", "text": text, "author_id": "alice" },
+                ],
+            }),
+        )
+        .unwrap())));
+        let ids = |app: &App| -> Vec<String> {
+            app.home_timeline
+                .tweets
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&app), ["1"]);
+        assert_eq!(app.duplicate_count("1"), 1);
+        assert_eq!(app.author_run_count("1"), 2);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1", "4"]);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1", "2", "3"]);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(ids(&app), ["1"]);
+    }
+
+    #[tokio::test]
+    async fn watched_tweets_are_delivered_even_when_folded() {
+        let mut config = AppConfig {
+            collapse_author_runs: 2,
+            ..AppConfig::default()
+        };
+        config.watch.notify = true;
+        config.watch.rules.push(crate::config::WatchRule {
+            keywords: vec!["outage".into()],
+            command: Some("cat > /dev/null".into()),
+            ..Default::default()
+        });
+        let mut app = App::headless(config);
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [
+                    { "id": "1", "text": "status page", "author_id": "ops" },
+                    { "id": "2", "text": "Outage in us-east", "author_id": "ops" },
+                ],
+            }),
+        )
+        .unwrap())));
+
+        assert_eq!(app.home_timeline.tweets.len(), 1);
+        assert!(app.delivered_watched.contains(&WatchDelivery {
+            tweet_id: "2".into(),
+            sink: "command cat > /dev/null".into(),
+        }));
+        assert_eq!(
+            app.toasts.latest().unwrap().text,
+            "1 new tweet matches your watch rules"
+        );
+    }
+
+    #[test]
+    fn the_timeline_cap_evicts_scrolled_past_tweets_but_not_the_selected_one() {
+        let mut app = App::headless(AppConfig {
+            max_timeline_tweets: 3,
+            ..AppConfig::default()
+        });
+        let page = |ids: [&str; 3]| -> Event {
+            let data: Vec<_> = ids
+                .iter()
+                .map(|id| json!({ "id": id, "text": "hi" }))
+                .collect();
+            Event::App(Box::new(AppEvent::HomeTimelineLoaded(Ok(
+                serde_json::from_value(json!({ "data": data })).unwrap(),
+            ))))
+        };
+        let Event::App(first) = page(["1", "2", "3"]) else {
+            unreachable!()
+        };
+        app.handle_app_event(*first);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+
+        let Event::App(second) = page(["4", "5", "6"]) else {
+            unreachable!()
+        };
+        app.handle_app_event(*second);
+        let ids: Vec<_> = app
+            .home_timeline
+            .tweets
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        // Only "1" was scrolled past; "2" stays under the cursor.
+        assert_eq!(ids, ["2", "3", "4", "5", "6"]);
+        assert_eq!(app.selected_index(), 0);
+
+        for _ in 0..4 {
+            app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        }
+        let Event::App(third) = page(["7", "8", "9"]) else {
+            unreachable!()
+        };
+        app.handle_app_event(*third);
+        let ids: Vec<_> = app
+            .home_timeline
+            .tweets
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(ids, ["6", "7", "8", "9"]);
+        assert_eq!(app.home_timeline.tweets[app.selected_index()].id, "6");
+    }
+
+    #[test]
+    fn l_likes_optimistically_and_rolls_back_on_error() {
+        let mut app = App::headless(AppConfig::default());
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [{
+                    "id": "1",
+                    "text": "hello",
+                    "public_metrics": { "like_count": 4, "retweet_count": 0, "reply_count": 0, "quote_count": 0 },
+                }],
+            }),
+        )
+        .unwrap())));
+        while app.events.try_next().is_some() {}
+        let likes = |app: &App| {
+            app.home_timeline.tweets[0]
+                .public_metrics
+                .as_ref()
+                .unwrap()
+                .like_count
+        };
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('l')));
+        assert_eq!(likes(&app), 5);
+        assert!(app.liked_tweets.contains("1"));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::LikeTweet { tweet_id, like: true } if tweet_id == "1")
+        ));
+
+        app.handle_app_event(AppEvent::LikeFinished {
+            tweet_id: "1".into(),
+            like: true,
+            result: Err(Arc::new("403 Forbidden".into())),
+        });
+        assert_eq!(likes(&app), 4);
+        assert!(!app.liked_tweets.contains("1"));
+        assert!(app.toasts.has_errors());
+    }
+
+    #[test]
+    fn m_in_a_profile_mutes_and_unmutes_the_account() {
+        let mut app = App::headless(AppConfig::default());
+        app.viewed_user = Some(
+            serde_json::from_value(json!({ "id": "7", "username": "bob", "name": "Bob" })).unwrap(),
+        );
+        app.push_view(ViewKind::UserProfile("bob".into()));
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('m')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::MuteUser { username, user_id: Some(id), mute: true } if username == "bob" && id == "7"
+            )
+        ));
+        app.handle_app_event(AppEvent::MuteFinished {
+            username: "bob".into(),
+            mute: true,
+            result: Ok("7".into()),
+        });
+        assert!(app.muted_users.contains("7"));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('m')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::MuteUser { mute: false, .. })
+        ));
+
+        // Someone else's profile isn't open, so their ID has to be looked up.
+        app.command_input = "mute @carol".into();
+        app.execute_command();
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::MuteUser { username, user_id: None, mute: true } if username == "carol"
+            )
+        ));
+    }
+
+    #[test]
+    fn the_block_command_asks_for_confirmation() {
+        let mut app = App::headless(AppConfig::default());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char(':')));
+        for c in "block x".chars() {
+            app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, AppMode::Confirm);
+        assert_eq!(
+            app.confirm.as_ref().map(|c| c.title.as_str()),
+            Some("Block @x")
+        );
+    }
+
+    #[test]
+    fn accessible_mode_reads_a_confirmation_out_in_the_status_line() {
+        for accessible in [false, true] {
+            let mut app = App::headless(AppConfig {
+                accessible,
+                ..AppConfig::default()
+            });
+            app.command_input = "block x".into();
+            app.execute_command();
+            assert_eq!(app.mode, AppMode::Confirm);
+            let announced = app.toasts.iter().any(|t| {
+                t.text
+                    == "Block @x? They will be unfollowed and won't be able to follow you or \
+                        see your posts. (y/n)"
+            });
+            assert_eq!(announced, accessible);
+        }
+    }
+
+    #[test]
+    fn shift_x_in_a_profile_blocks_after_confirming_and_unblocks_directly() {
+        let mut app = App::headless(AppConfig::default());
+        app.viewed_user = Some(
+            serde_json::from_value(json!({ "id": "7", "username": "bob", "name": "Bob" })).unwrap(),
+        );
+        app.push_view(ViewKind::UserProfile("bob".into()));
+        while app.events.try_next().is_some() {}
+        let shift_x = KeyEvent::new(KeyCode::Char('X'), KeyModifiers::SHIFT);
+
+        app.handle_key_event(shift_x);
+        assert_eq!(app.mode, AppMode::Confirm);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('n')));
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(app.events.try_next().is_none());
+
+        app.handle_key_event(shift_x);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::BlockUser { username, user_id: Some(id), block: true } if username == "bob" && id == "7"
+            )
+        ));
+        app.handle_app_event(AppEvent::BlockFinished {
+            username: "bob".into(),
+            block: true,
+            result: Ok("7".into()),
+        });
+        assert!(app.blocked_users.contains("7"));
+
+        app.handle_key_event(shift_x);
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::BlockUser { block: false, .. })
+        ));
+    }
+
+    #[test]
+    fn shift_h_charts_the_open_profiles_posting_times() {
+        let mut app = App::headless(AppConfig::default());
+        app.viewed_user = Some(
+            serde_json::from_value(json!({ "id": "7", "username": "bob", "name": "Bob" })).unwrap(),
+        );
+        app.push_view(ViewKind::UserProfile("bob".into()));
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT));
+        assert!(app.posting_heatmap.as_ref().unwrap().loading);
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchPostingActivity { user_id } if user_id == "7")
+        ));
+
+        let times: Vec<chrono::DateTime<chrono::Utc>> = [
+            "2026-03-02T09:00:00Z",
+            "2026-03-02T09:30:00Z",
+            "2026-03-07T23:00:00Z",
+        ]
+        .iter()
+        .map(|t| t.parse().unwrap())
+        .collect();
+        // Results for a profile that has since been closed are dropped.
+        app.handle_app_event(AppEvent::PostingActivityLoaded {
+            user_id: "8".into(),
+            result: Ok(times.clone()),
+        });
+        assert_eq!(app.posting_heatmap.as_ref().unwrap().tweets, 0);
+        app.handle_app_event(AppEvent::PostingActivityLoaded {
+            user_id: "7".into(),
+            result: Ok(times),
+        });
+        let heatmap = app.posting_heatmap.as_ref().unwrap();
+        assert!(!heatmap.loading);
+        assert_eq!(heatmap.tweets, 3);
+        assert_eq!(heatmap.counts.iter().flatten().sum::<u32>(), 3);
+        assert_eq!(heatmap.max(), 2);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT));
+        assert!(app.posting_heatmap.is_none());
+    }
+
+    #[test]
+    fn the_posting_heatmap_stops_loading_without_a_client() {
+        let mut app = App::headless(AppConfig::default());
+        app.viewed_user = Some(
+            serde_json::from_value(json!({ "id": "7", "username": "bob", "name": "Bob" })).unwrap(),
+        );
+        app.push_view(ViewKind::UserProfile("bob".into()));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT));
+        while let Some(Event::App(e)) = app.events.try_next() {
+            app.handle_app_event(*e);
+        }
+        let heatmap = app.posting_heatmap.as_ref().unwrap();
+        assert!(!heatmap.loading);
+        assert_eq!(
+            heatmap.error.as_deref(),
+            Some("No API client configured. Use :auth first.")
+        );
+    }
+
+    #[tokio::test]
+    async fn domains_all_counts_the_store_in_the_background() {
+        let mut app = App::headless(AppConfig::default());
+        while app.events.try_next().is_some() {}
+        app.command_input = "domains all".into();
+        app.execute_command();
+        assert_ne!(app.current_view(), Some(&ViewKind::Domains));
+
+        let counted = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                if let Event::App(event) = app.events.next().await.unwrap()
+                    && matches!(*event, AppEvent::DomainsCounted(_))
+                {
+                    return *event;
+                }
+            }
+        })
+        .await
+        .unwrap();
+        let AppEvent::DomainsCounted(report) = &counted else {
+            unreachable!();
+        };
+        assert_eq!(report.source, "the local store");
+
+        let tweet: Tweet = serde_json::from_value(json!({
+            "id": "1",
+            "text": "read this",
+            "entities": { "urls": [{
+                "start": 0,
+                "end": 23,
+                "url": "https://t.co/x",
+                "expanded_url": "https://ratatui.rs/x",
+            }] },
+        }))
+        .unwrap();
+        app.handle_app_event(AppEvent::DomainsCounted(DomainReport::new(
+            "the local store".into(),
+            &[tweet],
+        )));
+        assert_eq!(app.current_view(), Some(&ViewKind::Domains));
+        assert_eq!(app.domain_report.as_ref().unwrap().linking, 1);
+    }
+
+    #[test]
+    fn tags_view_counts_the_current_view_and_enter_searches() {
+        let mut app = App::headless(AppConfig::default());
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [
+                    { "id": "1", "text": "#tui", "entities": { "hashtags": [{ "start": 0, "end": 4, "tag": "tui" }] } },
+                    {
+                        "id": "2",
+                        "text": "ratatui",
+                        "context_annotations": [{
+                            "domain": { "id": "66", "name": "Interests and Hobbies Category" },
+                            "entity": { "id": "847", "name": "Terminal apps" },
+                        }],
+                    },
+                    {
+                        "id": "3",
+                        "text": "#TUI again",
+                        "entities": { "hashtags": [{ "start": 0, "end": 4, "tag": "TUI" }] },
+                    },
+                ],
+            }),
+        )
+        .unwrap())));
+        while app.events.try_next().is_some() {}
+
+        app.command_input = "tags".into();
+        app.execute_command();
+        assert_eq!(app.current_view(), Some(&ViewKind::Tags));
+        let report = app.tag_report.as_ref().unwrap();
+        assert_eq!(report.tags[0].label, "#tui");
+        assert_eq!(report.tags[0].tweets, 2);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.search_query, "context:66.847");
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchSearch { query, .. } if query == "context:66.847")
+        ));
+    }
+
+    #[test]
+    fn b_bookmarks_elsewhere_and_removes_in_the_bookmarks_view() {
+        let page = |ids: &[&str]| -> ListResponse<Tweet> {
+            let data: Vec<_> = ids
+                .iter()
+                .map(|id| json!({ "id": id, "text": id }))
+                .collect();
+            serde_json::from_value(json!({ "data": data })).unwrap()
+        };
+        let mut app = App::headless(AppConfig::default());
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(page(&["1", "2"]))));
+        app.handle_app_event(AppEvent::BookmarksLoaded(Ok(page(&["8", "9"]))));
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('b')));
+        let Some(Event::App(event)) = app.events.try_next() else {
+            panic!("expected a bookmark request");
+        };
+        let AppEvent::BookmarkTweet { tweet, bookmark } = *event else {
+            panic!("expected a bookmark request");
+        };
+        assert_eq!(tweet.id, "1");
+        assert!(bookmark);
+        app.handle_app_event(AppEvent::BookmarkFinished {
+            tweet,
+            bookmark,
+            result: Ok(serde_json::from_value(json!({ "data": { "bookmarked": true } })).unwrap()),
+        });
+        let ids = |app: &App| -> Vec<String> {
+            app.bookmarks.tweets.iter().map(|t| t.id.clone()).collect()
+        };
+        assert_eq!(ids(&app), ["1", "8", "9"]);
+
+        app.push_view(ViewKind::Bookmarks);
+        while app.events.try_next().is_some() {}
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('b')));
+        let Some(Event::App(event)) = app.events.try_next() else {
+            panic!("expected a removal request");
+        };
+        let AppEvent::BookmarkTweet { tweet, bookmark } = *event else {
+            panic!("expected a removal request");
+        };
+        assert_eq!(tweet.id, "9");
+        assert!(!bookmark);
+        app.handle_app_event(AppEvent::BookmarkFinished {
+            tweet,
+            bookmark,
+            result: Ok(serde_json::from_value(json!({ "data": { "bookmarked": false } })).unwrap()),
+        });
+        assert_eq!(ids(&app), ["1", "8"]);
+        assert_eq!(app.selected_index(), 1);
+    }
+
+    #[test]
+    fn h_hides_replies_only_under_your_own_tweets() {
+        let mut app = App::headless(AppConfig::default());
+        let tweet = |id: &str, author: &str| -> Tweet {
+            serde_json::from_value(json!({ "id": id, "text": "", "author_id": author })).unwrap()
+        };
+        app.current_user = Some(
+            serde_json::from_value(json!({ "id": "42", "username": "me", "name": "Me" })).unwrap(),
+        );
+        app.thread_root = Some(tweet("1", "42"));
+        app.thread_tweets = vec![tweet("2", "7")];
+        app.push_view(ViewKind::Thread("1".into()));
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('h')));
+        assert!(app.hidden_replies.contains("2"));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::HideReply { tweet_id, hide: true } if tweet_id == "2")
+        ));
+        app.handle_app_event(AppEvent::HideReplyFinished {
+            tweet_id: "2".into(),
+            hide: true,
+            result: Err(Arc::new("403 Forbidden".into())),
+        });
+        assert!(!app.hidden_replies.contains("2"));
+
+        app.thread_root = Some(tweet("1", "7"));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('h')));
+        assert!(app.hidden_replies.is_empty());
+        assert!(app.events.try_next().is_none());
+    }
+
+    #[test]
+    fn an_app_only_token_refuses_the_personal_views() {
+        let mut app = App::headless(AppConfig::default());
+        let auth = AuthProvider {
+            method: AuthMethod::BearerOnly,
+            credentials: CredentialSet::default(),
+        };
+        app.api_client = Some(Arc::new(XApiClient::new(auth, 0)));
+        app.push_view(ViewKind::Search);
+        while app.events.try_next().is_some() {}
+        assert!(app.read_only());
+
+        app.handle_app_event(AppEvent::SwitchView(ViewKind::Bookmarks));
+        app.command_input = "mentions".into();
+        app.execute_command();
+        while let Some(Event::App(e)) = app.events.try_next() {
+            app.handle_app_event(*e);
+        }
+        assert_eq!(app.current_view(), Some(&ViewKind::Search));
+        assert!(
+            app.toasts
+                .latest()
+                .is_some_and(|t| t.text.starts_with("Mentions needs a signed-in user"))
+        );
+        assert!(app.events.try_next().is_none());
+    }
+
+    #[test]
+    fn an_app_only_token_refuses_the_keys_that_change_the_account() {
+        let mut app = App::headless(AppConfig::default());
+        let auth = AuthProvider {
+            method: AuthMethod::BearerOnly,
+            credentials: CredentialSet::default(),
+        };
+        app.api_client = Some(Arc::new(XApiClient::new(auth, 0)));
+        app.home_timeline.tweets = vec![tweet("1")];
+        while app.events.try_next().is_some() {}
+
+        for (key, action) in [
+            ('l', "Liking"),
+            ('b', "Bookmarking"),
+            ('t', "Retweeting"),
+            ('m', "Muting"),
+            ('X', "Blocking"),
+            ('c', "Posting"),
+        ] {
+            app.handle_key_event(KeyEvent::from(KeyCode::Char(key)));
+            assert_eq!(app.mode, AppMode::Normal);
+            assert!(
+                app.toasts.latest().is_some_and(|t| t
+                    .text
+                    .starts_with(&format!("{action} needs a signed-in user"))),
+                "{key}"
+            );
+            assert!(app.events.try_next().is_none(), "{key}");
+        }
+        assert!(app.liked_tweets.is_empty());
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('v')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('B')));
+        assert!(app.events.try_next().is_none());
+        assert!(app.toasts.latest().unwrap().text.starts_with("Bookmarking"));
+    }
+
+    #[tokio::test]
+    async fn only_the_failed_watch_sink_is_tried_again_on_the_next_load() {
+        let file = std::env::temp_dir().join(format!("xplorertui-watched-{}", std::process::id()));
+        let mut config = AppConfig::default();
+        config.watch.rules.push(crate::config::WatchRule {
+            keywords: vec!["outage".into()],
+            append_to: Some(file.display().to_string()),
+            command: Some("exit 3".into()),
+            ..Default::default()
+        });
+        let mut app = App::headless(config);
+        while app.events.try_next().is_some() {}
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({ "data": [{ "id": "1", "text": "Outage in us-east" }] }),
+        )
+        .unwrap())));
+        let appended = WatchDelivery {
+            tweet_id: "1".into(),
+            sink: format!("append_to {}", file.display()),
+        };
+        let run = WatchDelivery {
+            tweet_id: "1".into(),
+            sink: "command exit 3".into(),
+        };
+        assert!(app.delivered_watched.contains(&appended));
+        assert!(app.delivered_watched.contains(&run));
+
+        let failed = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                if let Event::App(event) = app.events.next().await.unwrap()
+                    && matches!(*event, AppEvent::WatchSinksFailed(_))
+                {
+                    return *event;
+                }
+            }
+        })
+        .await
+        .unwrap();
+        app.handle_app_event(failed);
+        assert!(app.delivered_watched.contains(&appended));
+        assert!(!app.delivered_watched.contains(&run));
+        assert!(
+            app.toasts
+                .latest()
+                .unwrap()
+                .text
+                .starts_with("Watch rule sink failed: exit 3")
+        );
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_missing_scope_offers_to_sign_in_again_and_retry() {
+        let mut app = App::headless(AppConfig::default());
+        let auth = AuthProvider {
+            method: AuthMethod::OAuth2Pkce,
+            credentials: CredentialSet::default(),
+        };
+        app.api_client = Some(Arc::new(XApiClient::new(auth, 0)));
+        while app.events.try_next().is_some() {}
+        app.liked_tweets.insert("1".into());
+
+        app.handle_app_event(AppEvent::ScopeMissing {
+            scope: "like.write",
+            request: Box::new(AppEvent::LikeTweet {
+                tweet_id: "1".into(),
+                like: true,
+            }),
+            response: Box::new(AppEvent::LikeFinished {
+                tweet_id: "1".into(),
+                like: true,
+                result: Err(Arc::new("forbidden".into())),
+            }),
+        });
+        assert!(!app.liked_tweets.contains("1"));
+        assert!(app.error_detail.is_none());
+        assert_eq!(app.mode, AppMode::Confirm);
+        assert_eq!(
+            app.confirm.as_ref().map(|c| c.title.as_str()),
+            Some("Grant like.write")
+        );
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        let Some(Event::App(upgrade)) = app.events.try_next() else {
+            panic!("expected UpgradeScope");
+        };
+        let AppEvent::UpgradeScope { scope, retry } = *upgrade else {
+            panic!("expected UpgradeScope, got {upgrade:?}");
+        };
+        assert_eq!(scope, "like.write");
+
+        // Once signed in again, the retried like is applied optimistically.
+        app.handle_app_event(*retry);
+        assert!(app.liked_tweets.contains("1"));
+    }
+
+    #[test]
+    fn five_opens_lists_and_fetches_them_once() {
+        let mut app = App::headless(AppConfig::default());
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('5')));
+        let Some(Event::App(switch)) = app.events.try_next() else {
+            panic!("expected SwitchView");
+        };
+        app.handle_app_event(*switch);
+        assert_eq!(app.current_view(), Some(&ViewKind::Lists));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchLists)
+        ));
+
+        let list = |id: &str, name: &str| -> List {
+            serde_json::from_value(json!({ "id": id, "name": name })).unwrap()
+        };
+        app.handle_app_event(AppEvent::ListsLoaded(Ok((
+            vec![list("1", "Mine")],
+            vec![list("2", "Theirs")],
+        ))));
+        let names: Vec<&str> = app.lists().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, ["Mine", "Theirs"]);
+
+        app.handle_app_event(AppEvent::SwitchView(ViewKind::Lists));
+        assert!(app.events.try_next().is_none());
+    }
+
+    #[test]
+    fn six_lists_conversations_and_enter_opens_one_as_a_chat() {
+        let mut app = App::headless(AppConfig::default());
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('6')));
+        let Some(Event::App(switch)) = app.events.try_next() else {
+            panic!("expected SwitchView");
+        };
+        app.handle_app_event(*switch);
+        assert_eq!(app.current_view(), Some(&ViewKind::Messages));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchDmEvents)
+        ));
+
+        // Newest first: two messages with Alice, then one in a group.
+        let inbox: ListResponse<DmEvent> = serde_json::from_value(json!({
+            "data": [
+                { "id": "5", "event_type": "MessageCreate", "text": "see you",
+                  "sender_id": "1", "dm_conversation_id": "1-7" },
+                { "id": "4", "event_type": "MessageCreate", "text": "hi all",
+                  "sender_id": "8", "dm_conversation_id": "1400" },
+                { "id": "3", "event_type": "MessageCreate", "text": "hello",
+                  "sender_id": "7", "dm_conversation_id": "1-7" },
+            ],
+        }))
+        .unwrap();
+        app.handle_app_event(AppEvent::DmEventsLoaded(Ok(("1".into(), inbox))));
+        let latest: Vec<&str> = app.dm_inbox().iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(latest, ["5", "4"]);
+        assert_eq!(app.dm_participant("1-7"), Some("7"));
+        assert_eq!(app.dm_participant("1400"), None);
+
+        // Group conversations stay on x.com.
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.current_view(), Some(&ViewKind::Messages));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('k')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        let conversation = ViewKind::DmConversation("7".into());
+        assert_eq!(app.current_view(), Some(&conversation));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::FetchDmConversation { participant_id } if participant_id == "7"
+            )
+        ));
+
+        let events: ListResponse<DmEvent> = serde_json::from_value(json!({
+            "data": [
+                { "id": "5", "event_type": "MessageCreate", "text": "see you", "sender_id": "1" },
+                { "id": "3", "event_type": "MessageCreate", "text": "hello", "sender_id": "7" },
+            ],
+        }))
+        .unwrap();
+        app.handle_app_event(AppEvent::DmConversationLoaded {
+            participant_id: "7".into(),
+            result: Ok(events),
+        });
+        let ids: Vec<&str> = app.dm_conversation.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["3", "5"]);
+        assert_eq!(app.selected_index(), 1);
+    }
+
+    #[test]
+    fn shift_l_adds_the_profile_to_an_owned_list_and_enter_shows_members() {
+        let mut app = App::headless(AppConfig::default());
+        let list = |id: &str, name: &str| -> List {
+            serde_json::from_value(json!({ "id": id, "name": name, "member_count": 3 })).unwrap()
+        };
+        app.owned_lists = vec![list("1", "Mine")];
+        app.followed_lists = vec![list("2", "Theirs")];
+        let bob: User =
+            serde_json::from_value(json!({ "id": "7", "username": "bob", "name": "Bob" })).unwrap();
+        app.viewed_user = Some(bob.clone());
+        app.push_view(ViewKind::UserProfile("bob".into()));
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT));
+        assert_eq!(app.current_view(), Some(&ViewKind::Lists));
+        assert!(app.list_pick_user.is_some());
+
+        // Followed Lists can't be added to; the pick stays open.
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.current_view(), Some(&ViewKind::Lists));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('k')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(
+            app.current_view(),
+            Some(&ViewKind::UserProfile("bob".into()))
+        );
+        assert!(app.list_pick_user.is_none());
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::SetListMember { list, user, member: true }
+                if list.id == "1" && user.id == "7")
+        ));
+        app.handle_app_event(AppEvent::ListMemberFinished {
+            list: list("1", "Mine"),
+            user: bob,
+            member: true,
+            result: Ok(()),
+        });
+        assert_eq!(app.owned_lists[0].member_count, Some(4));
+
+        // Without a pick, Enter opens the List's members.
+        app.push_view(ViewKind::Lists);
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.current_view(), Some(&ViewKind::ListMembers("1".into())));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchListMembers { list_id } if list_id == "1")
+        ));
+    }
+
+    #[test]
+    fn list_commands_create_through_the_form_and_delete_after_confirming() {
+        let mut app = App::headless(AppConfig::default());
+        app.push_view(ViewKind::Lists);
+        while app.events.try_next().is_some() {}
+        let type_keys = |app: &mut App, text: &str| {
+            for c in text.chars() {
+                app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+            }
+        };
+
+        type_keys(&mut app, ":list create Rust");
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, AppMode::ListForm);
+        app.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        type_keys(&mut app, "Crabs");
+        app.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char(' ')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, AppMode::Normal);
+        let fields = ListFields {
+            name: "Rust".into(),
+            description: Some("Crabs".into()),
+            private: Some(true),
+        };
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::SaveList { list_id: None, fields: f }
+                if *f == fields)
+        ));
+        app.handle_app_event(AppEvent::ListSaved {
+            list_id: None,
+            fields,
+            result: Ok("9".into()),
+        });
+        assert_eq!(app.owned_lists[0].id, "9");
+        assert_eq!(app.owned_lists[0].private, Some(true));
+
+        // Deleting the selected List asks first.
+        type_keys(&mut app, ":list delete");
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, AppMode::Confirm);
+        let list = app.owned_lists[0].clone();
+        app.handle_app_event(AppEvent::ListDeleted {
+            list,
+            result: Ok(()),
+        });
+        assert!(app.owned_lists.is_empty());
+    }
+
+    #[test]
+    fn t_reposts_and_undoes_the_repost() {
+        let mut app = App::headless(AppConfig::default());
+        app.handle_app_event(AppEvent::HomeTimelineLoaded(Ok(serde_json::from_value(
+            json!({
+                "data": [{
+                    "id": "1",
+                    "text": "hello",
+                    "public_metrics": { "like_count": 0, "retweet_count": 2, "reply_count": 0, "quote_count": 0 },
+                }],
+            }),
+        )
+        .unwrap())));
+        while app.events.try_next().is_some() {}
+        let reposts = |app: &App| {
+            app.home_timeline.tweets[0]
+                .public_metrics
+                .as_ref()
+                .unwrap()
+                .retweet_count
+        };
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('t')));
+        assert_eq!(reposts(&app), 3);
+        assert!(app.retweeted_tweets.contains("1"));
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('t')));
+        assert_eq!(reposts(&app), 2);
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::RetweetTweet { tweet_id, retweet: false } if tweet_id == "1")
+        ));
+        app.handle_app_event(AppEvent::RetweetFinished {
+            tweet_id: "1".into(),
+            retweet: false,
+            result: Err(Arc::new("429 Too Many Requests".into())),
+        });
+        assert_eq!(reposts(&app), 3);
+        assert!(app.retweeted_tweets.contains("1"));
+    }
+
+    #[tokio::test]
+    async fn capital_r_and_f_list_who_reposted_or_liked_the_selected_tweet() {
+        for (key, kind) in [
+            ('R', TweetUsersKind::RetweetedBy),
+            ('F', TweetUsersKind::LikingUsers),
+        ] {
+            let mut app = App::headless(AppConfig::default());
+            app.thread_tweets = vec![tweet("1")];
+            app.push_view(ViewKind::Thread("1".into()));
+            app.handle_key_event(KeyEvent::from(KeyCode::Char(key)));
+            let view = ViewKind::TweetUsers {
+                tweet_id: "1".into(),
+                kind,
+            };
+            assert_eq!(app.current_view(), Some(&view));
+            assert!(matches!(
+                app.events.try_next(),
+                Some(Event::App(e)) if matches!(
+                    &*e,
+                    AppEvent::FetchTweetUsers { tweet_id, kind: k } if tweet_id == "1" && *k == kind
+                )
+            ));
+
+            let alice: User =
+                serde_json::from_value(json!({ "id": "7", "username": "alice", "name": "Alice" }))
+                    .unwrap();
+            // A page for the other view is dropped.
+            let other = match kind {
+                TweetUsersKind::RetweetedBy => TweetUsersKind::LikingUsers,
+                TweetUsersKind::LikingUsers => TweetUsersKind::RetweetedBy,
+            };
+            app.handle_app_event(AppEvent::TweetUsersLoaded {
+                tweet_id: "1".into(),
+                kind: other,
+                result: Ok(vec![alice.clone()]),
+            });
+            assert!(app.tweet_users.is_empty());
+            app.handle_app_event(AppEvent::TweetUsersLoaded {
+                tweet_id: "1".into(),
+                kind,
+                result: Ok(vec![alice]),
+            });
+            app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+            assert!(matches!(
+                app.events.try_next(),
+                Some(Event::App(e)) if matches!(&*e, AppEvent::FetchUser { username } if username == "alice")
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn similar_needs_an_embedding_provider_and_shows_results_as_a_local_search() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets =
+            vec![serde_json::from_value(json!({ "id": "1", "text": "ratatui\ncharts" })).unwrap()];
+        app.command_input = "similar".into();
+        app.execute_command();
+        assert!(
+            app.error_detail
+                .as_deref()
+                .unwrap()
+                .starts_with("No embedding provider")
+        );
+        assert!(app.events.try_next().is_none());
+
+        let query = "like \"ratatui charts\"".to_string();
+        app.search_query = query.clone();
+        app.handle_app_event(AppEvent::FindSimilar {
+            query: query.clone(),
+            tweet: Box::new(app.home_timeline.tweets[0].clone()),
+        });
+        assert!(app.search_local);
+        assert!(app.search_results.loading);
+
+        let tweet: Tweet =
+            serde_json::from_value(json!({ "id": "2", "text": "charts in the terminal" })).unwrap();
+        app.handle_app_event(AppEvent::SimilarLoaded {
+            query: query.clone(),
+            result: Ok((vec![tweet], Includes::default())),
+        });
+        assert!(!app.search_results.loading);
+        assert_eq!(app.search_results.tweets[0].id, "2");
+
+        app.handle_app_event(AppEvent::SimilarLoaded {
+            query,
+            result: Err(Arc::new("401 Unauthorized".into())),
+        });
+        assert!(app.error_detail.as_deref().unwrap().contains("401"));
+    }
+
+    #[tokio::test]
+    async fn local_search_fills_the_search_view_until_an_api_search_replaces_it() {
+        let mut app = App::headless(AppConfig::default());
+        app.command_input = "local ratatui".into();
+        app.execute_command();
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::SearchLocal { query } if query == "ratatui")
+        ));
+        app.handle_app_event(AppEvent::SearchLocal {
+            query: "ratatui".into(),
+        });
+        assert!(app.search_local);
+        assert!(app.search_results.loading);
+
+        let tweet: Tweet = serde_json::from_value(json!({
+            "id": "1",
+            "text": "ratatui charts",
+            "attachments": { "media_keys": ["3_1"] },
+        }))
+        .unwrap();
+        let includes: Includes = serde_json::from_value(json!({
+            "media": [{ "media_key": "3_1", "type": "photo" }],
+        }))
+        .unwrap();
+        app.handle_app_event(AppEvent::LocalSearchLoaded {
+            query: "ratatui".into(),
+            tweets: vec![tweet.clone()],
+            includes: includes.clone(),
+        });
+        assert!(!app.search_results.loading);
+        assert_eq!(app.search_results.tweets.len(), 1);
+        // Stored media shows instead of a "media unavailable" placeholder.
+        assert_eq!(
+            crate::ui::tweet::attachments(&tweet, &app.included),
+            [crate::ui::tweet::Attachment::Media("photo")]
+        );
+
+        // Results of a local search overtaken by an API search are dropped.
+        app.handle_app_event(AppEvent::FetchSearch {
+            query: "ratatui".into(),
+            pagination_token: None,
+        });
+        assert!(!app.search_local);
+        app.search_results.tweets.clear();
+        app.handle_app_event(AppEvent::LocalSearchLoaded {
+            query: "ratatui".into(),
+            tweets: vec![tweet],
+            includes,
+        });
+        assert!(app.search_results.tweets.is_empty());
+    }
+
+    #[test]
+    fn s_sorts_search_results_by_engagement_keeping_the_selection() {
+        let tweet = |id: &str, likes: u64, reposts: u64| {
+            json!({
+                "id": id,
+                "text": "hit",
+                "public_metrics": {
+                    "like_count": likes, "retweet_count": reposts, "reply_count": 0, "quote_count": 0,
+                },
+            })
+        };
+        let mut app = App::headless(AppConfig::default());
+        app.push_view(ViewKind::Search);
+        app.search_query = "rust".into();
+        app.handle_app_event(AppEvent::SearchLoaded {
+            query: "rust".into(),
+            result: Ok(serde_json::from_value(json!({
+                "data": [tweet("30", 1, 0), tweet("20", 5, 5), tweet("10", 2, 1)],
+            }))
+            .unwrap()),
+        });
+        let ids = |app: &App| -> Vec<String> {
+            app.search_results
+                .tweets
+                .iter()
+                .map(|t| t.id.clone())
+                .collect()
+        };
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('s')));
+        assert_eq!(ids(&app), ["20", "10", "30"]);
+        assert_eq!(app.selected_index(), 0);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('s')));
+        assert_eq!(ids(&app), ["30", "20", "10"]);
+        assert_eq!(app.selected_index(), 1);
+    }
+
+    #[test]
+    fn a_toggles_the_reply_authors_preview_in_threads() {
+        let mut app = App::headless(AppConfig::default());
+        app.push_view(ViewKind::Thread("1".into()));
+        app.handle_app_event(AppEvent::ThreadLoaded {
+            conversation_id: "1".into(),
+            result: Ok(serde_json::from_value(json!({
+                "data": [{ "id": "2", "text": "reply", "author_id": "8", "conversation_id": "1" }],
+            }))
+            .unwrap()),
+        });
+        while app.events.try_next().is_some() {}
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('a')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::FetchAuthorPreview { user_id } if user_id == "8")
+        ));
+        app.handle_app_event(AppEvent::AuthorPreviewLoaded {
+            user_id: "8".into(),
+            result: Ok(serde_json::from_value(json!({
+                "data": [{ "id": "9", "text": "earlier post", "author_id": "8" }],
+            }))
+            .unwrap()),
+        });
+        let preview = app.author_preview.as_ref().unwrap();
+        assert!(!preview.loading);
+        assert_eq!(preview.tweets[0].text, "earlier post");
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('a')));
+        assert!(app.author_preview.is_none());
+    }
+
+    #[test]
+    fn a_profile_refresh_landing_after_navigating_away_stays_in_the_background() {
+        let mut app = App::headless(AppConfig::default());
+        let bob = |name: &str| -> User {
+            serde_json::from_value(json!({ "id": "7", "name": name, "username": "bob" })).unwrap()
+        };
+        // Everything cached is stale, so opening it refreshes it.
+        app.profile_cache = crate::store::ProfileCache::new(0);
+        app.profile_cache.insert(bob("Bob"));
+        app.handle_app_event(AppEvent::FetchUser {
+            username: "bob".into(),
+        });
+        while app.events.try_next().is_some() {}
+        assert_eq!(
+            app.current_view(),
+            Some(&ViewKind::UserProfile("bob".into()))
+        );
+        assert!(app.revalidating_profiles.contains("7"));
+
+        app.handle_app_event(AppEvent::PopView);
+        app.handle_app_event(AppEvent::UserLoaded {
+            username: Some("bob".into()),
+            result: Ok(serde_json::from_value(json!({
+                "data": { "id": "7", "name": "Bob Renamed", "username": "bob" },
+            }))
+            .unwrap()),
+        });
+        assert_eq!(app.current_view(), Some(&ViewKind::Home));
+        assert!(!app.loading);
+        assert!(app.revalidating_profiles.is_empty());
+        assert_eq!(app.profile_cache.get("bob").unwrap().0.name, "Bob Renamed");
+    }
+
+    #[test]
+    fn unavailable_account_opens_its_profile_without_recording_history() {
+        let mut app = App::headless(AppConfig::default());
+        let suspended = |requested_from| AppEvent::AccountUnavailable {
+            view: ViewKind::UserProfile("spam".into()),
+            state: AccountState::Suspended,
+            detail: "User has been suspended: [spam].".into(),
+            requested_from,
+        };
+        // The lookup answers after the user moved on: stay where they are.
+        app.handle_app_event(suspended(Some(ViewKind::Mentions)));
+        assert_eq!(app.current_view(), Some(&ViewKind::Home));
+
+        app.handle_app_event(suspended(Some(ViewKind::Home)));
+        assert_eq!(
+            app.current_view(),
+            Some(&ViewKind::UserProfile("spam".into()))
+        );
+        assert!(app.history.entries().is_empty());
+
+        // A later successful lookup replaces the state screen.
+        app.handle_app_event(AppEvent::UserLoaded {
+            username: Some("spam".into()),
+            result: Ok(serde_json::from_value(json!({
+                "data": { "id": "9", "name": "Spam", "username": "spam" },
+            }))
+            .unwrap()),
+        });
+        assert!(app.unavailable_account.is_none());
+    }
+
+    #[test]
+    fn composer_posts_the_multiline_draft_on_ctrl_s() {
+        let mut app = App::headless(AppConfig::default());
+        let type_text = |app: &mut App, text: &str| {
+            for c in text.chars() {
+                app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+            }
+        };
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(app.mode, AppMode::Compose);
+        type_text(&mut app, "hello");
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        type_text(&mut app, "world ");
+
+        // Esc closes the composer but keeps the draft.
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, AppMode::Normal);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(app.compose_input, "hello\nworld ");
+
+        // Ctrl-R cycles who may reply.
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(app.compose_reply_settings, ReplySettings::MentionedUsers);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(app.mode, AppMode::Normal);
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::PostTweet(post) if post.text == "hello\nworld "
+                    && post.reply_to.is_none()
+                    && post.reply_settings == ReplySettings::MentionedUsers
+            )
+        ));
+    }
+
+    #[test]
+    fn a_post_on_its_way_leaves_a_draft_resumed_meanwhile_alone() {
+        let mut app = App::headless(AppConfig::default());
+        for (id, text) in [("b", "draft B"), ("a", "draft A")] {
+            app.compose_draft_id = Some(id.into());
+            app.compose_input = text.into();
+            app.save_compose_draft();
+        }
+        app.push_view(ViewKind::Drafts);
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.compose_input, "draft A");
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected a PostTweet");
+        };
+        let AppEvent::PostTweet(post) = *e else {
+            panic!("expected a PostTweet, got {e:?}");
+        };
+        app.posting = true;
+        assert!(app.compose_input.is_empty());
+
+        // Draft B is resumed while A is on its way, and can't be sent yet.
+        app.view_stack.last_mut().unwrap().selected_index = 1;
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.compose_input, "draft B");
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert_eq!(
+            app.toasts.latest().unwrap().text,
+            "Still sending the last post"
+        );
+        assert!(app.events.try_next().is_none());
+
+        app.handle_app_event(AppEvent::TweetPosted {
+            post,
+            result: Ok(serde_json::from_value(json!({
+                "data": { "id": "2", "text": "draft A" },
+            }))
+            .unwrap()),
+        });
+        assert!(!app.posting);
+        assert_eq!(app.compose_input, "draft B");
+        assert_eq!(app.compose_draft_id.as_deref(), Some("b"));
+        let saved: Vec<_> = app.drafts.entries().iter().map(|d| &d.text).collect();
+        assert_eq!(saved, ["draft B"]);
+
+        // Undoing reopens A, keeping B in the Drafts view.
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('Z')));
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected a DeletePosts event");
+        };
+        let AppEvent::DeletePosts { tweet_ids } = *e else {
+            panic!("expected a DeletePosts event, got {e:?}");
+        };
+        app.handle_app_event(AppEvent::PostsDeleted {
+            deleted: tweet_ids.clone(),
+            tweet_ids,
+            result: Ok(()),
+        });
+        assert_eq!(app.compose_input, "draft A");
+        let saved: Vec<_> = app.drafts.entries().iter().map(|d| &d.text).collect();
+        assert_eq!(saved, ["draft B"]);
+    }
+
+    #[test]
+    fn a_failed_post_goes_to_drafts_when_the_composer_moved_on() {
+        let mut app = App::headless(AppConfig::default());
+        app.compose_input = "first".into();
+        let post = app.take_pending_post();
+        app.compose_input = "second".into();
+        app.handle_app_event(AppEvent::TweetPosted {
+            post,
+            result: Err(Arc::new("503 upstream unavailable".into())),
+        });
+        assert_eq!(app.compose_input, "second");
+        assert_eq!(app.drafts.entries()[0].text, "first");
+        assert_eq!(
+            app.error_detail.as_deref(),
+            Some("Failed to post (draft kept in :drafts): 503 upstream unavailable")
+        );
+    }
+
+    #[test]
+    fn closed_drafts_are_saved_resumed_and_deleted() {
+        let mut app = App::headless(AppConfig::default());
+        app.mode = AppMode::Compose;
+        app.compose_input = "half a reply".into();
+        app.compose_reply_to = Some(ReplyTarget {
+            tweet_id: "1".into(),
+            username: Some("alice".into()),
+        });
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        let draft = &app.drafts.entries()[0];
+        assert_eq!(draft.text, "half a reply");
+        assert_eq!(draft.reply_to_username.as_deref(), Some("alice"));
+
+        // Closing it again replaces the saved draft instead of adding one.
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('!')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.drafts.entries().len(), 1);
+        assert_eq!(app.drafts.entries()[0].text, "half a reply!");
+
+        // Resuming it from the Drafts view brings back the reply target.
+        app.compose_input.clear();
+        app.compose_reply_to = None;
+        app.compose_draft_id = None;
+        app.push_view(ViewKind::Drafts);
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, AppMode::Compose);
+        assert_eq!(app.compose_input, "half a reply!");
+        assert_eq!(
+            app.compose_reply_to.as_ref().map(|r| r.tweet_id.as_str()),
+            Some("1")
+        );
+
+        // Posting it deletes the draft.
+        let post = app.take_pending_post();
+        app.handle_app_event(AppEvent::TweetPosted {
+            post,
+            result: Ok(serde_json::from_value(json!({
+                "data": { "id": "2", "text": "half a reply!" },
+            }))
+            .unwrap()),
+        });
+        assert!(app.drafts.entries().is_empty());
+
+        app.mode = AppMode::Compose;
+        app.compose_input = "another".into();
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        assert_eq!(app.mode, AppMode::Confirm);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        while let Some(Event::App(e)) = app.events.try_next() {
+            app.handle_app_event(*e);
+        }
+        assert!(app.drafts.entries().is_empty());
+    }
+
+    #[test]
+    fn stream_rules_are_added_refused_and_deleted() {
+        let mut app = App::headless(AppConfig::default());
+        app.command_input = "rules add ratatui lang:en --tag rust".into();
+        app.execute_command();
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected an AddStreamRules event");
+        };
+        let AppEvent::AddStreamRules { rules } = *e else {
+            panic!("expected an AddStreamRules event");
+        };
+        assert_eq!(rules[0].value, "ratatui lang:en");
+        assert_eq!(rules[0].tag.as_deref(), Some("rust"));
+
+        app.handle_app_event(AppEvent::StreamRulesAdded(Ok(serde_json::from_value(
+            json!({
+                "data": [{ "id": "1001", "value": "ratatui lang:en", "tag": "rust" }],
+                "errors": [{ "title": "DuplicateRule", "detail": "from:alice already exists" }],
+            }),
+        )
+        .unwrap())));
+        assert_eq!(app.stream_rules.len(), 1);
+        assert_eq!(
+            app.error_detail.as_deref(),
+            Some("Stream rule refused:\nDuplicateRule: from:alice already exists")
+        );
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+
+        app.push_view(ViewKind::StreamRules);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        assert_eq!(app.mode, AppMode::Confirm);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected a DeleteStreamRules event");
+        };
+        let AppEvent::DeleteStreamRules { ids } = *e else {
+            panic!("expected a DeleteStreamRules event");
+        };
+        assert_eq!(ids, ["1001"]);
+        app.handle_app_event(AppEvent::StreamRulesDeleted {
+            ids,
+            result: Ok(serde_json::from_value(json!({
+                "meta": { "summary": { "deleted": 1, "not_deleted": 0 } },
+            }))
+            .unwrap()),
+        });
+        assert!(app.stream_rules.is_empty());
+    }
+
+    #[test]
+    fn stream_rules_x_did_not_delete_stay_listed_and_are_reported() {
+        let mut app = App::headless(AppConfig::default());
+        app.stream_rules = serde_json::from_value(json!([
+            { "id": "1001", "value": "ratatui" },
+            { "id": "1002", "value": "from:alice" },
+        ]))
+        .unwrap();
+        app.push_view(ViewKind::StreamRules);
+
+        app.handle_app_event(AppEvent::StreamRulesDeleted {
+            ids: vec!["1001".into(), "1002".into()],
+            result: Ok(serde_json::from_value(json!({
+                "meta": { "summary": { "deleted": 1, "not_deleted": 1 } },
+                "errors": [{ "title": "Invalid Request", "detail": "Rule does not exist" }],
+            }))
+            .unwrap()),
+        });
+        assert_eq!(app.stream_rules.len(), 2);
+        assert!(app.toasts.iter().any(|t| t.text == "Deleted stream rule"));
+        assert_eq!(
+            app.error_detail.as_deref(),
+            Some("1 of 2 stream rules not deleted:\nInvalid Request: Rule does not exist")
+        );
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected the rules to be reloaded");
+        };
+        assert!(matches!(*e, AppEvent::FetchStreamRules));
+    }
+
+    #[test]
+    fn the_stream_view_shows_new_tweets_on_top_and_disconnects_when_closed() {
+        let mut app = App::headless(AppConfig::default());
+        app.command_input = "stream".into();
+        app.execute_command();
+        assert_eq!(app.current_view(), Some(&ViewKind::FilteredStream));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(*e, AppEvent::ConnectStream)
+        ));
+
+        // Stand in for the task dispatch_filtered_stream would start.
+        let cancel = CancellationToken::new();
+        app.stream_cancel = Some(cancel.clone());
+        app.handle_app_event(AppEvent::StreamStatusChanged {
+            generation: 0,
+            status: StreamStatus::Connected,
+        });
+        let streamed = |generation: u64, id: &str| AppEvent::StreamMessageReceived {
+            generation,
+            message: Box::new(
+                serde_json::from_value(json!({
+                    "data": { "id": id, "text": "ratatui" },
+                    "matching_rules": [{ "id": "9" }],
+                }))
+                .unwrap(),
+            ),
+        };
+        app.handle_app_event(streamed(0, "1"));
+        app.handle_app_event(streamed(0, "2"));
+        assert_eq!(app.selected_tweet().unwrap().id, "2");
+
+        // A selection below the top stays on its tweet as new ones arrive.
+        app.view_stack.last_mut().unwrap().selected_index = 1;
+        app.handle_app_event(streamed(0, "3"));
+        app.handle_app_event(streamed(0, "3"));
+        assert_eq!(app.filtered_stream.tweets.len(), 3);
+        assert_eq!(app.selected_tweet().unwrap().id, "1");
+
+        app.handle_app_event(AppEvent::StreamMessageReceived {
+            generation: 0,
+            message: Box::new(
+                serde_json::from_value(json!({
+                    "errors": [{ "title": "operational-disconnect" }],
+                }))
+                .unwrap(),
+            ),
+        });
+        assert_eq!(
+            app.toasts.latest().unwrap().text,
+            "Stream: operational-disconnect"
+        );
+
+        app.pop_view();
+        assert!(cancel.is_cancelled());
+        assert_eq!(app.stream_status, StreamStatus::Stopped);
+        app.handle_app_event(streamed(0, "4"));
+        assert_eq!(app.filtered_stream.tweets.len(), 3);
+
+        // Once reconnected, what the stopped task still had queued is dropped.
+        app.push_view(ViewKind::FilteredStream);
+        app.stream_cancel = Some(CancellationToken::new());
+        app.stream_generation = 1;
+        app.handle_app_event(AppEvent::StreamStatusChanged {
+            generation: 0,
+            status: StreamStatus::Reconnecting {
+                delay: Duration::from_secs(5),
+                reason: "stale".into(),
+            },
+        });
+        assert_eq!(app.stream_status, StreamStatus::Stopped);
+        app.handle_app_event(streamed(0, "5"));
+        app.handle_app_event(streamed(1, "6"));
+        assert_eq!(app.filtered_stream.tweets[0].id, "6");
+        assert_eq!(app.filtered_stream.tweets.len(), 4);
+    }
+
+    #[test]
+    fn switching_away_from_the_stream_view_disconnects_it() {
+        // Opened over another view, and as the only one.
+        for depth in [2, 1] {
+            let mut app = App::headless(AppConfig::default());
+            if depth == 1 {
+                app.view_stack.clear();
+            }
+            app.push_view(ViewKind::FilteredStream);
+            assert_eq!(app.view_stack.len(), depth);
+            let cancel = CancellationToken::new();
+            app.stream_cancel = Some(cancel.clone());
+            app.handle_app_event(AppEvent::StreamStatusChanged {
+                generation: 0,
+                status: StreamStatus::Connected,
+            });
+
+            app.handle_app_event(AppEvent::SwitchView(ViewKind::Bookmarks));
+            assert_eq!(app.current_view(), Some(&ViewKind::Bookmarks));
+            assert!(cancel.is_cancelled(), "depth {depth}");
+            assert_eq!(app.stream_status, StreamStatus::Stopped);
+        }
+    }
+
+    #[tokio::test]
+    async fn streamed_tweets_go_to_watch_sinks() {
+        let mut config = AppConfig::default();
+        config.watch.rules.push(crate::config::WatchRule {
+            keywords: vec!["ratatui".into()],
+            command: Some("cat > /dev/null".into()),
+            ..Default::default()
+        });
+        let mut app = App::headless(config);
+        app.push_view(ViewKind::FilteredStream);
+        app.stream_cancel = Some(CancellationToken::new());
+        app.handle_app_event(AppEvent::StreamMessageReceived {
+            generation: 0,
+            message: Box::new(
+                serde_json::from_value(json!({ "data": { "id": "1", "text": "ratatui 1.0" } }))
+                    .unwrap(),
+            ),
+        });
+        assert!(app.delivered_watched.contains(&WatchDelivery {
+            tweet_id: "1".into(),
+            sink: "command cat > /dev/null".into(),
+        }));
+    }
+
+    #[test]
+    fn thread_mode_posts_a_numbered_thread_and_retries_the_rest() {
+        let mut app = App::headless(AppConfig::default());
+        app.mode = AppMode::Compose;
+        app.compose_input = "word ".repeat(100);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL));
+        assert!(app.compose_thread);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected a PostThread");
+        };
+        let AppEvent::PostThread {
+            posts,
+            reply_to,
+            post,
+            ..
+        } = *e
+        else {
+            panic!("expected a PostThread, got {e:?}");
+        };
+        assert_eq!(posts.len(), 2);
+        assert!(posts[1].ends_with(" 2/2"));
+        assert_eq!(reply_to, None);
+
+        // The second post failed: the draft stays and `r` posts the rest
+        // under the first.
+        let rest = AppEvent::PostThread {
+            posts: posts[1..].to_vec(),
+            reply_to: Some("11".into()),
+            posted: vec!["11".into()],
+            post: post.clone(),
+        };
+        app.handle_app_event(AppEvent::RequestFailed {
+            request: Box::new(rest),
+            response: Box::new(AppEvent::ThreadPosted {
+                posted: vec!["11".into()],
+                post: post.clone(),
+                result: Err(Arc::new("503 upstream unavailable".into())),
+            }),
+        });
+        assert!(
+            app.error_detail
+                .as_deref()
+                .unwrap()
+                .contains("after 1 posts")
+        );
+        assert!(!app.compose_input.is_empty());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('r')));
+        while let Some(event) = app.events.try_next() {
+            if let Event::App(e) = event
+                && let AppEvent::PostThread {
+                    posts, reply_to, ..
+                } = *e
+            {
+                assert_eq!(posts.len(), 1);
+                assert_eq!(reply_to.as_deref(), Some("11"));
+            }
+        }
+
+        app.handle_app_event(AppEvent::ThreadPosted {
+            posted: vec!["11".into(), "12".into()],
+            post,
+            result: Ok(()),
+        });
+        assert!(app.compose_input.is_empty());
+        assert!(!app.compose_thread);
+
+        // Undoing deletes the whole thread, the newest post first.
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('Z')));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::DeletePosts { tweet_ids } if tweet_ids == &["11", "12"])
+        ));
+    }
+
+    #[test]
+    fn posting_a_stopped_thread_again_sends_only_the_rest() {
+        let mut app = App::headless(AppConfig::default());
+        app.compose_input = "word ".repeat(100);
+        app.compose_thread = true;
+        let post = app.take_pending_post();
+        app.handle_app_event(AppEvent::ThreadPosted {
+            posted: vec!["11".into()],
+            post,
+            result: Err(Arc::new("503 upstream unavailable".into())),
+        });
+        app.error_detail = None;
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected a PostThread");
+        };
+        let AppEvent::PostThread {
+            posts,
+            reply_to,
+            posted,
+            post,
+        } = *e
+        else {
+            panic!("expected a PostThread, got {e:?}");
+        };
+        assert_eq!(posts.len(), 1);
+        assert!(posts[0].ends_with(" 2/2"));
+        assert_eq!(reply_to.as_deref(), Some("11"));
+        assert_eq!(posted, ["11"]);
+
+        // Edited, it's a new thread.
+        app.handle_app_event(AppEvent::ThreadPosted {
+            posted: vec!["11".into()],
+            post,
+            result: Err(Arc::new("503 upstream unavailable".into())),
+        });
+        app.error_detail = None;
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('!')));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(&*e, AppEvent::PostThread { posts, posted, .. } if posts.len() == 2 && posted.is_empty())
+        ));
+    }
+
+    #[test]
+    fn z_deletes_a_fresh_post_and_reopens_it_in_the_composer() {
+        let mut app = App::headless(AppConfig::default());
+        app.mode = AppMode::Compose;
+        app.compose_input = "typo'd".into();
+        app.compose_reply_to = Some(ReplyTarget {
+            tweet_id: "1".into(),
+            username: None,
+        });
+        let posted = |app: &mut App| AppEvent::TweetPosted {
+            post: app.take_pending_post(),
+            result: Ok(serde_json::from_value(json!({
+                "data": { "id": "2", "text": "typo'd" },
+            }))
+            .unwrap()),
+        };
+        let event = posted(&mut app);
+        app.handle_app_event(event);
+        app.mode = AppMode::Normal;
+        assert_eq!(app.toasts.latest().unwrap().text, "Replied · Z to undo");
+        assert!(app.compose_input.is_empty());
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('Z')));
+        let Some(Event::App(e)) = app.events.try_next() else {
+            panic!("expected a DeletePosts event");
+        };
+        let AppEvent::DeletePosts { tweet_ids } = *e else {
+            panic!("expected a DeletePosts event, got {e:?}");
+        };
+        assert_eq!(tweet_ids, ["2"]);
+        app.handle_app_event(AppEvent::PostsDeleted {
+            deleted: tweet_ids.clone(),
+            tweet_ids,
+            result: Ok(()),
+        });
+        assert_eq!(app.mode, AppMode::Compose);
+        assert_eq!(app.compose_input, "typo'd");
+        assert_eq!(
+            app.compose_reply_to.as_ref().map(|r| r.tweet_id.as_str()),
+            Some("1")
+        );
+
+        // Once the window has passed (or with it turned off), Z does nothing.
+        app.config.undo_post_secs = 0;
+        let event = posted(&mut app);
+        app.handle_app_event(event);
+        app.mode = AppMode::Normal;
+        assert_eq!(app.toasts.latest().unwrap().text, "Replied");
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('Z')));
+        assert_eq!(app.toasts.latest().unwrap().text, "Nothing to undo");
+        assert!(app.events.try_next().is_none());
+    }
+
+    #[test]
+    fn z_keeps_the_rest_of_a_thread_it_failed_to_delete_for_another_try() {
+        let mut app = App::headless(AppConfig::default());
+        app.mode = AppMode::Compose;
+        app.compose_thread = true;
+        app.compose_input = "one\n\ntwo\n\nthree".into();
+        let post = app.take_pending_post();
+        app.handle_app_event(AppEvent::ThreadPosted {
+            posted: vec!["10".into(), "11".into(), "12".into()],
+            post,
+            result: Ok(()),
+        });
+        app.mode = AppMode::Normal;
+
+        let undo = |app: &mut App| {
+            app.handle_key_event(KeyEvent::from(KeyCode::Char('Z')));
+            let Some(Event::App(e)) = app.events.try_next() else {
+                panic!("expected a DeletePosts event");
+            };
+            let AppEvent::DeletePosts { tweet_ids } = *e else {
+                panic!("expected a DeletePosts event, got {e:?}");
+            };
+            tweet_ids
+        };
+        let tweet_ids = undo(&mut app);
+        assert_eq!(tweet_ids, ["10", "11", "12"]);
+        app.handle_app_event(AppEvent::PostsDeleted {
+            tweet_ids,
+            deleted: vec!["12".into()],
+            result: Err(Arc::new("503 Service Unavailable".into())),
+        });
+        assert_eq!(app.mode, AppMode::Normal);
+        assert_eq!(
+            app.error_detail.as_deref(),
+            Some("Deleted 1 of 3 posts, then failed: 503 Service Unavailable\nZ tries again.")
+        );
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+
+        let tweet_ids = undo(&mut app);
+        assert_eq!(tweet_ids, ["10", "11"]);
+        app.handle_app_event(AppEvent::PostsDeleted {
+            deleted: tweet_ids.clone(),
+            tweet_ids,
+            result: Ok(()),
+        });
+        assert_eq!(app.mode, AppMode::Compose);
+        assert_eq!(app.compose_input, "one\n\ntwo\n\nthree");
+        assert!(app.compose_thread);
+    }
+
+    #[test]
+    fn an_empty_composer_opens_as_a_new_post() {
+        let mut app = App::headless(AppConfig::default());
+        app.compose_reply_to = Some(ReplyTarget {
+            tweet_id: "1".into(),
+            username: None,
+        });
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(app.mode, AppMode::Compose);
+        assert!(app.compose_reply_to.is_none());
+        assert_eq!(app.compose_reply_settings, ReplySettings::Everyone);
+
+        // Text left in it is still the same reply.
+        app.compose_reply_to = Some(ReplyTarget {
+            tweet_id: "1".into(),
+            username: None,
+        });
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('x')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert!(app.compose_reply_to.is_some());
+    }
+
+    #[test]
+    fn a_picked_reply_draft_is_edited_and_posted_as_a_reply() {
+        let mut app = App::headless(AppConfig::default());
+        app.home_timeline.tweets =
+            vec![serde_json::from_value(json!({ "id": "1", "text": "shipped it" })).unwrap()];
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL));
+        assert!(
+            app.error_detail
+                .as_deref()
+                .unwrap()
+                .starts_with("No chat provider")
+        );
+        assert!(app.reply_drafts.is_none());
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, AppMode::Compose);
+        for c in "my take".chars() {
+            app.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+
+        app.reply_drafts = Some(ReplyDrafts {
+            target: ReplyTarget {
+                tweet_id: "1".into(),
+                username: None,
+            },
+            drafts: Vec::new(),
+            selected: 0,
+            loading: true,
+        });
+        app.handle_app_event(AppEvent::ReplyDraftsGenerated {
+            tweet_id: "1".into(),
+            result: Ok(vec!["Congrats".into(), "Nice work".into()]),
+        });
+        app.handle_key_event(KeyEvent::from(KeyCode::Down));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(app.reply_drafts.is_none());
+        assert_eq!(app.compose_input, "Nice work");
+        assert_eq!(app.mode, AppMode::Compose);
+        // Picking a draft never posts it, and what was typed is kept.
+        assert!(app.events.try_next().is_none());
+        let saved: Vec<_> = app.drafts.entries().iter().map(|d| &d.text).collect();
+        assert_eq!(saved, ["my take"]);
+        assert!(app.compose_draft_id.is_none());
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('!')));
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert!(matches!(
+            app.events.try_next(),
+            Some(Event::App(e)) if matches!(
+                &*e,
+                AppEvent::PostTweet(post) if post.text == "Nice work!"
+                    && post.reply_to.as_ref().is_some_and(|r| r.tweet_id == "1")
+            )
+        ));
+    }
+
+    #[test]
+    fn error_popup_retries_the_failed_page() {
+        let mut app = App::headless(AppConfig::default());
+        let request = AppEvent::FetchSearch {
+            query: "rust".into(),
+            pagination_token: Some("page2".into()),
+        };
+        app.handle_app_event(AppEvent::RequestFailed {
+            request: Box::new(request.clone()),
+            response: Box::new(AppEvent::SearchLoaded {
+                query: "rust".into(),
+                result: Err(Arc::new("503 upstream unavailable".into())),
+            }),
+        });
+        assert!(app.error_detail.is_some());
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('r')));
+        assert!(app.error_detail.is_none());
+        assert!(!app.toasts.has_errors());
+        let Some(Event::App(event)) = app.events.try_next() else {
+            panic!("expected the request to be re-sent");
+        };
+        assert_eq!(event.request_key(), request.request_key());
+        assert!(app.retry_request.is_none());
+    }
 
     #[test]
     fn strip_think_tags_removes_reasoning() {
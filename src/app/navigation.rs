@@ -1,13 +1,27 @@
-use super::{App, ClusterSource, TimelineState, tweet_url};
-use crate::api::types::Tweet;
-use crate::event::{AppEvent, ViewKind};
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    App, AppMode, AuthorPreview, ClusterSource, DuplicateGroup, PostingHeatmap, TimelineState,
+    compare_ids, tweet_url,
+};
+use crate::api::types::{Includes, List, Tweet, User};
+use crate::domains::DomainReport;
+use crate::event::{AppEvent, BulkAction, TweetUsersKind, ViewKind};
 use crate::openrouter;
 use crate::openrouter::types::Model;
+use crate::stats::TextStats;
+use crate::store::{HistoryTarget, TimelineKey};
+use crate::tags::TagReport;
+use crate::ui::status_bar::view_name;
 
 impl App {
     // -- Selection helpers --------------------------------------------------
 
     pub(super) fn move_selection_down(&mut self) {
+        if self.current_view() == Some(&ViewKind::Help) {
+            self.scroll_help(1);
+            return;
+        }
         let count = self.current_item_count();
         if let Some(vs) = self.view_stack.last_mut()
             && vs.selected_index + 1 < count
@@ -17,17 +31,37 @@ impl App {
     }
 
     pub(super) fn move_selection_up(&mut self) {
+        if self.current_view() == Some(&ViewKind::Help) {
+            self.scroll_help(-1);
+            return;
+        }
         if let Some(vs) = self.view_stack.last_mut() {
             vs.selected_index = vs.selected_index.saturating_sub(1);
         }
     }
 
+    /// Scroll the help overlay by `lines`, no further than the last frame
+    /// showed it could go.
+    pub(super) fn scroll_help(&mut self, lines: isize) {
+        let max = self.help_scroll_max.get();
+        if let Some(vs) = self.view_stack.last_mut() {
+            vs.scroll_offset = vs.scroll_offset.saturating_add_signed(lines).min(max);
+        }
+    }
+
+    /// PgDn/PgUp in the help overlay: scroll by what it shows at once.
+    pub(super) fn page_help(&mut self, down: bool) {
+        let page = self.help_page.get().max(1) as isize;
+        self.scroll_help(if down { page } else { -page });
+    }
+
     fn current_item_count(&self) -> usize {
         match self.current_view() {
             Some(ViewKind::Home) => self.home_timeline.tweets.len(),
             Some(ViewKind::Mentions) => self.mentions.tweets.len(),
             Some(ViewKind::Bookmarks) => self.bookmarks.tweets.len(),
             Some(ViewKind::Search) => self.search_results.tweets.len(),
+            Some(ViewKind::FilteredStream) => self.filtered_stream.tweets.len(),
             Some(ViewKind::UserTimeline(_)) => self.viewed_user_timeline.tweets.len(),
             Some(ViewKind::Thread(_)) => self.thread_tweets.len(),
             Some(ViewKind::UserProfile(_)) => 0,
@@ -46,6 +80,22 @@ impl App {
                 }
             }
             Some(ViewKind::HuggingFaceModels) => self.filtered_hf_models().len(),
+            Some(ViewKind::History) => self.history.entries().len(),
+            Some(ViewKind::Drafts) => self.drafts.entries().len(),
+            Some(ViewKind::Domains) => self
+                .domain_report
+                .as_ref()
+                .map_or(0, |report| report.domains.len()),
+            Some(ViewKind::Tags) => self
+                .tag_report
+                .as_ref()
+                .map_or(0, |report| report.tags.len()),
+            Some(ViewKind::Lists) => self.owned_lists.len() + self.followed_lists.len(),
+            Some(ViewKind::ListMembers(_)) => self.list_members.len(),
+            Some(ViewKind::TweetUsers { .. }) => self.tweet_users.len(),
+            Some(ViewKind::StreamRules) => self.stream_rules.len(),
+            Some(ViewKind::Messages) => self.dm_inbox().len(),
+            Some(ViewKind::DmConversation(_)) => self.dm_conversation.len(),
             Some(ViewKind::Help) => 0,
             None => 0,
         }
@@ -114,6 +164,23 @@ impl App {
             .collect()
     }
 
+    /// The Lists view's rows: owned lists, then followed ones.
+    pub fn lists(&self) -> impl Iterator<Item = &List> {
+        self.owned_lists.iter().chain(&self.followed_lists)
+    }
+
+    /// The List whose members are shown in the current view.
+    pub fn members_list(&self) -> Option<&List> {
+        let Some(ViewKind::ListMembers(list_id)) = self.current_view() else {
+            return None;
+        };
+        self.lists().find(|l| &l.id == list_id)
+    }
+
+    fn owns_list(&self, list_id: &str) -> bool {
+        self.owned_lists.iter().any(|l| l.id == list_id)
+    }
+
     // -- HuggingFace model helpers ------------------------------------------
 
     /// Returns HF models filtered by the active org filter, sorted by org then ID.
@@ -189,6 +256,18 @@ impl App {
                     });
                 }
             }
+            Some(ViewKind::FilteredStream) => {
+                if let Some(tweet) = self.filtered_stream.tweets.get(idx) {
+                    let conv_id = tweet
+                        .conversation_id
+                        .clone()
+                        .unwrap_or_else(|| tweet.id.clone());
+                    self.events.send(AppEvent::FetchThread {
+                        conversation_id: conv_id,
+                        pagination_token: None,
+                    });
+                }
+            }
             Some(ViewKind::UserTimeline(_)) => {
                 if let Some(tweet) = self.viewed_user_timeline.tweets.get(idx) {
                     let conv_id = tweet
@@ -220,7 +299,7 @@ impl App {
                 let filtered = self.filtered_hf_models();
                 if let Some(model) = filtered.get(idx) {
                     if !model.is_chat_capable() {
-                        self.status_message = Some(format!(
+                        self.notify(format!(
                             "Model {} is not chat-capable (pipeline: {})",
                             model.id,
                             model.pipeline_tag.as_deref().unwrap_or("unknown"),
@@ -237,7 +316,7 @@ impl App {
                     }
                     let model_id = model.id.clone();
                     self.config.mlx_chat_model = Some(model_id.clone());
-                    self.status_message = Some(format!("MLX chat model set to: {model_id}"));
+                    self.notify(format!("MLX chat model set to: {model_id}"));
                     self.pop_view();
                 }
             }
@@ -267,27 +346,954 @@ impl App {
                     }
                 }
             }
+            Some(ViewKind::History) => {
+                if let Some(entry) = self.history.entries().get(idx) {
+                    let target = entry.target.clone();
+                    self.reopen(target);
+                }
+            }
+            Some(ViewKind::Drafts) => self.resume_selected_draft(),
+            Some(ViewKind::Lists) => {
+                let list = self.lists().nth(idx).cloned();
+                if let Some(list) = list {
+                    self.open_list(list);
+                }
+            }
+            Some(ViewKind::ListMembers(_)) => {
+                if let Some(user) = self.list_members.get(idx) {
+                    let username = user.username.clone();
+                    self.events.send(AppEvent::FetchUser { username });
+                }
+            }
+            Some(ViewKind::TweetUsers { .. }) => {
+                if let Some(user) = self.tweet_users.get(idx) {
+                    let username = user.username.clone();
+                    self.events.send(AppEvent::FetchUser { username });
+                }
+            }
+            Some(ViewKind::Messages) => self.open_selected_dm(),
+            Some(ViewKind::Tags) => {
+                let tag = self.tag_report.as_ref().and_then(|r| r.tags.get(idx));
+                if let Some(query) = tag.map(|t| t.query.clone()) {
+                    self.search_query = query.clone();
+                    self.events.send(AppEvent::FetchSearch {
+                        query,
+                        pagination_token: None,
+                    });
+                    self.events.send(AppEvent::SwitchView(ViewKind::Search));
+                }
+            }
             _ => {}
         }
     }
 
+    // -- Domains and tags ---------------------------------------------------
+
+    /// Rank the domains linked from the current view's tweets, or with
+    /// `all` from every tweet in the local store, counted in the background.
+    pub(super) fn show_domains(&mut self, all: bool) {
+        if all {
+            self.dispatch_store_domains();
+            return;
+        }
+        let Some(tweets) = self.current_tweets() else {
+            self.notify("No tweets here. :domains all reads the local store");
+            return;
+        };
+        let report = DomainReport::new(view_name(self), tweets);
+        self.show_domain_report(report);
+    }
+
+    pub(super) fn show_domain_report(&mut self, report: DomainReport) {
+        if report.scanned == 0 {
+            self.notify(format!("No tweets in {}", report.source));
+            return;
+        }
+        self.domain_report = Some(report);
+        if self.current_view() == Some(&ViewKind::Domains) {
+            if let Some(vs) = self.view_stack.last_mut() {
+                vs.selected_index = 0;
+            }
+        } else {
+            self.push_view(ViewKind::Domains);
+        }
+    }
+
+    /// Rank the hashtags and topics in the current view's tweets.
+    pub(super) fn show_tags(&mut self) {
+        let Some(tweets) = self.current_tweets() else {
+            self.notify("No tweets here to count tags in");
+            return;
+        };
+        let report = TagReport::new(view_name(self), tweets);
+        if report.scanned == 0 {
+            self.notify(format!("No tweets in {}", report.source));
+            return;
+        }
+        self.tag_report = Some(report);
+        if self.current_view() == Some(&ViewKind::Tags) {
+            if let Some(vs) = self.view_stack.last_mut() {
+                vs.selected_index = 0;
+            }
+        } else {
+            self.push_view(ViewKind::Tags);
+        }
+    }
+
+    // -- History ------------------------------------------------------------
+
+    /// Add a thread or profile view being opened to the history, labelled
+    /// with what is already known about it.
+    pub(super) fn record_history(&mut self, kind: &ViewKind) {
+        // Nothing to come back to on an account X won't show.
+        if self
+            .unavailable_account
+            .as_ref()
+            .is_some_and(|account| &account.view == kind)
+        {
+            return;
+        }
+        let (target, label) = match kind {
+            ViewKind::Thread(conversation_id) => {
+                let first = self
+                    .thread_root
+                    .iter()
+                    .chain(&self.thread_tweets)
+                    .find(|t| t.conversation_id.as_deref().unwrap_or(&t.id) == conversation_id);
+                let label = match first {
+                    Some(tweet) => {
+                        let text = tweet.text.split_whitespace().collect::<Vec<_>>().join(" ");
+                        match tweet
+                            .author_id
+                            .as_deref()
+                            .and_then(|id| self.lookup_user(id))
+                        {
+                            Some(author) => format!("@{}: {text}", author.username),
+                            None => text,
+                        }
+                    }
+                    None => format!("Thread {conversation_id}"),
+                };
+                let target = HistoryTarget::Thread {
+                    conversation_id: conversation_id.clone(),
+                };
+                (target, label)
+            }
+            ViewKind::UserProfile(username) => {
+                let label = match &self.viewed_user {
+                    Some(user) if user.username.eq_ignore_ascii_case(username) => {
+                        format!("@{} ({})", user.username, user.name)
+                    }
+                    _ => format!("@{username}"),
+                };
+                let target = HistoryTarget::Profile {
+                    username: username.clone(),
+                };
+                (target, label)
+            }
+            _ => return,
+        };
+        self.history.record(target, label);
+    }
+
+    /// Open a history entry again. Threads start from the locally stored
+    /// root tweet when there is one.
+    fn reopen(&mut self, target: HistoryTarget) {
+        match target {
+            HistoryTarget::Thread { conversation_id } => {
+                self.thread_root = crate::store::find_tweet(&conversation_id);
+                self.events.send(AppEvent::FetchThread {
+                    conversation_id,
+                    pagination_token: None,
+                });
+            }
+            HistoryTarget::Profile { username } => {
+                self.events.send(AppEvent::FetchUser { username });
+            }
+        }
+    }
+
     /// Returns a reference to the currently selected tweet, if any.
-    fn selected_tweet(&self) -> Option<&Tweet> {
-        let idx = self.selected_index();
+    pub(super) fn selected_tweet(&self) -> Option<&Tweet> {
+        self.current_tweets()?.get(self.selected_index())
+    }
+
+    /// The tweet list shown by the current view, if it is a tweet view.
+    fn current_tweets(&self) -> Option<&[Tweet]> {
         match self.current_view() {
-            Some(ViewKind::Home) => self.home_timeline.tweets.get(idx),
-            Some(ViewKind::Mentions) => self.mentions.tweets.get(idx),
-            Some(ViewKind::Bookmarks) => self.bookmarks.tweets.get(idx),
-            Some(ViewKind::Search) => self.search_results.tweets.get(idx),
-            Some(ViewKind::UserTimeline(_)) => self.viewed_user_timeline.tweets.get(idx),
-            Some(ViewKind::Thread(_)) => self.thread_tweets.get(idx),
+            Some(ViewKind::Home) => Some(&self.home_timeline.tweets),
+            Some(ViewKind::Mentions) => Some(&self.mentions.tweets),
+            Some(ViewKind::Bookmarks) => Some(&self.bookmarks.tweets),
+            Some(ViewKind::Search) => Some(&self.search_results.tweets),
+            Some(ViewKind::FilteredStream) => Some(&self.filtered_stream.tweets),
+            Some(ViewKind::UserTimeline(_)) => Some(&self.viewed_user_timeline.tweets),
+            Some(ViewKind::Thread(_)) => Some(&self.thread_tweets),
             _ => None,
         }
     }
 
+    // -- Bookmarks ------------------------------------------------------------
+
+    /// Bookmark the selected tweet, or in the Bookmarks view remove it.
+    pub(super) fn toggle_bookmark(&mut self) {
+        let Some(tweet) = self.selected_tweet().cloned() else {
+            return;
+        };
+        let bookmark = self.current_view() != Some(&ViewKind::Bookmarks);
+        self.events
+            .send(AppEvent::BookmarkTweet { tweet, bookmark });
+    }
+
+    /// Put a newly bookmarked tweet at the top of the loaded bookmarks. If
+    /// they haven't been loaded yet, the first fetch will include it.
+    pub(super) fn add_to_bookmarks(&mut self, tweet: Tweet) {
+        let bookmarks = &mut self.bookmarks.tweets;
+        if bookmarks.is_empty() || bookmarks.iter().any(|t| t.id == tweet.id) {
+            return;
+        }
+        bookmarks.insert(0, tweet);
+        if self.current_view() == Some(&ViewKind::Bookmarks)
+            && let Some(vs) = self.view_stack.last_mut()
+        {
+            vs.selected_index += 1;
+        }
+    }
+
+    /// Drop a tweet from the loaded bookmarks, keeping the cursor in range.
+    pub(super) fn remove_from_bookmarks(&mut self, tweet_id: &str) {
+        self.bookmarks.tweets.retain(|t| t.id != tweet_id);
+        let len = self.bookmarks.tweets.len();
+        if self.current_view() == Some(&ViewKind::Bookmarks)
+            && let Some(vs) = self.view_stack.last_mut()
+        {
+            vs.selected_index = vs.selected_index.min(len.saturating_sub(1));
+        }
+    }
+
+    // -- Likes and reposts --------------------------------------------------
+
+    /// Like the selected tweet, or unlike it if it was liked this session.
+    /// The count updates right away and is rolled back if the request fails.
+    pub(super) fn toggle_like(&mut self) {
+        let Some(tweet_id) = self.selected_tweet().map(|t| t.id.clone()) else {
+            return;
+        };
+        let like = !self.liked_tweets.contains(&tweet_id);
+        self.set_liked(&tweet_id, like);
+        self.events.send(AppEvent::LikeTweet { tweet_id, like });
+    }
+
+    /// Record `tweet_id` as liked or not and adjust its like count in every
+    /// loaded copy. Does nothing if it is already in that state.
+    pub(super) fn set_liked(&mut self, tweet_id: &str, liked: bool) {
+        let changed = if liked {
+            self.liked_tweets.insert(tweet_id.to_string())
+        } else {
+            self.liked_tweets.remove(tweet_id)
+        };
+        if !changed {
+            return;
+        }
+        for tweet in self.loaded_copies_mut(tweet_id) {
+            if let Some(metrics) = tweet.public_metrics.as_mut() {
+                metrics.like_count = if liked {
+                    metrics.like_count + 1
+                } else {
+                    metrics.like_count.saturating_sub(1)
+                };
+            }
+        }
+    }
+
+    /// Repost the selected tweet, or undo the repost if it was made this
+    /// session. Like [`Self::toggle_like`], the count updates right away.
+    pub(super) fn toggle_retweet(&mut self) {
+        let Some(tweet_id) = self.selected_tweet().map(|t| t.id.clone()) else {
+            return;
+        };
+        let retweet = !self.retweeted_tweets.contains(&tweet_id);
+        self.set_retweeted(&tweet_id, retweet);
+        self.events
+            .send(AppEvent::RetweetTweet { tweet_id, retweet });
+    }
+
+    /// List the accounts that reposted or liked the selected tweet.
+    pub(super) fn show_tweet_users(&mut self, kind: TweetUsersKind) {
+        let Some(tweet_id) = self.selected_tweet().map(|t| t.id.clone()) else {
+            return;
+        };
+        let view = ViewKind::TweetUsers { tweet_id, kind };
+        self.tweet_users.clear();
+        self.push_view(view.clone());
+        self.fetch_for_view(&view);
+    }
+
+    /// In a thread under one of your tweets, hide the selected reply, or
+    /// unhide it if it was hidden this session.
+    pub(super) fn toggle_hide_reply(&mut self) {
+        if !matches!(self.current_view(), Some(ViewKind::Thread(_))) {
+            return;
+        }
+        let Some(reply) = self.selected_tweet() else {
+            return;
+        };
+        let tweet_id = reply.id.clone();
+        // Only the conversation's author can hide replies. Without the
+        // signed-in account or the root, let the API decide.
+        let me = self.current_user.as_ref().map(|u| &u.id);
+        let author = self.thread_root.as_ref().and_then(|t| t.author_id.as_ref());
+        if let (Some(me), Some(author)) = (me, author)
+            && me != author
+        {
+            self.notify("Only replies to your own tweets can be hidden");
+            return;
+        }
+        let hide = !self.hidden_replies.contains(&tweet_id);
+        self.set_reply_hidden(&tweet_id, hide);
+        self.events.send(AppEvent::HideReply { tweet_id, hide });
+    }
+
+    pub(super) fn set_reply_hidden(&mut self, tweet_id: &str, hidden: bool) {
+        if hidden {
+            self.hidden_replies.insert(tweet_id.to_string());
+        } else {
+            self.hidden_replies.remove(tweet_id);
+        }
+    }
+
+    /// Record `tweet_id` as reposted or not and adjust its repost count in
+    /// every loaded copy. Does nothing if it is already in that state.
+    pub(super) fn set_retweeted(&mut self, tweet_id: &str, retweeted: bool) {
+        let changed = if retweeted {
+            self.retweeted_tweets.insert(tweet_id.to_string())
+        } else {
+            self.retweeted_tweets.remove(tweet_id)
+        };
+        if !changed {
+            return;
+        }
+        for tweet in self.loaded_copies_mut(tweet_id) {
+            if let Some(metrics) = tweet.public_metrics.as_mut() {
+                metrics.retweet_count = if retweeted {
+                    metrics.retweet_count + 1
+                } else {
+                    metrics.retweet_count.saturating_sub(1)
+                };
+            }
+        }
+    }
+
+    /// Every loaded copy of `tweet_id`, across timelines and the thread.
+    fn loaded_copies_mut<'a>(
+        &'a mut self,
+        tweet_id: &'a str,
+    ) -> impl Iterator<Item = &'a mut Tweet> {
+        [
+            &mut self.home_timeline.tweets,
+            &mut self.mentions.tweets,
+            &mut self.bookmarks.tweets,
+            &mut self.search_results.tweets,
+            &mut self.viewed_user_timeline.tweets,
+            &mut self.thread_tweets,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.thread_root.as_mut())
+        .filter(move |t| t.id == tweet_id)
+    }
+
+    // -- Batch selection ----------------------------------------------------
+
+    /// Whether `tweet` matches a `[watch]` rule.
+    pub fn is_watched(&self, tweet: &Tweet) -> bool {
+        let author = tweet
+            .author_id
+            .as_deref()
+            .and_then(|id| self.lookup_user(id));
+        self.config.watch.matches(tweet, author)
+    }
+
+    /// Whether the tweet at `index` of the current view is in the batch
+    /// selection: marked with Space, or inside the visual-mode range.
+    pub fn is_batch_selected(&self, index: usize, tweet_id: &str) -> bool {
+        self.marked_tweets.contains(tweet_id)
+            || self.visual_anchor.is_some_and(|anchor| {
+                let cursor = self.selected_index();
+                (anchor.min(cursor)..=anchor.max(cursor)).contains(&index)
+            })
+    }
+
+    /// Tweets of the current view in the batch selection, in display order.
+    pub fn batch_selection(&self) -> Vec<&Tweet> {
+        self.current_tweets()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter(|(i, t)| self.is_batch_selected(*i, &t.id))
+            .map(|(_, t)| t)
+            .collect()
+    }
+
+    /// Toggle the mark on the selected tweet, then move to the next one so
+    /// runs of tweets can be marked quickly.
+    pub(super) fn toggle_mark(&mut self) {
+        let Some(tweet) = self.selected_tweet() else {
+            return;
+        };
+        let id = tweet.id.clone();
+        if !self.marked_tweets.remove(&id) {
+            self.marked_tweets.insert(id);
+        }
+        self.notify(format!("{} marked", self.marked_tweets.len()));
+        self.move_selection_down();
+    }
+
+    /// Enter visual mode with the range anchored at the selected tweet.
+    pub(super) fn enter_visual_mode(&mut self) {
+        if self.current_tweets().is_none_or(|tweets| tweets.is_empty()) {
+            return;
+        }
+        self.visual_anchor = Some(self.selected_index());
+        self.mode = AppMode::Visual;
+    }
+
+    /// Drop the visual range and all marks, leaving visual mode.
+    pub(super) fn clear_batch_selection(&mut self) {
+        self.marked_tweets.clear();
+        self.visual_anchor = None;
+        if self.mode == AppMode::Visual {
+            self.mode = AppMode::Normal;
+        }
+    }
+
+    /// Bookmark the selection. With nothing selected in the Search view,
+    /// bookmark every result.
+    pub(super) fn bookmark_selection(&mut self) {
+        let mut ids: Vec<String> = self
+            .batch_selection()
+            .iter()
+            .map(|t| t.id.clone())
+            .collect();
+        if ids.is_empty() && self.current_view() == Some(&ViewKind::Search) {
+            ids = self
+                .search_results
+                .tweets
+                .iter()
+                .map(|t| t.id.clone())
+                .collect();
+        }
+        self.start_bulk(BulkAction::Bookmark, ids);
+    }
+
+    /// Mute the authors of the selection (each once, never yourself).
+    pub(super) fn mute_selection_authors(&mut self) {
+        let me = self.current_user.as_ref().map(|u| u.id.as_str());
+        let mut ids: Vec<String> = Vec::new();
+        for author in self
+            .batch_selection()
+            .iter()
+            .filter_map(|t| t.author_id.as_deref())
+        {
+            if Some(author) != me && !ids.iter().any(|id| id == author) {
+                ids.push(author.to_string());
+            }
+        }
+        self.start_bulk(BulkAction::Mute, ids);
+    }
+
+    /// Remove the selection from bookmarks.
+    pub(super) fn unbookmark_selection(&mut self) {
+        let ids = self
+            .batch_selection()
+            .iter()
+            .map(|t| t.id.clone())
+            .collect();
+        self.start_bulk(BulkAction::Unbookmark, ids);
+    }
+
+    /// Run `action` over `ids`, asking first if the action is destructive.
+    /// The selection is kept until the run actually starts, so cancelling
+    /// the confirmation leaves it intact.
+    fn start_bulk(&mut self, action: BulkAction, ids: Vec<String>) {
+        if ids.is_empty() {
+            self.notify("Nothing selected (Space marks a tweet, v selects a range)");
+            return;
+        }
+        let Some(kind) = action.confirm_kind() else {
+            self.events.send(AppEvent::StartBulk { action, ids });
+            return;
+        };
+        let (_, noun) = action.summary();
+        let (verb, message) = match action {
+            BulkAction::Mute => (
+                "Mute",
+                "Their posts will no longer show up in your timelines.",
+            ),
+            _ => ("Unbookmark", "They will be removed from your bookmarks."),
+        };
+        let title = format!("{verb} {} {noun}", ids.len());
+        self.confirm(kind, title, message, AppEvent::StartBulk { action, ids });
+    }
+
+    /// Write the selection as JSONL (the CLI output format) under
+    /// `~/.config/xplorertui/exports/`.
+    pub(super) fn export_selection(&mut self) {
+        let tweets = self.batch_selection();
+        if tweets.is_empty() {
+            self.notify("Nothing selected to export");
+            return;
+        }
+        let count = tweets.len();
+        let stats = TextStats::of_tweets(tweets.iter().copied());
+        let jsonl = self.tweets_jsonl(&tweets);
+        let name = crate::ui::view_label(self.current_view());
+        self.clear_batch_selection();
+        match crate::store::write_export(name, &jsonl) {
+            Ok(path) => {
+                self.notify(format!(
+                    "Exported {count} tweets ({} words, {}) to {}",
+                    stats.words,
+                    stats.reading_time(),
+                    path.display()
+                ));
+            }
+            Err(e) => self.set_error(format!("Export failed: {e}")),
+        }
+    }
+
+    /// Freeze the selection into marks and open `:pipe ` in the command line.
+    pub(super) fn start_pipe(&mut self) {
+        let ids: Vec<String> = self
+            .batch_selection()
+            .iter()
+            .map(|t| t.id.clone())
+            .collect();
+        self.visual_anchor = None;
+        self.marked_tweets = ids.into_iter().collect();
+        self.mode = AppMode::Command;
+        self.command_input = "pipe ".to_string();
+    }
+
+    /// `:pipe <command>` — send the selection (or the selected tweet) to
+    /// `command` as JSONL on stdin.
+    pub(super) fn pipe_selection(&mut self, command: String) {
+        let mut tweets = self.batch_selection();
+        if tweets.is_empty() {
+            tweets.extend(self.selected_tweet());
+        }
+        if tweets.is_empty() {
+            self.notify("No tweets to pipe");
+            return;
+        }
+        let jsonl = self.tweets_jsonl(&tweets);
+        self.clear_batch_selection();
+        self.notify(format!("Running {command}..."));
+        self.dispatch_pipe(command, jsonl);
+    }
+
+    /// Tweets as `{"tweet", "author", "media"}` JSONL, like `xplorertui` commands print.
+    fn tweets_jsonl(&self, tweets: &[&Tweet]) -> String {
+        let includes = self.current_includes();
+        let mut out = String::new();
+        for tweet in tweets {
+            let mut value = crate::cli::denormalize_tweet(tweet, includes);
+            if value["author"].is_null()
+                && let Some(author) = tweet
+                    .author_id
+                    .as_deref()
+                    .and_then(|id| self.lookup_user(id))
+            {
+                value["author"] = serde_json::json!(author);
+            }
+            out.push_str(&value.to_string());
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Expansions that came with the current view's tweets.
+    fn current_includes(&self) -> &Option<Includes> {
+        const NONE: &Option<Includes> = &None;
+        match self.current_view() {
+            Some(ViewKind::Home) => &self.home_timeline.includes,
+            Some(ViewKind::Mentions) => &self.mentions.includes,
+            Some(ViewKind::Bookmarks) => &self.bookmarks.includes,
+            Some(ViewKind::Search) => &self.search_results.includes,
+            Some(ViewKind::UserTimeline(_)) => &self.viewed_user_timeline.includes,
+            _ => NONE,
+        }
+    }
+
+    /// `:goto` — select `tweet_id` if the current view has it loaded, open
+    /// its thread from the local store if it was seen before, and otherwise
+    /// fetch it like `:open`.
+    pub(super) fn goto_tweet(&mut self, tweet_id: String) {
+        let position = self
+            .current_tweets()
+            .and_then(|tweets| tweets.iter().position(|t| t.id == tweet_id));
+        if let Some(idx) = position {
+            if let Some(vs) = self.view_stack.last_mut() {
+                vs.selected_index = idx;
+            }
+            self.notify(format!("Jumped to tweet {tweet_id}"));
+            return;
+        }
+
+        match crate::store::find_tweet(&tweet_id) {
+            Some(tweet) => {
+                let conv_id = tweet
+                    .conversation_id
+                    .clone()
+                    .unwrap_or_else(|| tweet.id.clone());
+                self.thread_root = Some(tweet);
+                // The previous thread's replies aren't this one's.
+                self.thread_tweets.clear();
+                self.thread_partial_errors.clear();
+                self.events.send(AppEvent::FetchThread {
+                    conversation_id: conv_id.clone(),
+                    pagination_token: None,
+                });
+                self.push_view(ViewKind::Thread(conv_id));
+            }
+            None => self.events.send(AppEvent::FetchTweet { tweet_id }),
+        }
+    }
+
+    /// Open the full profile of the selected tweet's author. Known authors
+    /// go through `FetchUser` so the profile cache is used; otherwise the
+    /// author is looked up by ID.
+    pub(super) fn open_author_profile(&mut self) {
+        let Some(author_id) = self.selected_tweet().and_then(|t| t.author_id.clone()) else {
+            return;
+        };
+        match self.lookup_user(&author_id) {
+            Some(user) => {
+                let username = user.username.clone();
+                self.events.send(AppEvent::FetchUser { username });
+            }
+            None => self
+                .events
+                .send(AppEvent::FetchUserById { user_id: author_id }),
+        }
+    }
+
+    /// In a profile, mute the account, or unmute it if it was muted this
+    /// session.
+    pub(super) fn toggle_profile_mute(&mut self) {
+        if !matches!(self.current_view(), Some(ViewKind::UserProfile(_))) {
+            return;
+        }
+        let Some(user) = &self.viewed_user else {
+            return;
+        };
+        let mute = !self.muted_users.contains(&user.id);
+        self.mute_user(user.username.clone(), mute);
+    }
+
+    /// In a profile, open the Lists view to pick one of your Lists to add
+    /// the account to.
+    pub(super) fn add_profile_to_list(&mut self) {
+        if !matches!(self.current_view(), Some(ViewKind::UserProfile(_))) {
+            return;
+        }
+        let Some(user) = self.viewed_user.clone() else {
+            return;
+        };
+        if self.refuse_without_user_context(&ViewKind::Lists) {
+            return;
+        }
+        self.push_view(ViewKind::Lists);
+        self.fetch_for_view(&ViewKind::Lists);
+        self.list_pick_user = Some(user);
+    }
+
+    /// Enter in the Lists view: add the user being picked for to `list`,
+    /// or show its members.
+    fn open_list(&mut self, list: List) {
+        if self.list_pick_user.is_none() {
+            let kind = ViewKind::ListMembers(list.id);
+            self.list_members.clear();
+            self.push_view(kind.clone());
+            self.fetch_for_view(&kind);
+            return;
+        }
+        if !self.owns_list(&list.id) {
+            self.notify("You can only add members to Lists you own");
+            return;
+        }
+        if let Some(user) = self.list_pick_user.take() {
+            self.pop_view();
+            self.events.send(AppEvent::SetListMember {
+                list,
+                user,
+                member: true,
+            });
+        }
+    }
+
+    /// In the members of a List you own, remove the selected member after
+    /// a confirmation.
+    pub(super) fn remove_list_member(&mut self) {
+        let Some(list) = self.members_list().cloned() else {
+            return;
+        };
+        let Some(user) = self.list_members.get(self.selected_index()).cloned() else {
+            return;
+        };
+        if !self.owns_list(&list.id) {
+            self.notify("You can only remove members from Lists you own");
+            return;
+        }
+        self.confirm(
+            "list_member",
+            format!("Remove @{} from {}", user.username, list.name),
+            format!("Their posts will no longer show in {}.", list.name),
+            AppEvent::SetListMember {
+                list,
+                user,
+                member: false,
+            },
+        );
+    }
+
+    /// Record a member added to or removed from `list`: its member count,
+    /// and the members view if it is showing that List.
+    pub(super) fn apply_list_member(&mut self, list_id: &str, user: User, member: bool) {
+        if let Some(list) = self.owned_lists.iter_mut().find(|l| l.id == list_id)
+            && let Some(count) = list.member_count.as_mut()
+        {
+            *count = if member {
+                *count + 1
+            } else {
+                count.saturating_sub(1)
+            };
+        }
+        if self.current_view() != Some(&ViewKind::ListMembers(list_id.to_string())) {
+            return;
+        }
+        if member {
+            if !self.list_members.iter().any(|u| u.id == user.id) {
+                self.list_members.push(user);
+            }
+        } else {
+            self.list_members.retain(|u| u.id != user.id);
+            let len = self.list_members.len();
+            if let Some(vs) = self.view_stack.last_mut() {
+                vs.selected_index = vs.selected_index.min(len.saturating_sub(1));
+            }
+        }
+    }
+
+    /// In a profile, block the account (after a confirmation), or unblock
+    /// it if it was blocked this session.
+    pub(super) fn toggle_profile_block(&mut self) {
+        if !matches!(self.current_view(), Some(ViewKind::UserProfile(_))) {
+            return;
+        }
+        let Some(user) = &self.viewed_user else {
+            return;
+        };
+        let block = !self.blocked_users.contains(&user.id);
+        self.block_user(user.username.clone(), block);
+    }
+
+    /// In a profile, show or hide the chart of when the user posts. It is
+    /// fetched once per profile and kept while the profile is open.
+    pub(super) fn toggle_posting_heatmap(&mut self) {
+        if !matches!(self.current_view(), Some(ViewKind::UserProfile(_))) {
+            return;
+        }
+        let Some(user_id) = self.viewed_user.as_ref().map(|u| u.id.clone()) else {
+            return;
+        };
+        if self
+            .posting_heatmap
+            .take()
+            .is_some_and(|h| h.user_id == user_id)
+        {
+            return;
+        }
+        self.posting_heatmap = Some(PostingHeatmap {
+            user_id: user_id.clone(),
+            loading: true,
+            ..PostingHeatmap::default()
+        });
+        self.events.send(AppEvent::FetchPostingActivity { user_id });
+    }
+
+    /// Timeline behind the current view, if it shows one.
+    fn current_timeline_key(&self) -> Option<TimelineKey> {
+        match self.current_view()? {
+            ViewKind::Home => Some(TimelineKey::Home),
+            ViewKind::Mentions => Some(TimelineKey::Mentions),
+            ViewKind::Bookmarks => Some(TimelineKey::Bookmarks),
+            ViewKind::UserTimeline(_) => Some(TimelineKey::UserTimeline),
+            _ => None,
+        }
+    }
+
+    /// The current view's timeline, if tweets are folded in it.
+    fn folding_timeline(&self) -> Option<&TimelineState> {
+        match self.current_timeline_key()? {
+            TimelineKey::Home => Some(&self.home_timeline),
+            TimelineKey::Mentions => Some(&self.mentions),
+            TimelineKey::UserTimeline => Some(&self.viewed_user_timeline),
+            TimelineKey::Bookmarks => None,
+        }
+    }
+
+    /// How many copies are folded into `tweet_id` in the current timeline.
+    pub fn duplicate_count(&self, tweet_id: &str) -> usize {
+        self.folding_timeline()
+            .and_then(|tl| tl.duplicates.get(tweet_id))
+            .map_or(0, |group| group.tweets.len())
+    }
+
+    /// How many tweets of its author's run are folded into `tweet_id` in
+    /// the current timeline.
+    pub fn author_run_count(&self, tweet_id: &str) -> usize {
+        self.folding_timeline()
+            .and_then(|tl| tl.author_runs.get(tweet_id))
+            .map_or(0, |group| group.tweets.len())
+    }
+
+    /// Show or fold the copies of the selected tweet, or the rest of its
+    /// author's run. Works from the first tweet or from any expanded one.
+    /// A tweet with both opens its copies, then its run instead, then
+    /// folds them again.
+    pub(super) fn toggle_folded(&mut self) {
+        let Some(key) = self.current_timeline_key() else {
+            return;
+        };
+        let Some(selected) = self.selected_tweet().map(|t| t.id.clone()) else {
+            return;
+        };
+        let TimelineState {
+            tweets,
+            duplicates,
+            author_runs,
+            ..
+        } = self.timeline_mut(key);
+        let expanded =
+            |groups: &HashMap<String, DuplicateGroup>| groups.get(&selected).map(|g| g.expanded);
+        let (groups, original): (&mut HashMap<_, _>, _) =
+            match (expanded(duplicates), expanded(author_runs)) {
+                (Some(true), Some(false)) => {
+                    toggle_group(tweets, duplicates, &selected);
+                    (author_runs, selected)
+                }
+                (_, Some(true)) | (None, Some(false)) => (author_runs, selected),
+                (Some(_), _) => (duplicates, selected),
+                (None, None) => {
+                    let member_of = |groups: &HashMap<String, DuplicateGroup>| {
+                        groups
+                            .iter()
+                            .find(|(_, g)| g.expanded && g.tweets.iter().any(|t| t.id == selected))
+                            .map(|(original, _)| original.clone())
+                    };
+                    if let Some(original) = member_of(duplicates) {
+                        (duplicates, original)
+                    } else if let Some(original) = member_of(author_runs) {
+                        (author_runs, original)
+                    } else {
+                        self.notify("Nothing is folded into this tweet");
+                        return;
+                    }
+                }
+            };
+        if let Some(pos) = toggle_group(tweets, groups, &original)
+            && let Some(vs) = self.view_stack.last_mut()
+        {
+            vs.selected_index = pos;
+        }
+    }
+
+    /// Switch search results between recency and engagement order, keeping
+    /// the same tweet selected.
+    pub(super) fn toggle_search_sort(&mut self) {
+        if self.current_view() != Some(&ViewKind::Search) {
+            return;
+        }
+        let selected = self.selected_tweet().map(|t| t.id.clone());
+        self.search_by_engagement = !self.search_by_engagement;
+        self.sort_search_results();
+        if let Some(id) = selected
+            && let Some(pos) = self.search_results.tweets.iter().position(|t| t.id == id)
+            && let Some(vs) = self.view_stack.last_mut()
+        {
+            vs.selected_index = pos;
+        }
+        self.notify(if self.search_by_engagement {
+            "Sorted by engagement"
+        } else {
+            "Sorted by recency"
+        });
+    }
+
+    /// Order the loaded search results by the current sort: likes plus
+    /// reposts, highest first, or newest first.
+    pub(super) fn sort_search_results(&mut self) {
+        let tweets = &mut self.search_results.tweets;
+        if self.search_by_engagement {
+            let engagement = |t: &Tweet| {
+                t.public_metrics
+                    .as_ref()
+                    .map_or(0, |m| m.like_count + m.retweet_count)
+            };
+            tweets.sort_by(|a, b| {
+                engagement(b)
+                    .cmp(&engagement(a))
+                    .then_with(|| compare_ids(&b.id, &a.id))
+            });
+        } else {
+            tweets.sort_by(|a, b| compare_ids(&b.id, &a.id));
+        }
+    }
+
+    /// Toggle the thread view's preview of the selected reply author's
+    /// recent posts. Opening it on another author switches to them.
+    pub(super) fn toggle_author_preview(&mut self) {
+        let Some(ViewKind::Thread(conversation_id)) = self.current_view().cloned() else {
+            return;
+        };
+        let Some(user_id) = self.selected_tweet().and_then(|t| t.author_id.clone()) else {
+            return;
+        };
+        if self
+            .author_preview
+            .take()
+            .is_some_and(|p| p.user_id == user_id && p.conversation_id == conversation_id)
+        {
+            return;
+        }
+        self.author_preview = Some(AuthorPreview {
+            conversation_id,
+            user_id: user_id.clone(),
+            loading: true,
+            ..AuthorPreview::default()
+        });
+        self.events.send(AppEvent::FetchAuthorPreview { user_id });
+    }
+
     /// Builds the tweet URL for the current selection, handling both regular
     /// views (via `selected_tweet()`) and the cluster tweet-list view.
     fn selected_tweet_url(&self) -> Option<String> {
+        if self.current_view() == Some(&ViewKind::Lists) {
+            let list = self.lists().nth(self.selected_index())?;
+            return Some(format!("https://x.com/i/lists/{}", list.id));
+        }
+        if let Some(ViewKind::ListMembers(_)) = self.current_view() {
+            let user = self.list_members.get(self.selected_index())?;
+            return Some(format!("https://x.com/{}", user.username));
+        }
+        if let Some(ViewKind::TweetUsers { .. }) = self.current_view() {
+            let user = self.tweet_users.get(self.selected_index())?;
+            return Some(format!("https://x.com/{}", user.username));
+        }
+        if let Some(ViewKind::Messages | ViewKind::DmConversation(_)) = self.current_view() {
+            return self.selected_dm_url();
+        }
+
         // Cluster tweet-list view: tweets stored as IDs, not Tweet objects.
         if self.current_view() == Some(&ViewKind::Cluster) {
             if let Some(c) = self.selected_cluster
@@ -316,16 +1322,19 @@ impl App {
 
     pub(super) fn copy_tweet_url(&mut self) {
         match self.selected_tweet_url() {
-            Some(url) => match crate::clipboard::copy_to_clipboard(&url) {
-                Ok(()) => {
-                    self.status_message = Some(format!("Copied: {url}"));
+            Some(url) => match crate::clipboard::copy(&url, self.config.clipboard) {
+                Ok("system") => {
+                    self.notify(format!("Copied: {url}"));
+                }
+                Ok(backend) => {
+                    self.notify(format!("Copied ({backend}): {url}"));
                 }
                 Err(e) => {
-                    self.status_message = Some(format!("Clipboard error: {e}"));
+                    self.notify(format!("Clipboard error: {e}"));
                 }
             },
             None => {
-                self.status_message = Some("No tweet selected".into());
+                self.notify("No tweet selected");
             }
         }
     }
@@ -334,14 +1343,14 @@ impl App {
         match self.selected_tweet_url() {
             Some(url) => match open::that(&url) {
                 Ok(()) => {
-                    self.status_message = Some(format!("Opened: {url}"));
+                    self.notify(format!("Opened: {url}"));
                 }
                 Err(e) => {
-                    self.status_message = Some(format!("Failed to open browser: {e}"));
+                    self.notify(format!("Failed to open browser: {e}"));
                 }
             },
             None => {
-                self.status_message = Some("No tweet selected".into());
+                self.notify("No tweet selected");
             }
         }
     }
@@ -399,6 +1408,53 @@ impl App {
         }
     }
 
+    /// Fetch the page before the first one loaded, e.g. tweets newer than
+    /// the top of a timeline reached by paging.
+    pub(super) fn load_previous_page(&mut self) {
+        match self.current_view().cloned() {
+            Some(ViewKind::Home) => {
+                if let Some(token) = self.home_timeline.previous_token.clone() {
+                    self.events.send(AppEvent::FetchHomeTimeline {
+                        pagination_token: Some(token),
+                    });
+                }
+            }
+            Some(ViewKind::Mentions) => {
+                if let Some(token) = self.mentions.previous_token.clone() {
+                    self.events.send(AppEvent::FetchMentions {
+                        pagination_token: Some(token),
+                    });
+                }
+            }
+            Some(ViewKind::Bookmarks) => {
+                if let Some(token) = self.bookmarks.previous_token.clone() {
+                    self.events.send(AppEvent::FetchBookmarks {
+                        pagination_token: Some(token),
+                    });
+                }
+            }
+            Some(ViewKind::Search) => {
+                if let Some(token) = self.search_results.previous_token.clone() {
+                    let query = self.search_query.clone();
+                    self.events.send(AppEvent::FetchSearch {
+                        query,
+                        pagination_token: Some(token),
+                    });
+                }
+            }
+            Some(ViewKind::UserTimeline(ref user_id)) => {
+                let user_id = user_id.clone();
+                if let Some(token) = self.viewed_user_timeline.previous_token.clone() {
+                    self.events.send(AppEvent::FetchUserTimeline {
+                        user_id,
+                        pagination_token: Some(token),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub(super) fn refresh_current_view(&mut self) {
         match self.current_view().cloned() {
             Some(ViewKind::Home) => {
@@ -419,10 +1475,41 @@ impl App {
                     pagination_token: None,
                 });
             }
+            Some(ViewKind::Lists) => {
+                self.notify("Refreshing...");
+                self.events.send(AppEvent::FetchLists);
+            }
+            Some(ViewKind::ListMembers(list_id)) => {
+                self.notify("Refreshing...");
+                self.events.send(AppEvent::FetchListMembers { list_id });
+            }
+            Some(ViewKind::TweetUsers { tweet_id, kind }) => {
+                self.notify("Refreshing...");
+                self.events
+                    .send(AppEvent::FetchTweetUsers { tweet_id, kind });
+            }
+            Some(ViewKind::StreamRules) => {
+                self.notify("Refreshing...");
+                self.events.send(AppEvent::FetchStreamRules);
+            }
+            Some(ViewKind::FilteredStream) => {
+                // The stream has no history to reload; reconnect if stopped.
+                if self.stream_cancel.is_none() {
+                    self.events.send(AppEvent::ConnectStream);
+                }
+            }
+            Some(ViewKind::Messages) => {
+                self.notify("Refreshing...");
+                self.events.send(AppEvent::FetchDmEvents);
+            }
+            Some(ViewKind::DmConversation(participant_id)) => {
+                self.notify("Refreshing...");
+                self.events
+                    .send(AppEvent::FetchDmConversation { participant_id });
+            }
             Some(ViewKind::Cluster) => {
                 let Some(source) = self.cluster_source else {
-                    self.status_message =
-                        Some("No cluster source to refresh. Run :cluster again.".into());
+                    self.notify("No cluster source to refresh. Run :cluster again.");
                     return;
                 };
                 self.cluster_result = None;
@@ -450,9 +1537,8 @@ impl App {
                     ClusterSource::Search => {
                         let query = self.search_query.clone();
                         if query.is_empty() {
-                            self.status_message = Some(
-                                "No search query to re-run. Go to Search view and try again."
-                                    .into(),
+                            self.notify(
+                                "No search query to re-run. Go to Search view and try again.",
                             );
                             self.refresh_then_cluster = false;
                             return;
@@ -466,23 +1552,39 @@ impl App {
                 }
             }
             _ => {
-                self.status_message = Some("Refresh not supported for this view".into());
+                self.notify("Refresh not supported for this view");
             }
         }
     }
 
+    /// Re-send the request behind the last API error, pagination token
+    /// and all, and dismiss its error.
+    pub(super) fn retry_failed_request(&mut self) {
+        let Some(request) = self.retry_request.take() else {
+            self.notify("Nothing to retry");
+            return;
+        };
+        self.error_detail = None;
+        self.toasts.dismiss_errors();
+        self.notify("Retrying...");
+        self.events.send(request);
+    }
+
     /// Clear a timeline and reset the view stack's selection/scroll to the top.
     fn reset_timeline(&mut self, timeline_fn: &mut dyn FnMut(&mut Self) -> &mut TimelineState) {
         let tl = timeline_fn(self);
-        tl.tweets.clear();
+        tl.clear_tweets();
         tl.next_token = None;
+        tl.previous_token = None;
+        tl.cached_at = None;
+        tl.partial_errors.clear();
         tl.selected_index = 0;
         tl.scroll_offset = 0;
         if let Some(vs) = self.view_stack.last_mut() {
             vs.selected_index = 0;
             vs.scroll_offset = 0;
         }
-        self.status_message = Some("Refreshing...".into());
+        self.notify("Refreshing...");
     }
 
     fn home_timeline_ref(&mut self) -> &mut TimelineState {
@@ -501,3 +1603,24 @@ impl App {
         &mut self.search_results
     }
 }
+
+/// Show or fold the group folded into `original`. Returns where `original`
+/// is when the group was folded, to select it.
+fn toggle_group(
+    tweets: &mut Vec<Tweet>,
+    groups: &mut HashMap<String, DuplicateGroup>,
+    original: &str,
+) -> Option<usize> {
+    let group = groups.get_mut(original)?;
+    let pos = tweets.iter().position(|t| t.id == original)?;
+    if group.expanded {
+        let ids: HashSet<&str> = group.tweets.iter().map(|t| t.id.as_str()).collect();
+        tweets.retain(|t| !ids.contains(t.id.as_str()));
+        group.expanded = false;
+        Some(pos)
+    } else {
+        tweets.splice(pos + 1..pos + 1, group.tweets.iter().cloned());
+        group.expanded = true;
+        None
+    }
+}
@@ -0,0 +1,218 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::App;
+use crate::api::types::Tweet;
+use crate::event::ViewKind;
+use crate::openrouter::types::ChatMessage;
+
+/// Drafts asked for per request.
+const DRAFT_COUNT: usize = 3;
+
+/// The tweet a composer draft answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplyTarget {
+    pub tweet_id: String,
+    /// The author's handle, if known, for the composer's title.
+    pub username: Option<String>,
+}
+
+/// Replies the chat model drafted for a tweet, offered in the composer.
+/// Picking one only fills the editor; posting is still up to you.
+#[derive(Debug, Clone)]
+pub struct ReplyDrafts {
+    pub target: ReplyTarget,
+    pub drafts: Vec<String>,
+    pub selected: usize,
+    pub loading: bool,
+}
+
+impl App {
+    /// Ctrl-G in the composer: draft replies to the selected tweet, with
+    /// the thread's root as context when it is open.
+    pub(super) fn request_reply_drafts(&mut self) {
+        let Some(tweet) = self.selected_tweet().cloned() else {
+            self.notify("Select a tweet to draft replies to");
+            return;
+        };
+        if !self.has_chat_provider() {
+            self.set_error(
+                "No chat provider configured. Set mlx_server_url in config \
+                 or use :openrouter-auth + :openrouter-models."
+                    .into(),
+            );
+            return;
+        }
+        let mut context = Vec::new();
+        if let (Some(ViewKind::Thread(_)), Some(root)) = (self.current_view(), &self.thread_root)
+            && root.id != tweet.id
+        {
+            context.push(self.quoted(root));
+        }
+        let messages = reply_drafts_messages(&context, &self.quoted(&tweet));
+        let target = ReplyTarget {
+            tweet_id: tweet.id.clone(),
+            username: self.author_username(&tweet),
+        };
+        self.reply_drafts = Some(ReplyDrafts {
+            target,
+            drafts: Vec::new(),
+            selected: 0,
+            loading: true,
+        });
+        self.dispatch_reply_drafts(tweet.id, messages);
+    }
+
+    /// Keys while drafts are offered: Up/Down choose, Enter puts the draft
+    /// in the editor as a reply, Esc goes back to what was there. Text
+    /// already in the editor is kept in the Drafts view first.
+    pub(super) fn handle_reply_drafts_key(&mut self, key: KeyEvent) {
+        let Some(offer) = self.reply_drafts.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.reply_drafts = None,
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab
+                if offer.selected + 1 < offer.drafts.len() =>
+            {
+                offer.selected += 1;
+            }
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => {
+                offer.selected = offer.selected.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                let Some(draft) = offer.drafts.get(offer.selected).cloned() else {
+                    return;
+                };
+                let offer = self.reply_drafts.take().expect("checked above");
+                if !self.compose_input.trim().is_empty() {
+                    self.save_compose_draft();
+                    self.compose_draft_id = None;
+                }
+                self.compose_input = draft;
+                self.compose_reply_to = Some(offer.target);
+            }
+            _ => {}
+        }
+    }
+
+    /// Show drafts for the tweet still being answered; a failure closes
+    /// the offer.
+    pub(super) fn apply_reply_drafts(
+        &mut self,
+        tweet_id: &str,
+        result: crate::event::ApiResult<Vec<String>>,
+    ) {
+        let Some(offer) = self
+            .reply_drafts
+            .as_mut()
+            .filter(|o| o.target.tweet_id == tweet_id)
+        else {
+            return;
+        };
+        match result {
+            Ok(drafts) => {
+                offer.drafts = drafts;
+                offer.loading = false;
+            }
+            Err(e) => {
+                self.reply_drafts = None;
+                self.set_error(format!("Drafting replies failed: {e}"));
+            }
+        }
+    }
+
+    /// `@handle: text` for the prompt.
+    fn quoted(&self, tweet: &Tweet) -> String {
+        let text = tweet
+            .note_tweet
+            .as_ref()
+            .map_or(&tweet.text, |note| &note.text);
+        match self.author_username(tweet) {
+            Some(username) => format!("@{username}: {text}"),
+            None => text.clone(),
+        }
+    }
+
+    fn author_username(&self, tweet: &Tweet) -> Option<String> {
+        let author_id = tweet.author_id.as_deref()?;
+        Some(self.lookup_user(author_id)?.username.clone())
+    }
+}
+
+/// The chat request for drafts answering `tweet`, after `context` (earlier
+/// tweets of its thread).
+fn reply_drafts_messages(context: &[String], tweet: &str) -> Vec<ChatMessage> {
+    let mut content = String::new();
+    if !context.is_empty() {
+        content.push_str("Earlier in the thread:\n");
+        for earlier in context {
+            content.push_str(&format!("{earlier}\n"));
+        }
+        content.push('\n');
+    }
+    content.push_str(&format!("Reply to:\n{tweet}\n"));
+    vec![
+        ChatMessage {
+            role: "system".into(),
+            content: format!(
+                "You draft replies to a tweet for the user to edit and post \
+                 themselves. Write {DRAFT_COUNT} different replies, each under \
+                 280 characters, in the tweet's language and a natural, \
+                 conversational tone. Reply with exactly one draft per line. \
+                 No numbering, no quotes, no preamble or explanations."
+            ),
+        },
+        ChatMessage {
+            role: "user".into(),
+            content,
+        },
+    ]
+}
+
+/// Drafts from the model's reply, one per line, without the bullets,
+/// numbers and quotes models add anyway.
+pub(super) fn parse_reply_drafts(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .unwrap_or(line);
+            let line = line
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .strip_prefix(['.', ')'])
+                .map_or(line, str::trim_start);
+            line.trim_matches(['"', '\u{201C}', '\u{201D}']).trim()
+        })
+        .filter(|line| !line.is_empty())
+        .take(DRAFT_COUNT)
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drafts_lose_their_numbering_and_quotes() {
+        let content = "1. \"Congrats on shipping!\"\n\n- Love the charts\n2) 3 cheers\n4. extra";
+        assert_eq!(
+            parse_reply_drafts(content),
+            ["Congrats on shipping!", "Love the charts", "3 cheers"]
+        );
+        // Numbers that start the reply itself are kept.
+        assert_eq!(parse_reply_drafts("42 is the answer"), ["42 is the answer"]);
+    }
+
+    #[test]
+    fn the_prompt_names_the_thread_and_the_tweet() {
+        let messages = reply_drafts_messages(&["@alice: root".into()], "@bob: reply");
+        assert_eq!(
+            messages[1].content,
+            "Earlier in the thread:\n@alice: root\n\nReply to:\n@bob: reply\n"
+        );
+    }
+}
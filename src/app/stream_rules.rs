@@ -0,0 +1,127 @@
+use super::App;
+use crate::api::types::{ListResponse, NewStreamRule, StreamRule};
+use crate::command::RulesCommand;
+use crate::event::{ApiResult, AppEvent, ViewKind};
+
+impl App {
+    pub(super) fn run_rules_command(&mut self, command: RulesCommand) {
+        match command {
+            RulesCommand::List => {
+                let kind = ViewKind::StreamRules;
+                self.stream_rules.clear();
+                self.push_view(kind.clone());
+                self.fetch_for_view(&kind);
+            }
+            RulesCommand::Add { value, tag } => {
+                self.events.send(AppEvent::AddStreamRules {
+                    rules: vec![NewStreamRule { value, tag }],
+                });
+            }
+            RulesCommand::Delete(ids) => {
+                self.events.send(AppEvent::DeleteStreamRules { ids });
+            }
+        }
+    }
+
+    /// `d` in the rules view: delete the selected rule after a
+    /// confirmation.
+    pub(super) fn delete_selected_rule(&mut self) {
+        let Some(rule) = self.stream_rules.get(self.selected_index()) else {
+            return;
+        };
+        self.confirm(
+            "stream_rule",
+            "Delete stream rule",
+            format!("The stream will stop matching {}.", rule.value),
+            AppEvent::DeleteStreamRules {
+                ids: vec![rule.id.clone()],
+            },
+        );
+    }
+
+    /// Show the rules X created and report the ones it refused.
+    pub(super) fn apply_stream_rules_added(&mut self, result: ApiResult<ListResponse<StreamRule>>) {
+        let resp = match result {
+            Ok(resp) => resp,
+            Err(e) => {
+                self.set_error(format!("Adding the rule failed: {e}"));
+                return;
+            }
+        };
+        let added = resp.data.unwrap_or_default();
+        match added.as_slice() {
+            [] => {}
+            [rule] => self.notify(format!("Added stream rule {}", rule.value)),
+            rules => self.notify(format!("Added {} stream rules", rules.len())),
+        }
+        self.stream_rules.extend(added);
+        let refused: Vec<String> = resp
+            .errors
+            .unwrap_or_default()
+            .iter()
+            .map(|e| e.summary())
+            .collect();
+        if !refused.is_empty() {
+            self.set_error(format!("Stream rule refused:\n{}", refused.join("\n")));
+        }
+    }
+
+    /// Drop the deleted rules. When X kept some, it doesn't say which,
+    /// so reload the list instead of guessing and report what it refused.
+    pub(super) fn apply_stream_rules_deleted(
+        &mut self,
+        ids: &[String],
+        result: ApiResult<ListResponse<StreamRule>>,
+    ) {
+        let resp = match result {
+            Ok(resp) => resp,
+            Err(e) => {
+                self.set_error(format!("Deleting the rule failed: {e}"));
+                return;
+            }
+        };
+        let summary = resp.meta.and_then(|meta| meta.summary);
+        let errors: Vec<String> = resp
+            .errors
+            .unwrap_or_default()
+            .iter()
+            .map(|e| e.summary())
+            .collect();
+        let not_deleted = summary.as_ref().map_or(0, |s| s.not_deleted as usize);
+        if not_deleted == 0 && errors.is_empty() {
+            self.stream_rules.retain(|rule| !ids.contains(&rule.id));
+            if self.current_view() == Some(&ViewKind::StreamRules) {
+                let len = self.stream_rules.len();
+                if let Some(vs) = self.view_stack.last_mut() {
+                    vs.selected_index = vs.selected_index.min(len.saturating_sub(1));
+                }
+            }
+            self.notify(match ids {
+                [_] => "Deleted stream rule".to_string(),
+                ids => format!("Deleted {} stream rules", ids.len()),
+            });
+            return;
+        }
+
+        match summary.map_or(0, |s| s.deleted) {
+            0 => {}
+            1 => self.notify("Deleted stream rule"),
+            deleted => self.notify(format!("Deleted {deleted} stream rules")),
+        }
+        self.events.send(AppEvent::FetchStreamRules);
+        let kept = if not_deleted > 0 {
+            not_deleted
+        } else {
+            errors.len().min(ids.len())
+        };
+        let mut message = format!(
+            "{kept} of {} stream rule{} not deleted",
+            ids.len(),
+            if ids.len() == 1 { "" } else { "s" }
+        );
+        if !errors.is_empty() {
+            message = format!("{message}:\n{}", errors.join("\n"));
+        }
+        self.set_error(message);
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Most toasts kept at once; the oldest informational ones go first.
+const MAX_TOASTS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Error,
+}
+
+/// A short notification shown in the corner of the screen.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub level: ToastLevel,
+    /// When an info toast disappears. Errors have none and stay until
+    /// dismissed.
+    expires_at: Option<Instant>,
+}
+
+/// Notifications, oldest first.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    /// Show `text` for `ttl`.
+    pub fn info(&mut self, text: impl Into<String>, ttl: Duration) {
+        self.push(Toast {
+            text: text.into(),
+            level: ToastLevel::Info,
+            expires_at: Some(Instant::now() + ttl),
+        });
+    }
+
+    /// Show `text` until [`dismiss_errors`](Self::dismiss_errors).
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.push(Toast {
+            text: text.into(),
+            level: ToastLevel::Error,
+            expires_at: None,
+        });
+    }
+
+    fn push(&mut self, toast: Toast) {
+        // The same message twice in a row (e.g. a repeated key press) just
+        // refreshes the existing toast.
+        if let Some(last) = self.queue.back()
+            && last.text == toast.text
+            && last.level == toast.level
+        {
+            self.queue.pop_back();
+        }
+        self.queue.push_back(toast);
+        while self.queue.len() > MAX_TOASTS {
+            let oldest = self
+                .queue
+                .iter()
+                .position(|t| t.level == ToastLevel::Info)
+                .unwrap_or(0);
+            self.queue.remove(oldest);
+        }
+    }
+
+    /// Drop toasts that have expired by `now`. Returns whether any did.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.queue.len();
+        self.queue
+            .retain(|t| t.expires_at.is_none_or(|expires| expires > now));
+        self.queue.len() != before
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.queue.iter().any(|t| t.level == ToastLevel::Error)
+    }
+
+    pub fn dismiss_errors(&mut self) {
+        self.queue.retain(|t| t.level != ToastLevel::Error);
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    /// The most recent toast.
+    pub fn latest(&self) -> Option<&Toast> {
+        self.queue.back()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Toast> {
+        self.queue.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn info_expires_and_errors_persist() {
+        let mut toasts = Toasts::default();
+        toasts.error("Failed to load");
+        toasts.info("Copied", Duration::from_secs(4));
+        toasts.info("Copied", Duration::from_secs(4));
+        assert_eq!(toasts.iter().count(), 2);
+
+        assert!(!toasts.expire(Instant::now()));
+        assert!(toasts.expire(Instant::now() + Duration::from_secs(5)));
+        assert_eq!(toasts.latest().unwrap().text, "Failed to load");
+
+        toasts.dismiss_errors();
+        assert!(toasts.is_empty());
+    }
+
+    #[test]
+    fn overflow_drops_oldest_info_before_errors() {
+        let mut toasts = Toasts::default();
+        toasts.error("first error");
+        for i in 0..MAX_TOASTS {
+            toasts.info(format!("info {i}"), Duration::from_secs(4));
+        }
+        assert_eq!(toasts.iter().count(), MAX_TOASTS);
+        assert!(toasts.has_errors());
+        assert_eq!(toasts.iter().nth(1).unwrap().text, "info 1");
+    }
+}
@@ -0,0 +1,114 @@
+use std::time::{Duration, Instant};
+
+use super::{App, AppMode};
+use crate::event::{ApiResult, AppEvent, PendingPost};
+
+/// A post (or thread) that `Z` can still take back, with the composer
+/// state it was posted from.
+#[derive(Debug)]
+pub(super) struct UndoPost {
+    tweet_ids: Vec<String>,
+    /// How many posts went out, for the message once they are deleted.
+    posted: usize,
+    post: PendingPost,
+    /// When `Z` stops deleting it.
+    until: Instant,
+    /// Set once `Z` sent the deletes.
+    deleting: bool,
+}
+
+impl App {
+    /// Announce a post that went out, offering `Z` to delete it for
+    /// `undo_post_secs` and put it back in the composer.
+    pub(super) fn offer_undo_post(
+        &mut self,
+        message: &str,
+        tweet_ids: Vec<String>,
+        post: PendingPost,
+    ) {
+        let window = Duration::from_secs(self.config.undo_post_secs);
+        if window.is_zero() || tweet_ids.is_empty() {
+            self.undo_post = None;
+            self.notify(message);
+            return;
+        }
+        self.toasts.info(format!("{message} · Z to undo"), window);
+        self.undo_post = Some(UndoPost {
+            posted: tweet_ids.len(),
+            tweet_ids,
+            post,
+            until: Instant::now() + window,
+            deleting: false,
+        });
+    }
+
+    /// `Z`: delete what was just posted while the undo window is open.
+    pub(super) fn undo_post(&mut self) {
+        let now = Instant::now();
+        match &mut self.undo_post {
+            Some(undo) if !undo.deleting && undo.until > now => {
+                undo.deleting = true;
+                let tweet_ids = undo.tweet_ids.clone();
+                self.notify("Deleting…");
+                self.events.send(AppEvent::DeletePosts { tweet_ids });
+            }
+            Some(undo) if undo.deleting => {}
+            _ => self.notify("Nothing to undo"),
+        }
+    }
+
+    /// Close the undo window once it has passed.
+    pub(super) fn expire_undo_post(&mut self, now: Instant) {
+        if self
+            .undo_post
+            .as_ref()
+            .is_some_and(|undo| !undo.deleting && undo.until <= now)
+        {
+            self.undo_post = None;
+        }
+    }
+
+    /// Once an undone post is deleted, reopen the composer with its text
+    /// so it can be fixed and posted again. When some of a thread's
+    /// deletes failed, keep the rest for `Z` to try again.
+    pub(super) fn apply_posts_deleted(
+        &mut self,
+        tweet_ids: &[String],
+        deleted: &[String],
+        result: ApiResult<()>,
+    ) {
+        let Some(mut undo) = self
+            .undo_post
+            .take_if(|undo| undo.deleting && undo.tweet_ids == tweet_ids)
+        else {
+            return;
+        };
+        if let Err(e) = result {
+            let message = match deleted.len() {
+                0 if tweet_ids.len() > 1 => format!("Failed to delete the thread: {e}"),
+                0 => format!("Failed to delete the post: {e}"),
+                n => format!("Deleted {n} of {} posts, then failed: {e}", tweet_ids.len()),
+            };
+            undo.tweet_ids.retain(|id| !deleted.contains(id));
+            undo.deleting = false;
+            undo.until = Instant::now() + Duration::from_secs(self.config.undo_post_secs);
+            self.undo_post = Some(undo);
+            self.set_error(format!("{message}\nZ tries again."));
+            return;
+        }
+        // Whatever the composer holds by now is kept as a draft.
+        self.save_compose_draft();
+        self.compose_input = undo.post.text;
+        self.compose_reply_to = undo.post.reply_to;
+        self.compose_thread = undo.post.thread;
+        self.compose_reply_settings = undo.post.reply_settings;
+        self.compose_draft_id = None;
+        self.reply_drafts = None;
+        self.mode = AppMode::Compose;
+        self.notify(if undo.posted > 1 {
+            "Thread deleted"
+        } else {
+            "Post deleted"
+        });
+    }
+}
@@ -53,21 +53,80 @@ pub fn load_env_files() {
     }
 }
 
-/// Return candidate .env paths in priority order.
+/// Return candidate .env paths in priority order. A named profile reads
+/// only its own `.env`, so its credentials never mix with another app's.
 fn env_file_paths() -> Vec<PathBuf> {
     let mut paths = Vec::new();
+    if let Some(dir) = crate::config::config_dir() {
+        paths.push(dir.join(".env"));
+    }
+    if crate::config::profile().is_some() {
+        return paths;
+    }
     if let Some(home) = dirs::home_dir() {
-        paths.push(home.join(".config/xplorertui/.env"));
         paths.push(home.join(".config/x-cli/.env"));
     }
     paths.push(PathBuf::from(".env"));
     paths
 }
 
+/// Interactive first-run setup for OAuth 2.0 PKCE.
+///
+/// Prompts on stdin for the X app's client ID (and optional secret) and
+/// appends them to `~/.config/xplorertui/.env` so later launches pick them up.
+/// Must be called with the terminal in cooked mode. Returns `Ok(None)` if the
+/// user leaves the client ID empty.
+pub fn run_setup_wizard() -> std::io::Result<Option<OAuth2Credentials>> {
+    use std::io::{BufRead, Write};
+
+    let prompt = |label: &str| -> std::io::Result<String> {
+        print!("{label}");
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        std::io::stdin().lock().read_line(&mut line)?;
+        Ok(line.trim().to_string())
+    };
+
+    println!("xplorertui setup");
+    println!();
+    println!("Create an app at https://developer.x.com with OAuth 2.0 enabled and");
+    println!("add the callback URL shown during sign-in to its settings.");
+    println!();
+    let client_id = prompt("X client ID (empty to cancel): ")?;
+    if client_id.is_empty() {
+        return Ok(None);
+    }
+    let client_secret = prompt("X client secret (empty for a public client): ")?;
+    let client_secret = (!client_secret.is_empty()).then_some(client_secret);
+
+    let path = crate::config::config_dir()
+        .ok_or_else(|| std::io::Error::other("no home directory"))?
+        .join(".env");
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)?;
+    writeln!(file, "X_CLIENT_ID={client_id}")?;
+    if let Some(ref secret) = client_secret {
+        writeln!(file, "X_CLIENT_SECRET={secret}")?;
+    }
+    println!("Saved to {}", path.display());
+    println!();
+
+    Ok(Some(OAuth2Credentials {
+        client_id,
+        client_secret,
+    }))
+}
+
 /// Load credentials from environment variables, trying .env files first.
 ///
 /// Priority: ~/.config/xplorertui/.env > ~/.config/x-cli/.env > cwd .env
-/// Variables already set in the environment take precedence.
+/// (only the profile's `.env` with `--profile`). Variables already set in
+/// the environment take precedence.
 pub fn load_credentials() -> Result<CredentialSet, CredentialError> {
     load_env_files();
 
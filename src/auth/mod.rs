@@ -20,6 +20,8 @@ pub enum AuthError {
     NoAuthMethod,
     #[error("oauth1 credentials required for this endpoint")]
     OAuth1Required,
+    #[error("this action needs user context; app-only bearer tokens are read-only")]
+    UserContextRequired,
     #[error("http request failed: {0}")]
     Http(#[from] reqwest::Error),
     #[error("failed to parse /2/users/me response: {0}")]
@@ -37,6 +39,16 @@ pub enum AuthMethod {
     BearerOnly,
 }
 
+impl AuthMethod {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OAuth2Pkce => "OAuth 2.0 PKCE",
+            Self::OAuth1 => "OAuth 1.0a",
+            Self::BearerOnly => "app-only bearer token (read-only)",
+        }
+    }
+}
+
 /// Central auth provider that wraps the active strategy.
 #[derive(Debug, Clone)]
 pub struct AuthProvider {
@@ -106,12 +118,13 @@ impl AuthProvider {
         Ok(oauth1::generate_oauth_header(method, url, creds, params))
     }
 
-    /// Call `GET /2/users/me` and return the authenticated user's ID.
+    /// Call `GET {base_url}/users/me` and return the authenticated user's ID.
     pub async fn get_authenticated_user_id(
         &self,
         client: &reqwest::Client,
+        base_url: &str,
     ) -> Result<String, AuthError> {
-        let url = "https://api.x.com/2/users/me";
+        let url = &format!("{base_url}/users/me");
         let auth_header = match self.method {
             AuthMethod::OAuth1 => self.get_oauth_header("GET", url, None)?,
             AuthMethod::BearerOnly => self.get_bearer_header()?,
@@ -1,6 +1,8 @@
 //! OAuth 2.0 Authorization Code flow with PKCE for X API v2.
 
-use std::path::PathBuf;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use oauth2::basic::BasicClient;
@@ -20,8 +22,17 @@ const TOKEN_URL: &str = "https://api.x.com/2/oauth2/token";
 
 const DEFAULT_SCOPES: &[&str] = &[
     "tweet.read",
+    "tweet.write",
     "users.read",
     "bookmark.read",
+    "bookmark.write",
+    "list.read",
+    "list.write",
+    "dm.read",
+    "like.write",
+    "mute.write",
+    "block.write",
+    "tweet.moderate.write",
     "offline.access",
 ];
 
@@ -39,10 +50,8 @@ pub enum OAuth2Error {
     Json(#[from] serde_json::Error),
     #[error("no refresh token available")]
     NoRefreshToken,
-    #[error(
-        "port {0} is already in use — check for conflicts or set oauth_callback_port in config.toml"
-    )]
-    PortInUse(u16),
+    #[error("{0} already in use — check for conflicts or set oauth_callback_port in config.toml")]
+    PortInUse(String),
 }
 
 /// Persisted token data.
@@ -51,37 +60,99 @@ pub struct TokenData {
     pub access_token: String,
     pub refresh_token: Option<String>,
     pub expires_at: Option<DateTime<Utc>>,
+    /// Scopes the token was granted. Empty for tokens saved before these
+    /// were recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
 }
 
-fn tokens_path() -> PathBuf {
-    dirs::home_dir()
+/// Where OAuth 2.0 tokens are stored for the active profile.
+pub fn tokens_path() -> PathBuf {
+    crate::config::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
-        .join(".config/xplorertui/tokens.json")
+        .join("tokens.json")
+}
+
+/// Exclusive advisory lock on `tokens.json`, released on drop. Keeps
+/// concurrent xplorertui processes (e.g. the CLI and the TUI) from
+/// interleaving writes or refreshing the same single-use refresh token.
+struct TokenFileLock {
+    _file: File,
+}
+
+impl TokenFileLock {
+    /// Block until the lock for the token file at `path` is held.
+    fn acquire(path: &Path) -> Result<Self, OAuth2Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path.with_extension("json.lock"))?;
+        file.lock()?;
+        Ok(Self { _file: file })
+    }
+
+    /// [`acquire`](Self::acquire) without blocking the async runtime while
+    /// another process holds the lock.
+    async fn acquire_async(path: PathBuf) -> Result<Self, OAuth2Error> {
+        tokio::task::spawn_blocking(move || Self::acquire(&path))
+            .await
+            .map_err(|e| OAuth2Error::Io(std::io::Error::other(e)))?
+    }
 }
 
 pub fn save_tokens(data: &TokenData) -> Result<(), OAuth2Error> {
     let path = tokens_path();
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
+    let _lock = TokenFileLock::acquire(&path)?;
+    write_tokens(&path, data)
+}
+
+/// Write `data` to a temporary file readable only by the owner, then rename
+/// it over `path`, so readers never see a half-written file. The caller
+/// holds the [`TokenFileLock`].
+fn write_tokens(path: &Path, data: &TokenData) -> Result<(), OAuth2Error> {
     let json = serde_json::to_string_pretty(data)?;
-    std::fs::write(&path, json)?;
+    let tmp = path.with_extension("json.tmp");
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // `mode` only applies when the file is created.
+        if tmp.exists() {
+            fs::set_permissions(&tmp, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    let mut file = options.open(&tmp)?;
+    file.write_all(json.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)?;
     Ok(())
 }
 
 pub fn load_tokens() -> Result<Option<TokenData>, OAuth2Error> {
-    let path = tokens_path();
+    read_tokens(&tokens_path())
+}
+
+fn read_tokens(path: &Path) -> Result<Option<TokenData>, OAuth2Error> {
     if !path.exists() {
         return Ok(None);
     }
-    let json = std::fs::read_to_string(&path)?;
+    let json = fs::read_to_string(path)?;
     let data: TokenData = serde_json::from_str(&json)?;
     Ok(Some(data))
 }
 
+/// `existing_refresh` and `existing_scopes` stand in for what the token
+/// endpoint leaves out of its response.
 fn token_response_to_data<T: TokenResponse>(
     token_result: &T,
     existing_refresh: Option<&str>,
+    existing_scopes: &[String],
 ) -> TokenData {
     let expires_at = token_result
         .expires_in()
@@ -92,47 +163,99 @@ fn token_response_to_data<T: TokenResponse>(
         .map(|t| t.secret().clone())
         .or_else(|| existing_refresh.map(|s| s.to_string()));
 
+    let scopes = match token_result.scopes() {
+        Some(granted) => granted.iter().map(|s| s.to_string()).collect(),
+        None => existing_scopes.to_vec(),
+    };
+
     TokenData {
         access_token: token_result.access_token().secret().clone(),
         refresh_token,
         expires_at,
+        scopes,
     }
 }
 
+/// The scopes to ask for: the defaults, whatever the current token was
+/// granted beyond them, and `extra`, without repeats.
+fn requested_scopes(granted: &[String], extra: &[String]) -> Vec<String> {
+    let mut scopes: Vec<String> = DEFAULT_SCOPES.iter().map(|s| s.to_string()).collect();
+    for scope in granted.iter().chain(extra) {
+        if !scopes.contains(scope) {
+            scopes.push(scope.clone());
+        }
+    }
+    scopes
+}
+
 /// Build the redirect URL for OAuth callbacks.
 ///
 /// Must match the callback URL registered in the X Developer Portal.
-fn redirect_url(port: u16) -> String {
+pub fn redirect_url(port: u16) -> String {
     format!("http://127.0.0.1:{port}/callback")
 }
 
+/// The callback ports to try, in order: `port` and the ones after it, up
+/// to `range` in all. Each one's redirect URL has to be registered for the
+/// fallback to work.
+pub fn callback_ports(port: u16, range: u16) -> Vec<u16> {
+    (0..range.max(1))
+        .map_while(|offset| port.checked_add(offset))
+        .collect()
+}
+
+/// Listen on the first of `ports` that is free, returning the port.
+async fn bind_callback(ports: &[u16]) -> Result<(TcpListener, u16), OAuth2Error> {
+    for &port in ports {
+        match TcpListener::bind(("127.0.0.1", port)).await {
+            Ok(listener) => {
+                let port = listener.local_addr()?.port();
+                return Ok((listener, port));
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => continue,
+            Err(e) => return Err(OAuth2Error::Io(e)),
+        }
+    }
+    Err(OAuth2Error::PortInUse(match ports {
+        [port] => format!("port {port} is"),
+        [first, .., last] => format!("ports {first}-{last} are"),
+        [] => "no callback port is".to_string(),
+    }))
+}
+
 /// Run the full OAuth 2.0 PKCE authorization flow.
 ///
-/// 1. Bind a local TCP listener on the configured callback port.
+/// 1. Bind a local TCP listener on the first free callback port.
 /// 2. Open the user's browser to the X authorization page.
 /// 3. Wait for the redirect callback.
 /// 4. Exchange the authorization code for tokens.
 /// 5. Persist tokens to disk.
+///
+/// Asks for the default scopes plus any the stored token already has and
+/// `extra_scopes`, so signing in again for one more scope doesn't drop the
+/// others. The stored refresh token is kept if the new grant has none.
 pub async fn start_pkce_flow(
     creds: &OAuth2Credentials,
-    port: u16,
+    ports: &[u16],
+    extra_scopes: &[String],
 ) -> Result<TokenData, OAuth2Error> {
-    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
-        .await
-        .map_err(|e| {
-            if e.kind() == std::io::ErrorKind::AddrInUse {
-                OAuth2Error::PortInUse(port)
-            } else {
-                OAuth2Error::Io(e)
-            }
-        })?;
+    let (listener, port) = bind_callback(ports).await?;
+    let redirect_url = redirect_url(port);
 
     println!("Starting OAuth 2.0 PKCE authorization flow...");
+    if ports.first().is_some_and(|&first| first != port) {
+        println!(
+            "Port {} is busy, so the callback uses port {port}. X only redirects to \
+             callback URIs registered for your app in the developer portal, so \
+             {redirect_url} must be one of them.",
+            ports[0]
+        );
+    } else {
+        println!("Callback URI: {redirect_url}");
+    }
     println!("Your browser should open for authorization.");
     println!();
 
-    let redirect_url = redirect_url(port);
-
     let mut client = BasicClient::new(ClientId::new(creds.client_id.clone()))
         .set_auth_uri(AuthUrl::new(AUTH_URL.to_string()).expect("valid auth URL"))
         .set_token_uri(TokenUrl::new(TOKEN_URL.to_string()).expect("valid token URL"))
@@ -148,8 +271,13 @@ pub async fn start_pkce_flow(
     // Build auth URL.
     let mut auth_request = client.authorize_url(CsrfToken::new_random);
 
-    for scope in DEFAULT_SCOPES {
-        auth_request = auth_request.add_scope(Scope::new(scope.to_string()));
+    let previous = load_tokens().ok().flatten();
+    let scopes = requested_scopes(
+        previous.as_ref().map_or(&[][..], |t| &t.scopes),
+        extra_scopes,
+    );
+    for scope in &scopes {
+        auth_request = auth_request.add_scope(Scope::new(scope.clone()));
     }
 
     let (auth_url, csrf_state) = auth_request.set_pkce_challenge(pkce_challenge).url();
@@ -220,12 +348,17 @@ pub async fn start_pkce_flow(
         .await
         .map_err(|e| OAuth2Error::Request(e.to_string()))?;
 
-    let data = token_response_to_data(&token_result, None);
+    let previous_refresh = previous.as_ref().and_then(|t| t.refresh_token.as_deref());
+    let data = token_response_to_data(&token_result, previous_refresh, &scopes);
     save_tokens(&data)?;
     Ok(data)
 }
 
 /// Refresh an expired access token using a stored refresh token.
+///
+/// Holds the token file lock throughout. If another process rotated the
+/// refresh token in the meantime, its tokens are returned instead of
+/// spending the now-invalid `refresh`.
 pub async fn refresh_token(
     creds: &OAuth2Credentials,
     refresh: &str,
@@ -240,6 +373,15 @@ pub async fn refresh_token(
         client = client.set_client_secret(ClientSecret::new(secret.clone()));
     }
 
+    let path = tokens_path();
+    let _lock = TokenFileLock::acquire_async(path.clone()).await?;
+    let stored = read_tokens(&path)?;
+    if let Some(stored) = &stored
+        && stored.refresh_token.as_deref() != Some(refresh)
+    {
+        return Ok(stored.clone());
+    }
+
     let http_client = reqwest::Client::new();
     let token_result = client
         .exchange_refresh_token(&RefreshToken::new(refresh.to_string()))
@@ -247,7 +389,77 @@ pub async fn refresh_token(
         .await
         .map_err(|e| OAuth2Error::Request(e.to_string()))?;
 
-    let data = token_response_to_data(&token_result, Some(refresh));
-    save_tokens(&data)?;
+    let scopes = stored.map(|t| t.scopes).unwrap_or_default();
+    let data = token_response_to_data(&token_result, Some(refresh), &scopes);
+    write_tokens(&path, &data)?;
     Ok(data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_saves_leave_a_complete_private_file() {
+        let dir = std::env::temp_dir().join(format!("xplorertui-tokens-{}", std::process::id()));
+        let path = dir.join("tokens.json");
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let _lock = TokenFileLock::acquire(&path).unwrap();
+                    let data = TokenData {
+                        access_token: format!("access-{i}"),
+                        refresh_token: Some(format!("refresh-{i}")),
+                        expires_at: None,
+                        scopes: Vec::new(),
+                    };
+                    write_tokens(&path, &data).unwrap();
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let tokens = read_tokens(&path).unwrap().expect("tokens written");
+        assert!(tokens.access_token.starts_with("access-"));
+        assert!(!path.with_extension("json.tmp").exists());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_busy_callback_port_falls_back_to_the_next() {
+        assert_eq!(callback_ports(8477, 3), [8477, 8478, 8479]);
+        assert_eq!(callback_ports(8477, 0), [8477]);
+        assert_eq!(callback_ports(u16::MAX, 3), [u16::MAX]);
+
+        let held = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let busy = held.local_addr().unwrap().port();
+        // Port 0 stands in for a free one: the OS picks it.
+        let (_listener, port) = bind_callback(&[busy, 0]).await.unwrap();
+        assert_ne!(port, busy);
+        let Err(OAuth2Error::PortInUse(ports)) = bind_callback(&[busy]).await else {
+            panic!("expected PortInUse");
+        };
+        assert_eq!(ports, format!("port {busy} is"));
+    }
+
+    #[test]
+    fn signing_in_again_keeps_granted_scopes_and_adds_the_missing_one() {
+        let granted = vec!["tweet.read".to_string(), "follows.read".to_string()];
+        let scopes = requested_scopes(&granted, &["follows.write".to_string()]);
+        assert_eq!(scopes.len(), DEFAULT_SCOPES.len() + 2);
+        assert_eq!(
+            scopes[DEFAULT_SCOPES.len()..],
+            ["follows.read", "follows.write"]
+        );
+        assert_eq!(requested_scopes(&[], &[]).len(), DEFAULT_SCOPES.len());
+    }
+}
@@ -1,12 +1,21 @@
-use clap::{Parser, Subcommand};
-use color_eyre::eyre::{self, eyre};
+use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::api::XApiClient;
-use crate::api::types::{Includes, Tweet};
-use crate::auth::credentials::load_credentials;
+use clap::{Parser, Subcommand};
+use color_eyre::eyre::{self, bail, eyre};
+
+use crate::api::error::XApiError;
+use crate::api::pagination::Paginator;
+use crate::api::recording::Recording;
+use crate::api::types::{ApiError, Includes, Tweet, User};
+use crate::api::{ApiClientError, FieldSets, XApiClient};
+use crate::app::App;
+use crate::auth::credentials::{CredentialSet, load_credentials};
 use crate::auth::{AuthMethod, AuthProvider};
-use crate::config::load_config;
+use crate::config::{AppConfig, PageKind, load_config};
+use crate::event::ViewKind;
 use crate::openrouter::client::OpenRouterClient;
+use crate::store;
 
 // ---------------------------------------------------------------------------
 // CLI definition
@@ -17,6 +26,34 @@ use crate::openrouter::client::OpenRouterClient;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<CliCommand>,
+    /// Screen-reader-friendly TUI: linear text, no decorative glyphs
+    #[arg(long, global = true)]
+    pub accessible: bool,
+    /// Save every API response under DIR for later replay
+    #[arg(long, global = true, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+    /// Serve API responses from a --record directory instead of the network
+    #[arg(long, global = true, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+    /// Use a separate config, .env, tokens and cache under
+    /// ~/.config/xplorertui/profiles/NAME
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        value_parser = crate::config::parse_profile_name
+    )]
+    pub profile: Option<String>,
+}
+
+impl Cli {
+    pub fn recording(&self) -> Option<Recording> {
+        match (&self.record, &self.replay) {
+            (Some(dir), _) => Some(Recording::Record(dir.clone())),
+            (None, Some(dir)) => Some(Recording::Replay(dir.clone())),
+            (None, None) => None,
+        }
+    }
 }
 
 #[derive(Subcommand)]
@@ -24,20 +61,39 @@ pub enum CliCommand {
     /// Launch the interactive TUI (default)
     Tui,
     /// Run the OAuth 2.0 PKCE authentication flow
-    Auth,
+    Auth {
+        /// Print the callback URIs to register for your app in the X
+        /// developer portal, one per line, and exit
+        #[arg(long)]
+        print_redirect_uri: bool,
+    },
     /// Fetch your Following feed — reverse-chronological posts from accounts
     /// you follow (JSONL). Does not include the algorithmic "For You" feed,
     /// which isn't exposed by the X API v2.
-    Home,
+    Home {
+        #[command(flatten)]
+        pages: PageArgs,
+    },
     /// Fetch your mentions (JSONL)
-    Mentions,
+    Mentions {
+        #[command(flatten)]
+        pages: PageArgs,
+    },
     /// Fetch your bookmarks (JSONL)
-    Bookmarks,
+    Bookmarks {
+        #[command(flatten)]
+        pages: PageArgs,
+    },
     /// Search recent tweets (JSONL)
     Search {
         /// Search query
         query: String,
+        #[command(flatten)]
+        pages: PageArgs,
     },
+    /// Show this month's post consumption against the project cap (JSON).
+    /// Needs an app-only bearer token.
+    Usage,
     /// Look up a user profile (JSONL)
     User {
         /// Username (without @)
@@ -70,6 +126,163 @@ pub enum CliCommand {
         #[arg(short, long)]
         model: String,
     },
+    /// Render a TUI view as plain text
+    Render {
+        /// View to render
+        view: RenderView,
+        /// Search query (search), username (user), or tweet ID/URL (thread)
+        arg: Option<String>,
+        /// Output width in columns
+        #[arg(long, default_value_t = 80)]
+        width: u16,
+        /// Output height in rows
+        #[arg(long, default_value_t = 40)]
+        height: u16,
+        /// Read JSONL as printed by the other commands from stdin instead
+        /// of fetching
+        #[arg(long)]
+        stdin: bool,
+    },
+    /// Check config, credentials, tokens, ports and terminal support
+    Doctor,
+    /// Inspect config.toml
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+    /// Manage the local cache (timeline snapshots, spilled tweets, profiles)
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Print tweets from the 1% sampled stream as they are posted (JSONL)
+    /// until Ctrl-C. Dropped connections are retried with backoff.
+    #[command(name = "stream-sample")]
+    StreamSample {
+        /// Stop after this many tweets
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+    },
+    /// Run the `[[jobs]]` from the config on their schedules until Ctrl-C
+    Cron {
+        /// Run each job once and exit
+        #[arg(long)]
+        once: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum CacheAction {
+    /// Show how much disk the cache uses
+    Stats,
+    /// Delete everything in the cache
+    Clear,
+    /// Compress files left by older versions and drop old spilled tweets
+    Gc {
+        /// Delete spilled tweets not added to in this many days
+        #[arg(long, value_name = "DAYS", default_value_t = 30)]
+        max_age_days: u64,
+    },
+}
+
+impl CacheAction {
+    fn run(self) -> eyre::Result<()> {
+        match self {
+            Self::Stats => println!("{}", store::cache_stats()?),
+            Self::Clear => println!("Cleared {}", store::clear_cache()?),
+            Self::Gc { max_age_days } => {
+                let max_age = Duration::from_secs(max_age_days.saturating_mul(24 * 60 * 60));
+                let (before, after) = store::gc_cache(max_age)?;
+                println!(
+                    "Freed {}; cache is now {after}",
+                    store::format_size(before.bytes.saturating_sub(after.bytes))
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub enum ConfigAction {
+    /// Report unknown keys, bad types and out-of-range values
+    Check,
+}
+
+impl ConfigAction {
+    fn run(self) -> eyre::Result<()> {
+        match self {
+            Self::Check => {
+                let path = crate::config::config_path()
+                    .ok_or_else(|| eyre!("no home directory for config.toml"))?;
+                // Without a file, the environment overrides are still checked.
+                let (contents, found) = match std::fs::read_to_string(&path) {
+                    Ok(contents) => (contents, true),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => (String::new(), false),
+                    Err(e) => bail!("can't read {}: {e}", path.display()),
+                };
+                let problems =
+                    match crate::config::parse_config(&contents, crate::config::config_env()) {
+                        Ok(config) => config.problems,
+                        Err(e) => vec![e],
+                    };
+                if problems.is_empty() {
+                    if found {
+                        println!("{}: ok", path.display());
+                    } else {
+                        println!("{}: not found, using defaults", path.display());
+                    }
+                    return Ok(());
+                }
+                for problem in &problems {
+                    println!("{}: {problem}", path.display());
+                }
+                // Non-zero for scripts, like `doctor`.
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// How many pages a list command fetches. Without either flag, one page.
+#[derive(Debug, Clone, Copy, clap::Args)]
+pub struct PageArgs {
+    /// Follow pagination through every page, waiting out rate limits
+    #[arg(long)]
+    pub all: bool,
+    /// Stop after this many tweets (follows pagination like --all)
+    #[arg(long, value_name = "N")]
+    pub limit: Option<usize>,
+}
+
+impl PageArgs {
+    /// Fetch the pages these flags ask for and print them as JSONL.
+    async fn print(self, pages: Paginator<'_, Tweet>) -> eyre::Result<()> {
+        let resp = if self.all || self.limit.is_some() {
+            pages
+                .wait_on_rate_limit(true)
+                .all_pages(self.limit.unwrap_or(usize::MAX))
+                .await
+        } else {
+            pages.page_at(None).await
+        }
+        .map_err(|e| eyre!("{}", e.with_remediation()))?;
+        if let Some(tweets) = &resp.data {
+            print_tweets(tweets, &resp.includes)?;
+        }
+        print_errors(&resp.errors)
+    }
+}
+
+/// Views the `render` command can draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum RenderView {
+    Home,
+    Mentions,
+    Bookmarks,
+    Search,
+    User,
+    Thread,
 }
 
 // ---------------------------------------------------------------------------
@@ -78,7 +291,7 @@ pub enum CliCommand {
 
 /// Build a self-contained JSON object for a tweet with its author and media
 /// embedded. Returns a `serde_json::Value` ready for serialization.
-fn denormalize_tweet(tweet: &Tweet, includes: &Option<Includes>) -> serde_json::Value {
+pub(crate) fn denormalize_tweet(tweet: &Tweet, includes: &Option<Includes>) -> serde_json::Value {
     let author = includes
         .as_ref()
         .and_then(|inc| inc.users.as_ref())
@@ -126,6 +339,86 @@ fn print_tweets(tweets: &[Tweet], includes: &Option<Includes>) -> eyre::Result<(
     Ok(())
 }
 
+/// Print partial errors from an otherwise successful response as a
+/// trailing `{"errors": [...]}` JSONL record. Prints nothing when there are none.
+fn print_errors(errors: &Option<Vec<ApiError>>) -> eyre::Result<()> {
+    if let Some(errors) = errors.as_ref().filter(|e| !e.is_empty()) {
+        let line = serde_json::to_string(&serde_json::json!({ "errors": errors }))?;
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Print the sampled stream as JSONL until `limit` tweets, Ctrl-C, or the
+/// reader of stdout goes away. Each line is flushed so `jq` and `tail -f`
+/// see tweets as they arrive.
+async fn stream_sample(client: &XApiClient, limit: Option<usize>) -> eyre::Result<()> {
+    use std::io::Write;
+
+    use crate::api::stream::Backoff;
+
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut printed = 0;
+    let mut backoff = Backoff::default();
+    let mut stdout = std::io::stdout().lock();
+    let run = async {
+        while printed < limit {
+            let error = match client.connect_sample_stream().await {
+                Ok(mut stream) => {
+                    backoff.reset();
+                    loop {
+                        let message = match stream.next_message().await {
+                            Ok(message) => message,
+                            Err(ApiClientError::Deserialize(e)) => {
+                                tracing::warn!("skipping unreadable stream message: {e}");
+                                continue;
+                            }
+                            Err(e) => break e,
+                        };
+                        let line = match &message.data {
+                            Some(tweet) => {
+                                printed += 1;
+                                denormalize_tweet(tweet, &message.includes)
+                            }
+                            None => match message.errors.filter(|e| !e.is_empty()) {
+                                Some(errors) => serde_json::json!({ "errors": errors }),
+                                None => continue,
+                            },
+                        };
+                        writeln!(stdout, "{}", serde_json::to_string(&line)?)?;
+                        stdout.flush()?;
+                        if printed >= limit {
+                            return Ok(());
+                        }
+                    }
+                }
+                Err(e) => e,
+            };
+            let Some(delay) = backoff.next_delay(&error) else {
+                return Err(eyre!("{}", error.with_remediation()));
+            };
+            eprintln!("sampled stream disconnected ({error}), reconnecting in {delay:?}");
+            tokio::time::sleep(delay).await;
+        }
+        Ok(())
+    };
+    let result: eyre::Result<()> = tokio::select! {
+        result = run => result,
+        _ = tokio::signal::ctrl_c() => Ok(()),
+    };
+    match result {
+        // `xplorertui stream-sample | head` closing the pipe is a normal
+        // way to stop.
+        Err(e)
+            if e.downcast_ref::<std::io::Error>()
+                .is_some_and(|e| e.kind() == std::io::ErrorKind::BrokenPipe) =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Client construction (shared with main.rs TUI path)
 // ---------------------------------------------------------------------------
@@ -138,17 +431,29 @@ pub fn build_openrouter_client() -> eyre::Result<OpenRouterClient> {
 }
 
 /// Build an authenticated `XApiClient` from env credentials + config.
-/// Returns an error if no credentials are found or auth setup fails.
-pub fn build_api_client() -> eyre::Result<(XApiClient, crate::auth::credentials::CredentialSet)> {
+/// Returns an error if no credentials are found or auth setup fails, except
+/// when replaying, which never sends a request.
+pub fn build_api_client(
+    recording: Option<Recording>,
+) -> eyre::Result<(XApiClient, crate::auth::credentials::CredentialSet)> {
     let config = load_config();
-    let creds = load_credentials()?;
-    let auth = AuthProvider::new(creds.clone())?;
+    let auth = match (load_credentials(), &recording) {
+        (Ok(creds), _) => AuthProvider::new(creds)?,
+        (Err(_), Some(Recording::Replay(_))) => AuthProvider {
+            method: AuthMethod::BearerOnly,
+            credentials: CredentialSet::default(),
+        },
+        (Err(e), _) => return Err(e.into()),
+    };
+    let creds = auth.credentials.clone();
 
     if auth.method == AuthMethod::OAuth2Pkce && !crate::auth::has_stored_tokens() {
         eprintln!("Hint: Run `xplorertui auth` to authenticate with OAuth 2.0 PKCE.");
     }
 
-    let client = XApiClient::new(auth, config.oauth_callback_port);
+    let client = XApiClient::new(auth, config.oauth_callback_port)
+        .with_recording(recording)
+        .with_fields(FieldSets::from_config(&config.fields));
     Ok((client, creds))
 }
 
@@ -174,7 +479,211 @@ fn parse_tweet_id(id_or_url: &str) -> eyre::Result<String> {
     }
 }
 
-pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
+// ---------------------------------------------------------------------------
+// Headless rendering
+// ---------------------------------------------------------------------------
+
+/// Tweets and users parsed from piped JSONL.
+#[derive(Debug, Default)]
+struct PipedData {
+    tweets: Vec<Tweet>,
+    users: Vec<User>,
+}
+
+/// Parse the JSONL the other commands print: `{"tweet", "author", ...}` and
+/// `{"user"}` records, or bare tweet objects. `{"errors"}` records and blank
+/// lines are skipped.
+fn parse_piped(input: &str) -> eyre::Result<PipedData> {
+    let mut data = PipedData::default();
+    for (n, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut value: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| eyre!("stdin line {}: {e}", n + 1))?;
+        if value.get("errors").is_some() {
+            continue;
+        }
+        if let Some(user) = value.get_mut("user") {
+            data.users.push(serde_json::from_value(user.take())?);
+            continue;
+        }
+        if let Some(author) = value.get_mut("author").filter(|a| !a.is_null()) {
+            data.users.push(serde_json::from_value(author.take())?);
+        }
+        let tweet = value.get_mut("tweet").map(|t| t.take()).unwrap_or(value);
+        data.tweets
+            .push(serde_json::from_value(tweet).map_err(|e| eyre!("stdin line {}: {e}", n + 1))?);
+    }
+    Ok(data)
+}
+
+/// Fetch what `view` shows from the API, in the same shape as piped input.
+async fn fetch_for_render(
+    client: &XApiClient,
+    view: RenderView,
+    arg: Option<&str>,
+    config: &AppConfig,
+) -> eyre::Result<PipedData> {
+    let need_arg = || arg.ok_or_else(|| eyre!("`render {view:?}` needs an argument"));
+    let remediate = |e: ApiClientError| eyre!("{}", e.with_remediation());
+    let users_of = |includes: Option<Includes>| includes.and_then(|i| i.users).unwrap_or_default();
+
+    let resp = match view {
+        RenderView::Home => {
+            let max = config.max_results_for(PageKind::Home);
+            client
+                .get_home_timeline(max, config.timeline_exclude(), None)
+                .await
+        }
+        RenderView::Mentions => {
+            let max = config.max_results_for(PageKind::Mentions);
+            client.get_mentions(max, None).await
+        }
+        RenderView::Bookmarks => {
+            let max = config.max_results_for(PageKind::Bookmarks);
+            client.get_bookmarks(max, None).await
+        }
+        RenderView::Search => {
+            let max = config.max_results_for(PageKind::Search);
+            client.search_tweets(need_arg()?, max, None).await
+        }
+        RenderView::User => {
+            let username = need_arg()?;
+            let user = client
+                .get_user(username.strip_prefix('@').unwrap_or(username))
+                .await
+                .map_err(remediate)?
+                .data
+                .ok_or_else(|| eyre!("user {username} not found"))?;
+            return Ok(PipedData {
+                tweets: Vec::new(),
+                users: vec![user],
+            });
+        }
+        RenderView::Thread => {
+            let tweet_id = parse_tweet_id(need_arg()?)?;
+            let resp = client.get_tweet(&tweet_id).await.map_err(remediate)?;
+            let root = resp
+                .data
+                .ok_or_else(|| eyre!("tweet {tweet_id} not found"))?;
+            let mut users = users_of(resp.includes);
+            let mut tweets = Vec::new();
+            if let Some(ref conv_id) = root.conversation_id {
+                let max = config.max_results_for(PageKind::Thread);
+                let thread = client
+                    .get_conversation_thread(conv_id, max, None)
+                    .await
+                    .map_err(remediate)?;
+                users.extend(users_of(thread.includes));
+                tweets.extend(thread.data.unwrap_or_default());
+                tweets.retain(|t| t.id != tweet_id);
+            }
+            tweets.insert(0, root);
+            return Ok(PipedData { tweets, users });
+        }
+    };
+    let resp = resp.map_err(remediate)?;
+    Ok(PipedData {
+        tweets: resp.data.unwrap_or_default(),
+        users: users_of(resp.includes),
+    })
+}
+
+/// Build an app showing `view` with `data` and print it as plain text.
+fn render_view(
+    view: RenderView,
+    arg: Option<&str>,
+    data: PipedData,
+    width: u16,
+    height: u16,
+) -> eyre::Result<()> {
+    let mut app = App::headless(load_config());
+    for user in data.users.iter().cloned() {
+        app.users_cache.insert(user);
+    }
+    let tweets = data.tweets;
+    let kind = match view {
+        RenderView::Home => {
+            app.home_timeline.tweets = tweets;
+            ViewKind::Home
+        }
+        RenderView::Mentions => {
+            app.mentions.tweets = tweets;
+            ViewKind::Mentions
+        }
+        RenderView::Bookmarks => {
+            app.bookmarks.tweets = tweets;
+            ViewKind::Bookmarks
+        }
+        RenderView::Search => {
+            app.search_query = arg.unwrap_or_default().to_string();
+            app.search_results.tweets = tweets;
+            ViewKind::Search
+        }
+        RenderView::User => {
+            let user = data
+                .users
+                .into_iter()
+                .next()
+                .ok_or_else(|| eyre!("no user to render"))?;
+            let username = user.username.clone();
+            app.viewed_user = Some(user);
+            ViewKind::UserProfile(username)
+        }
+        RenderView::Thread => {
+            let mut tweets = tweets.into_iter();
+            let root = tweets.next().ok_or_else(|| eyre!("no tweet to render"))?;
+            let conv_id = root.conversation_id.clone().unwrap_or(root.id.clone());
+            app.thread_root = Some(root);
+            app.thread_tweets = tweets.collect();
+            ViewKind::Thread(conv_id)
+        }
+    };
+    app.view_stack.clear();
+    app.push_view(kind);
+
+    print!("{}", crate::ui::render_to_text(&app, width, height));
+    Ok(())
+}
+
+pub async fn run_command(cmd: CliCommand, recording: Option<Recording>) -> eyre::Result<()> {
+    if let CliCommand::Render {
+        view,
+        ref arg,
+        width,
+        height,
+        stdin,
+    } = cmd
+    {
+        let data = if stdin {
+            parse_piped(&std::io::read_to_string(std::io::stdin())?)?
+        } else {
+            let (client, _creds) = build_api_client(recording)?;
+            fetch_for_render(&client, view, arg.as_deref(), &load_config()).await?
+        };
+        return render_view(view, arg.as_deref(), data, width, height);
+    }
+
+    if let CliCommand::Cache { action } = cmd {
+        return action.run();
+    }
+
+    if let CliCommand::Config { action } = cmd {
+        return action.run();
+    }
+
+    if let CliCommand::Doctor = cmd {
+        let checks = crate::doctor::run_checks();
+        print!("{}", crate::doctor::report(&checks));
+        // Exit non-zero for scripts, without an error report on top of
+        // the one just printed.
+        if crate::doctor::has_failures(&checks) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Commands that only need the OpenRouter client.
     if let CliCommand::Embed { text, model } = cmd {
         let or_client = build_openrouter_client()?;
@@ -192,64 +701,78 @@ pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
         return Ok(());
     }
 
-    let (mut client, _creds) = build_api_client()?;
+    let (client, _creds) = build_api_client(recording)?;
     let config = load_config();
-    let max = config.default_max_results;
+    let max = |kind| config.max_results_for(kind);
 
     match cmd {
         CliCommand::Tui
-        | CliCommand::Auth
+        | CliCommand::Auth { .. }
         | CliCommand::OpenRouterAuth
-        | CliCommand::Embed { .. } => {
-            unreachable!("tui, auth, openrouter-auth, and embed are handled above")
+        | CliCommand::Embed { .. }
+        | CliCommand::Render { .. }
+        | CliCommand::Cache { .. }
+        | CliCommand::Config { .. }
+        | CliCommand::Doctor => {
+            unreachable!(
+                "tui, auth, openrouter-auth, embed, render, cache, config, and doctor are handled above"
+            )
         }
 
-        CliCommand::Home => {
-            let resp = client
-                .get_home_timeline(max, None)
-                .await
-                .map_err(|e| eyre!("{e}"))?;
-            if let Some(tweets) = &resp.data {
-                print_tweets(tweets, &resp.includes)?;
-            }
+        CliCommand::Home { pages } => {
+            pages
+                .print(client.home_timeline_pages(max(PageKind::Home), config.timeline_exclude()))
+                .await?
         }
-
-        CliCommand::Mentions => {
-            let resp = client
-                .get_mentions(max, None)
-                .await
-                .map_err(|e| eyre!("{e}"))?;
-            if let Some(tweets) = &resp.data {
-                print_tweets(tweets, &resp.includes)?;
-            }
+        CliCommand::Mentions { pages } => {
+            pages
+                .print(client.mentions_pages(max(PageKind::Mentions)))
+                .await?
         }
-
-        CliCommand::Bookmarks => {
-            let resp = client
-                .get_bookmarks(max, None)
-                .await
-                .map_err(|e| eyre!("{e}"))?;
-            if let Some(tweets) = &resp.data {
-                print_tweets(tweets, &resp.includes)?;
-            }
+        CliCommand::Bookmarks { pages } => {
+            pages
+                .print(client.bookmarks_pages(max(PageKind::Bookmarks)))
+                .await?
         }
+        CliCommand::Search { query, pages } => {
+            pages
+                .print(client.search_pages(&query, max(PageKind::Search)))
+                .await?;
+        }
+        CliCommand::StreamSample { limit } => stream_sample(&client, limit).await?,
+        CliCommand::Cron { once } => crate::cron::run(&client, &config, once).await?,
 
-        CliCommand::Search { query } => {
+        CliCommand::Usage => {
             let resp = client
-                .search_tweets(&query, max, None)
+                .get_usage()
                 .await
-                .map_err(|e| eyre!("{e}"))?;
-            if let Some(tweets) = &resp.data {
-                print_tweets(tweets, &resp.includes)?;
-            }
+                .map_err(|e| eyre!("{}", e.with_remediation()))?;
+            let usage = resp.data.unwrap_or_default();
+            let today = chrono::Local::now().date_naive();
+            let line = serde_json::to_string(&serde_json::json!({
+                "usage": usage,
+                "percent_used": usage.percent_used(),
+                "next_reset": usage.next_reset(today),
+            }))?;
+            println!("{line}");
+            print_errors(&resp.errors)?;
         }
 
         CliCommand::User { username } => {
             let username = username.strip_prefix('@').unwrap_or(&username);
-            let resp = client.get_user(username).await.map_err(|e| eyre!("{e}"))?;
+            let resp = client.get_user(username).await.map_err(|e| match e {
+                ApiClientError::ApiError(XApiError::NotFound(_)) => {
+                    eyre!("user @{username} not found")
+                }
+                ApiClientError::ApiError(XApiError::Suspended(_)) => {
+                    eyre!("user @{username} is suspended")
+                }
+                e => eyre!("{}", e.with_remediation()),
+            })?;
             if let Some(user) = &resp.data {
                 let line = serde_json::to_string(&serde_json::json!({ "user": user }))?;
                 println!("{line}");
+                print_errors(&resp.errors)?;
             } else {
                 return Err(eyre!("user @{username} not found"));
             }
@@ -262,7 +785,7 @@ pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
             let resp = client
                 .get_tweet(&tweet_id)
                 .await
-                .map_err(|e| eyre!("{e}"))?;
+                .map_err(|e| eyre!("{}", e.with_remediation()))?;
             let root = resp
                 .data
                 .as_ref()
@@ -271,13 +794,14 @@ pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
             // Print the root tweet.
             let line = serde_json::to_string(&denormalize_tweet(root, &resp.includes))?;
             println!("{line}");
+            print_errors(&resp.errors)?;
 
             // Fetch the conversation thread if there is a conversation_id.
             if let Some(conv_id) = &root.conversation_id {
                 let thread = client
-                    .get_conversation_thread(conv_id, max, None)
+                    .get_conversation_thread(conv_id, max(PageKind::Thread), None)
                     .await
-                    .map_err(|e| eyre!("{e}"))?;
+                    .map_err(|e| eyre!("{}", e.with_remediation()))?;
                 if let Some(tweets) = &thread.data {
                     // Filter out the root tweet (already printed).
                     let replies: Vec<&Tweet> = tweets.iter().filter(|t| t.id != tweet_id).collect();
@@ -287,6 +811,7 @@ pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
                         println!("{line}");
                     }
                 }
+                print_errors(&thread.errors)?;
             }
         }
 
@@ -306,9 +831,9 @@ pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
         CliCommand::Similar { query, model } => {
             let or_client = build_openrouter_client()?;
             let resp = client
-                .search_tweets(&query, max, None)
+                .search_tweets(&query, max(PageKind::Search), None)
                 .await
-                .map_err(|e| eyre!("{e}"))?;
+                .map_err(|e| eyre!("{}", e.with_remediation()))?;
 
             let tweets = resp.data.unwrap_or_default();
             if tweets.is_empty() {
@@ -343,6 +868,7 @@ pub async fn run_command(cmd: CliCommand) -> eyre::Result<()> {
                     println!("{}", serde_json::to_string(&obj)?);
                 }
             }
+            print_errors(&resp.errors)?;
         }
     }
 
@@ -374,6 +900,25 @@ mod tests {
         assert_eq!(parse_tweet_id(url).unwrap(), "9876543210");
     }
 
+    #[test]
+    fn parse_piped_reads_command_output() {
+        let input = concat!(
+            r#"{"tweet":{"id":"1","text":"hi","author_id":"7"},"author":{"id":"7","username":"al","name":"Al"},"media":[]}"#,
+            "\n\n",
+            r#"{"id":"2","text":"bare"}"#,
+            "\n",
+            r#"{"errors":[{"title":"Not Found Error"}]}"#,
+            "\n",
+            r#"{"user":{"id":"8","username":"bo","name":"Bo"}}"#,
+        );
+        let data = parse_piped(input).unwrap();
+        let ids: Vec<&str> = data.tweets.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+        let users: Vec<&str> = data.users.iter().map(|u| u.username.as_str()).collect();
+        assert_eq!(users, ["al", "bo"]);
+        assert!(parse_piped("not json").is_err());
+    }
+
     #[test]
     fn parse_tweet_id_bad_url() {
         let url = "https://example.com/no-status-here";
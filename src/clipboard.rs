@@ -1,5 +1,55 @@
+use std::io::Write;
 use std::process::{Command, Stdio};
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use crate::config::ClipboardMode;
+
+/// Copy text to the clipboard using the configured backend.
+///
+/// Returns the backend that succeeded, for the status line.
+pub fn copy(text: &str, mode: ClipboardMode) -> Result<&'static str, String> {
+    match mode {
+        ClipboardMode::System => copy_to_clipboard(text).map(|()| "system"),
+        ClipboardMode::Osc52 => copy_osc52(text).map(|()| "OSC 52"),
+        ClipboardMode::Auto if is_remote_session() => copy_osc52(text).map(|()| "OSC 52"),
+        ClipboardMode::Auto => match copy_to_clipboard(text) {
+            Ok(()) => Ok("system"),
+            Err(_) => copy_osc52(text).map(|()| "OSC 52"),
+        },
+    }
+}
+
+/// Whether we are running over SSH, where the system clipboard commands
+/// would target the remote host rather than the user's machine.
+fn is_remote_session() -> bool {
+    std::env::var_os("SSH_TTY").is_some() || std::env::var_os("SSH_CONNECTION").is_some()
+}
+
+/// Copy text by asking the terminal emulator to set its clipboard via the
+/// OSC 52 escape sequence. Works across SSH as long as the local terminal
+/// supports it.
+pub fn copy_osc52(text: &str) -> Result<(), String> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(osc52_sequence(text, in_tmux).as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|e| format!("failed to write OSC 52 sequence: {e}"))
+}
+
+/// Build the OSC 52 "set clipboard" sequence, wrapped in a DCS passthrough
+/// when running inside tmux.
+fn osc52_sequence(text: &str, in_tmux: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", STANDARD.encode(text));
+    if in_tmux {
+        format!("\x1bPtmux;\x1b{osc}\x1b\\")
+    } else {
+        osc
+    }
+}
+
 /// Copy text to the system clipboard using platform-specific commands.
 pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
     let result = if cfg!(target_os = "macos") {
@@ -7,7 +57,6 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
             .stdin(Stdio::piped())
             .spawn()
             .and_then(|mut child| {
-                use std::io::Write;
                 if let Some(ref mut stdin) = child.stdin {
                     stdin.write_all(text.as_bytes())?;
                 }
@@ -18,7 +67,6 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
             .stdin(Stdio::piped())
             .spawn()
             .and_then(|mut child| {
-                use std::io::Write;
                 if let Some(ref mut stdin) = child.stdin {
                     stdin.write_all(text.as_bytes())?;
                 }
@@ -31,7 +79,6 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
             .stdin(Stdio::piped())
             .spawn()
             .and_then(|mut child| {
-                use std::io::Write;
                 if let Some(ref mut stdin) = child.stdin {
                     stdin.write_all(text.as_bytes())?;
                 }
@@ -45,3 +92,17 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
         Err(e) => Err(format!("failed to run clipboard command: {e}")),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osc52_sequence_encodes_text() {
+        assert_eq!(osc52_sequence("hi", false), "\x1b]52;c;aGk=\x07");
+        assert_eq!(
+            osc52_sequence("hi", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+        );
+    }
+}
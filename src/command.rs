@@ -3,11 +3,29 @@ use url::Url;
 #[derive(Debug, Clone, PartialEq)]
 pub enum Command {
     User(String),
-    Search(String),
+    /// `:search <query> [--max N]`
+    Search(String, Option<u32>),
+    /// `:local <query>` — search tweets in the local store, offline.
+    Local(String),
     Open(String),
-    Home,
-    Mentions,
-    Bookmarks,
+    /// `:goto <id|url>` — select a loaded tweet, or open it if not loaded.
+    Goto(String),
+    /// `:pipe <shell command>` — send the selection to a command as JSONL.
+    Pipe(String),
+    /// `:home [--max N]`; likewise `:mentions` and `:bookmarks`. With
+    /// `--max`, the view is reloaded with pages of that size.
+    Home(Option<u32>),
+    Mentions(Option<u32>),
+    Bookmarks(Option<u32>),
+    /// `:history` — list the threads and profiles opened recently.
+    History,
+    /// `:drafts` — list the composer drafts closed without posting.
+    Drafts,
+    /// `:domains [all]` — rank the sites linked from the current view, or
+    /// with `all` from every timeline saved locally.
+    Domains(bool),
+    /// `:tags` — rank the hashtags and topics in the current view.
+    Tags,
     Help,
     Auth,
     OpenRouterAuth,
@@ -16,11 +34,73 @@ pub enum Command {
     HuggingFaceModels,
     Cluster,
     Topics,
+    /// `:similar` — rank the stored tweets by how alike they are to the
+    /// selected one.
+    Similar,
     Provider(Option<String>),
     Refresh,
+    /// `:retry` — re-send the request behind the last API error.
+    Retry,
+    Perf,
+    /// `:cache stats` — show how much disk the local cache uses.
+    CacheStats,
+    /// `:doctor` — check the setup and show a report.
+    Doctor,
+    /// `:usage` — show this month's post consumption against the cap.
+    Usage,
+    /// `:mute <user>` — mute an account on X.
+    Mute(String),
+    /// `:unmute <user>` — unmute an account on X.
+    Unmute(String),
+    /// `:block <user>` — block an account on X, after a confirmation.
+    Block(String),
+    /// `:unblock <user>` — unblock an account on X.
+    Unblock(String),
+    /// `:mute-thread` — hide the open thread from Home and Mentions.
+    MuteThread,
+    /// `:unmute-thread` — show the open thread in Home and Mentions again.
+    UnmuteThread,
+    /// `:set <key> <value>` — change a runtime setting.
+    Set(String, String),
+    /// `:list create|edit|rename|delete` — manage your Lists.
+    List(ListCommand),
+    /// `:rules [add|delete]` — manage the filtered stream's rules.
+    Rules(RulesCommand),
+    /// `:stream [stop]` — show tweets matching the rules as they are
+    /// posted, or with `stop` disconnect.
+    Stream(bool),
+    /// `:messages` — list your direct message conversations.
+    Messages,
+    /// `:dm [user]` — open the conversation with a user, or with the
+    /// profile being viewed.
+    Dm(Option<String>),
     Quit,
 }
 
+/// What `:list` does. All but `create` act on the selected List.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListCommand {
+    /// `:list create [name]` — open the List form, with the name if given.
+    Create(String),
+    /// `:list edit` — open the List form for the selected List.
+    Edit,
+    /// `:list rename <name>`
+    Rename(String),
+    /// `:list delete` — after a confirmation.
+    Delete,
+}
+
+/// What `:rules` does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesCommand {
+    /// `:rules` — list the rules.
+    List,
+    /// `:rules add <rule> [--tag <name>]`
+    Add { value: String, tag: Option<String> },
+    /// `:rules delete <id>...`
+    Delete(Vec<String>),
+}
+
 pub fn parse_command(input: &str) -> Option<Command> {
     let input = input.strip_prefix(':').unwrap_or(input).trim();
 
@@ -35,11 +115,22 @@ pub fn parse_command(input: &str) -> Option<Command> {
 
     match cmd {
         "user" if !args.is_empty() => Some(Command::User(strip_at(args).to_owned())),
-        "search" if !args.is_empty() => Some(Command::Search(args.to_owned())),
+        "search" => match split_max_results(args)? {
+            ("", _) => None,
+            (query, max_results) => Some(Command::Search(query.to_owned(), max_results)),
+        },
+        "local" if !args.is_empty() => Some(Command::Local(args.to_owned())),
         "open" if !args.is_empty() => Some(Command::Open(args.to_owned())),
-        "home" | "following" => Some(Command::Home),
-        "mentions" | "m" => Some(Command::Mentions),
-        "bookmarks" | "b" => Some(Command::Bookmarks),
+        "goto" if !args.is_empty() => Some(Command::Goto(args.to_owned())),
+        "pipe" if !args.is_empty() => Some(Command::Pipe(args.to_owned())),
+        "home" | "following" => Some(Command::Home(split_max_results(args)?.1)),
+        "mentions" | "m" => Some(Command::Mentions(split_max_results(args)?.1)),
+        "bookmarks" | "b" => Some(Command::Bookmarks(split_max_results(args)?.1)),
+        "history" => Some(Command::History),
+        "drafts" => Some(Command::Drafts),
+        "domains" if args.is_empty() => Some(Command::Domains(false)),
+        "domains" if args == "all" => Some(Command::Domains(true)),
+        "tags" => Some(Command::Tags),
         "help" | "h" => Some(Command::Help),
         "auth" | "login" => Some(Command::Auth),
         "openrouter-auth" | "or-auth" => Some(Command::OpenRouterAuth),
@@ -48,14 +139,98 @@ pub fn parse_command(input: &str) -> Option<Command> {
         "hf-models" | "hf" => Some(Command::HuggingFaceModels),
         "cluster" => Some(Command::Cluster),
         "topics" => Some(Command::Topics),
+        "similar" | "more-like-this" => Some(Command::Similar),
         "provider" if args.is_empty() => Some(Command::Provider(None)),
         "provider" => Some(Command::Provider(Some(args.to_owned()))),
         "refresh" | "r" => Some(Command::Refresh),
+        "retry" => Some(Command::Retry),
+        "perf" => Some(Command::Perf),
+        "cache" if args.is_empty() || args == "stats" => Some(Command::CacheStats),
+        "doctor" => Some(Command::Doctor),
+        "usage" => Some(Command::Usage),
+        "mute" if !args.is_empty() => Some(Command::Mute(strip_at(args).to_owned())),
+        "unmute" if !args.is_empty() => Some(Command::Unmute(strip_at(args).to_owned())),
+        "block" if !args.is_empty() => Some(Command::Block(strip_at(args).to_owned())),
+        "unblock" if !args.is_empty() => Some(Command::Unblock(strip_at(args).to_owned())),
+        "mute-thread" => Some(Command::MuteThread),
+        "unmute-thread" => Some(Command::UnmuteThread),
+        "set" => {
+            let (key, value) = args.split_once(char::is_whitespace)?;
+            Some(Command::Set(key.to_owned(), value.trim().to_owned()))
+        }
+        "list" => {
+            let (sub, rest) = match args.split_once(char::is_whitespace) {
+                Some((sub, rest)) => (sub, rest.trim()),
+                None => (args, ""),
+            };
+            let list_command = match sub {
+                "create" | "new" => ListCommand::Create(rest.to_owned()),
+                "edit" if rest.is_empty() => ListCommand::Edit,
+                "rename" if !rest.is_empty() => ListCommand::Rename(rest.to_owned()),
+                "delete" if rest.is_empty() => ListCommand::Delete,
+                _ => return None,
+            };
+            Some(Command::List(list_command))
+        }
+        "rules" => {
+            let (sub, rest) = match args.split_once(char::is_whitespace) {
+                Some((sub, rest)) => (sub, rest.trim()),
+                None => (args, ""),
+            };
+            let rules_command = match sub {
+                "" | "list" if rest.is_empty() => RulesCommand::List,
+                "add" if !rest.is_empty() => {
+                    let (value, tag) = split_tag(rest);
+                    RulesCommand::Add {
+                        value: value.to_owned(),
+                        tag: tag.map(str::to_owned),
+                    }
+                }
+                "delete" | "rm" if !rest.is_empty() => {
+                    RulesCommand::Delete(rest.split_whitespace().map(str::to_owned).collect())
+                }
+                _ => return None,
+            };
+            Some(Command::Rules(rules_command))
+        }
+        "stream" if args.is_empty() => Some(Command::Stream(false)),
+        "stream" if args == "stop" => Some(Command::Stream(true)),
+        "messages" | "dms" => Some(Command::Messages),
+        "dm" if args.is_empty() => Some(Command::Dm(None)),
+        "dm" => Some(Command::Dm(Some(strip_at(args).to_owned()))),
         "quit" | "q" => Some(Command::Quit),
         _ => None,
     }
 }
 
+/// Take a trailing `--tag <name>` off a stream rule.
+fn split_tag(args: &str) -> (&str, Option<&str>) {
+    match args.rsplit_once("--tag") {
+        Some((rule, tag))
+            if rule.ends_with(char::is_whitespace)
+                && tag.starts_with(char::is_whitespace)
+                && !rule.trim().is_empty()
+                && !tag.trim().is_empty() =>
+        {
+            (rule.trim_end(), Some(tag.trim()))
+        }
+        _ => (args, None),
+    }
+}
+
+/// Take a trailing `--max N` off `args`. `None` if `N` isn't a number.
+fn split_max_results(args: &str) -> Option<(&str, Option<u32>)> {
+    match args.rsplit_once("--max") {
+        Some((rest, n))
+            if (rest.is_empty() || rest.ends_with(char::is_whitespace))
+                && n.starts_with(char::is_whitespace) =>
+        {
+            Some((rest.trim_end(), Some(n.trim().parse().ok()?)))
+        }
+        _ => Some((args, None)),
+    }
+}
+
 pub fn parse_tweet_url(input: &str) -> Option<String> {
     let trimmed = input.trim();
 
@@ -105,7 +280,21 @@ mod tests {
     fn test_parse_command_search() {
         assert_eq!(
             parse_command(":search rust lang"),
-            Some(Command::Search("rust lang".into()))
+            Some(Command::Search("rust lang".into(), None))
+        );
+        assert_eq!(
+            parse_command(":search rust --max 100"),
+            Some(Command::Search("rust".into(), Some(100)))
+        );
+        assert_eq!(parse_command(":search rust --max lots"), None);
+        assert_eq!(
+            parse_command(":local rust lang"),
+            Some(Command::Local("rust lang".into()))
+        );
+        assert_eq!(parse_command(":local"), None);
+        assert_eq!(
+            parse_command(":mentions --max 50"),
+            Some(Command::Mentions(Some(50)))
         );
     }
 
@@ -113,19 +302,80 @@ mod tests {
     fn test_parse_command_aliases() {
         assert_eq!(parse_command(":q"), Some(Command::Quit));
         assert_eq!(parse_command(":h"), Some(Command::Help));
-        assert_eq!(parse_command(":b"), Some(Command::Bookmarks));
-        assert_eq!(parse_command(":m"), Some(Command::Mentions));
+        assert_eq!(parse_command(":b"), Some(Command::Bookmarks(None)));
+        assert_eq!(parse_command(":m"), Some(Command::Mentions(None)));
         assert_eq!(parse_command(":auth"), Some(Command::Auth));
         assert_eq!(parse_command(":login"), Some(Command::Auth));
         assert_eq!(parse_command(":refresh"), Some(Command::Refresh));
         assert_eq!(parse_command(":r"), Some(Command::Refresh));
+        assert_eq!(parse_command(":retry"), Some(Command::Retry));
         assert_eq!(parse_command(":embeddings"), Some(Command::Embeddings));
+        assert_eq!(parse_command(":similar"), Some(Command::Similar));
         assert_eq!(
             parse_command(":openrouter-models"),
             Some(Command::OpenRouter)
         );
         assert_eq!(parse_command(":openrouter"), Some(Command::OpenRouter));
         assert_eq!(parse_command(":topics"), Some(Command::Topics));
+        assert_eq!(parse_command(":perf"), Some(Command::Perf));
+        assert_eq!(parse_command(":cache stats"), Some(Command::CacheStats));
+        assert_eq!(parse_command(":cache"), Some(Command::CacheStats));
+        assert_eq!(parse_command(":cache nuke"), None);
+        assert_eq!(parse_command(":doctor"), Some(Command::Doctor));
+        assert_eq!(parse_command(":usage"), Some(Command::Usage));
+        assert_eq!(parse_command(":mute-thread"), Some(Command::MuteThread));
+        assert_eq!(
+            parse_command(":mute @bob"),
+            Some(Command::Mute("bob".into()))
+        );
+        assert_eq!(
+            parse_command(":unmute bob"),
+            Some(Command::Unmute("bob".into()))
+        );
+        assert_eq!(parse_command(":mute"), None);
+        assert_eq!(
+            parse_command(":block @bob"),
+            Some(Command::Block("bob".into()))
+        );
+        assert_eq!(
+            parse_command(":unblock bob"),
+            Some(Command::Unblock("bob".into()))
+        );
+        assert_eq!(parse_command(":block"), None);
+        assert_eq!(parse_command(":unmute-thread"), Some(Command::UnmuteThread));
+        assert_eq!(parse_command(":history"), Some(Command::History));
+        assert_eq!(parse_command(":drafts"), Some(Command::Drafts));
+        assert_eq!(parse_command(":domains"), Some(Command::Domains(false)));
+        assert_eq!(parse_command(":domains all"), Some(Command::Domains(true)));
+        assert_eq!(parse_command(":domains foo"), None);
+        assert_eq!(parse_command(":tags"), Some(Command::Tags));
+    }
+
+    #[test]
+    fn test_parse_command_set() {
+        assert_eq!(
+            parse_command(":set tick_rate_fps 60"),
+            Some(Command::Set("tick_rate_fps".into(), "60".into()))
+        );
+        assert_eq!(parse_command(":set tick_rate_fps"), None);
+    }
+
+    #[test]
+    fn test_parse_command_goto() {
+        assert_eq!(
+            parse_command(":goto https://x.com/alice/status/42"),
+            Some(Command::Goto("https://x.com/alice/status/42".into()))
+        );
+        assert_eq!(parse_command(":goto"), None);
+    }
+
+    #[test]
+    fn test_parse_command_pipe_keeps_shell_syntax() {
+        assert_eq!(
+            parse_command(":pipe jq -r '.tweet.text' | wc -l"),
+            Some(Command::Pipe("jq -r '.tweet.text' | wc -l".into()))
+        );
+        assert_eq!(parse_command(":pipe "), None);
     }
 
     #[test]
@@ -138,13 +388,13 @@ mod tests {
     fn test_parse_command_cluster() {
         assert_eq!(parse_command(":cluster"), Some(Command::Cluster));
         assert_eq!(parse_command("cluster"), Some(Command::Cluster));
-        assert_eq!(parse_command(":home"), Some(Command::Home));
+        assert_eq!(parse_command(":home"), Some(Command::Home(None)));
     }
 
     #[test]
     fn test_parse_command_following_alias() {
-        assert_eq!(parse_command(":following"), Some(Command::Home));
-        assert_eq!(parse_command("following"), Some(Command::Home));
+        assert_eq!(parse_command(":following"), Some(Command::Home(None)));
+        assert_eq!(parse_command("following"), Some(Command::Home(None)));
     }
 
     #[test]
@@ -174,6 +424,74 @@ mod tests {
         assert_eq!(parse_tweet_url("not a url at all"), None);
     }
 
+    #[test]
+    fn test_parse_command_dm() {
+        assert_eq!(parse_command(":dms"), Some(Command::Messages));
+        assert_eq!(parse_command(":dm"), Some(Command::Dm(None)));
+        assert_eq!(
+            parse_command(":dm @alice"),
+            Some(Command::Dm(Some("alice".into())))
+        );
+    }
+
+    #[test]
+    fn test_parse_command_list() {
+        assert_eq!(
+            parse_command(":list create Rust folks"),
+            Some(Command::List(ListCommand::Create("Rust folks".into())))
+        );
+        assert_eq!(
+            parse_command(":list new"),
+            Some(Command::List(ListCommand::Create(String::new())))
+        );
+        assert_eq!(
+            parse_command(":list rename  Rustaceans "),
+            Some(Command::List(ListCommand::Rename("Rustaceans".into())))
+        );
+        assert_eq!(
+            parse_command(":list delete"),
+            Some(Command::List(ListCommand::Delete))
+        );
+        assert_eq!(parse_command(":list rename"), None);
+        assert_eq!(parse_command(":list delete now"), None);
+        assert_eq!(parse_command(":list"), None);
+    }
+
+    #[test]
+    fn test_parse_command_rules() {
+        assert_eq!(
+            parse_command(":rules"),
+            Some(Command::Rules(RulesCommand::List))
+        );
+        assert_eq!(
+            parse_command(":rules add ratatui -is:retweet --tag tui news"),
+            Some(Command::Rules(RulesCommand::Add {
+                value: "ratatui -is:retweet".into(),
+                tag: Some("tui news".into()),
+            }))
+        );
+        assert_eq!(
+            parse_command(":rules add from:alice"),
+            Some(Command::Rules(RulesCommand::Add {
+                value: "from:alice".into(),
+                tag: None,
+            }))
+        );
+        assert_eq!(
+            parse_command(":rules rm 1 2"),
+            Some(Command::Rules(RulesCommand::Delete(vec![
+                "1".into(),
+                "2".into()
+            ])))
+        );
+        assert_eq!(parse_command(":rules add"), None);
+        assert_eq!(parse_command(":rules delete"), None);
+
+        assert_eq!(parse_command(":stream"), Some(Command::Stream(false)));
+        assert_eq!(parse_command(":stream stop"), Some(Command::Stream(true)));
+        assert_eq!(parse_command(":stream now"), None);
+    }
+
     #[test]
     fn test_strip_at() {
         assert_eq!(strip_at("@alice"), "alice");
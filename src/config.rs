@@ -1,18 +1,31 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use serde::{Deserialize, Serialize};
 
+use crate::api::TimelineExclude;
+use crate::api::recording::Recording;
+use crate::api::types::{Tweet, User};
+use crate::ui::theme::ThemeChoice;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     #[serde(default = "default_tick_rate")]
     pub tick_rate_fps: f64,
     #[serde(default = "default_max_results")]
     pub default_max_results: u32,
+    /// Page sizes for particular views, overriding `default_max_results`.
+    #[serde(default)]
+    pub max_results: MaxResultsConfig,
     #[serde(default)]
     pub default_view: DefaultView,
     #[serde(default = "default_oauth_callback_port")]
     pub oauth_callback_port: u16,
+    /// How many ports, from `oauth_callback_port` up, sign-in tries when
+    /// the ones before are busy.
+    #[serde(default = "default_oauth_callback_port_range")]
+    pub oauth_callback_port_range: u16,
     #[serde(default = "default_openrouter_callback_port")]
     pub openrouter_callback_port: u16,
     /// Base URL for a local MLX embedding server (e.g. "http://localhost:8678").
@@ -28,6 +41,505 @@ pub struct AppConfig {
     /// Falls back to `DEFAULT_MLX_CHAT_MODEL` when not set.
     #[serde(default)]
     pub mlx_chat_model: Option<String>,
+    /// How long a cached user profile is served without revalidating.
+    #[serde(default = "default_profile_cache_ttl_secs")]
+    pub profile_cache_ttl_secs: u64,
+    /// Maximum number of users kept in the author cache.
+    #[serde(default = "default_users_cache_capacity")]
+    pub users_cache_capacity: usize,
+    /// Maximum tweets held in memory per timeline; the oldest loaded tweets
+    /// beyond this are spilled to the local store. `0` disables the cap.
+    #[serde(default = "default_max_timeline_tweets")]
+    pub max_timeline_tweets: usize,
+    /// How yank actions reach the clipboard.
+    #[serde(default)]
+    pub clipboard: ClipboardMode,
+    /// Fetch OpenGraph metadata for links in the selected tweet and show a
+    /// preview card. Off by default since it contacts third-party sites.
+    #[serde(default)]
+    pub link_previews: bool,
+    /// Screen-reader-friendly output: linear text instead of boxed cards,
+    /// no decorative glyphs, and view changes announced in the status line.
+    /// Also enabled by `--accessible`.
+    #[serde(default)]
+    pub accessible: bool,
+    /// Record or replay API responses. Set from `--record`/`--replay`, not
+    /// read from the config file.
+    #[serde(skip)]
+    pub recording: Option<Recording>,
+    /// Color theme: `auto`, `default`, `basic16`, or `high-contrast`.
+    #[serde(default)]
+    pub theme: ThemeChoice,
+    /// Which elements a tweet card shows, and in what order.
+    #[serde(default)]
+    pub card_layout: CardLayout,
+    /// Confirmation prompts to skip, by kind: `mute`, `unbookmark`, `block`.
+    #[serde(default)]
+    pub skip_confirm: Vec<String>,
+    /// How long a fetch may go without a response before it is abandoned.
+    /// `0` waits forever.
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// How long informational toasts stay up. Errors stay until dismissed.
+    #[serde(default = "default_toast_duration_secs")]
+    pub toast_duration_secs: u64,
+    /// How long after posting `Z` can still delete the post. `0` turns the
+    /// undo window off.
+    #[serde(default = "default_undo_post_secs")]
+    pub undo_post_secs: u64,
+    /// Changes to the field and expansion lists sent with API requests.
+    #[serde(default)]
+    pub fields: FieldsConfig,
+    /// Leave replies out of the home and user timelines (server-side).
+    #[serde(default)]
+    pub exclude_replies: bool,
+    /// Leave retweets out of the home and user timelines (server-side).
+    #[serde(default)]
+    pub exclude_retweets: bool,
+    /// Fold tweets that share a link or repeat another tweet's text into one
+    /// card with a count, in the home, mentions and user timelines.
+    #[serde(default = "default_collapse_duplicates")]
+    pub collapse_duplicates: bool,
+    /// Fold this many or more consecutive tweets by one author into one
+    /// card, in the same timelines. `0` leaves them apart.
+    #[serde(default)]
+    pub collapse_author_runs: usize,
+    /// Tweets to highlight wherever they show up.
+    #[serde(default)]
+    pub watch: WatchConfig,
+    /// What the status bar shows, left- and right-aligned.
+    #[serde(default)]
+    pub status_bar: StatusBarConfig,
+    /// Scheduled jobs run by `xplorertui cron`.
+    #[serde(default)]
+    pub jobs: Vec<JobConfig>,
+    /// What [`load_config`] found wrong with the file, for the TUI to show.
+    #[serde(skip)]
+    pub problems: Vec<String>,
+}
+
+impl AppConfig {
+    /// Page size for `kind`: its `[max_results]` entry, or the default.
+    pub fn max_results_for(&self, kind: PageKind) -> u32 {
+        self.max_results
+            .get(kind)
+            .unwrap_or(self.default_max_results)
+    }
+
+    /// What the timeline endpoints should leave out.
+    pub fn timeline_exclude(&self) -> TimelineExclude {
+        TimelineExclude {
+            replies: self.exclude_replies,
+            retweets: self.exclude_retweets,
+        }
+    }
+
+    /// The OAuth callback ports sign-in tries, in order.
+    pub fn oauth_callback_ports(&self) -> Vec<u16> {
+        crate::auth::oauth2_pkce::callback_ports(
+            self.oauth_callback_port,
+            self.oauth_callback_port_range,
+        )
+    }
+}
+
+/// Per-list changes to the fields requested from the API, e.g. to drop
+/// `entities` and media for low bandwidth or to add `geo`:
+///
+/// ```toml
+/// [fields.tweet]
+/// remove = ["entities"]
+/// add = ["geo"]
+///
+/// [fields.expansions]
+/// remove = ["attachments.media_keys"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldsConfig {
+    /// `tweet.fields`
+    #[serde(default)]
+    pub tweet: FieldOverrides,
+    #[serde(default)]
+    pub expansions: FieldOverrides,
+    /// `user.fields`
+    #[serde(default)]
+    pub user: FieldOverrides,
+    /// `media.fields`
+    #[serde(default)]
+    pub media: FieldOverrides,
+}
+
+/// Lists whose page size can be set on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageKind {
+    Home,
+    Mentions,
+    Bookmarks,
+    Search,
+    UserTimeline,
+    Thread,
+    /// Followers and following.
+    Follows,
+}
+
+/// Per-view page sizes; unset views use `default_max_results`. Each
+/// endpoint still clamps to its own limits.
+///
+/// ```toml
+/// [max_results]
+/// search = 100
+/// home = 20
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaxResultsConfig {
+    #[serde(default)]
+    pub home: Option<u32>,
+    #[serde(default)]
+    pub mentions: Option<u32>,
+    #[serde(default)]
+    pub bookmarks: Option<u32>,
+    #[serde(default)]
+    pub search: Option<u32>,
+    #[serde(default)]
+    pub user_timeline: Option<u32>,
+    #[serde(default)]
+    pub thread: Option<u32>,
+    #[serde(default)]
+    pub follows: Option<u32>,
+}
+
+impl MaxResultsConfig {
+    pub fn get(&self, kind: PageKind) -> Option<u32> {
+        *self.slot(kind)
+    }
+
+    pub fn set(&mut self, kind: PageKind, max_results: u32) {
+        *self.slot_mut(kind) = Some(max_results);
+    }
+
+    fn slot(&self, kind: PageKind) -> &Option<u32> {
+        match kind {
+            PageKind::Home => &self.home,
+            PageKind::Mentions => &self.mentions,
+            PageKind::Bookmarks => &self.bookmarks,
+            PageKind::Search => &self.search,
+            PageKind::UserTimeline => &self.user_timeline,
+            PageKind::Thread => &self.thread,
+            PageKind::Follows => &self.follows,
+        }
+    }
+
+    fn slot_mut(&mut self, kind: PageKind) -> &mut Option<u32> {
+        match kind {
+            PageKind::Home => &mut self.home,
+            PageKind::Mentions => &mut self.mentions,
+            PageKind::Bookmarks => &mut self.bookmarks,
+            PageKind::Search => &mut self.search,
+            PageKind::UserTimeline => &mut self.user_timeline,
+            PageKind::Thread => &mut self.thread,
+            PageKind::Follows => &mut self.follows,
+        }
+    }
+}
+
+/// Watch rules: matching tweets get a colored gutter in every timeline.
+/// Unlike the exclusions, nothing is hidden.
+///
+/// ```toml
+/// [watch]
+/// keywords = ["rust 2024", "ratatui"]
+/// authors = ["jessearmand"]
+/// notify = true
+///
+/// [[watch.rules]]
+/// keywords = ["outage"]
+/// append_to = "~/x/outages.jsonl"
+/// webhook = "https://example.com/hooks/x"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchConfig {
+    /// Words or phrases to look for in the text, case-insensitive.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Usernames (with or without `@`), case-insensitive.
+    #[serde(default)]
+    pub authors: Vec<String>,
+    /// Show a toast when newly loaded tweets match.
+    #[serde(default)]
+    pub notify: bool,
+    /// Further rules, each delivering the tweets it matches to its sinks.
+    #[serde(default)]
+    pub rules: Vec<WatchRule>,
+}
+
+impl WatchConfig {
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty() && self.authors.is_empty() && self.rules.is_empty()
+    }
+
+    /// Whether `tweet` (by `author`, when known) matches a rule.
+    pub fn matches(&self, tweet: &Tweet, author: Option<&User>) -> bool {
+        matches_watch_terms(&self.keywords, &self.authors, tweet, author)
+            || self.rules.iter().any(|rule| rule.matches(tweet, author))
+    }
+
+    /// Whether any rule has somewhere to send the tweets it matches.
+    pub fn has_sinks(&self) -> bool {
+        self.rules.iter().any(WatchRule::has_sink)
+    }
+
+    /// The rules with a sink that `tweet` (by `author`, when known) matches.
+    pub fn sink_rules<'a>(
+        &'a self,
+        tweet: &'a Tweet,
+        author: Option<&'a User>,
+    ) -> impl Iterator<Item = &'a WatchRule> {
+        self.rules
+            .iter()
+            .filter(move |rule| rule.has_sink() && rule.matches(tweet, author))
+    }
+}
+
+/// A `[[watch.rules]]` entry. Matching tweets are highlighted like the
+/// top-level `[watch]` ones and, the first time they load, delivered as a
+/// JSONL record (as printed by the CLI) to every sink that is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchRule {
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub authors: Vec<String>,
+    /// File to append the record to (`~/` is expanded).
+    #[serde(default)]
+    pub append_to: Option<String>,
+    /// URL to POST the record to as JSON.
+    #[serde(default)]
+    pub webhook: Option<String>,
+    /// Shell command to run with the record on stdin.
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+impl WatchRule {
+    pub fn matches(&self, tweet: &Tweet, author: Option<&User>) -> bool {
+        matches_watch_terms(&self.keywords, &self.authors, tweet, author)
+    }
+
+    pub fn has_sink(&self) -> bool {
+        self.append_to.is_some() || self.webhook.is_some() || self.command.is_some()
+    }
+}
+
+/// Whether `tweet` is by one of `authors` or mentions one of `keywords`.
+fn matches_watch_terms(
+    keywords: &[String],
+    authors: &[String],
+    tweet: &Tweet,
+    author: Option<&User>,
+) -> bool {
+    if let Some(author) = author {
+        let by_author = authors.iter().any(|name| {
+            name.trim_start_matches('@')
+                .eq_ignore_ascii_case(&author.username)
+        });
+        if by_author {
+            return true;
+        }
+    }
+    if keywords.is_empty() {
+        return false;
+    }
+    let text = tweet
+        .note_tweet
+        .as_ref()
+        .map_or(tweet.text.as_str(), |nt| nt.text.as_str())
+        .to_lowercase();
+    keywords
+        .iter()
+        .any(|keyword| !keyword.is_empty() && text.contains(&keyword.to_lowercase()))
+}
+
+/// Status bar segments, in order, for each side of the bar:
+///
+/// ```toml
+/// [status_bar]
+/// left = ["mode", "view", "spinner"]
+/// right = ["unread", "rate_limit", "clock"]
+/// ```
+///
+/// Segments: `mode`, `account` (the `--profile` in use, and what an
+/// app-only bearer token can't do), `view`,
+/// `spinner`, `selection` (batch selection size), `progress` (bulk
+/// actions), `unread` (new tweets in Following and Mentions), `rate_limit`
+/// and `clock`. Unknown segments are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusBarConfig {
+    #[serde(default = "default_status_left")]
+    pub left: StatusSegments,
+    #[serde(default = "default_status_right")]
+    pub right: StatusSegments,
+}
+
+impl StatusBarConfig {
+    pub fn shows(&self, segment: StatusSegment) -> bool {
+        self.left.0.contains(&segment) || self.right.0.contains(&segment)
+    }
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        Self {
+            left: default_status_left(),
+            right: default_status_right(),
+        }
+    }
+}
+
+fn default_status_left() -> StatusSegments {
+    vec![
+        StatusSegment::Mode,
+        StatusSegment::Account,
+        StatusSegment::View,
+        StatusSegment::Spinner,
+        StatusSegment::Selection,
+        StatusSegment::Progress,
+    ]
+    .into()
+}
+
+fn default_status_right() -> StatusSegments {
+    vec![StatusSegment::RateLimit].into()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<String>", into = "Vec<String>")]
+pub struct StatusSegments(pub Vec<StatusSegment>);
+
+impl From<Vec<StatusSegment>> for StatusSegments {
+    fn from(segments: Vec<StatusSegment>) -> Self {
+        Self(segments)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusSegment {
+    Mode,
+    Account,
+    View,
+    Spinner,
+    Selection,
+    Progress,
+    Unread,
+    RateLimit,
+    Clock,
+}
+
+impl StatusSegment {
+    fn parse(token: &str) -> Option<Self> {
+        Some(match token {
+            "mode" => Self::Mode,
+            "account" => Self::Account,
+            "view" => Self::View,
+            "spinner" => Self::Spinner,
+            "selection" => Self::Selection,
+            "progress" => Self::Progress,
+            "unread" => Self::Unread,
+            "rate_limit" => Self::RateLimit,
+            "clock" => Self::Clock,
+            _ => return None,
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Mode => "mode",
+            Self::Account => "account",
+            Self::View => "view",
+            Self::Spinner => "spinner",
+            Self::Selection => "selection",
+            Self::Progress => "progress",
+            Self::Unread => "unread",
+            Self::RateLimit => "rate_limit",
+            Self::Clock => "clock",
+        }
+    }
+}
+
+impl From<Vec<String>> for StatusSegments {
+    fn from(tokens: Vec<String>) -> Self {
+        let segments = tokens
+            .iter()
+            .filter_map(|token| {
+                let segment = StatusSegment::parse(token.trim());
+                if segment.is_none() {
+                    tracing::warn!("unknown status_bar segment: {token}");
+                }
+                segment
+            })
+            .collect();
+        Self(segments)
+    }
+}
+
+impl From<StatusSegments> for Vec<String> {
+    fn from(segments: StatusSegments) -> Self {
+        segments.0.iter().map(|s| s.as_str().to_string()).collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldOverrides {
+    /// Fields to request in addition to the defaults.
+    #[serde(default)]
+    pub add: Vec<String>,
+    /// Default fields not to request.
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+/// A `[[jobs]]` entry for `xplorertui cron`: fetch a timeline or search
+/// and append the tweets not seen before as JSONL, or run a shell command.
+///
+/// ```toml
+/// [[jobs]]
+/// every = "30m"
+/// fetch = "search"
+/// query = "ratatui"
+/// append_to = "~/x/ratatui.jsonl"
+///
+/// [[jobs]]
+/// every = "1d"
+/// command = "xplorertui render home --width 100 > ~/x/digest.txt"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobConfig {
+    /// Shown in the log; defaults to what the job does.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// How often to run: a number and `s`, `m`, `h` or `d`, e.g. `30m`.
+    pub every: String,
+    #[serde(default)]
+    pub fetch: Option<JobFetch>,
+    /// Search query, for `fetch = "search"`.
+    #[serde(default)]
+    pub query: Option<String>,
+    /// JSONL file new tweets are appended to (`~/` is expanded). Without
+    /// it they are printed to stdout.
+    #[serde(default)]
+    pub append_to: Option<String>,
+    /// Shell command to run instead of fetching.
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+/// What a fetch job reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobFetch {
+    Home,
+    Mentions,
+    Bookmarks,
+    Search,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -40,6 +552,134 @@ pub enum DefaultView {
     Search,
 }
 
+/// Clipboard backend for yank actions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardMode {
+    /// OSC 52 over SSH, otherwise the system clipboard with OSC 52 as a
+    /// fallback.
+    #[default]
+    Auto,
+    /// Platform clipboard commands only (pbcopy, clip, xclip).
+    System,
+    /// OSC 52 terminal escape only.
+    Osc52,
+}
+
+/// Tweet card layout template: one entry per row, top to bottom.
+///
+/// A row is either `text` (the wrapped tweet text), `preview` (the link
+/// preview card), or a space-separated list of inline elements: `author`,
+/// `name`, `time`, `indicators`, `source`, `metrics`. Unknown elements are
+/// ignored.
+///
+/// ```toml
+/// card_layout = ["author name indicators", "text", "time source"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<String>", into = "Vec<String>")]
+pub struct CardLayout {
+    pub rows: Vec<CardRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardRow {
+    Text,
+    Preview,
+    Inline(Vec<CardElement>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardElement {
+    Author,
+    Name,
+    Time,
+    Indicators,
+    Source,
+    Metrics,
+}
+
+impl CardElement {
+    fn parse(token: &str) -> Option<Self> {
+        Some(match token {
+            "author" => Self::Author,
+            "name" => Self::Name,
+            "time" => Self::Time,
+            "indicators" => Self::Indicators,
+            "source" => Self::Source,
+            "metrics" => Self::Metrics,
+            _ => return None,
+        })
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Author => "author",
+            Self::Name => "name",
+            Self::Time => "time",
+            Self::Indicators => "indicators",
+            Self::Source => "source",
+            Self::Metrics => "metrics",
+        }
+    }
+}
+
+impl Default for CardLayout {
+    fn default() -> Self {
+        vec![
+            "author name time indicators".to_string(),
+            "text".to_string(),
+            "preview".to_string(),
+            "metrics".to_string(),
+        ]
+        .into()
+    }
+}
+
+impl From<Vec<String>> for CardLayout {
+    fn from(rows: Vec<String>) -> Self {
+        let rows = rows
+            .iter()
+            .filter_map(|row| match row.trim() {
+                "text" => Some(CardRow::Text),
+                "preview" => Some(CardRow::Preview),
+                row => {
+                    let elements: Vec<CardElement> = row
+                        .split_whitespace()
+                        .filter_map(|token| {
+                            let element = CardElement::parse(token);
+                            if element.is_none() {
+                                tracing::warn!("unknown card_layout element: {token}");
+                            }
+                            element
+                        })
+                        .collect();
+                    (!elements.is_empty()).then_some(CardRow::Inline(elements))
+                }
+            })
+            .collect();
+        Self { rows }
+    }
+}
+
+impl From<CardLayout> for Vec<String> {
+    fn from(layout: CardLayout) -> Self {
+        layout
+            .rows
+            .iter()
+            .map(|row| match row {
+                CardRow::Text => "text".to_string(),
+                CardRow::Preview => "preview".to_string(),
+                CardRow::Inline(elements) => elements
+                    .iter()
+                    .map(|e| e.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            })
+            .collect()
+    }
+}
+
 fn default_tick_rate() -> f64 {
     30.0
 }
@@ -52,37 +692,679 @@ fn default_oauth_callback_port() -> u16 {
     8477
 }
 
+fn default_oauth_callback_port_range() -> u16 {
+    3
+}
+
 fn default_openrouter_callback_port() -> u16 {
     3000
 }
 
+fn default_profile_cache_ttl_secs() -> u64 {
+    60 * 60
+}
+
+fn default_users_cache_capacity() -> usize {
+    5000
+}
+
+fn default_max_timeline_tweets() -> usize {
+    2000
+}
+
+fn default_collapse_duplicates() -> bool {
+    true
+}
+
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+fn default_toast_duration_secs() -> u64 {
+    4
+}
+
+fn default_undo_post_secs() -> u64 {
+    5
+}
+
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             tick_rate_fps: default_tick_rate(),
             default_max_results: default_max_results(),
+            max_results: MaxResultsConfig::default(),
             default_view: DefaultView::default(),
             oauth_callback_port: default_oauth_callback_port(),
+            oauth_callback_port_range: default_oauth_callback_port_range(),
             openrouter_callback_port: default_openrouter_callback_port(),
             mlx_server_url: None,
             mlx_embedding_model: None,
             mlx_chat_model: None,
+            profile_cache_ttl_secs: default_profile_cache_ttl_secs(),
+            users_cache_capacity: default_users_cache_capacity(),
+            max_timeline_tweets: default_max_timeline_tweets(),
+            clipboard: ClipboardMode::default(),
+            link_previews: false,
+            accessible: false,
+            theme: ThemeChoice::default(),
+            recording: None,
+            card_layout: CardLayout::default(),
+            skip_confirm: Vec::new(),
+            request_timeout_secs: default_request_timeout_secs(),
+            toast_duration_secs: default_toast_duration_secs(),
+            undo_post_secs: default_undo_post_secs(),
+            fields: FieldsConfig::default(),
+            exclude_replies: false,
+            exclude_retweets: false,
+            collapse_duplicates: default_collapse_duplicates(),
+            collapse_author_runs: 0,
+            watch: WatchConfig::default(),
+            status_bar: StatusBarConfig::default(),
+            jobs: Vec::new(),
+            problems: Vec::new(),
         }
     }
 }
 
-fn config_path() -> Option<PathBuf> {
-    dirs::home_dir().map(|home| home.join(".config/xplorertui/config.toml"))
+/// Profile chosen with `--profile`, set once at startup.
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Use the named profile's directory tree for config, `.env`, tokens and
+/// cache for the rest of the process. Call before anything reads them.
+pub fn set_profile(name: String) {
+    let _ = PROFILE.set(name);
+}
+
+/// The active named profile, or `None` for the default one.
+pub fn profile() -> Option<&'static str> {
+    PROFILE.get().map(String::as_str)
+}
+
+/// Check a `--profile` name: it becomes a directory name, so only letters,
+/// digits, `-` and `_` are allowed.
+pub fn parse_profile_name(name: &str) -> Result<String, String> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(name.to_string())
+    } else {
+        Err("use only letters, digits, '-' and '_'".to_string())
+    }
+}
+
+/// Root directory for xplorertui's config and local state
+/// (`~/.config/xplorertui`, or `~/.config/xplorertui/profiles/<name>` with
+/// `--profile <name>`).
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| profile_dir(&home.join(".config/xplorertui"), profile()))
+}
+
+fn profile_dir(root: &Path, profile: Option<&str>) -> PathBuf {
+    match profile {
+        Some(name) => root.join("profiles").join(name),
+        None => root.to_path_buf(),
+    }
 }
 
+pub fn config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// Expand a leading `~/` in a configured path to the home directory.
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Prefix of the environment variables that override `config.toml` keys:
+/// `XPLORERTUI_THEME` sets `theme`, and `__` reaches into tables, so
+/// `XPLORERTUI_MAX_RESULTS__HOME` sets `max_results.home`.
+pub const ENV_PREFIX: &str = "XPLORERTUI_";
+
+/// `XPLORERTUI_*` variables that aren't config keys.
+const NOT_CONFIG_ENV: &[&str] = &[crate::api::API_BASE_ENV];
+
+/// Load `config.toml`, or the defaults without one, with `XPLORERTUI_*`
+/// environment overrides on top. Problems with either are kept in
+/// [`AppConfig::problems`] and printed to stderr the first time.
 pub fn load_config() -> AppConfig {
-    let Some(path) = config_path() else {
-        return AppConfig::default();
+    static REPORTED: std::sync::Once = std::sync::Once::new();
+
+    let path = config_path();
+    let contents = path
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .unwrap_or_default();
+
+    let config = match parse_config(&contents, config_env()) {
+        Ok(config) => config,
+        Err(e) => {
+            let mut config = parse_config("", config_env()).unwrap_or_default();
+            config.problems.insert(
+                0,
+                format!("{e}; using the defaults for every setting in the file"),
+            );
+            config
+        }
     };
+    REPORTED.call_once(|| {
+        for problem in &config.problems {
+            match &path {
+                Some(path) => eprintln!("Warning: {}: {problem}", path.display()),
+                None => eprintln!("Warning: {problem}"),
+            }
+        }
+    });
+    config
+}
 
-    let Ok(contents) = fs::read_to_string(&path) else {
-        return AppConfig::default();
+/// The `XPLORERTUI_*` environment variables, for [`parse_config`].
+pub fn config_env() -> Vec<(String, String)> {
+    std::env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+        .filter(|(name, _)| name.starts_with(ENV_PREFIX))
+        .collect()
+}
+
+/// Parse the contents of `config.toml`, then apply the `XPLORERTUI_*`
+/// overrides in `env`. A TOML syntax or type error in the file fails the
+/// whole file. Unknown keys are ignored, an override that doesn't fit its
+/// key is skipped, and out-of-range values reset to their default; each is
+/// noted in [`AppConfig::problems`].
+pub fn parse_config(
+    contents: &str,
+    env: impl IntoIterator<Item = (String, String)>,
+) -> Result<AppConfig, String> {
+    let located = |e: toml::de::Error| match e.span() {
+        Some(span) => {
+            let line = contents[..span.start].matches('\n').count() + 1;
+            format!("line {line}: {}", e.message().trim_end())
+        }
+        None => e.message().trim_end().to_string(),
+    };
+    let given: toml::Table = toml::from_str(contents).map_err(located)?;
+    // Deserializing the file on its own points type errors at a line.
+    toml::from_str::<AppConfig>(contents).map_err(located)?;
+
+    // Sorted, so `XPLORERTUI_MAX_RESULTS__HOME` lands after a whole
+    // `XPLORERTUI_MAX_RESULTS` table and wins.
+    let mut env: Vec<(String, String, String)> = env
+        .into_iter()
+        .filter(|(name, _)| !NOT_CONFIG_ENV.contains(&name.as_str()))
+        .filter_map(|(name, value)| Some((name.clone(), env_key(&name)?, value)))
+        .collect();
+    env.sort();
+    let mut merged = given.clone();
+    let mut overridden = toml::Table::new();
+    let mut env_problems = Vec::new();
+    let mut env_keys = Vec::new();
+    for (name, key, raw) in env {
+        match override_value(&merged, &key, &raw) {
+            Ok(value) => {
+                set_key(&mut merged, &key, value.clone());
+                set_key(&mut overridden, &key, value);
+                env_keys.push((key, name));
+            }
+            Err(e) => env_problems.push(format!("{name}: {e}; ignored")),
+        }
+    }
+    let mut config: AppConfig = toml::Value::Table(merged)
+        .try_into()
+        .map_err(|e: toml::de::Error| e.message().trim_end().to_string())?;
+
+    // Anything serde skipped over doesn't survive a round trip.
+    let parsed = toml::Table::try_from(&config).unwrap_or_default();
+    let mut unknown = Vec::new();
+    unknown_keys(&given, &parsed, "", &mut unknown);
+    config.problems = unknown
+        .into_iter()
+        .map(|(key, suggestion)| match suggestion {
+            Some(known) => format!("unknown key `{key}` (did you mean `{known}`?)"),
+            None => format!("unknown key `{key}`"),
+        })
+        .collect();
+    let mut unknown = Vec::new();
+    unknown_keys(&overridden, &parsed, "", &mut unknown);
+    for (key, suggestion) in unknown {
+        let Some((set, name)) = env_keys
+            .iter()
+            .find(|(set, _)| key == *set || key.starts_with(&format!("{set}.")))
+        else {
+            continue;
+        };
+        let suggestion = match suggestion {
+            // A typo in the variable's own name: suggest the variable.
+            Some(known) if key == *set => {
+                let parent = key.rsplit_once('.').map_or("", |(parent, _)| parent);
+                let known = [parent, known.as_str()].join(".");
+                format!(
+                    " (did you mean `{}`?)",
+                    env_name(known.trim_start_matches('.'))
+                )
+            }
+            Some(known) => format!(" (did you mean `{known}`?)"),
+            None => String::new(),
+        };
+        config
+            .problems
+            .push(format!("{name}: unknown key `{key}`{suggestion}"));
+    }
+    config.problems.extend(env_problems);
+    let out_of_range = config.reset_out_of_range();
+    config.problems.extend(out_of_range);
+    Ok(config)
+}
+
+/// The dotted config key an `XPLORERTUI_*` variable sets.
+fn env_key(name: &str) -> Option<String> {
+    let key = name.strip_prefix(ENV_PREFIX)?.to_lowercase();
+    let parts: Vec<&str> = key.split("__").collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+    Some(parts.join("."))
+}
+
+/// The variable that sets the dotted config `key`.
+fn env_name(key: &str) -> String {
+    format!("{ENV_PREFIX}{}", key.replace('.', "__").to_uppercase())
+}
+
+/// The value `raw` gives `key` on top of `config`: a TOML literal such as
+/// `100`, `true` or `["mute"]`, or else the text as a string.
+fn override_value(config: &toml::Table, key: &str, raw: &str) -> Result<toml::Value, String> {
+    let literal = toml::from_str::<toml::Table>(&format!("value = {raw}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"));
+    let mut error = None;
+    for value in literal.into_iter().chain([toml::Value::String(raw.into())]) {
+        let mut trial = config.clone();
+        set_key(&mut trial, key, value.clone());
+        match toml::Value::Table(trial).try_into::<AppConfig>() {
+            Ok(_) => return Ok(value),
+            Err(e) => {
+                error.get_or_insert_with(|| e.message().trim_end().to_string());
+            }
+        }
+    }
+    Err(error.unwrap_or_default())
+}
+
+/// Set the dotted `key` in `table`, making tables along the way.
+fn set_key(table: &mut toml::Table, key: &str, value: toml::Value) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let Some(last) = parts.pop() else {
+        return;
     };
+    let mut table = table;
+    for part in parts {
+        let entry = table
+            .entry(part)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if !entry.is_table() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        table = entry.as_table_mut().expect("replaced with a table above");
+    }
+    table.insert(last.to_string(), value);
+}
 
-    toml::from_str(&contents).unwrap_or_default()
+/// Keys in `given` missing from `parsed`, with the closest known key at
+/// the same level when one is a typo away or starts the same way.
+fn unknown_keys(
+    given: &toml::Table,
+    parsed: &toml::Table,
+    prefix: &str,
+    out: &mut Vec<(String, Option<String>)>,
+) {
+    for (key, value) in given {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (value, parsed.get(key)) {
+            (_, None) => {
+                let suggestion = parsed
+                    .keys()
+                    .filter(|known| !given.contains_key(*known))
+                    .map(|known| {
+                        let distance = if known.starts_with(key.as_str()) {
+                            0
+                        } else {
+                            edit_distance(key, known)
+                        };
+                        (distance, known)
+                    })
+                    .filter(|(distance, _)| *distance <= 2)
+                    .min()
+                    .map(|(_, known)| known.clone());
+                out.push((path, suggestion));
+            }
+            (toml::Value::Table(given), Some(toml::Value::Table(parsed))) => {
+                unknown_keys(given, parsed, &path, out);
+            }
+            (toml::Value::Array(given), Some(toml::Value::Array(parsed))) => {
+                for (i, pair) in given.iter().zip(parsed).enumerate() {
+                    if let (toml::Value::Table(given), toml::Value::Table(parsed)) = pair {
+                        unknown_keys(given, parsed, &format!("{path}[{i}]"), out);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Levenshtein distance, for suggesting the key a typo meant.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substituted = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substituted.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl AppConfig {
+    /// Put settings outside what X or the UI accepts back to their
+    /// defaults, describing each.
+    fn reset_out_of_range(&mut self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut out_of_range = |key: &str, value: &dyn std::fmt::Display, range: &str| {
+            problems.push(format!(
+                "{key} = {value} is out of range ({range}); using the default"
+            ));
+        };
+
+        if !(self.tick_rate_fps.is_finite() && (1.0..=240.0).contains(&self.tick_rate_fps)) {
+            out_of_range("tick_rate_fps", &self.tick_rate_fps, "1-240");
+            self.tick_rate_fps = default_tick_rate();
+        }
+        if !(1..=100).contains(&self.default_max_results) {
+            out_of_range("default_max_results", &self.default_max_results, "1-100");
+            self.default_max_results = default_max_results();
+        }
+        for (name, kind) in [
+            ("home", PageKind::Home),
+            ("mentions", PageKind::Mentions),
+            ("bookmarks", PageKind::Bookmarks),
+            ("search", PageKind::Search),
+            ("user_timeline", PageKind::UserTimeline),
+            ("thread", PageKind::Thread),
+            ("follows", PageKind::Follows),
+        ] {
+            let (limit, range) = match kind {
+                PageKind::Follows => (1000, "1-1000"),
+                _ => (100, "1-100"),
+            };
+            let slot = self.max_results.slot_mut(kind);
+            if let Some(n) = *slot
+                && !(1..=limit).contains(&n)
+            {
+                out_of_range(&format!("max_results.{name}"), &n, range);
+                *slot = None;
+            }
+        }
+        for (key, port, default) in [
+            (
+                "oauth_callback_port",
+                &mut self.oauth_callback_port,
+                default_oauth_callback_port(),
+            ),
+            (
+                "openrouter_callback_port",
+                &mut self.openrouter_callback_port,
+                default_openrouter_callback_port(),
+            ),
+        ] {
+            if *port == 0 {
+                out_of_range(key, port, "1-65535");
+                *port = default;
+            }
+        }
+        if !(1..=20).contains(&self.oauth_callback_port_range) {
+            out_of_range(
+                "oauth_callback_port_range",
+                &self.oauth_callback_port_range,
+                "1-20",
+            );
+            self.oauth_callback_port_range = default_oauth_callback_port_range();
+        }
+        // Both are added to `Instant::now()`, which panics on overflow.
+        if !(1..=3600).contains(&self.toast_duration_secs) {
+            out_of_range("toast_duration_secs", &self.toast_duration_secs, "1-3600");
+            self.toast_duration_secs = default_toast_duration_secs();
+        }
+        if self.undo_post_secs > 3600 {
+            out_of_range("undo_post_secs", &self.undo_post_secs, "0-3600");
+            self.undo_post_secs = default_undo_post_secs();
+        }
+        if self.collapse_author_runs == 1 {
+            out_of_range("collapse_author_runs", &1, "0 or at least 2");
+            self.collapse_author_runs = 0;
+        }
+        if self.users_cache_capacity == 0 {
+            out_of_range("users_cache_capacity", &0, "at least 1");
+            self.users_cache_capacity = default_users_cache_capacity();
+        }
+        for (i, job) in self.jobs.iter().enumerate() {
+            if let Err(e) = crate::cron::parse_interval(&job.every) {
+                problems.push(format!("jobs[{i}].every: {e}"));
+            }
+        }
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_layout_parses_rows_and_skips_unknown_elements() {
+        let config: AppConfig =
+            toml::from_str(r#"card_layout = ["author bogus", "text", "time source", "nope"]"#)
+                .unwrap();
+        assert_eq!(
+            config.card_layout.rows,
+            vec![
+                CardRow::Inline(vec![CardElement::Author]),
+                CardRow::Text,
+                CardRow::Inline(vec![CardElement::Time, CardElement::Source]),
+            ]
+        );
+        let round_trip: Vec<String> = CardLayout::default().into();
+        assert_eq!(CardLayout::from(round_trip), CardLayout::default());
+    }
+
+    #[test]
+    fn status_bar_segments_parse_and_skip_unknown_ones() {
+        let config: AppConfig = toml::from_str(
+            r#"
+            [status_bar]
+            right = ["clock", "weather", "unread"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.status_bar.left, default_status_left());
+        assert_eq!(
+            config.status_bar.right.0,
+            [StatusSegment::Clock, StatusSegment::Unread]
+        );
+        assert!(config.status_bar.shows(StatusSegment::Mode));
+        assert!(!config.status_bar.shows(StatusSegment::RateLimit));
+    }
+
+    #[test]
+    fn field_overrides_parse_from_tables() {
+        let config: AppConfig = toml::from_str(
+            r#"
+            [fields.tweet]
+            remove = ["entities"]
+            add = ["geo"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.fields.tweet.remove, vec!["entities"]);
+        assert_eq!(config.fields.tweet.add, vec!["geo"]);
+        assert_eq!(config.fields.user, FieldOverrides::default());
+    }
+
+    #[test]
+    fn per_view_max_results_override_the_default() {
+        let config: AppConfig = toml::from_str(
+            r#"
+            default_max_results = 30
+            [max_results]
+            search = 100
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.max_results_for(PageKind::Search), 100);
+        assert_eq!(config.max_results_for(PageKind::Home), 30);
+    }
+
+    #[test]
+    fn watch_rules_match_keywords_and_authors() {
+        let config: AppConfig = toml::from_str(
+            r#"
+            [watch]
+            keywords = ["Ratatui"]
+            authors = ["@Alice"]
+
+            [[watch.rules]]
+            keywords = ["outage"]
+            command = "cat >> outages.jsonl"
+            "#,
+        )
+        .unwrap();
+        let tweet = |text: &str| -> Tweet {
+            serde_json::from_value(serde_json::json!({ "id": "1", "text": text })).unwrap()
+        };
+        let user = |username: &str| -> User {
+            serde_json::from_value(
+                serde_json::json!({ "id": "2", "username": username, "name": "N" }),
+            )
+            .unwrap()
+        };
+        let watch = &config.watch;
+        assert!(watch.matches(&tweet("built with ratatui"), None));
+        assert!(watch.matches(&tweet("hello"), Some(&user("alice"))));
+        assert!(!watch.matches(&tweet("hello"), Some(&user("bob"))));
+        assert!(watch.matches(&tweet("Outage in us-east"), None));
+        assert!(!watch.rules[0].matches(&tweet("built with ratatui"), None));
+        assert!(watch.has_sinks());
+        assert!(!watch.notify);
+        assert!(!WatchConfig::default().matches(&tweet("ratatui"), None));
+    }
+
+    #[test]
+    fn problems_name_unknown_keys_and_reset_out_of_range_values() {
+        let config = parse_config(
+            r#"
+            tick_rate = 60
+            default_max_results = 500
+            undo_post_secs = 9223372036854775807
+            colour = "red"
+
+            [max_results]
+            follows = 1000
+            serch = 50
+
+            [watch]
+            keywrds = ["rust"]
+
+            [[jobs]]
+            every = "2w"
+            comand = "true"
+            "#,
+            [],
+        )
+        .unwrap();
+        assert_eq!(
+            config.problems,
+            [
+                "unknown key `colour`",
+                "unknown key `jobs[0].comand`",
+                "unknown key `max_results.serch`",
+                "unknown key `tick_rate` (did you mean `tick_rate_fps`?)",
+                "unknown key `watch.keywrds` (did you mean `keywords`?)",
+                "default_max_results = 500 is out of range (1-100); using the default",
+                "undo_post_secs = 9223372036854775807 is out of range (0-3600); using the default",
+                "jobs[0].every: invalid interval \"2w\": use s, m, h or d",
+            ]
+        );
+        assert_eq!(config.default_max_results, default_max_results());
+        assert_eq!(config.undo_post_secs, default_undo_post_secs());
+        assert_eq!(config.max_results.follows, Some(1000));
+        assert!(parse_config("", []).unwrap().problems.is_empty());
+    }
+
+    #[test]
+    fn type_errors_say_which_line() {
+        let error = parse_config("theme = \"auto\"\ntick_rate_fps = \"fast\"\n", []).unwrap_err();
+        assert!(error.starts_with("line 2: "), "{error}");
+        assert!(parse_config("[watch", []).is_err());
+    }
+
+    #[test]
+    fn environment_variables_override_the_file() {
+        let env = [
+            ("XPLORERTUI_DEFAULT_MAX_RESULTS", "50"),
+            ("XPLORERTUI_THEME", "high-contrast"),
+            ("XPLORERTUI_MAX_RESULTS__HOME", "10"),
+            ("XPLORERTUI_SKIP_CONFIRM", r#"["mute"]"#),
+            ("XPLORERTUI_MLX_CHAT_MODEL", "2024-01-01"),
+            ("XPLORERTUI_TICK_RATE_FPS", "fast"),
+            ("XPLORERTUI_LINK_PREVIEW", "true"),
+            ("XPLORERTUI_API_BASE", "http://localhost:9000"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+        let config = parse_config(
+            "default_max_results = 20\ntick_rate_fps = 60\n[max_results]\nsearch = 100\n",
+            env,
+        )
+        .unwrap();
+        assert_eq!(config.default_max_results, 50);
+        assert_eq!(config.theme, ThemeChoice::HighContrast);
+        assert_eq!(config.max_results.home, Some(10));
+        assert_eq!(config.max_results.search, Some(100));
+        assert_eq!(config.skip_confirm, ["mute"]);
+        // Text that reads as another TOML type is kept as a string.
+        assert_eq!(config.mlx_chat_model.as_deref(), Some("2024-01-01"));
+        assert_eq!(config.tick_rate_fps, 60.0);
+        assert_eq!(
+            config.problems,
+            [
+                "XPLORERTUI_LINK_PREVIEW: unknown key `link_preview` \
+                 (did you mean `XPLORERTUI_LINK_PREVIEWS`?)",
+                "XPLORERTUI_TICK_RATE_FPS: invalid type: string \"fast\", expected f64; ignored",
+            ]
+        );
+    }
+
+    #[test]
+    fn named_profiles_get_their_own_directory() {
+        let root = Path::new("/home/me/.config/xplorertui");
+        assert_eq!(profile_dir(root, None), root);
+        assert_eq!(
+            profile_dir(root, Some("work")),
+            Path::new("/home/me/.config/xplorertui/profiles/work")
+        );
+        assert!(parse_profile_name("work-2_b").is_ok());
+        assert!(parse_profile_name("../work").is_err());
+        assert!(parse_profile_name("").is_err());
+    }
 }
@@ -0,0 +1,366 @@
+//! `xplorertui cron`: run the `[[jobs]]` from the config on their
+//! intervals in one long-lived process.
+//!
+//! Jobs run one at a time, so they never race each other for the rate
+//! limit. When a fetch is rate limited, every job reading the same endpoint
+//! waits until the limit resets instead of failing in turn.
+
+use std::collections::HashSet;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use color_eyre::eyre::{self, bail, eyre};
+use tokio::time::Instant;
+
+use crate::api::types::{Includes, Tweet};
+use crate::api::{ApiClientError, XApiClient};
+use crate::cli::denormalize_tweet;
+use crate::config::{AppConfig, JobConfig, JobFetch, PageKind, expand_home};
+use crate::sinks;
+
+/// Longest interval a job can run on. Anything longer could overflow the
+/// scheduler's `Instant` arithmetic.
+const MAX_INTERVAL_SECS: u64 = 365 * 24 * 60 * 60;
+
+/// Parse an interval like `90s`, `30m`, `6h` or `1d`.
+pub fn parse_interval(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (n, unit) = s.split_at(split);
+    let n: u64 = n
+        .parse()
+        .map_err(|_| format!("invalid interval {s:?}: expected e.g. 30m"))?;
+    let unit_secs = match unit.trim() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => return Err(format!("invalid interval {s:?}: use s, m, h or d")),
+    };
+    match n.checked_mul(unit_secs) {
+        Some(0) => Err(format!("invalid interval {s:?}: must be more than zero")),
+        Some(secs) if secs <= MAX_INTERVAL_SECS => Ok(Duration::from_secs(secs)),
+        _ => Err(format!("invalid interval {s:?}: must be at most 365d")),
+    }
+}
+
+/// Tweet ids already in a JSONL file written by a fetch job. Lines that
+/// aren't tweet records are skipped.
+fn seen_ids(contents: &str) -> HashSet<String> {
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|record| record["tweet"]["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+/// The tweets whose ids aren't in `seen`, oldest first, marking them seen.
+fn take_new<'a>(seen: &mut HashSet<String>, tweets: &'a [Tweet]) -> Vec<&'a Tweet> {
+    let mut new: Vec<&Tweet> = tweets
+        .iter()
+        .filter(|t| seen.insert(t.id.clone()))
+        .collect();
+    new.reverse();
+    new
+}
+
+enum JobAction {
+    Fetch {
+        fetch: JobFetch,
+        query: Option<String>,
+        append_to: Option<PathBuf>,
+        seen: HashSet<String>,
+    },
+    Command(String),
+}
+
+/// Why a run failed. Rate limits are kept apart so the scheduler can hold
+/// back the other jobs on the same endpoint.
+enum JobError {
+    RateLimited(DateTime<Utc>),
+    Failed(String),
+}
+
+impl From<ApiClientError> for JobError {
+    fn from(e: ApiClientError) -> Self {
+        match e {
+            ApiClientError::RateLimited { reset_at } => Self::RateLimited(reset_at),
+            e => Self::Failed(e.with_remediation()),
+        }
+    }
+}
+
+impl From<std::io::Error> for JobError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Failed(e.to_string())
+    }
+}
+
+struct Job {
+    name: String,
+    every: Duration,
+    action: JobAction,
+    next_run: Instant,
+}
+
+impl Job {
+    fn new(config: &JobConfig) -> Result<Self, String> {
+        let every = parse_interval(&config.every)?;
+        let action = match (config.fetch, &config.command) {
+            (Some(_), Some(_)) => return Err("set either fetch or command, not both".into()),
+            (None, None) => return Err("set fetch or command".into()),
+            (None, Some(command)) => JobAction::Command(command.clone()),
+            (Some(fetch), None) => {
+                if fetch == JobFetch::Search && config.query.is_none() {
+                    return Err("fetch = \"search\" needs a query".into());
+                }
+                let append_to = config.append_to.as_deref().map(expand_home);
+                let seen = append_to
+                    .as_ref()
+                    .and_then(|path| std::fs::read_to_string(path).ok())
+                    .map(|contents| seen_ids(&contents))
+                    .unwrap_or_default();
+                JobAction::Fetch {
+                    fetch,
+                    query: config.query.clone(),
+                    append_to,
+                    seen,
+                }
+            }
+        };
+        let name = config.name.clone().unwrap_or_else(|| match &action {
+            JobAction::Fetch {
+                fetch: JobFetch::Search,
+                query,
+                ..
+            } => format!("search {}", query.as_deref().unwrap_or_default()),
+            JobAction::Fetch { fetch, .. } => format!("{fetch:?}").to_lowercase(),
+            JobAction::Command(command) => command.clone(),
+        });
+        Ok(Self {
+            name,
+            every,
+            action,
+            next_run: Instant::now(),
+        })
+    }
+
+    /// The endpoint a fetch job reads, which its rate limit is tied to.
+    fn endpoint(&self) -> Option<JobFetch> {
+        match self.action {
+            JobAction::Fetch { fetch, .. } => Some(fetch),
+            JobAction::Command(_) => None,
+        }
+    }
+
+    /// Run the job once, returning a summary for the log.
+    async fn run(&mut self, client: &XApiClient, config: &AppConfig) -> Result<String, JobError> {
+        match &mut self.action {
+            JobAction::Command(command) => {
+                let status = tokio::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(command.as_str())
+                    .status()
+                    .await?;
+                if status.success() {
+                    Ok("done".into())
+                } else {
+                    Err(JobError::Failed(format!("command failed ({status})")))
+                }
+            }
+            JobAction::Fetch {
+                fetch,
+                query,
+                append_to,
+                seen,
+            } => {
+                let max = |kind| config.max_results_for(kind);
+                let pages = match fetch {
+                    JobFetch::Home => {
+                        client.home_timeline_pages(max(PageKind::Home), config.timeline_exclude())
+                    }
+                    JobFetch::Mentions => client.mentions_pages(max(PageKind::Mentions)),
+                    JobFetch::Bookmarks => client.bookmarks_pages(max(PageKind::Bookmarks)),
+                    JobFetch::Search => client
+                        .search_pages(query.as_deref().unwrap_or_default(), max(PageKind::Search)),
+                };
+                let resp = pages.page_at(None).await?;
+                let tweets = resp.data.unwrap_or_default();
+                let new = take_new(seen, &tweets);
+                write_tweets(append_to.as_deref(), &new, &resp.includes)?;
+                let mut summary = format!("{} new of {}", new.len(), tweets.len());
+                let failed = sinks::deliver_matching(&config.watch, new, &resp.includes).await;
+                for e in failed {
+                    summary.push_str(&format!("; watch rule sink failed: {e}"));
+                }
+                Ok(summary)
+            }
+        }
+    }
+
+    /// Print how a run went to stderr, with the time.
+    fn log(&self, outcome: Result<String, JobError>) {
+        let message = match outcome {
+            Ok(summary) => summary,
+            Err(JobError::Failed(e)) => e,
+            Err(JobError::RateLimited(reset_at)) => format!(
+                "rate limited; jobs on this endpoint wait until {}",
+                reset_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+            ),
+        };
+        let now = chrono::Local::now().format("%H:%M:%S");
+        eprintln!("[{now}] {}: {message}", self.name);
+    }
+}
+
+/// Append tweets as JSONL to `path`, or print them when there is none.
+fn write_tweets(
+    path: Option<&std::path::Path>,
+    tweets: &[&Tweet],
+    includes: &Option<Includes>,
+) -> std::io::Result<()> {
+    let mut lines = String::new();
+    for tweet in tweets {
+        lines.push_str(&denormalize_tweet(tweet, includes).to_string());
+        lines.push('\n');
+    }
+    match path {
+        Some(path) => {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?
+                .write_all(lines.as_bytes())
+        }
+        None => std::io::stdout().write_all(lines.as_bytes()),
+    }
+}
+
+/// Run the configured jobs until Ctrl-C, or each once with `once`.
+pub async fn run(client: &XApiClient, config: &AppConfig, once: bool) -> eyre::Result<()> {
+    let mut jobs = config
+        .jobs
+        .iter()
+        .enumerate()
+        .map(|(i, job)| Job::new(job).map_err(|e| eyre!("jobs[{i}]: {e}")))
+        .collect::<eyre::Result<Vec<_>>>()?;
+    if jobs.is_empty() {
+        bail!("No [[jobs]] in the config. See the README for the format.");
+    }
+
+    let stop = tokio::signal::ctrl_c();
+    tokio::pin!(stop);
+    if once {
+        for job in &mut jobs {
+            tokio::select! {
+                _ = &mut stop => break,
+                outcome = job.run(client, config) => job.log(outcome),
+            }
+        }
+        return Ok(());
+    }
+
+    loop {
+        // Ties go to the job listed first.
+        let i = (0..jobs.len())
+            .min_by_key(|&i| (jobs[i].next_run, i))
+            .unwrap_or_default();
+        let outcome = tokio::select! {
+            _ = &mut stop => return Ok(()),
+            outcome = async {
+                tokio::time::sleep_until(jobs[i].next_run).await;
+                jobs[i].run(client, config).await
+            } => outcome,
+        };
+
+        let job = &mut jobs[i];
+        job.next_run = Instant::now() + job.every;
+        if let Err(JobError::RateLimited(reset_at)) = outcome {
+            let wait = (reset_at - Utc::now()).to_std().unwrap_or_default();
+            let resume = Instant::now() + wait + Duration::from_secs(1);
+            let endpoint = job.endpoint();
+            for job in jobs.iter_mut().filter(|j| j.endpoint() == endpoint) {
+                job.next_run = job.next_run.max(resume);
+            }
+        }
+        jobs[i].log(outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet(id: &str) -> Tweet {
+        serde_json::from_value(serde_json::json!({ "id": id, "text": "" })).unwrap()
+    }
+
+    #[test]
+    fn intervals_take_a_number_and_a_unit() {
+        assert_eq!(parse_interval("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_interval("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_interval("6h"), Ok(Duration::from_secs(6 * 60 * 60)));
+        assert_eq!(parse_interval("1d"), Ok(Duration::from_secs(24 * 60 * 60)));
+        assert!(parse_interval("30").is_err());
+        assert!(parse_interval("m").is_err());
+        assert!(parse_interval("0m").is_err());
+        assert!(parse_interval("2w").is_err());
+        assert_eq!(
+            parse_interval("365d"),
+            Ok(Duration::from_secs(MAX_INTERVAL_SECS))
+        );
+        assert!(parse_interval("366d").is_err());
+        assert!(parse_interval("999999999999999999d").is_err());
+    }
+
+    #[test]
+    fn jobs_need_one_action_and_search_needs_a_query() {
+        let job = |fetch, query: Option<&str>, command: Option<&str>| {
+            Job::new(&JobConfig {
+                every: "30m".into(),
+                fetch,
+                query: query.map(str::to_string),
+                command: command.map(str::to_string),
+                ..JobConfig::default()
+            })
+            .map(|job| job.name)
+        };
+        assert_eq!(
+            job(Some(JobFetch::Search), Some("rust"), None),
+            Ok("search rust".into())
+        );
+        assert_eq!(
+            job(Some(JobFetch::Mentions), None, None),
+            Ok("mentions".into())
+        );
+        assert_eq!(job(None, None, Some("true")), Ok("true".into()));
+        assert!(job(Some(JobFetch::Search), None, None).is_err());
+        assert!(job(Some(JobFetch::Home), None, Some("true")).is_err());
+        assert!(job(None, None, None).is_err());
+    }
+
+    #[test]
+    fn only_tweets_not_already_appended_are_written_oldest_first() {
+        let existing = [tweet("2"), tweet("1")]
+            .iter()
+            .map(|t| denormalize_tweet(t, &None).to_string())
+            .chain(["{\"errors\": []}".to_string(), "not json".to_string()])
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut seen = seen_ids(&existing);
+        assert_eq!(seen.len(), 2);
+
+        let page = [tweet("4"), tweet("3"), tweet("2")];
+        let new: Vec<&str> = take_new(&mut seen, &page)
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(new, ["3", "4"]);
+        assert!(take_new(&mut seen, &page).is_empty());
+    }
+}
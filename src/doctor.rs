@@ -0,0 +1,423 @@
+//! Setup diagnostics for `xplorertui doctor` and `:doctor`.
+//!
+//! Every check is local: files, environment variables, ports and the
+//! terminal. Nothing is sent to X or OpenRouter, so the report is safe to
+//! run (and paste) when the network or the credentials are the problem.
+
+use std::fmt::Write as _;
+use std::net::TcpListener;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+
+use crate::auth::oauth2_pkce::{self, OAuth2Error, TokenData};
+use crate::config::{self, AppConfig};
+use crate::openrouter;
+use crate::terminal::{TerminalCaps, UnicodeLevel};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    /// Not configured, and not required.
+    Skip,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pass => " ok ",
+            Self::Skip => " -- ",
+            Self::Warn => "warn",
+            Self::Fail => "FAIL",
+        }
+    }
+}
+
+/// The outcome of one diagnostic.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// What to do about a warning or failure.
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// Run every check, in report order.
+pub fn run_checks() -> Vec<Check> {
+    crate::auth::credentials::load_env_files();
+    let (config_check, config) = check_config();
+
+    let mut checks = vec![config_check];
+    checks.extend(check_credentials(|name| {
+        std::env::var(name).ok().filter(|v| !v.is_empty())
+    }));
+    checks.push(token_check(
+        oauth2_pkce::load_tokens(),
+        &oauth2_pkce::tokens_path(),
+        Utc::now(),
+    ));
+    checks.push(port_check(
+        "OAuth callback port",
+        "oauth_callback_port",
+        &config.oauth_callback_ports(),
+    ));
+    checks.push(port_check(
+        "OpenRouter callback port",
+        "openrouter_callback_port",
+        &[config.openrouter_callback_port],
+    ));
+    checks.push(terminal_check(TerminalCaps::detect()));
+    checks.push(check_openrouter());
+    checks
+}
+
+/// Whether any check failed outright.
+pub fn has_failures(checks: &[Check]) -> bool {
+    checks.iter().any(|c| c.status == CheckStatus::Fail)
+}
+
+/// A plain-text report, one check per line with hints indented below.
+pub fn report(checks: &[Check]) -> String {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut out = String::new();
+    for check in checks {
+        let _ = writeln!(
+            out,
+            "[{}] {:width$}  {}",
+            check.status.label(),
+            check.name,
+            check.detail
+        );
+        if let Some(hint) = &check.hint {
+            let _ = writeln!(out, "       hint: {hint}");
+        }
+    }
+    out
+}
+
+fn check_config() -> (Check, AppConfig) {
+    let Some(path) = config::config_path() else {
+        let check = Check::new("Config", CheckStatus::Warn, "no home directory")
+            .hint("set $HOME so config, tokens and cache have somewhere to live");
+        return (check, AppConfig::default());
+    };
+    // Without a file, the environment overrides are still checked.
+    let (contents, found) = match std::fs::read_to_string(&path) {
+        Ok(contents) => (contents, true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => (String::new(), false),
+        Err(e) => {
+            let check = Check::new(
+                "Config",
+                CheckStatus::Fail,
+                format!("can't read {}: {e}", path.display()),
+            );
+            return (check, AppConfig::default());
+        }
+    };
+    match config::parse_config(&contents, config::config_env()) {
+        Ok(config) if config.problems.is_empty() => {
+            let detail = if found {
+                path.display().to_string()
+            } else {
+                format!("no {}, using defaults", path.display())
+            };
+            (Check::new("Config", CheckStatus::Pass, detail), config)
+        }
+        Ok(config) => {
+            let check = Check::new(
+                "Config",
+                CheckStatus::Warn,
+                format!("{}: {}", path.display(), config.problems.join("; ")),
+            )
+            .hint("run `xplorertui config check` after fixing the file or environment");
+            (check, config)
+        }
+        Err(reason) => {
+            let check = Check::new(
+                "Config",
+                CheckStatus::Fail,
+                format!("{} doesn't parse: {reason}", path.display()),
+            )
+            .hint("fix the file; until then every setting falls back to its default");
+            (check, AppConfig::default())
+        }
+    }
+}
+
+/// One check per auth method, from an environment lookup.
+fn check_credentials(var: impl Fn(&str) -> Option<String>) -> Vec<Check> {
+    let env_file = config::config_dir()
+        .map(|dir| dir.join(".env").display().to_string())
+        .unwrap_or_else(|| ".env".to_string());
+
+    let oauth2 = match var("X_CLIENT_ID") {
+        Some(_) if var("X_CLIENT_SECRET").is_some() => {
+            Check::new("OAuth 2.0 PKCE", CheckStatus::Pass, "confidential client")
+        }
+        Some(_) => Check::new("OAuth 2.0 PKCE", CheckStatus::Pass, "public client"),
+        None => Check::new("OAuth 2.0 PKCE", CheckStatus::Skip, "X_CLIENT_ID not set").hint(
+            format!("run `xplorertui auth` to set it up, or add X_CLIENT_ID to {env_file}"),
+        ),
+    };
+
+    const OAUTH1_VARS: [&str; 4] = [
+        "X_CONSUMER_KEY",
+        "X_CONSUMER_KEY_SECRET",
+        "X_ACCESS_TOKEN",
+        "X_ACCESS_TOKEN_SECRET",
+    ];
+    let missing: Vec<&str> = OAUTH1_VARS
+        .into_iter()
+        .filter(|name| var(name).is_none())
+        .collect();
+    let oauth1 = match missing.len() {
+        0 => Check::new("OAuth 1.0a", CheckStatus::Pass, "all four keys set"),
+        4 => Check::new("OAuth 1.0a", CheckStatus::Skip, "not configured"),
+        _ => Check::new(
+            "OAuth 1.0a",
+            CheckStatus::Fail,
+            format!("missing {}", missing.join(", ")),
+        )
+        .hint(format!(
+            "OAuth 1.0a needs all four keys; add them to {env_file}"
+        )),
+    };
+
+    let bearer = if var("X_BEARER_TOKEN").is_some() {
+        Check::new("Bearer token", CheckStatus::Pass, "set (read-only access)")
+    } else {
+        Check::new("Bearer token", CheckStatus::Skip, "X_BEARER_TOKEN not set")
+    };
+
+    let mut checks = vec![oauth2, oauth1, bearer];
+    if checks.iter().all(|c| c.status != CheckStatus::Pass) {
+        checks.push(
+            Check::new("Credentials", CheckStatus::Fail, "no X credentials found").hint(format!(
+                "run `xplorertui auth`, or put credentials in {env_file}"
+            )),
+        );
+    }
+    checks
+}
+
+/// Classify stored OAuth 2.0 tokens as of `now`.
+fn token_check(
+    tokens: Result<Option<TokenData>, OAuth2Error>,
+    path: &Path,
+    now: DateTime<Utc>,
+) -> Check {
+    let tokens = match tokens {
+        Ok(Some(tokens)) => tokens,
+        Ok(None) => {
+            return Check::new("OAuth 2.0 tokens", CheckStatus::Skip, "none stored")
+                .hint("run `xplorertui auth` to sign in for home, mentions and bookmarks");
+        }
+        Err(e) => {
+            return Check::new(
+                "OAuth 2.0 tokens",
+                CheckStatus::Fail,
+                format!("{} is unreadable: {e}", path.display()),
+            )
+            .hint("run `xplorertui auth` to sign in again");
+        }
+    };
+
+    let mut check = match (tokens.expires_at, &tokens.refresh_token) {
+        (Some(expires_at), None) if expires_at <= now => Check::new(
+            "OAuth 2.0 tokens",
+            CheckStatus::Fail,
+            "expired, no refresh token",
+        )
+        .hint("run `xplorertui auth` to sign in again"),
+        (Some(expires_at), Some(_)) if expires_at <= now => Check::new(
+            "OAuth 2.0 tokens",
+            CheckStatus::Pass,
+            "expired; refreshed on the next request",
+        ),
+        (Some(expires_at), _) => Check::new(
+            "OAuth 2.0 tokens",
+            CheckStatus::Pass,
+            format!(
+                "valid until {}",
+                expires_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+            ),
+        ),
+        (None, _) => Check::new("OAuth 2.0 tokens", CheckStatus::Pass, "stored, no expiry"),
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = std::fs::metadata(path)
+            && metadata.permissions().mode() & 0o077 != 0
+            && check.status == CheckStatus::Pass
+        {
+            check = Check::new(
+                "OAuth 2.0 tokens",
+                CheckStatus::Warn,
+                format!("{}; readable by other users", check.detail),
+            )
+            .hint(format!("chmod 600 {}", path.display()));
+        }
+    }
+    check
+}
+
+/// Passes when the first of `ports` is free, and warns when only a
+/// fallback is: its redirect URI has to be registered too.
+fn port_check(name: &'static str, setting: &str, ports: &[u16]) -> Check {
+    let mut error = None;
+    for (i, &port) in ports.iter().enumerate() {
+        match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(_) if i == 0 => {
+                return Check::new(name, CheckStatus::Pass, format!("{port} is free"));
+            }
+            Ok(_) => {
+                return Check::new(
+                    name,
+                    CheckStatus::Warn,
+                    format!("{} is busy; falling back to {port}", ports[0]),
+                )
+                .hint(format!(
+                    "register {} as a callback URI for your app",
+                    oauth2_pkce::redirect_url(port)
+                ));
+            }
+            Err(e) => {
+                error.get_or_insert(e);
+            }
+        }
+    }
+    let busy = match ports {
+        [first, .., last] => format!("{first}-{last}"),
+        _ => ports.first().map(u16::to_string).unwrap_or_default(),
+    };
+    let reason = error.map(|e| format!(": {e}")).unwrap_or_default();
+    Check::new(
+        name,
+        CheckStatus::Fail,
+        format!("can't listen on {busy}{reason}"),
+    )
+    .hint(format!(
+        "stop whatever uses the port, or set {setting} in config.toml"
+    ))
+}
+
+fn terminal_check(caps: TerminalCaps) -> Check {
+    let mut features = vec![if caps.truecolor {
+        "truecolor"
+    } else {
+        "256 colors"
+    }];
+    features.push(match caps.unicode {
+        UnicodeLevel::Full => "UTF-8",
+        UnicodeLevel::Basic => "basic Unicode",
+        UnicodeLevel::Ascii => "ASCII only",
+    });
+    features.push(match caps.graphics_protocol() {
+        Some(crate::terminal::GraphicsProtocol::Kitty) => "kitty images",
+        Some(crate::terminal::GraphicsProtocol::ITerm2) => "iTerm2 images",
+        Some(crate::terminal::GraphicsProtocol::Sixel) => "sixel images",
+        None => "no inline images",
+    });
+    let detail = features.join(", ");
+
+    if caps.unicode == UnicodeLevel::Ascii {
+        Check::new("Terminal", CheckStatus::Warn, detail)
+            .hint("use a UTF-8 locale (e.g. LANG=en_US.UTF-8) for borders and symbols")
+    } else if caps.multiplexed && (caps.kitty_graphics || caps.iterm2_images || caps.sixel) {
+        Check::new("Terminal", CheckStatus::Warn, detail)
+            .hint("tmux/screen hides inline images; run outside the multiplexer to see them")
+    } else {
+        Check::new("Terminal", CheckStatus::Pass, detail)
+    }
+}
+
+fn check_openrouter() -> Check {
+    if std::env::var("OPENROUTER_API_KEY").is_ok_and(|key| !key.is_empty()) {
+        return Check::new(
+            "OpenRouter key",
+            CheckStatus::Pass,
+            "from OPENROUTER_API_KEY",
+        );
+    }
+    let path = openrouter::auth::key_path();
+    match openrouter::auth::load_key_data() {
+        Ok(Some(data)) if !data.key.is_empty() => Check::new(
+            "OpenRouter key",
+            CheckStatus::Pass,
+            format!("stored in {}", path.display()),
+        ),
+        Ok(_) => Check::new("OpenRouter key", CheckStatus::Skip, "not configured")
+            .hint("run `xplorertui openrouter-auth` for embeddings, clustering and topics"),
+        Err(e) => Check::new(
+            "OpenRouter key",
+            CheckStatus::Fail,
+            format!("{} is unreadable: {e}", path.display()),
+        )
+        .hint("run `xplorertui openrouter-auth` to store a new key"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(expires_in_secs: i64, refresh: bool) -> TokenData {
+        TokenData {
+            access_token: "access".into(),
+            refresh_token: refresh.then(|| "refresh".into()),
+            expires_at: Some(Utc::now() + chrono::Duration::seconds(expires_in_secs)),
+            scopes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn expired_tokens_fail_only_without_a_refresh_token() {
+        let path = Path::new("/nonexistent/tokens.json");
+        let now = Utc::now();
+        let check = token_check(Ok(Some(tokens(-60, true))), path, now);
+        assert_eq!(check.status, CheckStatus::Pass);
+        let check = token_check(Ok(Some(tokens(-60, false))), path, now);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.hint.unwrap().contains("xplorertui auth"));
+        let check = token_check(Ok(None), path, now);
+        assert_eq!(check.status, CheckStatus::Skip);
+    }
+
+    #[test]
+    fn partial_oauth1_keys_fail_and_no_credentials_at_all_fails() {
+        let checks =
+            check_credentials(|name| (name == "X_CONSUMER_KEY").then(|| "key".to_string()));
+        let oauth1 = checks.iter().find(|c| c.name == "OAuth 1.0a").unwrap();
+        assert_eq!(oauth1.status, CheckStatus::Fail);
+        assert!(oauth1.detail.contains("X_ACCESS_TOKEN_SECRET"));
+        assert!(has_failures(&checks));
+
+        let checks =
+            check_credentials(|name| (name == "X_BEARER_TOKEN").then(|| "token".to_string()));
+        assert!(!has_failures(&checks));
+        assert!(report(&checks).contains("[ ok ] Bearer token"));
+    }
+}
@@ -0,0 +1,121 @@
+//! Which sites a set of tweets links to, for the `:domains` view.
+
+use std::collections::{HashMap, HashSet};
+
+use url::Url;
+
+use crate::api::types::Tweet;
+
+/// Hosts that serve X itself. Links to them are quotes, media and profiles
+/// rather than outside sources.
+const X_HOSTS: [&str; 3] = ["x.com", "twitter.com", "mobile.twitter.com"];
+
+/// Tweets linking to one domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainCount {
+    pub domain: String,
+    pub tweets: usize,
+}
+
+/// Domains linked from a set of tweets, most linked first.
+#[derive(Debug, Clone, Default)]
+pub struct DomainReport {
+    /// Where the tweets came from, e.g. the view's name.
+    pub source: String,
+    /// Tweets looked at, with or without links.
+    pub scanned: usize,
+    /// Tweets with at least one outside link.
+    pub linking: usize,
+    pub domains: Vec<DomainCount>,
+}
+
+impl DomainReport {
+    /// Count, for each domain, the tweets that link to it. A tweet linking
+    /// to the same site twice counts once.
+    pub fn new<'a>(source: String, tweets: impl IntoIterator<Item = &'a Tweet>) -> Self {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let (mut scanned, mut linking) = (0, 0);
+        for tweet in tweets {
+            scanned += 1;
+            let domains: HashSet<String> = tweet
+                .entities
+                .iter()
+                .flat_map(|e| e.urls.iter().flatten())
+                .filter_map(|u| u.expanded_url.as_deref())
+                .filter_map(|link| Url::parse(link).ok())
+                .filter_map(|url| link_host(&url).map(str::to_string))
+                .filter(|host| !is_x_host(host))
+                .collect();
+            if !domains.is_empty() {
+                linking += 1;
+            }
+            for domain in domains {
+                *counts.entry(domain).or_default() += 1;
+            }
+        }
+        let mut domains: Vec<DomainCount> = counts
+            .into_iter()
+            .map(|(domain, tweets)| DomainCount { domain, tweets })
+            .collect();
+        domains.sort_by(|a, b| {
+            b.tweets
+                .cmp(&a.tweets)
+                .then_with(|| a.domain.cmp(&b.domain))
+        });
+        Self {
+            source,
+            scanned,
+            linking,
+            domains,
+        }
+    }
+}
+
+/// The host of `url` without a leading `www.`.
+pub fn link_host(url: &Url) -> Option<&str> {
+    url.host_str().map(|host| host.trim_start_matches("www."))
+}
+
+/// Whether `host` (as returned by [`link_host`]) is X itself.
+pub fn is_x_host(host: &str) -> bool {
+    X_HOSTS.contains(&host)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn domains_are_counted_once_per_tweet_and_ranked() {
+        let tweet = |id: &str, links: &[&str]| -> Tweet {
+            let urls: Vec<_> = links
+                .iter()
+                .map(|link| json!({ "start": 0, "end": 23, "url": "https://t.co/x", "expanded_url": link }))
+                .collect();
+            serde_json::from_value(json!({ "id": id, "text": "", "entities": { "urls": urls } }))
+                .unwrap()
+        };
+        let tweets = [
+            tweet("1", &["https://www.example.com/a", "https://example.com/b"]),
+            tweet("2", &["https://news.site/story", "https://example.com/c"]),
+            tweet("3", &["https://x.com/alice/status/1/photo/1"]),
+            tweet("4", &["https://blog.dev/"]),
+            tweet("5", &[]),
+        ];
+
+        let report = DomainReport::new("Following".into(), &tweets);
+        assert_eq!(report.scanned, 5);
+        assert_eq!(report.linking, 3);
+        let ranked: Vec<(&str, usize)> = report
+            .domains
+            .iter()
+            .map(|d| (d.domain.as_str(), d.tweets))
+            .collect();
+        assert_eq!(
+            ranked,
+            [("example.com", 2), ("blog.dev", 1), ("news.site", 1)]
+        );
+    }
+}
@@ -1,17 +1,25 @@
+use chrono::{DateTime, Utc};
 use color_eyre::eyre::OptionExt;
 use crossterm::event::Event as CrosstermEvent;
 use futures::{FutureExt, StreamExt};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
-use crate::api::types::{ListResponse, SingleResponse, Tweet, User};
+use crate::api::error::AccountState;
+use crate::api::types::{
+    BookmarkStatus, DmEvent, HiddenStatus, Includes, LikeStatus, List, ListFields, ListResponse,
+    NewStreamRule, PostedTweet, ReplySettings, RetweetStatus, SingleResponse, StreamMessage,
+    StreamRule, Tweet, Usage, User,
+};
+use crate::app::ReplyTarget;
+use crate::config::AppConfig;
+use crate::domains::DomainReport;
 use crate::embeddings::cluster::ClusterResult;
 use crate::huggingface::types::HfModel;
+use crate::link_preview::LinkPreview;
 use crate::openrouter::types::Model;
-
-/// The frequency at which tick events are emitted.
-const TICK_FPS: f64 = 30.0;
+use crate::ui::halfblock::RgbImage;
 
 /// Representation of all possible events.
 #[derive(Clone, Debug)]
@@ -25,6 +33,8 @@ pub enum Event {
 }
 
 /// Application events for navigation, API requests, and API responses.
+// Always travels boxed inside `Event::App`, so variant size doesn't matter.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
 pub enum AppEvent {
     // -- Navigation --
@@ -36,6 +46,9 @@ pub enum AppEvent {
     // -- Refresh --
     RefreshView,
 
+    /// Play the next key of the running keyboard macro.
+    PlayMacroKey,
+
     // -- API request triggers (sent from key handlers) --
     FetchHomeTimeline {
         pagination_token: Option<String>,
@@ -54,6 +67,9 @@ pub enum AppEvent {
     FetchUser {
         username: String,
     },
+    FetchUserById {
+        user_id: String,
+    },
     FetchSearch {
         query: String,
         pagination_token: Option<String>,
@@ -73,7 +89,197 @@ pub enum AppEvent {
         pagination_token: Option<String>,
     },
 
+    // -- Bulk actions --
+    StartBulk {
+        action: BulkAction,
+        /// Tweet IDs to bookmark, or user IDs to mute.
+        ids: Vec<String>,
+    },
+    BulkProgressed {
+        done: usize,
+        total: usize,
+        /// Set while waiting out a rate limit.
+        rate_limited_until: Option<DateTime<Utc>>,
+    },
+    BulkFinished {
+        action: BulkAction,
+        succeeded: usize,
+        total: usize,
+        /// IDs that failed, with the error for each.
+        failed: Vec<(String, Arc<String>)>,
+    },
+    /// Mute (or, with `mute: false`, unmute) an account. Without a
+    /// `user_id`, the username is looked up first.
+    MuteUser {
+        username: String,
+        user_id: Option<String>,
+        mute: bool,
+    },
+    /// `result` holds the account's user ID.
+    MuteFinished {
+        username: String,
+        mute: bool,
+        result: ApiResult<String>,
+    },
+    /// Block (or, with `block: false`, unblock) an account. Without a
+    /// `user_id`, the username is looked up first.
+    BlockUser {
+        username: String,
+        user_id: Option<String>,
+        block: bool,
+    },
+    /// `result` holds the account's user ID.
+    BlockFinished {
+        username: String,
+        block: bool,
+        result: ApiResult<String>,
+    },
+    /// Bookmark a tweet, or with `bookmark: false` remove it from bookmarks.
+    BookmarkTweet {
+        tweet: Tweet,
+        bookmark: bool,
+    },
+    BookmarkFinished {
+        tweet: Tweet,
+        bookmark: bool,
+        result: ApiResult<SingleResponse<BookmarkStatus>>,
+    },
+    /// Like (or, with `like: false`, unlike) a tweet. The like count has
+    /// already been updated optimistically.
+    LikeTweet {
+        tweet_id: String,
+        like: bool,
+    },
+    LikeFinished {
+        tweet_id: String,
+        like: bool,
+        result: ApiResult<SingleResponse<LikeStatus>>,
+    },
+    /// Repost (or, with `retweet: false`, undo the repost of) a tweet. The
+    /// repost count has already been updated optimistically.
+    RetweetTweet {
+        tweet_id: String,
+        retweet: bool,
+    },
+    RetweetFinished {
+        tweet_id: String,
+        retweet: bool,
+        result: ApiResult<SingleResponse<RetweetStatus>>,
+    },
+    /// The accounts that reposted or liked a tweet, for its users view.
+    FetchTweetUsers {
+        tweet_id: String,
+        kind: TweetUsersKind,
+    },
+    TweetUsersLoaded {
+        tweet_id: String,
+        kind: TweetUsersKind,
+        result: ApiResult<Vec<User>>,
+    },
+    /// The filtered stream's rules, for the rules view.
+    FetchStreamRules,
+    StreamRulesLoaded(ApiResult<Vec<StreamRule>>),
+    AddStreamRules {
+        rules: Vec<NewStreamRule>,
+    },
+    /// The rules created; those refused are in its `errors`.
+    StreamRulesAdded(ApiResult<ListResponse<StreamRule>>),
+    DeleteStreamRules {
+        ids: Vec<String>,
+    },
+    /// The rules asked to be deleted; those X kept are counted in the
+    /// response's `meta.summary` and described in its `errors`.
+    StreamRulesDeleted {
+        ids: Vec<String>,
+        result: ApiResult<ListResponse<StreamRule>>,
+    },
+    /// Connect to the filtered stream; it reconnects until stopped.
+    ConnectStream,
+    /// Updates from the stream task started `generation`th. Each
+    /// `ConnectStream` starts a new one, so a stopped task's late updates
+    /// are told apart and dropped.
+    StreamStatusChanged {
+        generation: u64,
+        status: StreamStatus,
+    },
+    /// A tweet (or errors) from the filtered stream.
+    StreamMessageReceived {
+        generation: u64,
+        message: Box<StreamMessage>,
+    },
+    /// Hide (or, with `hide: false`, unhide) a reply to one of your
+    /// tweets. It is already marked `[hidden]` optimistically.
+    HideReply {
+        tweet_id: String,
+        hide: bool,
+    },
+    HideReplyFinished {
+        tweet_id: String,
+        hide: bool,
+        result: ApiResult<SingleResponse<HiddenStatus>>,
+    },
+    /// Publish a post taken from the composer.
+    PostTweet(PendingPost),
+    TweetPosted {
+        post: PendingPost,
+        result: ApiResult<SingleResponse<PostedTweet>>,
+    },
+    /// Publish numbered posts as a thread, the first as a reply to
+    /// `reply_to` if given. After a failure it is re-sent with the posts
+    /// that are left, replying to the last one that went out; `posted`
+    /// holds the IDs of those that did.
+    PostThread {
+        posts: Vec<String>,
+        reply_to: Option<String>,
+        posted: Vec<String>,
+        post: PendingPost,
+    },
+    /// The IDs of the thread's posts that went out, and how it ended.
+    ThreadPosted {
+        posted: Vec<String>,
+        post: PendingPost,
+        result: ApiResult<()>,
+    },
+    /// Delete posts that were just published (`Z`), newest first.
+    DeletePosts {
+        tweet_ids: Vec<String>,
+    },
+    /// The posts asked to be deleted, those that were, and how it ended.
+    PostsDeleted {
+        tweet_ids: Vec<String>,
+        deleted: Vec<String>,
+        result: ApiResult<()>,
+    },
+    /// Delete a saved composer draft, once confirmed.
+    DeleteDraft {
+        id: String,
+    },
+    /// Suggested replies to `tweet_id` for the composer.
+    ReplyDraftsGenerated {
+        tweet_id: String,
+        result: ApiResult<Vec<String>>,
+    },
+    /// Output of `:pipe`: stdout on success, otherwise stderr or the spawn error.
+    PipeFinished {
+        command: String,
+        result: Result<String, String>,
+    },
+
     // -- API response events (sent from async tasks back to the event loop) --
+    /// A `Fetch*` request failed. `response` is its `*Loaded(Err(..))`;
+    /// `request` is kept so the error popup and `:retry` can re-send it.
+    RequestFailed {
+        request: Box<AppEvent>,
+        response: Box<AppEvent>,
+    },
+    /// A request was refused because the OAuth 2.0 token lacks `scope`.
+    /// `response` is its failure to report; `request` is re-sent if the
+    /// user signs in again with the scope added.
+    ScopeMissing {
+        scope: &'static str,
+        request: Box<AppEvent>,
+        response: Box<AppEvent>,
+    },
     HomeTimelineLoaded(ApiResult<ListResponse<Tweet>>),
     UserTimelineLoaded {
         user_id: String,
@@ -84,11 +290,36 @@ pub enum AppEvent {
         conversation_id: String,
         result: ApiResult<ListResponse<Tweet>>,
     },
-    UserLoaded(ApiResult<SingleResponse<User>>),
+    /// A profile lookup's answer. `username` is set for lookups by name.
+    UserLoaded {
+        username: Option<String>,
+        result: ApiResult<SingleResponse<User>>,
+    },
+    /// A user lookup or timeline failed because the account is protected,
+    /// suspended, or gone. Sent instead of the `*Loaded` error.
+    AccountUnavailable {
+        view: ViewKind,
+        state: AccountState,
+        detail: String,
+        /// The view showing when the request went out. A profile only
+        /// opens if it still is.
+        requested_from: Option<ViewKind>,
+    },
     SearchLoaded {
         query: String,
         result: ApiResult<ListResponse<Tweet>>,
     },
+    /// Search the tweets in the local store instead of the API (`:local`).
+    SearchLocal {
+        query: String,
+    },
+    LocalSearchLoaded {
+        query: String,
+        tweets: Vec<Tweet>,
+        includes: Includes,
+    },
+    /// The domains linked from every stored tweet (`:domains all`).
+    DomainsCounted(DomainReport),
     MentionsLoaded(ApiResult<ListResponse<Tweet>>),
     BookmarksLoaded(ApiResult<ListResponse<Tweet>>),
     FollowersLoaded {
@@ -100,8 +331,79 @@ pub enum AppEvent {
         result: ApiResult<ListResponse<User>>,
     },
 
+    /// Sinks of `[[watch.rules]]` that couldn't take a matching tweet.
+    WatchSinksFailed(Vec<crate::sinks::SinkFailure>),
+
+    // -- Lists --
+    FetchLists,
+    /// The Lists you own, then the ones you follow.
+    ListsLoaded(ApiResult<(Vec<List>, Vec<List>)>),
+    FetchListMembers {
+        list_id: String,
+    },
+    ListMembersLoaded {
+        list_id: String,
+        result: ApiResult<Vec<User>>,
+    },
+    /// Add `user` to `list`, or remove them.
+    SetListMember {
+        list: List,
+        user: User,
+        member: bool,
+    },
+    ListMemberFinished {
+        list: List,
+        user: User,
+        member: bool,
+        result: ApiResult<()>,
+    },
+    /// Create a List, or update the one with `list_id`.
+    SaveList {
+        list_id: Option<String>,
+        fields: ListFields,
+    },
+    /// The saved List's id.
+    ListSaved {
+        list_id: Option<String>,
+        fields: ListFields,
+        result: ApiResult<String>,
+    },
+    DeleteList {
+        list: List,
+    },
+    ListDeleted {
+        list: List,
+        result: ApiResult<()>,
+    },
+
+    // -- Direct messages --
+    FetchDmEvents,
+    /// Your user ID, to tell which side of a conversation is yours, and
+    /// your recent DM events across conversations.
+    DmEventsLoaded(ApiResult<(String, ListResponse<DmEvent>)>),
+    /// Open the conversation with `username`, looking up their ID first.
+    OpenDm {
+        username: String,
+    },
+    DmParticipantResolved {
+        username: String,
+        result: ApiResult<String>,
+    },
+    FetchDmConversation {
+        participant_id: String,
+    },
+    DmConversationLoaded {
+        participant_id: String,
+        result: ApiResult<ListResponse<DmEvent>>,
+    },
+
     // -- Auth --
     StartAuth,
+    /// Sign in again asking for `scope` as well, then re-send `retry`.
+    UpgradeScope {
+        scope: &'static str,
+        retry: Box<AppEvent>,
+    },
     AuthCompleted(Result<String, String>),
 
     // -- OpenRouter --
@@ -124,6 +426,52 @@ pub enum AppEvent {
         chat: bool,
     },
 
+    // -- Media --
+    FetchProfileBanner {
+        url: String,
+    },
+    ProfileBannerLoaded {
+        url: String,
+        result: ApiResult<RgbImage>,
+    },
+
+    /// A reply author's recent posts, for the thread view's preview pane.
+    FetchAuthorPreview {
+        user_id: String,
+    },
+    AuthorPreviewLoaded {
+        user_id: String,
+        result: ApiResult<ListResponse<Tweet>>,
+    },
+
+    /// Enough of a user's timeline to chart when they post.
+    FetchPostingActivity {
+        user_id: String,
+    },
+    /// Creation times of the tweets fetched.
+    PostingActivityLoaded {
+        user_id: String,
+        result: ApiResult<Vec<DateTime<Utc>>>,
+    },
+
+    /// The signed-in user and this month's usage, for the startup splash.
+    FetchAccountSummary,
+    AccountSummaryLoaded {
+        me: ApiResult<SingleResponse<User>>,
+        usage: ApiResult<SingleResponse<Usage>>,
+    },
+    /// This month's usage, for the `:usage` popup.
+    FetchUsage,
+    UsageLoaded(ApiResult<Usage>),
+
+    FetchLinkPreview {
+        url: String,
+    },
+    LinkPreviewLoaded {
+        url: String,
+        result: ApiResult<LinkPreview>,
+    },
+
     // -- HuggingFace Hub --
     FetchHuggingFaceModels,
     HuggingFaceModelsLoaded {
@@ -142,16 +490,154 @@ pub enum AppEvent {
         model_id: String,
         result: ApiResult<Vec<(Tweet, f64)>>,
     },
+    /// `:similar`: find the stored tweets nearest the selected one.
+    FindSimilar {
+        query: String,
+        tweet: Box<Tweet>,
+    },
+    SimilarLoaded {
+        query: String,
+        result: ApiResult<(Vec<Tweet>, Includes)>,
+    },
     ClusterTimeline,
     ClusteringComplete(ApiResult<ClusterResult>),
     GenerateClusterTopics,
     ClusterTopicsGenerated(u64, ApiResult<Vec<String>>),
 }
 
+/// Where the filtered stream connection stands.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum StreamStatus {
+    #[default]
+    Stopped,
+    Connecting,
+    Connected,
+    /// Waiting `delay` to reconnect after the connection dropped.
+    Reconnecting {
+        delay: Duration,
+        reason: String,
+    },
+    /// Gave up, as reconnecting can't fix `reason`.
+    Failed(String),
+}
+
+impl StreamStatus {
+    /// Shown in the stream view's title.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Stopped => "stopped".into(),
+            Self::Connecting => "connecting…".into(),
+            Self::Connected => "live".into(),
+            Self::Reconnecting { delay, .. } => {
+                format!("reconnecting in {:.1}s", delay.as_secs_f32())
+            }
+            Self::Failed(_) => "failed".into(),
+        }
+    }
+}
+
+/// A write applied to many items, one request at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    /// Bookmark tweets.
+    Bookmark,
+    /// Remove tweets from bookmarks.
+    Unbookmark,
+    /// Mute users.
+    Mute,
+}
+
+impl BulkAction {
+    /// Shown in the status bar while the action runs.
+    pub fn progress_label(self) -> &'static str {
+        match self {
+            Self::Bookmark => "bookmarking",
+            Self::Unbookmark => "unbookmarking",
+            Self::Mute => "muting",
+        }
+    }
+
+    /// Past tense and item noun for the completion message.
+    pub fn summary(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Bookmark => ("Bookmarked", "tweets"),
+            Self::Unbookmark => ("Unbookmarked", "tweets"),
+            Self::Mute => ("Muted", "authors"),
+        }
+    }
+
+    /// Confirmation kind for actions that are hard to undo; `None` runs
+    /// without asking.
+    pub fn confirm_kind(self) -> Option<&'static str> {
+        match self {
+            Self::Bookmark => None,
+            Self::Unbookmark => Some("unbookmark"),
+            Self::Mute => Some("mute"),
+        }
+    }
+}
+
+/// What the composer held when it was sent. The response acts on this
+/// rather than on the composer, which may hold another draft by then.
+#[derive(Debug, Clone)]
+pub struct PendingPost {
+    /// The text as written, before a thread is split from it.
+    pub text: String,
+    pub reply_to: Option<ReplyTarget>,
+    pub thread: bool,
+    pub reply_settings: ReplySettings,
+    /// The saved draft it was resumed from, deleted once it's posted.
+    pub draft_id: Option<String>,
+}
+
 /// API result type using `Arc<String>` so errors are `Clone`.
 pub type ApiResult<T> = Result<T, Arc<String>>;
 
 impl AppEvent {
+    /// Identity of a `Fetch*` request: endpoint, target, and pagination token.
+    /// Two requests with the same key would return the same data, so the
+    /// dispatcher only lets one of them be in flight at a time.
+    ///
+    /// Returns `None` for non-`Fetch*` variants.
+    pub fn request_key(&self) -> Option<String> {
+        let page = |token: &Option<String>| token.clone().unwrap_or_default();
+        Some(match self {
+            AppEvent::FetchHomeTimeline { pagination_token } => {
+                format!("home:{}", page(pagination_token))
+            }
+            AppEvent::FetchUserTimeline {
+                user_id,
+                pagination_token,
+            } => format!("user_timeline:{user_id}:{}", page(pagination_token)),
+            AppEvent::FetchTweet { tweet_id } => format!("tweet:{tweet_id}"),
+            AppEvent::FetchThread {
+                conversation_id,
+                pagination_token,
+            } => format!("thread:{conversation_id}:{}", page(pagination_token)),
+            AppEvent::FetchUser { username } => format!("user:{}", username.to_lowercase()),
+            AppEvent::FetchUserById { user_id } => format!("user_id:{user_id}"),
+            AppEvent::FetchSearch {
+                query,
+                pagination_token,
+            } => format!("search:{query}:{}", page(pagination_token)),
+            AppEvent::FetchMentions { pagination_token } => {
+                format!("mentions:{}", page(pagination_token))
+            }
+            AppEvent::FetchBookmarks { pagination_token } => {
+                format!("bookmarks:{}", page(pagination_token))
+            }
+            AppEvent::FetchFollowers {
+                user_id,
+                pagination_token,
+            } => format!("followers:{user_id}:{}", page(pagination_token)),
+            AppEvent::FetchFollowing {
+                user_id,
+                pagination_token,
+            } => format!("following:{user_id}:{}", page(pagination_token)),
+            _ => return None,
+        })
+    }
+
     /// Convert a `Fetch*` request into its corresponding `*Loaded(Err(..))` response.
     ///
     /// Returns `None` for non-`Fetch*` variants. This keeps the Fetch→Loaded
@@ -170,7 +656,14 @@ impl AppEvent {
                 conversation_id,
                 result: Err(err),
             },
-            AppEvent::FetchUser { .. } => AppEvent::UserLoaded(Err(err)),
+            AppEvent::FetchUser { username } => AppEvent::UserLoaded {
+                username: Some(username),
+                result: Err(err),
+            },
+            AppEvent::FetchUserById { .. } => AppEvent::UserLoaded {
+                username: None,
+                result: Err(err),
+            },
             AppEvent::FetchSearch { query, .. } => AppEvent::SearchLoaded {
                 query,
                 result: Err(err),
@@ -204,9 +697,83 @@ pub enum ViewKind {
     TextModels,
     Cluster,
     HuggingFaceModels,
+    /// Threads and profiles opened recently.
+    History,
+    /// Composer drafts closed without posting.
+    Drafts,
+    /// Domains linked from a view or the local store (`:domains`).
+    Domains,
+    /// Hashtags and topics in a view's tweets (`:tags`).
+    Tags,
+    /// The Lists you own and follow.
+    Lists,
+    ListMembers(String), // list_id
+    /// The accounts that reposted or liked a tweet.
+    TweetUsers {
+        tweet_id: String,
+        kind: TweetUsersKind,
+    },
+    /// The filtered stream's rules (`:rules`).
+    StreamRules,
+    /// Tweets matching the stream rules as they are posted (`:stream`).
+    FilteredStream,
+    /// Your direct message conversations, latest message first.
+    Messages,
+    DmConversation(String), // participant user_id
     Help,
 }
 
+/// Which of a tweet's accounts a [`ViewKind::TweetUsers`] view lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweetUsersKind {
+    /// The accounts that reposted it.
+    RetweetedBy,
+    /// The accounts that liked it.
+    LikingUsers,
+}
+
+impl TweetUsersKind {
+    /// The view's title, e.g. "Reposted by".
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::RetweetedBy => "Reposted by",
+            Self::LikingUsers => "Liked by",
+        }
+    }
+
+    /// The view's name in file names, e.g. "reposted-by".
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::RetweetedBy => "reposted-by",
+            Self::LikingUsers => "liked-by",
+        }
+    }
+
+    /// What the view is loading, for errors, e.g. "reposts".
+    pub fn noun(self) -> &'static str {
+        match self {
+            Self::RetweetedBy => "reposts",
+            Self::LikingUsers => "likes",
+        }
+    }
+}
+
+impl ViewKind {
+    /// Views only a signed-in user can load; an app-only bearer token gets
+    /// a 403 for them.
+    pub fn needs_user_context(&self) -> bool {
+        matches!(
+            self,
+            Self::Home
+                | Self::Mentions
+                | Self::Bookmarks
+                | Self::Lists
+                | Self::Messages
+                | Self::DmConversation(_)
+        )
+    }
+}
+
 /// Terminal event handler.
 ///
 /// Spawns a background task that emits tick and crossterm events, and exposes
@@ -217,21 +784,46 @@ pub struct EventHandler {
     sender: mpsc::UnboundedSender<Event>,
     /// Event receiver channel.
     receiver: mpsc::UnboundedReceiver<Event>,
+    /// Tick rate (frames per second) watched by the event task.
+    tick_rate: watch::Sender<f64>,
 }
 
 impl Default for EventHandler {
     fn default() -> Self {
-        Self::new()
+        Self::new(&AppConfig::default())
     }
 }
 
 impl EventHandler {
-    /// Constructs a new instance of [`EventHandler`] and spawns the event task.
-    pub fn new() -> Self {
+    /// Constructs a new instance of [`EventHandler`] and spawns the event task,
+    /// ticking at the configured `tick_rate_fps`.
+    pub fn new(config: &AppConfig) -> Self {
         let (sender, receiver) = mpsc::unbounded_channel();
-        let actor = EventTask::new(sender.clone());
+        let (tick_rate, tick_rate_rx) = watch::channel(config.tick_rate_fps);
+        let actor = EventTask::new(sender.clone(), tick_rate_rx);
         tokio::spawn(async { actor.run().await });
-        Self { sender, receiver }
+        Self {
+            sender,
+            receiver,
+            tick_rate,
+        }
+    }
+
+    /// A handler with no background task: app events can still be queued,
+    /// but no ticks or terminal events are produced.
+    pub fn headless() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let (tick_rate, _) = watch::channel(AppConfig::default().tick_rate_fps);
+        Self {
+            sender,
+            receiver,
+            tick_rate,
+        }
+    }
+
+    /// Change the tick rate of the running event task.
+    pub fn set_tick_rate(&self, fps: f64) {
+        let _ = self.tick_rate.send(fps);
     }
 
     /// Receives the next event, blocking until one is available.
@@ -242,6 +834,11 @@ impl EventHandler {
             .ok_or_eyre("Failed to receive event")
     }
 
+    /// Returns the next event if one is already queued, without waiting.
+    pub fn try_next(&mut self) -> Option<Event> {
+        self.receiver.try_recv().ok()
+    }
+
     /// Queue an app event to be processed by the event loop.
     pub fn send(&self, app_event: AppEvent) {
         let _ = self.sender.send(Event::App(Box::new(app_event)));
@@ -256,17 +853,17 @@ impl EventHandler {
 /// Background task that reads crossterm events and emits ticks.
 struct EventTask {
     sender: mpsc::UnboundedSender<Event>,
+    tick_rate: watch::Receiver<f64>,
 }
 
 impl EventTask {
-    fn new(sender: mpsc::UnboundedSender<Event>) -> Self {
-        Self { sender }
+    fn new(sender: mpsc::UnboundedSender<Event>, tick_rate: watch::Receiver<f64>) -> Self {
+        Self { sender, tick_rate }
     }
 
-    async fn run(self) -> color_eyre::Result<()> {
-        let tick_rate = Duration::from_secs_f64(1.0 / TICK_FPS);
+    async fn run(mut self) -> color_eyre::Result<()> {
         let mut reader = crossterm::event::EventStream::new();
-        let mut tick = tokio::time::interval(tick_rate);
+        let mut tick = tokio::time::interval(tick_period(*self.tick_rate.borrow()));
         loop {
             let tick_delay = tick.tick();
             let crossterm_event = reader.next().fuse();
@@ -274,6 +871,9 @@ impl EventTask {
                 _ = self.sender.closed() => {
                     break;
                 }
+                Ok(()) = self.tick_rate.changed() => {
+                    tick = tokio::time::interval(tick_period(*self.tick_rate.borrow()));
+                }
                 _ = tick_delay => {
                     self.send(Event::Tick);
                 }
@@ -289,3 +889,14 @@ impl EventTask {
         let _ = self.sender.send(event);
     }
 }
+
+/// Interval between ticks for a rate in frames per second, clamped to
+/// 1–240 fps so a bad config value can't stall or spin the loop.
+fn tick_period(fps: f64) -> Duration {
+    let fps = if fps.is_nan() {
+        30.0
+    } else {
+        fps.clamp(1.0, 240.0)
+    };
+    Duration::from_secs_f64(1.0 / fps)
+}
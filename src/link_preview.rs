@@ -0,0 +1,217 @@
+//! Link preview cards built from a page's OpenGraph metadata.
+//!
+//! The X API only sometimes fills `UrlEntity.title`/`description`, so for the
+//! selected tweet we optionally fetch the linked page ourselves. Fetches are
+//! bounded by a short timeout and a body size cap, and only the `<head>`
+//! metadata is looked at.
+
+use std::time::Duration;
+
+use crate::api::types::{Tweet, UrlEntity};
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Stop reading the page after this many bytes; OpenGraph tags live in the
+/// `<head>`, well within this.
+const MAX_BODY_BYTES: usize = 256 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkPreview {
+    pub url: String,
+    pub site: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// The link a tweet's preview card is for: the first URL that isn't a link
+/// back to X itself (media and quoted tweets expand to x.com URLs).
+pub fn preview_entity(tweet: &Tweet) -> Option<&UrlEntity> {
+    tweet.entities.as_ref()?.urls.as_ref()?.iter().find(|u| {
+        let target = u.expanded_url.as_deref().unwrap_or(&u.url);
+        url::Url::parse(target).is_ok_and(|parsed| {
+            !matches!(
+                parsed.host_str(),
+                Some("x.com" | "www.x.com" | "twitter.com" | "www.twitter.com" | "t.co")
+            )
+        })
+    })
+}
+
+/// Preview from metadata the API already supplied, when it has a title.
+pub fn from_entity(entity: &UrlEntity) -> Option<LinkPreview> {
+    let url = entity
+        .expanded_url
+        .clone()
+        .unwrap_or_else(|| entity.url.clone());
+    Some(LinkPreview {
+        site: host_of(&url),
+        title: entity.title.clone().filter(|t| !t.trim().is_empty())?,
+        description: entity.description.clone().filter(|d| !d.trim().is_empty()),
+        url,
+    })
+}
+
+/// Fetch `url` and build a preview from its OpenGraph tags.
+pub async fn fetch(url: &str) -> Result<LinkPreview, String> {
+    let client = reqwest::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let mut resp = client
+        .get(url)
+        .header("Accept", "text/html")
+        .send()
+        .await
+        .and_then(|resp| resp.error_for_status())
+        .map_err(|e| format!("failed to fetch {url}: {e}"))?;
+
+    let is_html = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.contains("html"));
+    if !is_html {
+        return Err(format!("{url} is not an HTML page"));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = resp
+        .chunk()
+        .await
+        .map_err(|e| format!("failed to read {url}: {e}"))?
+    {
+        body.extend_from_slice(&chunk);
+        if body.len() >= MAX_BODY_BYTES {
+            break;
+        }
+    }
+
+    parse_open_graph(&String::from_utf8_lossy(&body), url)
+        .ok_or_else(|| format!("no preview metadata at {url}"))
+}
+
+/// Extract `og:title`/`og:description`/`og:site_name` (falling back to
+/// `<title>` and `description`) from an HTML document.
+pub fn parse_open_graph(html: &str, url: &str) -> Option<LinkPreview> {
+    let mut title = None;
+    let mut description = None;
+    let mut site = None;
+    let mut fallback_description = None;
+
+    let mut rest = html;
+    while let Some(start) = find_ignore_case(rest, "<meta") {
+        let tag = &rest[start..];
+        let end = tag.find('>').unwrap_or(tag.len());
+        let attrs = &tag[5..end];
+        rest = &tag[end..];
+
+        let key = attr(attrs, "property").or_else(|| attr(attrs, "name"));
+        let Some(content) = attr(attrs, "content").filter(|c| !c.trim().is_empty()) else {
+            continue;
+        };
+        match key.map(|k| k.to_ascii_lowercase()).as_deref() {
+            Some("og:title") => title = title.or(Some(content)),
+            Some("og:description") => description = description.or(Some(content)),
+            Some("og:site_name") => site = site.or(Some(content)),
+            Some("description") => fallback_description = fallback_description.or(Some(content)),
+            _ => {}
+        }
+    }
+
+    let title = title
+        .or_else(|| {
+            let start = find_ignore_case(html, "<title")?;
+            let open_end = start + html[start..].find('>')? + 1;
+            let close = open_end + find_ignore_case(&html[open_end..], "</title")?;
+            Some(html[open_end..close].to_string())
+        })
+        .filter(|t| !t.trim().is_empty())?;
+
+    Some(LinkPreview {
+        url: url.to_string(),
+        site: site.map(|s| decode_entities(&s)).or_else(|| host_of(url)),
+        title: decode_entities(title.trim()),
+        description: description
+            .or(fallback_description)
+            .map(|d| decode_entities(d.trim())),
+    })
+}
+
+fn host_of(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url).ok()?;
+    let host = parsed.host_str()?;
+    Some(host.strip_prefix("www.").unwrap_or(host).to_string())
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Value of `name="..."` (or single-quoted / bare) within a tag's attributes.
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    let mut search = attrs;
+    loop {
+        let pos = find_ignore_case(search, name)?;
+        let preceded_ok = pos == 0 || search.as_bytes()[pos - 1].is_ascii_whitespace();
+        let after = search[pos + name.len()..].trim_start();
+        search = &search[pos + name.len()..];
+        if !preceded_ok {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        return Some(match value.chars().next()? {
+            quote @ ('"' | '\'') => {
+                let inner = &value[1..];
+                inner[..inner.find(quote)?].to_string()
+            }
+            _ => value
+                .split(|c: char| c.is_ascii_whitespace() || c == '/')
+                .next()?
+                .to_string(),
+        });
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_open_graph_tags() {
+        let html = r#"<html><head>
+            <title>Fallback</title>
+            <meta property="og:title" content="Rust &amp; You">
+            <META name='description' content='plain description'>
+            <meta property="og:site_name" content="The Blog" />
+            </head><body></body></html>"#;
+        let preview = parse_open_graph(html, "https://www.example.com/post").unwrap();
+        assert_eq!(preview.title, "Rust & You");
+        assert_eq!(preview.site.as_deref(), Some("The Blog"));
+        assert_eq!(preview.description.as_deref(), Some("plain description"));
+    }
+
+    #[test]
+    fn falls_back_to_title_and_host() {
+        let html = "<head><title> Just a title </title></head>";
+        let preview = parse_open_graph(html, "https://www.example.com/").unwrap();
+        assert_eq!(preview.title, "Just a title");
+        assert_eq!(preview.site.as_deref(), Some("example.com"));
+        assert_eq!(preview.description, None);
+        assert_eq!(parse_open_graph("<p>nothing</p>", "https://a.b/"), None);
+    }
+}
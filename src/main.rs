@@ -5,13 +5,26 @@ pub mod cli;
 pub mod clipboard;
 pub mod command;
 pub mod config;
+pub mod cron;
+pub mod doctor;
+pub mod domains;
 pub mod embeddings;
 pub mod event;
 pub mod huggingface;
+pub mod link_preview;
+pub mod media;
 pub mod mlx;
 pub mod openrouter;
+pub mod perf;
+pub mod sinks;
+pub mod stats;
+pub mod store;
+pub mod tags;
+pub mod terminal;
+pub mod thread_split;
 pub mod ui;
 
+use api::recording::Recording;
 use app::App;
 use auth::credentials::CredentialSet;
 use clap::Parser;
@@ -29,25 +42,31 @@ async fn main() -> color_eyre::Result<()> {
         .init();
 
     let cli = Cli::parse();
+    if let Some(profile) = cli.profile.clone() {
+        config::set_profile(profile);
+    }
+    let recording = cli.recording();
 
     match cli.command {
         // No subcommand or explicit `tui` → launch the interactive TUI.
-        None | Some(CliCommand::Tui) => run_tui().await,
+        None | Some(CliCommand::Tui) => run_tui(cli.accessible, recording).await,
         // `auth` → standalone PKCE flow.
-        Some(CliCommand::Auth) => run_auth_command().await,
+        Some(CliCommand::Auth { print_redirect_uri }) => run_auth_command(print_redirect_uri).await,
         // `openrouter-auth` → OpenRouter PKCE flow.
         Some(CliCommand::OpenRouterAuth) => run_openrouter_auth_command().await,
         // All other subcommands → non-interactive JSONL output.
-        Some(cmd) => cli::run_command(cmd).await,
+        Some(cmd) => cli::run_command(cmd, recording).await,
     }
 }
 
 /// Launch the interactive TUI.
-async fn run_tui() -> color_eyre::Result<()> {
-    let config = load_config();
+async fn run_tui(accessible: bool, recording: Option<Recording>) -> color_eyre::Result<()> {
+    let mut config = load_config();
+    config.accessible |= accessible;
+    config.recording = recording.clone();
 
     // Load credentials, tolerating missing creds (TUI can still show help etc.).
-    let (creds, api_client) = match cli::build_api_client() {
+    let (creds, api_client) = match cli::build_api_client(recording) {
         Ok((client, creds)) => {
             tracing::info!(method = ?client.auth_method(), "auth initialized");
             (creds, Some(client))
@@ -94,8 +113,8 @@ async fn run_openrouter_auth_command() -> color_eyre::Result<()> {
     match openrouter::auth::start_openrouter_auth(port).await {
         Ok(_) => {
             println!(
-                "Authentication successful! API key saved to \
-                 ~/.config/xplorertui/openrouter_tokens.json"
+                "Authentication successful! API key saved to {}",
+                openrouter::auth::key_path().display()
             );
             Ok(())
         }
@@ -106,8 +125,15 @@ async fn run_openrouter_auth_command() -> color_eyre::Result<()> {
 }
 
 /// Standalone `xplorertui auth` command — runs the PKCE flow outside the TUI.
-async fn run_auth_command() -> color_eyre::Result<()> {
+/// With `--print-redirect-uri`, lists the callback URIs to register instead.
+async fn run_auth_command(print_redirect_uri: bool) -> color_eyre::Result<()> {
     let config = load_config();
+    if print_redirect_uri {
+        for port in config.oauth_callback_ports() {
+            println!("{}", auth::oauth2_pkce::redirect_url(port));
+        }
+        return Ok(());
+    }
 
     // Load .env files so X_CLIENT_ID is available, but don't require a full
     // credential set — the user may only have OAuth2 vars configured.
@@ -119,7 +145,11 @@ async fn run_auth_command() -> color_eyre::Result<()> {
         color_eyre::eyre::eyre!(
             "X_CLIENT_ID is not set.\n\
              OAuth 2.0 PKCE requires X_CLIENT_ID (and optionally X_CLIENT_SECRET).\n\
-             Add them to ~/.config/xplorertui/.env or your environment."
+             Add them to {} or your environment.",
+            config::config_dir()
+                .unwrap_or_default()
+                .join(".env")
+                .display()
         )
     })?;
 
@@ -139,9 +169,14 @@ async fn run_auth_command() -> color_eyre::Result<()> {
         }
     }
 
-    match auth::oauth2_pkce::start_pkce_flow(&oauth2_creds, config.oauth_callback_port).await {
+    match auth::oauth2_pkce::start_pkce_flow(&oauth2_creds, &config.oauth_callback_ports(), &[])
+        .await
+    {
         Ok(_) => {
-            println!("Authentication successful! Tokens saved to ~/.config/xplorertui/tokens.json");
+            println!(
+                "Authentication successful! Tokens saved to {}",
+                auth::oauth2_pkce::tokens_path().display()
+            );
             Ok(())
         }
         Err(e) => Err(color_eyre::eyre::eyre!("Authentication failed: {e}")),
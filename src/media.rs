@@ -0,0 +1,49 @@
+//! Fetching and decoding images for in-terminal previews.
+
+use std::time::Duration;
+
+use crate::ui::halfblock::RgbImage;
+
+/// Longest side, in pixels, images are downscaled to after decoding. Cells
+/// are far coarser than this, so there is no point keeping more.
+const MAX_DIMENSION: u32 = 600;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Download an image and decode it into a downscaled RGB buffer.
+pub async fn fetch_image(url: &str) -> Result<RgbImage, String> {
+    let client = reqwest::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let bytes = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|resp| resp.error_for_status())
+        .map_err(|e| format!("failed to fetch image: {e}"))?
+        .bytes()
+        .await
+        .map_err(|e| format!("failed to read image: {e}"))?;
+
+    tokio::task::spawn_blocking(move || decode(&bytes))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn decode(bytes: &[u8]) -> Result<RgbImage, String> {
+    let decoded =
+        image::load_from_memory(bytes).map_err(|e| format!("failed to decode image: {e}"))?;
+    let rgb = decoded.thumbnail(MAX_DIMENSION, MAX_DIMENSION).into_rgb8();
+    Ok(RgbImage {
+        width: rgb.width(),
+        height: rgb.height(),
+        pixels: rgb.pixels().map(|p| p.0).collect(),
+    })
+}
+
+/// URL of a profile banner at a size suited to a terminal strip. X serves
+/// banners at fixed size variants appended to the base URL.
+pub fn banner_url(profile_banner_url: &str) -> String {
+    format!("{}/600x200", profile_banner_url.trim_end_matches('/'))
+}
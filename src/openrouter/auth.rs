@@ -30,10 +30,11 @@ pub struct OpenRouterKeyData {
     pub user_id: Option<String>,
 }
 
-fn key_path() -> PathBuf {
-    dirs::home_dir()
+/// Where the OpenRouter API key is stored for the active profile.
+pub fn key_path() -> PathBuf {
+    crate::config::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
-        .join(".config/xplorertui/openrouter_tokens.json")
+        .join("openrouter_tokens.json")
 }
 
 pub fn save_key_data(data: &OpenRouterKeyData) -> Result<(), OpenRouterError> {
@@ -59,7 +60,7 @@ pub fn load_key_data() -> Result<Option<OpenRouterKeyData>, OpenRouterError> {
 /// Load the OpenRouter API key.
 ///
 /// Priority: `OPENROUTER_API_KEY` env var > stored file at
-/// `~/.config/xplorertui/openrouter_tokens.json`.
+/// `~/.config/xplorertui/openrouter_tokens.json` (see [`key_path`]).
 pub fn load_api_key() -> Result<String, OpenRouterError> {
     if let Ok(key) = std::env::var("OPENROUTER_API_KEY")
         && !key.is_empty()
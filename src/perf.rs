@@ -0,0 +1,116 @@
+//! Lightweight performance counters for the `:perf` overlay.
+//!
+//! Rendering only has `&App`, so per-frame values use interior mutability.
+//! Request latency is recorded from spawned fetch tasks through a shared
+//! handle.
+
+use std::cell::{Cell, RefCell};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Weight of the newest sample in the frame-time moving average.
+const FRAME_EMA_ALPHA: f64 = 0.1;
+
+/// Label and latency of a completed API request.
+#[derive(Debug, Clone)]
+pub struct RequestTiming {
+    pub label: String,
+    pub latency: Duration,
+}
+
+/// Shared slot that fetch tasks write their latency into.
+pub type RequestRecorder = Arc<Mutex<Option<RequestTiming>>>;
+
+#[derive(Debug, Default)]
+pub struct PerfStats {
+    last_frame: Cell<Duration>,
+    avg_frame: Cell<Duration>,
+    /// Render time of the most recent frame of each view, by view name.
+    layout: RefCell<Vec<(&'static str, Duration)>>,
+    last_request: RequestRecorder,
+}
+
+impl PerfStats {
+    /// Record how long a full `terminal.draw` took.
+    pub fn record_frame(&self, elapsed: Duration) {
+        self.last_frame.set(elapsed);
+        let avg = if self.avg_frame.get().is_zero() {
+            elapsed
+        } else {
+            self.avg_frame
+                .get()
+                .mul_f64(1.0 - FRAME_EMA_ALPHA)
+                .saturating_add(elapsed.mul_f64(FRAME_EMA_ALPHA))
+        };
+        self.avg_frame.set(avg);
+    }
+
+    /// Record how long rendering `view` took in the current frame.
+    pub fn record_layout(&self, view: &'static str, elapsed: Duration) {
+        let mut layout = self.layout.borrow_mut();
+        match layout.iter_mut().find(|(name, _)| *name == view) {
+            Some(entry) => entry.1 = elapsed,
+            None => layout.push((view, elapsed)),
+        }
+    }
+
+    /// Handle for spawned tasks to report request latency.
+    pub fn request_recorder(&self) -> RequestRecorder {
+        Arc::clone(&self.last_request)
+    }
+
+    pub fn last_frame(&self) -> Duration {
+        self.last_frame.get()
+    }
+
+    pub fn avg_frame(&self) -> Duration {
+        self.avg_frame.get()
+    }
+
+    pub fn layout(&self) -> Vec<(&'static str, Duration)> {
+        self.layout.borrow().clone()
+    }
+
+    pub fn last_request(&self) -> Option<RequestTiming> {
+        self.last_request
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+/// Store a request's latency into a recorder handle.
+pub fn record_request(recorder: &RequestRecorder, label: String, latency: Duration) {
+    *recorder.lock().unwrap_or_else(|e| e.into_inner()) = Some(RequestTiming { label, latency });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_average_starts_at_first_sample_and_smooths() {
+        let perf = PerfStats::default();
+        perf.record_frame(Duration::from_millis(10));
+        assert_eq!(perf.avg_frame(), Duration::from_millis(10));
+
+        perf.record_frame(Duration::from_millis(20));
+        assert_eq!(perf.last_frame(), Duration::from_millis(20));
+        assert_eq!(perf.avg_frame(), Duration::from_millis(11));
+    }
+
+    #[test]
+    fn layout_keeps_latest_sample_per_view() {
+        let perf = PerfStats::default();
+        perf.record_layout("home", Duration::from_millis(3));
+        perf.record_layout("thread", Duration::from_millis(1));
+        perf.record_layout("home", Duration::from_millis(2));
+        assert_eq!(
+            perf.layout(),
+            vec![
+                ("home", Duration::from_millis(2)),
+                ("thread", Duration::from_millis(1))
+            ]
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Delivery of tweets matching `[[watch.rules]]` to the rules' sinks: a
+//! JSONL file, a webhook, or a shell command.
+
+use std::fmt;
+use std::io::Write as _;
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::io::AsyncWriteExt;
+
+use crate::api::types::{Includes, Tweet, User};
+use crate::cli::denormalize_tweet;
+use crate::config::{WatchConfig, WatchRule, expand_home};
+
+/// How long a webhook may take to accept a record.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One place a `[[watch.rules]]` entry sends records to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Sink {
+    AppendTo(String),
+    Webhook(String),
+    Command(String),
+}
+
+impl Sink {
+    /// The sinks set on `rule`.
+    fn of(rule: &WatchRule) -> impl Iterator<Item = Sink> {
+        [
+            rule.append_to.clone().map(Sink::AppendTo),
+            rule.webhook.clone().map(Sink::Webhook),
+            rule.command.clone().map(Sink::Command),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Names the sink in the delivery log (see
+    /// [`crate::store::WatchDelivery`]).
+    pub fn key(&self) -> String {
+        match self {
+            Sink::AppendTo(path) => format!("append_to {path}"),
+            Sink::Webhook(url) => format!("webhook {url}"),
+            Sink::Command(command) => format!("command {command}"),
+        }
+    }
+
+    async fn deliver(&self, record: &serde_json::Value) -> Result<(), String> {
+        match self {
+            Sink::AppendTo(path) => {
+                let path = expand_home(path);
+                let appended = (|| {
+                    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    let mut file = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&path)?;
+                    writeln!(file, "{record}")
+                })();
+                appended.map_err(|e| format!("{}: {e}", path.display()))
+            }
+            Sink::Webhook(url) => reqwest::Client::new()
+                .post(url)
+                .timeout(WEBHOOK_TIMEOUT)
+                .json(record)
+                .send()
+                .await
+                .and_then(|resp| resp.error_for_status())
+                .map(drop)
+                .map_err(|e| format!("{url}: {e}")),
+            Sink::Command(command) => run_command(command, record)
+                .await
+                .map_err(|e| format!("{command}: {e}")),
+        }
+    }
+}
+
+/// The sinks of every rule `tweet` matches, each once even when several
+/// rules send to it.
+pub fn matching_sinks(watch: &WatchConfig, tweet: &Tweet, author: Option<&User>) -> Vec<Sink> {
+    let mut sinks = Vec::new();
+    for sink in watch.sink_rules(tweet, author).flat_map(Sink::of) {
+        if !sinks.contains(&sink) {
+            sinks.push(sink);
+        }
+    }
+    sinks
+}
+
+/// A sink that couldn't take a tweet's record.
+#[derive(Debug, Clone)]
+pub struct SinkFailure {
+    pub tweet_id: String,
+    pub sink: Sink,
+    /// The sink and what went wrong.
+    pub error: String,
+}
+
+impl fmt::Display for SinkFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.error)
+    }
+}
+
+/// Send each tweet to the sinks of every rule it matches, as the record the
+/// CLI prints for it. Returns what failed; one sink failing doesn't stop
+/// the others.
+pub async fn deliver_matching<'a>(
+    watch: &WatchConfig,
+    tweets: impl IntoIterator<Item = &'a Tweet>,
+    includes: &Option<Includes>,
+) -> Vec<SinkFailure> {
+    let mut deliveries = Vec::new();
+    for tweet in tweets {
+        for sink in matching_sinks(watch, tweet, author(tweet, includes)) {
+            deliveries.push((tweet.clone(), sink));
+        }
+    }
+    deliver(&deliveries, includes).await
+}
+
+/// Send each tweet's record to the sink paired with it. Returns what
+/// failed; one sink failing doesn't stop the others.
+pub async fn deliver(
+    deliveries: &[(Tweet, Sink)],
+    includes: &Option<Includes>,
+) -> Vec<SinkFailure> {
+    let mut failures = Vec::new();
+    let mut record: Option<(&str, serde_json::Value)> = None;
+    for (tweet, sink) in deliveries {
+        if record.as_ref().is_none_or(|(id, _)| *id != tweet.id) {
+            record = Some((&tweet.id, denormalize_tweet(tweet, includes)));
+        }
+        let Some((_, record)) = &record else {
+            continue;
+        };
+        if let Err(error) = sink.deliver(record).await {
+            failures.push(SinkFailure {
+                tweet_id: tweet.id.clone(),
+                sink: sink.clone(),
+                error,
+            });
+        }
+    }
+    failures
+}
+
+/// The tweet's author from the response's `includes`.
+fn author<'a>(tweet: &Tweet, includes: &'a Option<Includes>) -> Option<&'a User> {
+    let id = tweet.author_id.as_deref()?;
+    includes
+        .as_ref()?
+        .users
+        .as_ref()?
+        .iter()
+        .find(|u| u.id == id)
+}
+
+/// Run `command` with `sh -c`, writing the record to its stdin.
+async fn run_command(command: &str, record: &serde_json::Value) -> std::io::Result<()> {
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(format!("{record}\n").as_bytes()).await?;
+    }
+    let status = child.wait().await?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(std::io::Error::other(format!("exited with {status}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn matching_tweets_reach_every_sink_of_their_rules() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .and(body_partial_json(serde_json::json!({
+                "tweet": { "id": "1" },
+                "author": { "username": "alice" },
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = std::env::temp_dir().join(format!("xplorertui-sinks-{}", std::process::id()));
+        let file = dir.join("watched.jsonl");
+        let piped = dir.join("piped.jsonl");
+        let watch = WatchConfig {
+            rules: vec![
+                WatchRule {
+                    keywords: vec!["outage".into()],
+                    append_to: Some(file.display().to_string()),
+                    webhook: Some(format!("{}/hook", server.uri())),
+                    ..WatchRule::default()
+                },
+                WatchRule {
+                    authors: vec!["alice".into()],
+                    command: Some(format!("cat >> '{}'", piped.display())),
+                    ..WatchRule::default()
+                },
+                WatchRule {
+                    keywords: vec!["hello".into()],
+                    command: Some("exit 3".into()),
+                    ..WatchRule::default()
+                },
+            ],
+            ..WatchConfig::default()
+        };
+        let tweets: Vec<Tweet> = serde_json::from_value(serde_json::json!([
+            { "id": "1", "text": "Outage in us-east", "author_id": "7" },
+            { "id": "2", "text": "all good", "author_id": "8" },
+        ]))
+        .unwrap();
+        let includes = serde_json::from_value(serde_json::json!({
+            "users": [{ "id": "7", "username": "alice", "name": "Alice" }],
+        }))
+        .unwrap();
+
+        let errors = deliver_matching(&watch, &tweets, &Some(includes)).await;
+        assert!(errors.is_empty(), "{errors:?}");
+        for written in [&file, &piped] {
+            let contents = std::fs::read_to_string(written).unwrap();
+            let record: serde_json::Value = serde_json::from_str(contents.trim()).unwrap();
+            assert_eq!(record["tweet"]["id"], "1");
+        }
+
+        let hello: Vec<Tweet> =
+            serde_json::from_value(serde_json::json!([{ "id": "3", "text": "hello" }])).unwrap();
+        let failures = deliver_matching(&watch, &hello, &None).await;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].tweet_id, "3");
+        assert!(failures[0].error.starts_with("exit 3: "), "{failures:?}");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+//! Word counts and reading-time estimates, for the thread view and exports.
+
+use std::fmt;
+
+use crate::api::types::Tweet;
+
+/// Average silent reading speed for English prose.
+const WORDS_PER_MINUTE: usize = 238;
+
+/// How long a piece of text is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextStats {
+    pub words: usize,
+    /// Characters as people count them, not bytes.
+    pub chars: usize,
+}
+
+impl TextStats {
+    pub fn of(text: &str) -> Self {
+        Self {
+            words: text.split_whitespace().count(),
+            chars: text.chars().count(),
+        }
+    }
+
+    /// A tweet's full text, long-form posts included.
+    pub fn of_tweet(tweet: &Tweet) -> Self {
+        Self::of(
+            tweet
+                .note_tweet
+                .as_ref()
+                .map_or(&tweet.text, |note| &note.text),
+        )
+    }
+
+    /// The tweets' texts added up.
+    pub fn of_tweets<'a>(tweets: impl IntoIterator<Item = &'a Tweet>) -> Self {
+        tweets
+            .into_iter()
+            .map(Self::of_tweet)
+            .fold(Self::default(), |total, stats| Self {
+                words: total.words + stats.words,
+                chars: total.chars + stats.chars,
+            })
+    }
+
+    /// Whole minutes it takes to read, rounded up; at least one for any
+    /// text at all.
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE)
+    }
+
+    /// "~3 min read", or "<1 min read" for nothing to read.
+    pub fn reading_time(&self) -> String {
+        match self.reading_minutes() {
+            0 => "<1 min read".to_string(),
+            minutes => format!("~{minutes} min read"),
+        }
+    }
+}
+
+impl fmt::Display for TextStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} word{} \u{00B7} {} char{}",
+            self.words,
+            plural(self.words),
+            self.chars,
+            plural(self.chars)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_words_and_characters_and_rounds_reading_time_up() {
+        let stats = TextStats::of("héllo  wörld\n🦀");
+        assert_eq!(
+            stats,
+            TextStats {
+                words: 3,
+                chars: 14
+            }
+        );
+        assert_eq!(stats.to_string(), "3 words · 14 chars");
+        assert_eq!(stats.reading_time(), "~1 min read");
+
+        let long = TextStats {
+            words: WORDS_PER_MINUTE * 2 + 1,
+            chars: 0,
+        };
+        assert_eq!(long.reading_time(), "~3 min read");
+        assert_eq!(TextStats::default().reading_time(), "<1 min read");
+        assert_eq!(TextStats::of("one").to_string(), "1 word · 3 chars");
+    }
+}
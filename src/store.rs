@@ -0,0 +1,1628 @@
+//! Local on-disk store under `~/.config/xplorertui/cache/`.
+//!
+//! - Timeline snapshots: the last successfully loaded page set of each
+//!   top-level timeline, so the TUI can still show something useful when
+//!   there are no credentials or the API is unreachable.
+//! - Profile cache: user lookups with a TTL, served immediately and
+//!   revalidated in the background when stale.
+//! - Users cache: authors seen in `includes`, bounded and persisted so names
+//!   render immediately on the next launch.
+//!
+//! - History: threads and profiles opened, for the recently viewed list.
+//! - Metrics history: a tweet's like, repost and impression counts each
+//!   time it was fetched, charted in the thread view.
+//! - Embeddings: vectors of stored tweets from one embedding model, so
+//!   `:similar` only embeds tweets it hasn't seen before.
+//!
+//! Muted conversations, unsent composer drafts and the log of tweets sent
+//! to watch rule sinks are settings and work rather than cache, so they
+//! live next to `config.toml` (`muted_conversations.json`, `drafts.json`,
+//! `watch_deliveries.jsonl`) and survive `cache clear`.
+//!
+//! Everything under `cache/` is zstd-compressed (`*.zst`). Plain JSON files
+//! written by older versions are still read, and replaced on the next write
+//! or by `xplorertui cache gc`.
+//!
+//! The TUI and the CLI may run at the same time on one profile. Writes hold
+//! `state.lock` exclusively and replace files by renaming, while reads share
+//! it, so they never see a half-written file. Only the TUI holding
+//! [`InstanceLock`] saves its in-memory caches; a second TUI just reads them.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io::{Read as _, Write as _};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::api::types::{Includes, ReplySettings, Tweet, User};
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("no config directory available")]
+    NoConfigDir,
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("another xplorertui (pid {0}) is open on this profile")]
+    InUse(String),
+    #[error(
+        "{} couldn't be read ({reason}), so it was moved to {} and a new one started",
+        .path.display(),
+        .backup.display()
+    )]
+    SetAside {
+        path: PathBuf,
+        backup: PathBuf,
+        reason: String,
+    },
+}
+
+/// Timelines with on-disk state (snapshots and spilled tweets).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineKey {
+    Home,
+    Mentions,
+    Bookmarks,
+    UserTimeline,
+}
+
+impl TimelineKey {
+    pub const ALL: [Self; 4] = [
+        Self::Home,
+        Self::Mentions,
+        Self::Bookmarks,
+        Self::UserTimeline,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Home => "home",
+            Self::Mentions => "mentions",
+            Self::Bookmarks => "bookmarks",
+            Self::UserTimeline => "user_timeline",
+        }
+    }
+
+    fn file_name(self) -> String {
+        format!("{}.json", self.name())
+    }
+}
+
+/// A timeline as it was last seen online.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineSnapshot {
+    pub saved_at: DateTime<Utc>,
+    pub tweets: Vec<Tweet>,
+    #[serde(default)]
+    pub includes: Option<Includes>,
+}
+
+/// The profile's config directory, which everything here lives under. Tests
+/// get a scratch directory instead, so they never write to the real one.
+fn root_dir() -> Option<PathBuf> {
+    if cfg!(test) {
+        let dir = format!("xplorertui-test-{}", std::process::id());
+        return Some(std::env::temp_dir().join(dir));
+    }
+    crate::config::config_dir()
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    root_dir().map(|dir| dir.join("cache"))
+}
+
+// ---------------------------------------------------------------------------
+// Locking
+// ---------------------------------------------------------------------------
+
+const STATE_LOCK_FILE: &str = "state.lock";
+const INSTANCE_LOCK_FILE: &str = "tui.pid";
+
+/// Open (creating) a lock file in the config directory ([`root_dir`]).
+fn open_lock_file(name: &str) -> Result<fs::File, StoreError> {
+    let dir = root_dir().ok_or(StoreError::NoConfigDir)?;
+    fs::create_dir_all(&dir)?;
+    Ok(fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .read(true)
+        .write(true)
+        .open(dir.join(name))?)
+}
+
+/// Advisory lock on `state.lock`, released on drop. Writers hold it
+/// exclusively, so e.g. `cache clear` can't interleave with the TUI saving;
+/// readers share it, so any number of them can load at once.
+struct StateLock {
+    _file: fs::File,
+}
+
+impl StateLock {
+    fn exclusive() -> Result<Self, StoreError> {
+        let file = open_lock_file(STATE_LOCK_FILE)?;
+        file.lock()?;
+        Ok(Self { _file: file })
+    }
+
+    /// Readers go ahead unlocked if the lock file can't be opened; renamed
+    /// writes still keep them from seeing half a file.
+    fn shared() -> Option<Self> {
+        let file = open_lock_file(STATE_LOCK_FILE).ok()?;
+        file.lock_shared().ok()?;
+        Some(Self { _file: file })
+    }
+}
+
+/// Held by the TUI for as long as it runs, with its PID in `tui.pid`. Only
+/// the holder saves the caches kept in memory (profiles, users, history,
+/// metrics and timeline snapshots), which a second TUI would otherwise
+/// overwrite with its own copies on exit.
+#[derive(Debug)]
+pub struct InstanceLock {
+    _file: fs::File,
+}
+
+impl InstanceLock {
+    /// Take the lock without waiting. Fails with [`StoreError::InUse`] while
+    /// another TUI holds it.
+    pub fn try_acquire() -> Result<Self, StoreError> {
+        Self::try_acquire_file(open_lock_file(INSTANCE_LOCK_FILE)?)
+    }
+
+    fn try_acquire_file(mut file: fs::File) -> Result<Self, StoreError> {
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(fs::TryLockError::WouldBlock) => {
+                let mut pid = String::new();
+                let pid = match file.read_to_string(&mut pid) {
+                    Ok(_) if !pid.trim().is_empty() => pid.trim().to_string(),
+                    _ => "unknown".into(),
+                };
+                return Err(StoreError::InUse(pid));
+            }
+            Err(fs::TryLockError::Error(e)) => return Err(e.into()),
+        }
+        file.set_len(0)?;
+        write!(file, "{}", std::process::id())?;
+        Ok(Self { _file: file })
+    }
+}
+
+/// Write `contents` to a temporary file next to `path`, then rename it over
+/// `path`, so readers see either the old file or the new one.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), StoreError> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Compression
+// ---------------------------------------------------------------------------
+
+/// zstd level for cache files: fast to write, and JSON still shrinks
+/// several-fold.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// `path` with `.zst` appended, e.g. `home.json.zst`.
+fn compressed_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".zst");
+    PathBuf::from(name)
+}
+
+/// Write `contents` compressed to `<path>.zst`, replacing any plain file
+/// left at `path` by an older version.
+fn write_compressed(path: &Path, contents: &[u8]) -> Result<(), StoreError> {
+    let compressed = zstd::encode_all(contents, COMPRESSION_LEVEL)?;
+    write_atomic(&compressed_path(path), &compressed)?;
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Read `<path>.zst`, or the plain file at `path` if there is no
+/// compressed one.
+fn read_compressed(path: &Path) -> Option<String> {
+    let _lock = StateLock::shared();
+    decompress(&compressed_path(path)).or_else(|| fs::read_to_string(path).ok())
+}
+
+/// Decode every zstd frame in the file at `path`.
+fn decompress(path: &Path) -> Option<String> {
+    let compressed = fs::read(path).ok()?;
+    String::from_utf8(zstd::decode_all(compressed.as_slice()).ok()?).ok()
+}
+
+/// Persist `tweets` (and their includes) as the latest snapshot for `key`.
+pub fn save_timeline(
+    key: TimelineKey,
+    tweets: &[Tweet],
+    includes: Option<&Includes>,
+) -> Result<(), StoreError> {
+    let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+    let _lock = StateLock::exclusive()?;
+    fs::create_dir_all(&dir)?;
+
+    #[derive(Serialize)]
+    struct SnapshotRef<'a> {
+        saved_at: DateTime<Utc>,
+        tweets: &'a [Tweet],
+        includes: Option<&'a Includes>,
+    }
+
+    let json = serde_json::to_vec(&SnapshotRef {
+        saved_at: Utc::now(),
+        tweets,
+        includes,
+    })?;
+    write_compressed(&dir.join(key.file_name()), &json)
+}
+
+/// Size at which a spill file is set aside for a fresh one. Only the one
+/// before is kept, so each spills at most twice this.
+const MAX_SPILL_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Spill file with every fetched tweet, next to the per-timeline ones.
+const FETCHED_SPILL: &str = "fetched";
+
+/// Spill file with the media and authors of the fetched tweets.
+const FETCHED_INCLUDES_SPILL: &str = "fetched_includes";
+
+/// Append tweets evicted from an in-memory timeline to
+/// `cache/spill/<timeline>.jsonl.zst`, one JSON tweet per line. Each batch
+/// is its own zstd frame, so appending never rewrites the file.
+pub fn spill_tweets(key: TimelineKey, tweets: &[Tweet]) -> Result<(), StoreError> {
+    let dir = cache_dir().ok_or(StoreError::NoConfigDir)?.join("spill");
+    let _lock = StateLock::exclusive()?;
+    spill_in(&dir, key.name(), tweets, MAX_SPILL_BYTES)
+}
+
+/// Append fetched tweets (timeline pages, search results, threads, and the
+/// tweets they quote) to `cache/spill/fetched.jsonl.zst`, so `:local` still
+/// finds them once the snapshot they came in has been replaced. The media
+/// and authors they reference go to `fetched_includes.jsonl.zst`, so the
+/// results show them too (see [`archived_includes`]).
+pub fn archive_tweets(tweets: &[Tweet], includes: &Option<Includes>) -> Result<(), StoreError> {
+    if tweets.is_empty() {
+        return Ok(());
+    }
+    let dir = cache_dir().ok_or(StoreError::NoConfigDir)?.join("spill");
+    let _lock = StateLock::exclusive()?;
+    spill_in(&dir, FETCHED_SPILL, tweets, MAX_SPILL_BYTES)?;
+    let referenced = referenced_in(tweets, includes.clone());
+    if referenced.users.is_some() || referenced.media.is_some() {
+        spill_in(&dir, FETCHED_INCLUDES_SPILL, &[referenced], MAX_SPILL_BYTES)?;
+    }
+    Ok(())
+}
+
+/// The media and authors `tweets` reference, out of `includes`.
+fn referenced_in(tweets: &[Tweet], includes: impl IntoIterator<Item = Includes>) -> Includes {
+    let media_keys: HashSet<&str> = tweets
+        .iter()
+        .flat_map(|t| {
+            t.attachments
+                .iter()
+                .flat_map(|a| a.media_keys.iter().flatten())
+        })
+        .map(String::as_str)
+        .collect();
+    let author_ids: HashSet<&str> = tweets
+        .iter()
+        .filter_map(|t| t.author_id.as_deref())
+        .collect();
+    let mut media = HashMap::new();
+    let mut users = HashMap::new();
+    for includes in includes {
+        for m in includes.media.into_iter().flatten() {
+            if media_keys.contains(m.media_key.as_str()) {
+                media.insert(m.media_key.clone(), m);
+            }
+        }
+        for u in includes.users.into_iter().flatten() {
+            if author_ids.contains(u.id.as_str()) {
+                users.insert(u.id.clone(), u);
+            }
+        }
+    }
+    Includes {
+        users: (!users.is_empty()).then(|| users.into_values().collect()),
+        tweets: None,
+        media: (!media.is_empty()).then(|| media.into_values().collect()),
+    }
+}
+
+fn spill_in<T: Serialize>(
+    dir: &Path,
+    name: &str,
+    items: &[T],
+    max_bytes: u64,
+) -> Result<(), StoreError> {
+    fs::create_dir_all(dir)?;
+    let path = compressed_path(&spill_path(dir, name));
+    if fs::metadata(&path).is_ok_and(|m| m.len() >= max_bytes) {
+        // Replaces the file set aside last time.
+        fs::rename(&path, compressed_path(&old_spill_path(dir, name)))?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let mut buf = Vec::new();
+    for item in items {
+        serde_json::to_writer(&mut buf, item)?;
+        buf.push(b'\n');
+    }
+    file.write_all(&zstd::encode_all(buf.as_slice(), COMPRESSION_LEVEL)?)?;
+    Ok(())
+}
+
+fn spill_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.jsonl"))
+}
+
+/// Where the spill file goes once it reaches `MAX_SPILL_BYTES`.
+fn old_spill_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.old.jsonl"))
+}
+
+/// Load the latest snapshot for `key`, if one exists and parses.
+pub fn load_timeline(key: TimelineKey) -> Option<TimelineSnapshot> {
+    let json = read_compressed(&cache_dir()?.join(key.file_name()))?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Every tweet in the timeline snapshots, then every spilled and archived
+/// tweet. The same tweet may turn up more than once.
+fn stored_tweets() -> impl Iterator<Item = Tweet> {
+    let snapshots = TimelineKey::ALL
+        .into_iter()
+        .filter_map(load_timeline)
+        .flat_map(|snapshot| snapshot.tweets);
+    let names = TimelineKey::ALL.map(TimelineKey::name);
+    let spilled = spilled::<Tweet>(names.into_iter().chain([FETCHED_SPILL]));
+    snapshots.chain(spilled)
+}
+
+/// The records in the spill files called `names`, the set-aside ones too.
+fn spilled<T: DeserializeOwned>(
+    names: impl Iterator<Item = &'static str>,
+) -> impl Iterator<Item = T> {
+    let spill = cache_dir().map(|dir| dir.join("spill"));
+    names
+        .filter_map(move |name| {
+            let dir = spill.as_ref()?;
+            Some([spill_path(dir, name), old_spill_path(dir, name)])
+        })
+        .flatten()
+        .flat_map(|path| {
+            let _lock = StateLock::shared();
+            // Tweets spilled before compression may still be in a plain file.
+            [
+                decompress(&compressed_path(&path)),
+                fs::read_to_string(&path).ok(),
+            ]
+        })
+        .flatten()
+        .flat_map(|lines| {
+            lines
+                .lines()
+                .filter_map(|line| serde_json::from_str::<T>(line).ok())
+                .collect::<Vec<_>>()
+        })
+}
+
+/// What stored tweets need to show in full: the media and authors stored
+/// with them or with a timeline snapshot, and the stored tweets they quote.
+pub fn archived_includes(tweets: &[Tweet]) -> Includes {
+    let snapshots = TimelineKey::ALL
+        .into_iter()
+        .filter_map(load_timeline)
+        .filter_map(|snapshot| snapshot.includes);
+    let archived = spilled::<Includes>([FETCHED_INCLUDES_SPILL].into_iter());
+    let mut includes = referenced_in(tweets, snapshots.chain(archived));
+
+    let quoted_ids: HashSet<&str> = tweets
+        .iter()
+        .flat_map(|t| t.referenced_tweets.iter().flatten())
+        .filter(|rt| rt.type_ == "quoted")
+        .map(|rt| rt.id.as_str())
+        .collect();
+    if !quoted_ids.is_empty() {
+        let mut seen = HashSet::new();
+        let quoted: Vec<Tweet> = stored_tweets()
+            .filter(|t| quoted_ids.contains(t.id.as_str()) && seen.insert(t.id.clone()))
+            .collect();
+        includes.tweets = (!quoted.is_empty()).then_some(quoted);
+    }
+    includes
+}
+
+/// Every stored tweet once, newest first, at most `limit` of them.
+pub fn archived_tweets(limit: usize) -> Vec<Tweet> {
+    let mut seen = BTreeSet::new();
+    let mut tweets: Vec<Tweet> = stored_tweets()
+        .filter(|t| seen.insert(t.id.clone()))
+        .collect();
+    tweets.sort_by(|a, b| b.id.len().cmp(&a.id.len()).then_with(|| b.id.cmp(&a.id)));
+    tweets.truncate(limit);
+    tweets
+}
+
+/// Look up a tweet by ID in every timeline snapshot, then in the spilled
+/// tweets, without touching the network.
+pub fn find_tweet(tweet_id: &str) -> Option<Tweet> {
+    stored_tweets().find(|t| t.id == tweet_id)
+}
+
+/// Most results `:local` returns.
+const LOCAL_SEARCH_LIMIT: usize = 200;
+
+/// Search every stored tweet for `query`, newest first. A tweet matches
+/// when its text contains every word of the query, ignoring case, so old
+/// tweets outside the API's 7-day search window can still be found.
+pub fn search_local(query: &str) -> Vec<Tweet> {
+    let terms = search_terms(query);
+    archived_tweets(usize::MAX)
+        .into_iter()
+        .filter(|t| matches_terms(t, &terms))
+        .take(LOCAL_SEARCH_LIMIT)
+        .collect()
+}
+
+/// The query's words, lowercased.
+fn search_terms(query: &str) -> Vec<String> {
+    query.split_whitespace().map(str::to_lowercase).collect()
+}
+
+/// Whether the tweet's full text contains all of `terms` (lowercase).
+fn matches_terms(tweet: &Tweet, terms: &[String]) -> bool {
+    let text = tweet
+        .note_tweet
+        .as_ref()
+        .map_or(&tweet.text, |note| &note.text)
+        .to_lowercase();
+    !terms.is_empty() && terms.iter().all(|term| text.contains(term.as_str()))
+}
+
+/// Write `contents` to `exports/<name>-<timestamp>.jsonl` under the config
+/// directory and return the path.
+pub fn write_export(name: &str, contents: &str) -> Result<PathBuf, StoreError> {
+    let dir = root_dir().ok_or(StoreError::NoConfigDir)?.join("exports");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "{name}-{}.jsonl",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+// ---------------------------------------------------------------------------
+// Maintenance
+// ---------------------------------------------------------------------------
+
+/// Disk usage of the cache directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub files: usize,
+    pub bytes: u64,
+    /// Of `bytes`, how much is spilled tweets.
+    pub spill_bytes: u64,
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in {} files ({} spilled tweets)",
+            format_size(self.bytes),
+            self.files,
+            format_size(self.spill_bytes)
+        )
+    }
+}
+
+/// `bytes` as B, KB or MB.
+pub fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..1_000 => format!("{bytes} B"),
+        1_000..1_000_000 => format!("{:.1} KB", bytes as f64 / 1e3),
+        _ => format!("{:.1} MB", bytes as f64 / 1e6),
+    }
+}
+
+/// Files directly under `dir`, with their metadata. A missing directory is
+/// empty.
+fn cache_files(dir: &Path) -> Result<Vec<(PathBuf, fs::Metadata)>, StoreError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            files.push((entry.path(), metadata));
+        }
+    }
+    Ok(files)
+}
+
+pub fn cache_stats() -> Result<CacheStats, StoreError> {
+    let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+    stats_in(&dir)
+}
+
+fn stats_in(dir: &Path) -> Result<CacheStats, StoreError> {
+    let mut stats = CacheStats::default();
+    for (_, metadata) in cache_files(dir)? {
+        stats.files += 1;
+        stats.bytes += metadata.len();
+    }
+    for (_, metadata) in cache_files(&dir.join("spill"))? {
+        stats.files += 1;
+        stats.bytes += metadata.len();
+        stats.spill_bytes += metadata.len();
+    }
+    Ok(stats)
+}
+
+/// Delete the whole cache. Returns what it held.
+pub fn clear_cache() -> Result<CacheStats, StoreError> {
+    let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+    let _lock = StateLock::exclusive()?;
+    let stats = stats_in(&dir)?;
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    Ok(stats)
+}
+
+/// Compress any plain files left by older versions and delete spilled
+/// tweets not added to in `max_age`. Returns the cache before and after.
+pub fn gc_cache(max_age: Duration) -> Result<(CacheStats, CacheStats), StoreError> {
+    let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+    let _lock = StateLock::exclusive()?;
+    gc_in(&dir, max_age)
+}
+
+fn gc_in(dir: &Path, max_age: Duration) -> Result<(CacheStats, CacheStats), StoreError> {
+    let before = stats_in(dir)?;
+    let now = SystemTime::now();
+    let spill = dir.join("spill");
+    for (path, metadata) in cache_files(&spill)? {
+        let age = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .unwrap_or_default();
+        if age > max_age {
+            fs::remove_file(&path)?;
+        }
+    }
+    for (path, _) in cache_files(dir)?.into_iter().chain(cache_files(&spill)?) {
+        if path.extension().is_some_and(|ext| ext == "zst") {
+            continue;
+        }
+        let contents = fs::read(&path)?;
+        if path.parent() == Some(spill.as_path()) {
+            // Append to spilled tweets that were already compressed.
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(compressed_path(&path))?;
+            file.write_all(&zstd::encode_all(contents.as_slice(), COMPRESSION_LEVEL)?)?;
+            fs::remove_file(&path)?;
+        } else if compressed_path(&path).exists() {
+            // Already superseded by a compressed copy.
+            fs::remove_file(&path)?;
+        } else {
+            write_compressed(&path, &contents)?;
+        }
+    }
+    Ok((before, stats_in(dir)?))
+}
+
+// ---------------------------------------------------------------------------
+// Profile cache
+// ---------------------------------------------------------------------------
+
+const PROFILES_FILE: &str = "profiles.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedProfile {
+    pub user: User,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// User profiles keyed by lowercase username, persisted as one JSON file.
+#[derive(Debug, Default)]
+pub struct ProfileCache {
+    entries: HashMap<String, CachedProfile>,
+    ttl: chrono::Duration,
+}
+
+impl ProfileCache {
+    /// An empty cache whose entries go stale after `ttl_secs`.
+    pub fn new(ttl_secs: u64) -> Self {
+        Self {
+            entries: HashMap::new(),
+            ttl: chrono::Duration::seconds(ttl_secs.min(i64::MAX as u64) as i64),
+        }
+    }
+
+    /// Load persisted profiles, falling back to an empty cache.
+    pub fn load(ttl_secs: u64) -> Self {
+        let mut cache = Self::new(ttl_secs);
+        if let Some(entries) = cache_dir()
+            .and_then(|dir| read_compressed(&dir.join(PROFILES_FILE)))
+            .and_then(|json| serde_json::from_str(&json).ok())
+        {
+            cache.entries = entries;
+        }
+        cache
+    }
+
+    /// Look up a profile by username (case-insensitive, optional `@`).
+    /// The flag is `true` while the entry is still within its TTL.
+    pub fn get(&self, username: &str) -> Option<(&User, bool)> {
+        let entry = self.entries.get(&profile_key(username))?;
+        let fresh = Utc::now() - entry.fetched_at < self.ttl;
+        Some((&entry.user, fresh))
+    }
+
+    /// Record a freshly fetched profile.
+    pub fn insert(&mut self, user: User) {
+        self.entries.insert(
+            profile_key(&user.username),
+            CachedProfile {
+                user,
+                fetched_at: Utc::now(),
+            },
+        );
+    }
+
+    /// Write the cache to disk.
+    pub fn save(&self) -> Result<(), StoreError> {
+        let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+        let _lock = StateLock::exclusive()?;
+        fs::create_dir_all(&dir)?;
+        write_compressed(
+            &dir.join(PROFILES_FILE),
+            &serde_json::to_vec(&self.entries)?,
+        )
+    }
+}
+
+fn profile_key(username: &str) -> String {
+    username.trim_start_matches('@').to_lowercase()
+}
+
+// ---------------------------------------------------------------------------
+// Users cache
+// ---------------------------------------------------------------------------
+
+const USERS_FILE: &str = "users.json";
+
+/// Users keyed by ID, bounded to `capacity` entries by evicting the least
+/// recently seen. Lookups happen during rendering (`&self`), so recency is
+/// refreshed when a user shows up again in a response rather than on read.
+#[derive(Debug, Default)]
+pub struct UserCache {
+    entries: HashMap<String, (User, u64)>,
+    capacity: usize,
+    clock: u64,
+}
+
+impl UserCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            clock: 0,
+        }
+    }
+
+    /// Load persisted users (oldest first), falling back to an empty cache.
+    pub fn load(capacity: usize) -> Self {
+        let mut cache = Self::new(capacity);
+        let users: Vec<User> = cache_dir()
+            .and_then(|dir| read_compressed(&dir.join(USERS_FILE)))
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        for user in users {
+            cache.insert(user);
+        }
+        cache
+    }
+
+    pub fn get(&self, id: &str) -> Option<&User> {
+        self.entries.get(id).map(|(user, _)| user)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Insert or refresh a user, evicting the least recently seen entries
+    /// once over capacity.
+    pub fn insert(&mut self, user: User) {
+        self.clock += 1;
+        self.entries.insert(user.id.clone(), (user, self.clock));
+        if self.entries.len() > self.capacity {
+            self.evict();
+        }
+    }
+
+    /// Drop the oldest entries down to 90% of capacity, so a burst of new
+    /// users doesn't trigger an eviction pass on every insert.
+    fn evict(&mut self) {
+        let keep = (self.capacity * 9 / 10).max(1);
+        let mut stamps: Vec<u64> = self.entries.values().map(|(_, seen)| *seen).collect();
+        stamps.sort_unstable();
+        let cutoff = stamps[stamps.len() - keep];
+        self.entries.retain(|_, (_, seen)| *seen >= cutoff);
+    }
+
+    /// Write the cache to disk, oldest first so reloading keeps the order.
+    pub fn save(&self) -> Result<(), StoreError> {
+        let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+        let _lock = StateLock::exclusive()?;
+        fs::create_dir_all(&dir)?;
+        let mut users: Vec<&(User, u64)> = self.entries.values().collect();
+        users.sort_unstable_by_key(|(_, seen)| *seen);
+        let users: Vec<&User> = users.into_iter().map(|(user, _)| user).collect();
+        write_compressed(&dir.join(USERS_FILE), &serde_json::to_vec(&users)?)
+    }
+}
+
+/// Parse the JSON at `path`, a file only the user can recreate. One that
+/// doesn't parse is moved to `<path>.bak`, so saving can't overwrite it, and
+/// reads as empty along with where it went. A missing file reads as empty.
+/// Fails only if the file couldn't be moved, and then must not be saved over.
+fn read_or_set_aside<T: DeserializeOwned + Default>(
+    path: &Path,
+) -> Result<(T, Option<StoreError>), StoreError> {
+    let Ok(json) = fs::read_to_string(path) else {
+        return Ok((T::default(), None));
+    };
+    match serde_json::from_str(&json) {
+        Ok(value) => Ok((value, None)),
+        Err(e) => {
+            let mut backup = path.as_os_str().to_owned();
+            backup.push(".bak");
+            let backup = PathBuf::from(backup);
+            fs::rename(path, &backup)?;
+            let set_aside = StoreError::SetAside {
+                path: path.to_path_buf(),
+                backup,
+                reason: e.to_string(),
+            };
+            tracing::warn!("{set_aside}");
+            Ok((T::default(), Some(set_aside)))
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Muted conversations
+// ---------------------------------------------------------------------------
+
+const MUTED_CONVERSATIONS_FILE: &str = "muted_conversations.json";
+
+/// Conversation IDs muted with `:mute-thread`. X has no API for muting a
+/// conversation, so their tweets are dropped from Home and Mentions here.
+#[derive(Debug, Default)]
+pub struct MutedConversations {
+    ids: BTreeSet<String>,
+}
+
+impl MutedConversations {
+    /// Load the persisted list, falling back to an empty one. Also returns
+    /// why, if the file couldn't be read.
+    pub fn load() -> (Self, Option<StoreError>) {
+        let Some(dir) = root_dir() else {
+            return (Self::default(), None);
+        };
+        match read_or_set_aside(&dir.join(MUTED_CONVERSATIONS_FILE)) {
+            Ok((ids, set_aside)) => (Self { ids }, set_aside),
+            Err(e) => (Self::default(), Some(e)),
+        }
+    }
+
+    pub fn contains(&self, conversation_id: &str) -> bool {
+        self.ids.contains(conversation_id)
+    }
+
+    /// Whether `tweet` belongs to a muted conversation.
+    pub fn hides(&self, tweet: &Tweet) -> bool {
+        tweet
+            .conversation_id
+            .as_deref()
+            .is_some_and(|id| self.contains(id))
+    }
+
+    /// Returns `false` if it was already muted.
+    pub fn insert(&mut self, conversation_id: String) -> bool {
+        self.ids.insert(conversation_id)
+    }
+
+    /// Returns `false` if it wasn't muted.
+    pub fn remove(&mut self, conversation_id: &str) -> bool {
+        self.ids.remove(conversation_id)
+    }
+
+    /// Mute or unmute a conversation and save the list, starting from
+    /// what is on disk so threads muted by another instance are kept.
+    /// Returns `false` if nothing changed.
+    pub fn set(&mut self, conversation_id: String, muted: bool) -> Result<bool, StoreError> {
+        let dir = root_dir().ok_or(StoreError::NoConfigDir)?;
+        let _lock = StateLock::exclusive()?;
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(MUTED_CONVERSATIONS_FILE);
+        (self.ids, _) = read_or_set_aside(&path)?;
+        let changed = if muted {
+            self.insert(conversation_id)
+        } else {
+            self.remove(&conversation_id)
+        };
+        if changed {
+            write_atomic(&path, &serde_json::to_vec_pretty(&self.ids)?)?;
+        }
+        Ok(changed)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Drafts
+// ---------------------------------------------------------------------------
+
+const DRAFTS_FILE: &str = "drafts.json";
+
+/// A post written in the composer and closed without posting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Draft {
+    /// Identifies the draft while it is edited and saved again.
+    pub id: String,
+    pub text: String,
+    /// The tweet it answers, if it is a reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+    /// That tweet's author, for the Drafts view and the composer's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to_username: Option<String>,
+    /// To be split into a thread when posted.
+    #[serde(default)]
+    pub thread: bool,
+    /// Who may reply once it is posted.
+    #[serde(default)]
+    pub reply_settings: ReplySettings,
+    pub saved_at: DateTime<Utc>,
+}
+
+/// Unsent composer drafts, most recently saved first.
+#[derive(Debug, Default)]
+pub struct Drafts {
+    drafts: Vec<Draft>,
+    /// Where they are saved; without one they are only kept in memory.
+    path: Option<PathBuf>,
+}
+
+impl Drafts {
+    /// Load the persisted drafts, falling back to none. Also returns why,
+    /// if the file couldn't be read; if it couldn't be set aside either,
+    /// drafts are only kept in memory.
+    pub fn load() -> (Self, Option<StoreError>) {
+        let Some(path) = root_dir().map(|dir| dir.join(DRAFTS_FILE)) else {
+            return (Self::default(), None);
+        };
+        match read_or_set_aside(&path) {
+            Ok((drafts, set_aside)) => (
+                Self {
+                    drafts,
+                    path: Some(path),
+                },
+                set_aside,
+            ),
+            Err(e) => (Self::default(), Some(e)),
+        }
+    }
+
+    pub fn entries(&self) -> &[Draft] {
+        &self.drafts
+    }
+
+    /// Save `draft` at the top, replacing its earlier version.
+    pub fn save(&mut self, draft: Draft) -> Result<(), StoreError> {
+        self.update(|drafts| {
+            drafts.retain(|d| d.id != draft.id);
+            drafts.insert(0, draft);
+        })
+    }
+
+    /// Delete the draft with `id`, if there is one.
+    pub fn remove(&mut self, id: &str) -> Result<(), StoreError> {
+        self.update(|drafts| drafts.retain(|d| d.id != id))
+    }
+
+    /// Apply `change` and write the drafts back, starting from what is on
+    /// disk so drafts saved by another instance are kept.
+    fn update(&mut self, change: impl FnOnce(&mut Vec<Draft>)) -> Result<(), StoreError> {
+        let Some(path) = &self.path else {
+            change(&mut self.drafts);
+            return Ok(());
+        };
+        let _lock = StateLock::exclusive()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        (self.drafts, _) = read_or_set_aside(path)?;
+        change(&mut self.drafts);
+        write_atomic(path, &serde_json::to_vec_pretty(&self.drafts)?)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Watch deliveries
+// ---------------------------------------------------------------------------
+
+const WATCH_DELIVERIES_FILE: &str = "watch_deliveries.jsonl";
+
+/// Deliveries remembered. Older ones are dropped from the log when it is
+/// loaded; their tweets are long out of the timelines by then.
+const WATCH_DELIVERIES_CAPACITY: usize = 20_000;
+
+/// A tweet that reached one of a watch rule's sinks, named by
+/// [`crate::sinks::Sink::key`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WatchDelivery {
+    pub tweet_id: String,
+    pub sink: String,
+}
+
+/// The deliveries made by this and earlier runs, so a restart doesn't send
+/// a tweet to a sink again.
+pub fn load_watch_deliveries() -> HashSet<WatchDelivery> {
+    let Some(dir) = root_dir() else {
+        return HashSet::new();
+    };
+    let Ok(_lock) = StateLock::exclusive() else {
+        return HashSet::new();
+    };
+    load_deliveries_in(&dir, WATCH_DELIVERIES_CAPACITY)
+}
+
+fn load_deliveries_in(dir: &Path, capacity: usize) -> HashSet<WatchDelivery> {
+    let path = dir.join(WATCH_DELIVERIES_FILE);
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return HashSet::new();
+    };
+    let lines: Vec<&str> = contents.lines().collect();
+    let kept = &lines[lines.len().saturating_sub(capacity)..];
+    if kept.len() < lines.len() {
+        let trimmed: String = kept.iter().map(|line| format!("{line}\n")).collect();
+        if let Err(e) = write_atomic(&path, trimmed.as_bytes()) {
+            tracing::warn!("couldn't trim {}: {e}", path.display());
+        }
+    }
+    kept.iter()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Add deliveries to the log.
+pub fn record_watch_deliveries(deliveries: &[WatchDelivery]) -> Result<(), StoreError> {
+    let dir = root_dir().ok_or(StoreError::NoConfigDir)?;
+    let _lock = StateLock::exclusive()?;
+    fs::create_dir_all(&dir)?;
+    append_deliveries_in(&dir, deliveries)
+}
+
+fn append_deliveries_in(dir: &Path, deliveries: &[WatchDelivery]) -> Result<(), StoreError> {
+    let mut lines = Vec::new();
+    for delivery in deliveries {
+        serde_json::to_writer(&mut lines, delivery)?;
+        lines.push(b'\n');
+    }
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(WATCH_DELIVERIES_FILE))?
+        .write_all(&lines)?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Metrics history
+// ---------------------------------------------------------------------------
+
+const METRICS_FILE: &str = "metrics.json";
+
+/// Samples kept per tweet; the oldest are dropped first.
+const METRICS_SAMPLES_PER_TWEET: usize = 64;
+
+/// Tweets tracked; those sampled least recently are dropped first.
+const METRICS_CAPACITY: usize = 5000;
+
+/// A tweet's public counts at one fetch.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSample {
+    pub at: DateTime<Utc>,
+    pub likes: u64,
+    pub retweets: u64,
+    /// Only reported for the signed-in user's own tweets.
+    #[serde(default)]
+    pub impressions: Option<u64>,
+}
+
+impl MetricsSample {
+    fn same_counts(&self, other: &Self) -> bool {
+        (self.likes, self.retweets, self.impressions)
+            == (other.likes, other.retweets, other.impressions)
+    }
+}
+
+/// Metrics samples per tweet ID, recorded whenever a timeline or thread is
+/// fetched so repeated refreshes build up a trend.
+#[derive(Debug, Default)]
+pub struct MetricsHistory {
+    samples: HashMap<String, Vec<MetricsSample>>,
+}
+
+impl MetricsHistory {
+    /// Load the persisted history, falling back to an empty one.
+    pub fn load() -> Self {
+        let samples = cache_dir()
+            .and_then(|dir| read_compressed(&dir.join(METRICS_FILE)))
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self { samples }
+    }
+
+    /// Samples for `tweet_id`, oldest first.
+    pub fn get(&self, tweet_id: &str) -> &[MetricsSample] {
+        self.samples.get(tweet_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Record the counts of `tweets` as seen at `at`. A tweet whose counts
+    /// haven't changed since its last sample is skipped, so refreshing
+    /// often doesn't flood the history with flat stretches.
+    pub fn record<'a>(&mut self, tweets: impl IntoIterator<Item = &'a Tweet>, at: DateTime<Utc>) {
+        for tweet in tweets {
+            let Some(metrics) = &tweet.public_metrics else {
+                continue;
+            };
+            let sample = MetricsSample {
+                at,
+                likes: metrics.like_count,
+                retweets: metrics.retweet_count,
+                impressions: metrics.impression_count,
+            };
+            let samples = self.samples.entry(tweet.id.clone()).or_default();
+            if samples.last().is_some_and(|last| last.same_counts(&sample)) {
+                continue;
+            }
+            samples.push(sample);
+            if samples.len() > METRICS_SAMPLES_PER_TWEET {
+                samples.remove(0);
+            }
+        }
+        if self.samples.len() > METRICS_CAPACITY {
+            self.evict();
+        }
+    }
+
+    /// Drop the tweets sampled least recently down to 90% of capacity.
+    fn evict(&mut self) {
+        let keep = METRICS_CAPACITY * 9 / 10;
+        let mut latest: Vec<DateTime<Utc>> = self
+            .samples
+            .values()
+            .filter_map(|samples| samples.last().map(|s| s.at))
+            .collect();
+        latest.sort_unstable();
+        let Some(&cutoff) = latest.get(latest.len().saturating_sub(keep)) else {
+            return;
+        };
+        self.samples
+            .retain(|_, samples| samples.last().is_some_and(|s| s.at >= cutoff));
+    }
+
+    pub fn save(&self) -> Result<(), StoreError> {
+        let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+        let _lock = StateLock::exclusive()?;
+        fs::create_dir_all(&dir)?;
+        write_compressed(&dir.join(METRICS_FILE), &serde_json::to_vec(&self.samples)?)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Embeddings
+// ---------------------------------------------------------------------------
+
+const EMBEDDINGS_FILE: &str = "embeddings.json";
+
+/// Tweet embeddings from one model, keyed by tweet ID. Vectors are kept as
+/// base64 little-endian `f32`s, a fraction of the size of JSON numbers.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EmbeddingStore {
+    model: String,
+    vectors: HashMap<String, String>,
+}
+
+impl EmbeddingStore {
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            vectors: HashMap::new(),
+        }
+    }
+
+    /// Load the vectors `model` produced, under the shared state lock so a
+    /// save from another instance is never read half-written. Another
+    /// model's vectors can't be compared with its own, so they are dropped.
+    pub fn load(model: &str) -> Self {
+        cache_dir()
+            .and_then(|dir| read_compressed(&dir.join(EMBEDDINGS_FILE)))
+            .and_then(|json| serde_json::from_str::<Self>(&json).ok())
+            .filter(|store| store.model == model)
+            .unwrap_or_else(|| Self::new(model))
+    }
+
+    pub fn get(&self, tweet_id: &str) -> Option<Vec<f64>> {
+        let bytes = BASE64.decode(self.vectors.get(tweet_id)?).ok()?;
+        Some(
+            bytes
+                .chunks_exact(4)
+                .map(|b| f64::from(f32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+                .collect(),
+        )
+    }
+
+    pub fn insert(&mut self, tweet_id: String, vector: &[f64]) {
+        let bytes: Vec<u8> = vector
+            .iter()
+            .flat_map(|&x| (x as f32).to_le_bytes())
+            .collect();
+        self.vectors.insert(tweet_id, BASE64.encode(bytes));
+    }
+
+    /// Drop the vectors of tweets no longer stored.
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.vectors.retain(|id, _| keep(id));
+    }
+
+    pub fn save(&self) -> Result<(), StoreError> {
+        let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+        let _lock = StateLock::exclusive()?;
+        fs::create_dir_all(&dir)?;
+        write_compressed(&dir.join(EMBEDDINGS_FILE), &serde_json::to_vec(self)?)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Recently viewed
+// ---------------------------------------------------------------------------
+
+const HISTORY_FILE: &str = "history.json";
+
+/// Entries kept in the recently viewed list.
+const HISTORY_CAPACITY: usize = 200;
+
+/// A thread or profile that can be opened again from the history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HistoryTarget {
+    Thread { conversation_id: String },
+    Profile { username: String },
+}
+
+impl HistoryTarget {
+    fn same_as(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Profile { username: a }, Self::Profile { username: b }) => {
+                a.eq_ignore_ascii_case(b)
+            }
+            _ => self == other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub target: HistoryTarget,
+    /// What to list it as, e.g. the thread's first tweet or the user's name.
+    pub label: String,
+    pub opened_at: DateTime<Utc>,
+}
+
+/// Threads and profiles opened, newest first. Opening one again moves it
+/// back to the top instead of adding a duplicate.
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Load the persisted history, falling back to an empty one.
+    pub fn load() -> Self {
+        cache_dir()
+            .and_then(|dir| read_compressed(&dir.join(HISTORY_FILE)))
+            .map(|json| Self::from_json(&json))
+            .unwrap_or_default()
+    }
+
+    fn from_json(json: &str) -> Self {
+        Self {
+            entries: serde_json::from_str(json).unwrap_or_default(),
+        }
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, target: HistoryTarget, label: String) {
+        self.entries.retain(|e| !e.target.same_as(&target));
+        self.entries.insert(
+            0,
+            HistoryEntry {
+                target,
+                label,
+                opened_at: Utc::now(),
+            },
+        );
+        self.entries.truncate(HISTORY_CAPACITY);
+    }
+
+    pub fn save(&self) -> Result<(), StoreError> {
+        let dir = cache_dir().ok_or(StoreError::NoConfigDir)?;
+        let _lock = StateLock::exclusive()?;
+        fs::create_dir_all(&dir)?;
+        write_compressed(&dir.join(HISTORY_FILE), &serde_json::to_vec(&self.entries)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_user(username: &str) -> User {
+        make_user_with_id("1", username)
+    }
+
+    fn make_user_with_id(id: &str, username: &str) -> User {
+        User {
+            id: id.into(),
+            username: username.into(),
+            name: "Name".into(),
+            description: None,
+            created_at: None,
+            verified: None,
+            verified_type: None,
+            protected: None,
+            profile_image_url: None,
+            profile_banner_url: None,
+            url: None,
+            location: None,
+            pinned_tweet_id: None,
+            public_metrics: None,
+            withheld: None,
+        }
+    }
+
+    #[test]
+    fn profile_lookup_is_case_insensitive_and_ignores_at() {
+        let mut cache = ProfileCache::new(3600);
+        cache.insert(make_user("SomeOne"));
+        let (user, fresh) = cache.get("@someone").expect("cached");
+        assert_eq!(user.username, "SomeOne");
+        assert!(fresh);
+    }
+
+    #[test]
+    fn profile_goes_stale_after_ttl() {
+        let mut cache = ProfileCache::new(0);
+        cache.insert(make_user("someone"));
+        let (_, fresh) = cache
+            .get("someone")
+            .expect("stale entries are still served");
+        assert!(!fresh);
+    }
+
+    #[test]
+    fn embeddings_round_trip_as_f32() {
+        let mut store = EmbeddingStore::new("m");
+        store.insert("1".into(), &[0.5, -1.25, 3.0]);
+        store.insert("2".into(), &[1.0]);
+        assert_eq!(store.get("1"), Some(vec![0.5, -1.25, 3.0]));
+
+        store.retain(|id| id == "1");
+        assert!(store.get("2").is_none());
+        let json = serde_json::to_string(&store).unwrap();
+        let loaded: EmbeddingStore = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.get("1"), Some(vec![0.5, -1.25, 3.0]));
+    }
+
+    #[test]
+    fn user_cache_evicts_least_recently_seen() {
+        let mut cache = UserCache::new(10);
+        for i in 0..10 {
+            cache.insert(make_user_with_id(&i.to_string(), "u"));
+        }
+        // Seeing user 0 again makes it the most recent.
+        cache.insert(make_user_with_id("0", "u"));
+        cache.insert(make_user_with_id("10", "u"));
+
+        assert_eq!(cache.len(), 9);
+        assert!(cache.get("0").is_some());
+        assert!(cache.get("10").is_some());
+        assert!(cache.get("1").is_none());
+        assert!(cache.get("2").is_none());
+    }
+
+    #[test]
+    fn a_second_instance_lock_names_the_holder() {
+        let dir = std::env::temp_dir().join(format!("xplorertui-instance-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(INSTANCE_LOCK_FILE);
+        let open = || {
+            fs::OpenOptions::new()
+                .create(true)
+                .truncate(false)
+                .read(true)
+                .write(true)
+                .open(&path)
+                .unwrap()
+        };
+
+        let held = InstanceLock::try_acquire_file(open()).unwrap();
+        match InstanceLock::try_acquire_file(open()) {
+            Err(StoreError::InUse(pid)) => assert_eq!(pid, std::process::id().to_string()),
+            other => panic!("expected InUse, got {other:?}"),
+        }
+        drop(held);
+        assert!(InstanceLock::try_acquire_file(open()).is_ok());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn muted_conversations_hide_their_tweets() {
+        let mut muted = MutedConversations {
+            ids: BTreeSet::from(["100".to_string()]),
+        };
+        let tweet = |conversation_id: &str| -> Tweet {
+            serde_json::from_value(serde_json::json!({
+                "id": "1", "text": "hi", "conversation_id": conversation_id
+            }))
+            .unwrap()
+        };
+        assert!(muted.hides(&tweet("100")));
+        assert!(!muted.hides(&tweet("200")));
+        assert!(!muted.insert("100".into()));
+        assert!(muted.remove("100"));
+        assert!(!muted.hides(&tweet("100")));
+    }
+
+    #[test]
+    fn saved_drafts_replace_their_earlier_version_at_the_top() {
+        let draft = |id: &str, text: &str| Draft {
+            id: id.into(),
+            text: text.into(),
+            reply_to: None,
+            reply_to_username: None,
+            thread: false,
+            reply_settings: ReplySettings::Everyone,
+            saved_at: Utc::now(),
+        };
+        let mut drafts = Drafts::default();
+        drafts.save(draft("1", "first")).unwrap();
+        drafts.save(draft("2", "second")).unwrap();
+        drafts.save(draft("1", "first, edited")).unwrap();
+        let texts: Vec<_> = drafts.entries().iter().map(|d| d.text.as_str()).collect();
+        assert_eq!(texts, ["first, edited", "second"]);
+
+        drafts.remove("1").unwrap();
+        assert_eq!(drafts.entries().len(), 1);
+    }
+
+    #[test]
+    fn an_unreadable_drafts_file_is_set_aside_instead_of_overwritten() {
+        let dir = std::env::temp_dir().join(format!("xplorertui-set-aside-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(DRAFTS_FILE);
+        fs::write(&path, "[{\"id\": \"1\", \"text\": \"half").unwrap();
+
+        let (drafts, set_aside) = read_or_set_aside::<Vec<Draft>>(&path).unwrap();
+        assert!(drafts.is_empty());
+        let Some(StoreError::SetAside { backup, .. }) = set_aside else {
+            panic!("expected the file to be set aside, got {set_aside:?}");
+        };
+        assert_eq!(backup, dir.join("drafts.json.bak"));
+        assert!(fs::read_to_string(&backup).unwrap().contains("half"));
+        assert!(!path.exists());
+
+        let (drafts, set_aside) = read_or_set_aside::<Vec<Draft>>(&path).unwrap();
+        assert!(drafts.is_empty() && set_aside.is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn watch_deliveries_keep_only_the_newest() {
+        let dir =
+            std::env::temp_dir().join(format!("xplorertui-deliveries-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let delivery = |id: &str| WatchDelivery {
+            tweet_id: id.into(),
+            sink: "command cat".into(),
+        };
+        append_deliveries_in(&dir, &[delivery("1"), delivery("2")]).unwrap();
+        append_deliveries_in(&dir, &[delivery("3")]).unwrap();
+
+        let loaded = load_deliveries_in(&dir, 2);
+        assert_eq!(loaded, HashSet::from([delivery("2"), delivery("3")]));
+        assert_eq!(load_deliveries_in(&dir, 5), loaded);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn archived_tweets_keep_the_media_and_authors_they_reference() {
+        let tweets: Vec<Tweet> = serde_json::from_value(serde_json::json!([
+            { "id": "41", "text": "a", "author_id": "7", "attachments": { "media_keys": ["3_1"] } },
+            { "id": "42", "text": "b", "referenced_tweets": [{ "type": "quoted", "id": "41" }] },
+        ]))
+        .unwrap();
+        let includes: Includes = serde_json::from_value(serde_json::json!({
+            "users": [
+                { "id": "7", "username": "alice", "name": "Alice" },
+                { "id": "8", "username": "bob", "name": "Bob" },
+            ],
+            "media": [{ "media_key": "3_1", "type": "photo" }, { "media_key": "3_2", "type": "video" }],
+        }))
+        .unwrap();
+        archive_tweets(&tweets, &Some(includes)).unwrap();
+
+        let found = archived_includes(&tweets[1..]);
+        assert_eq!(found.tweets.unwrap()[0].id, "41");
+        let found = archived_includes(&tweets[..1]);
+        let users = found.users.unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].username, "alice");
+        let media = found.media.unwrap();
+        assert_eq!(media.len(), 1);
+        assert_eq!(media[0].media_key, "3_1");
+    }
+
+    #[test]
+    fn local_search_needs_every_word_in_the_full_text() {
+        let tweet: Tweet = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "text": "Short version…",
+            "note_tweet": { "text": "Short version of a long post about Ratatui widgets" },
+        }))
+        .unwrap();
+        assert!(matches_terms(&tweet, &search_terms("ratatui WIDGETS")));
+        assert!(!matches_terms(&tweet, &search_terms("ratatui crossterm")));
+        assert!(!matches_terms(&tweet, &search_terms("")));
+    }
+
+    #[test]
+    fn metrics_history_records_only_changed_counts() {
+        let tweet = |likes: u64| -> Tweet {
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "text": "hi",
+                "public_metrics": {
+                    "like_count": likes,
+                    "retweet_count": 1,
+                    "reply_count": 0,
+                    "quote_count": 0,
+                },
+            }))
+            .unwrap()
+        };
+        let at = |minute: u32| {
+            "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                + chrono::Duration::minutes(minute.into())
+        };
+        let mut history = MetricsHistory::default();
+        history.record(&[tweet(5)], at(0));
+        history.record(&[tweet(5)], at(1));
+        history.record(&[tweet(9)], at(2));
+
+        let likes: Vec<(DateTime<Utc>, u64)> =
+            history.get("1").iter().map(|s| (s.at, s.likes)).collect();
+        assert_eq!(likes, [(at(0), 5), (at(2), 9)]);
+        assert!(history.get("2").is_empty());
+    }
+
+    #[test]
+    fn history_moves_reopened_entries_to_the_top() {
+        let mut history = History::from_json(
+            r#"[{"target":{"kind":"profile","username":"Bob"},"label":"@Bob","opened_at":"2025-01-01T00:00:00Z"}]"#,
+        );
+        let thread = HistoryTarget::Thread {
+            conversation_id: "100".into(),
+        };
+        history.record(thread.clone(), "@alice: hi".into());
+        history.record(
+            HistoryTarget::Profile {
+                username: "bob".into(),
+            },
+            "@bob (Bob)".into(),
+        );
+        let labels: Vec<_> = history.entries().iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(labels, ["@bob (Bob)", "@alice: hi"]);
+        assert_eq!(history.entries()[1].target, thread);
+        assert!(History::from_json("not json").entries().is_empty());
+    }
+
+    #[test]
+    fn a_full_spill_file_is_set_aside_and_only_one_is_kept() {
+        let dir = std::env::temp_dir().join(format!("xplorertui-spill-{}", std::process::id()));
+        let tweet = |id: &str| -> Tweet {
+            serde_json::from_value(serde_json::json!({ "id": id, "text": "hi" })).unwrap()
+        };
+        let read = |path: PathBuf| -> Vec<String> {
+            decompress(&compressed_path(&path))
+                .unwrap_or_default()
+                .lines()
+                .map(|line| serde_json::from_str::<Tweet>(line).unwrap().id)
+                .collect()
+        };
+        for id in ["1", "2", "3"] {
+            // Any file is over a one-byte limit, so each batch rotates it.
+            spill_in(&dir, "home", &[tweet(id)], 1).unwrap();
+        }
+        assert_eq!(read(spill_path(&dir, "home")), ["3"]);
+        assert_eq!(read(old_spill_path(&dir, "home")), ["2"]);
+        assert_eq!(cache_files(&dir).unwrap().len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn gc_compresses_plain_files_and_drops_old_spill() {
+        let dir = std::env::temp_dir().join(format!("xplorertui-gc-{}", std::process::id()));
+        let spill = dir.join("spill");
+        fs::create_dir_all(&spill).unwrap();
+        let users = serde_json::to_vec(&[make_user("someone")]).unwrap();
+        fs::write(dir.join(USERS_FILE), &users).unwrap();
+        fs::write(spill.join("home.jsonl"), "{}\n").unwrap();
+
+        let (before, after) = gc_in(&dir, Duration::from_secs(3600)).unwrap();
+        assert_eq!(before.files, 2);
+        assert_eq!(after.files, 2);
+        assert!(!dir.join(USERS_FILE).exists());
+        assert_eq!(
+            read_compressed(&dir.join(USERS_FILE)).unwrap().as_bytes(),
+            users
+        );
+        assert!(compressed_path(&spill.join("home.jsonl")).exists());
+
+        let (_, after) = gc_in(&dir, Duration::ZERO).unwrap();
+        assert_eq!(after.files, 1);
+        assert_eq!(after.spill_bytes, 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
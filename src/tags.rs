@@ -0,0 +1,128 @@
+//! Hashtags and topics in a set of tweets, for the `:tags` view.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::api::types::Tweet;
+
+/// A hashtag or topic and how many tweets mention it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagCount {
+    /// `#tag`, or the topic's name.
+    pub label: String,
+    /// For topics, the kind of thing it is, e.g. "Programming Language".
+    pub domain: Option<String>,
+    /// What Enter searches for: the hashtag, or a `context:` operator.
+    pub query: String,
+    pub tweets: usize,
+}
+
+/// Hashtags and topics in a set of tweets, most frequent first.
+#[derive(Debug, Clone, Default)]
+pub struct TagReport {
+    /// Where the tweets came from, e.g. the view's name.
+    pub source: String,
+    pub scanned: usize,
+    pub tags: Vec<TagCount>,
+}
+
+impl TagReport {
+    /// Count the tweets mentioning each hashtag (ignoring case) and each
+    /// topic from the tweets' context annotations.
+    pub fn new<'a>(source: String, tweets: impl IntoIterator<Item = &'a Tweet>) -> Self {
+        let mut counts: HashMap<String, TagCount> = HashMap::new();
+        let mut scanned = 0;
+        for tweet in tweets {
+            scanned += 1;
+            let hashtags = tweet
+                .entities
+                .iter()
+                .flat_map(|e| e.hashtags.iter().flatten())
+                .map(|h| {
+                    let tag = TagCount {
+                        label: format!("#{}", h.tag),
+                        domain: None,
+                        query: format!("#{}", h.tag),
+                        tweets: 0,
+                    };
+                    (h.tag.to_lowercase(), tag)
+                });
+            // The same entity is often annotated under several domains;
+            // the first one names it well enough.
+            let topics = tweet.context_annotations.iter().flatten().map(|a| {
+                let tag = TagCount {
+                    label: a.entity.name.clone(),
+                    domain: Some(a.domain.name.clone()),
+                    query: format!("context:{}.{}", a.domain.id, a.entity.id),
+                    tweets: 0,
+                };
+                (format!("topic:{}", a.entity.id), tag)
+            });
+            let mut seen = HashSet::new();
+            for (key, tag) in hashtags.chain(topics) {
+                if seen.insert(key.clone()) {
+                    counts.entry(key).or_insert(tag).tweets += 1;
+                }
+            }
+        }
+        let mut tags: Vec<TagCount> = counts.into_values().collect();
+        tags.sort_by(|a, b| b.tweets.cmp(&a.tweets).then_with(|| a.label.cmp(&b.label)));
+        Self {
+            source,
+            scanned,
+            tags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn hashtags_and_topics_are_counted_once_per_tweet() {
+        let rust = json!({
+            "domain": { "id": "66", "name": "Interests and Hobbies Category" },
+            "entity": { "id": "1", "name": "Rust" },
+        });
+        let rust_again = json!({
+            "domain": { "id": "131", "name": "Unified Twitter Taxonomy" },
+            "entity": { "id": "1", "name": "Rust" },
+        });
+        let tweet = |id: &str, tags: &[&str], annotations: &[&serde_json::Value]| -> Tweet {
+            let hashtags: Vec<_> = tags
+                .iter()
+                .map(|tag| json!({ "start": 0, "end": 1, "tag": tag }))
+                .collect();
+            serde_json::from_value(json!({
+                "id": id,
+                "text": "",
+                "entities": { "hashtags": hashtags },
+                "context_annotations": annotations,
+            }))
+            .unwrap()
+        };
+        let tweets = [
+            tweet("1", &["RustLang", "rustlang"], &[&rust, &rust_again]),
+            tweet("2", &["rustlang", "tui"], &[]),
+            tweet("3", &[], &[&rust]),
+        ];
+
+        let report = TagReport::new("Following".into(), &tweets);
+        assert_eq!(report.scanned, 3);
+        let ranked: Vec<(&str, &str, usize)> = report
+            .tags
+            .iter()
+            .map(|t| (t.label.as_str(), t.query.as_str(), t.tweets))
+            .collect();
+        assert_eq!(
+            ranked,
+            [
+                ("#RustLang", "#RustLang", 2),
+                ("Rust", "context:66.1", 2),
+                ("#tui", "#tui", 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Terminal capability detection.
+//!
+//! Probed once at startup from the environment the terminal emulator sets up
+//! (`TERM`, `TERM_PROGRAM`, `COLORTERM`, locale, ...). Widgets consult the
+//! result to pick an image protocol or color depth, and degrade to plainer
+//! output instead of emitting escape sequences the terminal can't handle.
+
+/// Inline image protocols a terminal may support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    ITerm2,
+    Sixel,
+}
+
+/// How much of Unicode the terminal can be expected to draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UnicodeLevel {
+    /// Non-UTF-8 locale: stick to ASCII.
+    Ascii,
+    /// UTF-8, but a limited font (e.g. the Linux console): box drawing and
+    /// block elements only, no braille or emoji.
+    Basic,
+    /// Full UTF-8 rendering.
+    Full,
+}
+
+/// What the current terminal can render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalCaps {
+    pub kitty_graphics: bool,
+    pub iterm2_images: bool,
+    pub sixel: bool,
+    pub truecolor: bool,
+    pub unicode: UnicodeLevel,
+    /// Running under tmux/screen, which swallow graphics escapes unless
+    /// passthrough is configured.
+    pub multiplexed: bool,
+}
+
+impl Default for TerminalCaps {
+    /// The conservative baseline: no images, 256 colors, full Unicode.
+    fn default() -> Self {
+        Self {
+            kitty_graphics: false,
+            iterm2_images: false,
+            sixel: false,
+            truecolor: false,
+            unicode: UnicodeLevel::Full,
+            multiplexed: false,
+        }
+    }
+}
+
+impl TerminalCaps {
+    /// Probe the current process environment.
+    pub fn detect() -> Self {
+        let caps = Self::from_env(|key| std::env::var(key).ok());
+        tracing::info!(?caps, "terminal capabilities");
+        caps
+    }
+
+    /// Derive capabilities from an environment lookup.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+        let term = var("TERM").unwrap_or_default().to_lowercase();
+        let program = var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+        let colorterm = var("COLORTERM").unwrap_or_default().to_lowercase();
+        let multiplexed =
+            var("TMUX").is_some() || term.starts_with("screen") || term.starts_with("tmux");
+
+        let kitty_graphics = var("KITTY_WINDOW_ID").is_some()
+            || term.contains("kitty")
+            || program == "ghostty"
+            || program == "wezterm";
+        let iterm2_images = program == "iterm.app" || program == "wezterm";
+        let sixel = term.contains("sixel")
+            || term.starts_with("foot")
+            || term.starts_with("mlterm")
+            || term.starts_with("contour")
+            || program == "wezterm";
+
+        let truecolor =
+            matches!(colorterm.as_str(), "truecolor" | "24bit") || kitty_graphics || iterm2_images;
+
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .find_map(|key| var(key).filter(|v| !v.is_empty()))
+            .unwrap_or_default()
+            .to_lowercase();
+        let unicode = if !locale.is_empty() && !locale.contains("utf-8") && !locale.contains("utf8")
+        {
+            UnicodeLevel::Ascii
+        } else if term == "linux" {
+            UnicodeLevel::Basic
+        } else {
+            UnicodeLevel::Full
+        };
+
+        Self {
+            kitty_graphics,
+            iterm2_images,
+            sixel,
+            truecolor,
+            unicode,
+            multiplexed,
+        }
+    }
+
+    /// The best image protocol available, if any. Multiplexers hide the
+    /// protocols, so none is reported under tmux/screen.
+    pub fn graphics_protocol(&self) -> Option<GraphicsProtocol> {
+        if self.multiplexed {
+            None
+        } else if self.kitty_graphics {
+            Some(GraphicsProtocol::Kitty)
+        } else if self.iterm2_images {
+            Some(GraphicsProtocol::ITerm2)
+        } else if self.sixel {
+            Some(GraphicsProtocol::Sixel)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn caps(vars: &[(&str, &str)]) -> TerminalCaps {
+        TerminalCaps::from_env(|key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| (*v).to_owned())
+        })
+    }
+
+    #[test]
+    fn detects_protocols_from_env() {
+        let kitty = caps(&[("TERM", "xterm-kitty"), ("LANG", "en_US.UTF-8")]);
+        assert_eq!(kitty.graphics_protocol(), Some(GraphicsProtocol::Kitty));
+        assert!(kitty.truecolor);
+
+        let iterm = caps(&[("TERM_PROGRAM", "iTerm.app")]);
+        assert_eq!(iterm.graphics_protocol(), Some(GraphicsProtocol::ITerm2));
+
+        let foot = caps(&[("TERM", "foot"), ("COLORTERM", "truecolor")]);
+        assert_eq!(foot.graphics_protocol(), Some(GraphicsProtocol::Sixel));
+
+        let tmux = caps(&[("TERM", "tmux-256color"), ("KITTY_WINDOW_ID", "1")]);
+        assert_eq!(tmux.graphics_protocol(), None);
+    }
+
+    #[test]
+    fn unicode_level_follows_locale_and_console() {
+        assert_eq!(caps(&[("LANG", "C")]).unicode, UnicodeLevel::Ascii);
+        assert_eq!(
+            caps(&[("TERM", "linux"), ("LANG", "en_US.UTF-8")]).unicode,
+            UnicodeLevel::Basic
+        );
+        assert_eq!(
+            caps(&[("TERM", "xterm-256color"), ("LC_ALL", "en_US.utf8")]).unicode,
+            UnicodeLevel::Full
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Splitting a long draft into a numbered thread of posts.
+
+/// Posts longer than this are rejected unless the account has a subscription.
+pub const POST_LIMIT: usize = 280;
+
+/// What X counts a link as, however long it is: links are shortened to
+/// `t.co` URLs of this length.
+const URL_LENGTH: usize = 23;
+
+/// A post's length as X counts it against [`POST_LIMIT`]: Latin and most
+/// punctuation count 1, other characters (CJK, emoji) 2, and each
+/// `http(s)://` link 23. An emoji built from several code points (skin
+/// tones, ZWJ sequences, variation selectors) counts once.
+pub fn post_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut rest = text;
+    while !rest.is_empty() {
+        let start = rest
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(rest.len());
+        let (gap, after) = rest.split_at(start);
+        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+        let (word, after) = after.split_at(end);
+        len += chars_len(gap) + word_len(word);
+        rest = after;
+    }
+    len
+}
+
+/// A whitespace-free word's length: a link counts as a `t.co` URL.
+fn word_len(word: &str) -> usize {
+    if is_url(word) {
+        URL_LENGTH
+    } else {
+        chars_len(word)
+    }
+}
+
+fn is_url(word: &str) -> bool {
+    ["http://", "https://"].iter().any(|scheme| {
+        word.len() > scheme.len()
+            && word
+                .get(..scheme.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(scheme))
+    })
+}
+
+fn chars_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut prev = None;
+    for c in text.chars() {
+        len += char_weight(c, prev);
+        prev = Some(c);
+    }
+    len
+}
+
+/// What `c` adds to a post's length after `prev`. The ranges X counts as
+/// one are from twitter-text's configuration.
+fn char_weight(c: char, prev: Option<char>) -> usize {
+    let joins_emoji = matches!(c,
+        '\u{FE0F}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}');
+    if joins_emoji && prev.is_some() || prev == Some('\u{200D}') {
+        return 0;
+    }
+    if c == '\u{200D}' && prev.is_some_and(|p| char_weight(p, None) == 2) {
+        return 0;
+    }
+    match c {
+        '\u{0}'..='\u{10FF}'
+        | '\u{2000}'..='\u{200D}'
+        | '\u{2010}'..='\u{201F}'
+        | '\u{2032}'..='\u{2037}' => 1,
+        _ => 2,
+    }
+}
+
+/// Split `text` into posts of at most `limit` (see [`post_len`]), breaking
+/// between words and ending each with its ` i/n` position. Text that fits
+/// in one post is returned as is, unnumbered.
+pub fn split_thread(text: &str, limit: usize) -> Vec<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+    if post_len(text) <= limit {
+        return vec![text.to_string()];
+    }
+    // The numbering grows with the post count's digits, so pack again with
+    // less room until the count fits the room left for it.
+    let mut digits = 1;
+    loop {
+        let budget = limit.saturating_sub(2 + 2 * digits).max(1);
+        let posts = pack(text, budget);
+        if posts.len() < 10usize.pow(digits as u32) {
+            let total = posts.len();
+            return posts
+                .into_iter()
+                .enumerate()
+                .map(|(i, post)| format!("{post} {}/{total}", i + 1))
+                .collect();
+        }
+        digits += 1;
+    }
+}
+
+/// Greedily fill posts of up to `budget` with whole words, keeping the
+/// spacing and line breaks between words of the same post. A word longer
+/// than a post is cut.
+fn pack(text: &str, budget: usize) -> Vec<String> {
+    let mut posts = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+        let (gap, after) = rest.split_at(start);
+        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+        let (mut word, after) = after.split_at(end);
+        rest = after;
+
+        let gap_len = chars_len(gap);
+        let len = word_len(word);
+        if current_len > 0 && current_len + gap_len + len <= budget {
+            current.push_str(gap);
+            current.push_str(word);
+            current_len += gap_len + len;
+            continue;
+        }
+        if current_len > 0 {
+            posts.push(std::mem::take(&mut current));
+        }
+        while word_len(word) > budget {
+            let cut = cut_at(word, budget);
+            posts.push(word[..cut].to_string());
+            word = &word[cut..];
+        }
+        current.push_str(word);
+        current_len = word_len(word);
+    }
+    if current_len > 0 {
+        posts.push(current);
+    }
+    posts
+}
+
+/// The byte index of the longest start of `word` within `budget`, at least
+/// one character so cutting always moves on.
+fn cut_at(word: &str, budget: usize) -> usize {
+    let mut len = 0;
+    let mut prev = None;
+    for (i, c) in word.char_indices() {
+        len += char_weight(c, prev);
+        if len > budget && prev.is_some() {
+            return i;
+        }
+        prev = Some(c);
+    }
+    word.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_drafts_split_between_words_and_are_numbered() {
+        assert_eq!(split_thread("  fits  ", 10), ["fits"]);
+        assert!(split_thread(" \n ", 10).is_empty());
+
+        let posts = split_thread("one two three four\n\nfive", 14);
+        assert_eq!(posts, ["one two 1/3", "three four 2/3", "five 3/3"]);
+        assert!(posts.iter().all(|p| post_len(p) <= 14));
+
+        // Ten or more posts leave room for two-digit numbers.
+        let posts = split_thread(&"abcd ".repeat(12), 10);
+        assert_eq!(posts.len(), 12);
+        assert_eq!(posts[11], "abcd 12/12");
+
+        assert_eq!(
+            split_thread("abcdefghijkl", 7),
+            ["abc 1/4", "def 2/4", "ghi 3/4", "jkl 4/4"]
+        );
+    }
+
+    #[test]
+    fn length_is_counted_the_way_x_counts_it() {
+        assert_eq!(post_len("hello"), 5);
+        assert_eq!(post_len("日本語"), 6);
+        assert_eq!(post_len("café “quoted” — ok"), 18);
+        assert_eq!(post_len("👍"), 2);
+        assert_eq!(post_len("👍🏽 👩‍👩‍👧"), 5);
+        assert_eq!(
+            post_len("see https://example.com/a/very/long/path/indeed?q=1 now"),
+            4 + URL_LENGTH + 4
+        );
+    }
+
+    #[test]
+    fn cjk_drafts_split_at_half_the_characters() {
+        // 200 CJK characters count 400, so they don't fit in one post.
+        let text = "漢".repeat(200);
+        let posts = split_thread(&text, POST_LIMIT);
+        assert_eq!(posts.len(), 2);
+        assert!(posts.iter().all(|p| post_len(p) <= POST_LIMIT), "{posts:?}");
+        assert!(posts[0].ends_with(" 1/2"));
+        let kept: String = posts
+            .iter()
+            .map(|p| p.rsplit_once(' ').unwrap().0)
+            .collect();
+        assert_eq!(kept, text);
+
+        // A long link counts 23, so it doesn't push a short post over.
+        let link = format!("https://example.com/{}", "x".repeat(300));
+        assert_eq!(split_thread(&format!("read {link}"), POST_LIMIT).len(), 1);
+    }
+}
@@ -0,0 +1,66 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget, Wrap};
+
+use crate::api::error::AccountState;
+use crate::app::UnavailableAccount;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+/// Shown in place of a profile or timeline that X won't serve, saying why.
+pub struct AccountStateView<'a> {
+    title: &'a str,
+    account: &'a UnavailableAccount,
+}
+
+impl<'a> AccountStateView<'a> {
+    pub fn new(title: &'a str, account: &'a UnavailableAccount) -> Self {
+        Self { title, account }
+    }
+}
+
+impl Widget for AccountStateView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = panel_block(self.title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let (heading, color, explanation) = match self.account.state {
+            AccountState::Protected => (
+                "These posts are protected",
+                Color::Yellow,
+                "Only followers the account has approved can see them.",
+            ),
+            AccountState::Suspended => (
+                "This account is suspended",
+                Color::Red,
+                "X suspends accounts that break its rules.",
+            ),
+            AccountState::Deactivated => (
+                "This account doesn't exist",
+                Color::DarkGray,
+                "It may have been deactivated or renamed. Try searching for it.",
+            ),
+        };
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                heading,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(explanation),
+            Line::from(""),
+            Line::from(Span::styled(
+                self.account.detail.as_str(),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        Paragraph::new(lines)
+            .centered()
+            .wrap(Wrap { trim: true })
+            .render(inner, buf);
+    }
+}
@@ -0,0 +1,82 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget, Wrap};
+
+use crate::app::{App, AuthorPreview};
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+use crate::ui::tweet::{format_count, format_time_ago};
+
+/// A reply author's recent posts, shown beside the thread.
+pub struct AuthorPreviewView<'a> {
+    app: &'a App,
+    preview: &'a AuthorPreview,
+}
+
+impl<'a> AuthorPreviewView<'a> {
+    pub fn new(app: &'a App, preview: &'a AuthorPreview) -> Self {
+        Self { app, preview }
+    }
+}
+
+impl Widget for AuthorPreviewView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let author = self.app.lookup_user(&self.preview.user_id);
+        let title = match author {
+            Some(user) => format!("@{} recent posts", user.username),
+            None => "Recent posts".to_string(),
+        };
+        let block = panel_block(&title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines: Vec<Line> = Vec::new();
+        if let Some(user) = author
+            && let Some(metrics) = &user.public_metrics
+        {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} followers · {} posts",
+                    format_count(metrics.followers_count),
+                    format_count(metrics.tweet_count)
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+        }
+
+        if self.preview.loading {
+            lines.push(Line::from(Span::styled("Loading…", dim)));
+        } else if let Some(error) = &self.preview.error {
+            lines.push(Line::from(Span::styled(
+                error.as_str(),
+                Style::default().fg(Color::Red),
+            )));
+        } else if self.preview.tweets.is_empty() {
+            lines.push(Line::from(Span::styled("No recent posts", dim)));
+        }
+
+        for tweet in &self.preview.tweets {
+            let meta: Vec<String> = tweet
+                .created_at
+                .map(format_time_ago)
+                .into_iter()
+                .chain(
+                    tweet
+                        .public_metrics
+                        .as_ref()
+                        .map(|m| format!("♥ {}", format_count(m.like_count))),
+                )
+                .collect();
+            lines.push(Line::from(Span::styled(meta.join("  "), dim)));
+            lines.extend(tweet.text.lines().map(|l| Line::from(l.to_string())));
+            lines.push(Line::from(""));
+        }
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .render(inner, buf);
+    }
+}
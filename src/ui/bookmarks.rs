@@ -20,6 +20,7 @@ impl Widget for BookmarksView<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         TimelineView::new("Bookmarks", &self.app.bookmarks.tweets, self.app)
             .loading(self.app.bookmarks.loading)
+            .partial_errors(&self.app.bookmarks.partial_errors)
             .render(area, buf);
     }
 }
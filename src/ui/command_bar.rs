@@ -25,7 +25,11 @@ impl Widget for CommandBar<'_> {
             AppMode::Search => {
                 TextInput::new("/", &self.app.search_input).render(area, buf);
             }
-            AppMode::Normal => {}
+            AppMode::Normal
+            | AppMode::Visual
+            | AppMode::Confirm
+            | AppMode::Compose
+            | AppMode::ListForm => {}
         }
     }
 }
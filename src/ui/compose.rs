@@ -0,0 +1,269 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+use unicode_width::UnicodeWidthChar;
+
+use crate::api::types::ReplySettings;
+use crate::app::{ReplyDrafts, ReplyTarget};
+use crate::thread_split::{POST_LIMIT, post_len, split_thread};
+
+/// A centered multi-line editor for a new post or a reply, under a line
+/// choosing who may reply. While reply drafts are offered it lists them
+/// instead of the editor; for a thread it previews the posts the draft
+/// will be split into.
+pub struct ComposePopup<'a> {
+    text: &'a str,
+    reply_to: Option<&'a ReplyTarget>,
+    drafts: Option<&'a ReplyDrafts>,
+    thread: bool,
+    reply_settings: ReplySettings,
+}
+
+impl<'a> ComposePopup<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            reply_to: None,
+            drafts: None,
+            thread: false,
+            reply_settings: ReplySettings::Everyone,
+        }
+    }
+
+    pub fn reply_settings(mut self, reply_settings: ReplySettings) -> Self {
+        self.reply_settings = reply_settings;
+        self
+    }
+
+    pub fn thread(mut self, thread: bool) -> Self {
+        self.thread = thread;
+        self
+    }
+
+    pub fn reply_to(mut self, target: Option<&'a ReplyTarget>) -> Self {
+        self.reply_to = target;
+        self
+    }
+
+    pub fn drafts(mut self, drafts: Option<&'a ReplyDrafts>) -> Self {
+        self.drafts = drafts;
+        self
+    }
+}
+
+impl Widget for ComposePopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = 64u16.min(area.width.saturating_sub(4));
+        let height = if self.thread { 20u16 } else { 12 };
+        let height = height.min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let panel = Rect::new(x, y, width, height);
+
+        Clear.render(panel, buf);
+
+        let reply = if self.thread {
+            "Thread in reply"
+        } else {
+            "Reply"
+        };
+        let title = match self.drafts.map(|d| &d.target).or(self.reply_to) {
+            Some(ReplyTarget {
+                username: Some(username),
+                ..
+            }) => format!(" {reply} to @{username} "),
+            Some(_) => format!(" {reply} "),
+            None if self.thread => " New thread ".to_string(),
+            None => " New post ".to_string(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+
+        // The footer (count and keys) matters more than the text.
+        if inner.height < 2 || inner.width == 0 {
+            return;
+        }
+        let footer_area = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
+        let text_area = Rect::new(inner.x, inner.y, inner.width, inner.height - 1);
+        let dim = Style::default().fg(Color::DarkGray);
+
+        if let Some(drafts) = self.drafts {
+            render_drafts(drafts, text_area, buf);
+            Paragraph::new(Line::styled("Up/Down choose · Enter edit · Esc back", dim))
+                .render(footer_area, buf);
+            return;
+        }
+
+        render_reply_settings(
+            self.reply_settings,
+            Rect::new(text_area.x, text_area.y, text_area.width, 1),
+            buf,
+        );
+        let text_area = Rect::new(
+            text_area.x,
+            text_area.y + 1,
+            text_area.width,
+            text_area.height.saturating_sub(1),
+        );
+
+        if self.thread {
+            let posts = split_thread(self.text, POST_LIMIT);
+            let editor_height = (text_area.height / 3).max(1);
+            let editor_area = Rect::new(text_area.x, text_area.y, text_area.width, editor_height);
+            let cards_area = Rect::new(
+                text_area.x,
+                text_area.y + editor_height,
+                text_area.width,
+                text_area.height - editor_height,
+            );
+            render_editor(self.text, editor_area, buf);
+            render_thread_cards(&posts, cards_area, buf);
+            let plural = if posts.len() == 1 { "" } else { "s" };
+            Paragraph::new(Line::from(vec![
+                Span::styled(format!("{} post{plural}", posts.len()), dim),
+                Span::styled("  Ctrl-S post thread · Ctrl-T one post · Esc close", dim),
+            ]))
+            .render(footer_area, buf);
+            return;
+        }
+
+        render_editor(self.text, text_area, buf);
+
+        let count = post_len(self.text);
+        let keys = if count > POST_LIMIT {
+            "  Ctrl-T split into a thread · Esc close"
+        } else {
+            "  Ctrl-S post · Ctrl-G draft replies · Esc close"
+        };
+        Paragraph::new(Line::from(vec![
+            Span::styled(format!("{count}/{POST_LIMIT}"), count_style(count)),
+            Span::styled(keys, dim),
+        ]))
+        .render(footer_area, buf);
+    }
+}
+
+/// Red once a post of length `count` no longer fits.
+fn count_style(count: usize) -> Style {
+    if count > POST_LIMIT {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    }
+}
+
+/// Who may reply, as a row of the choices with the picked one
+/// highlighted.
+fn render_reply_settings(picked: ReplySettings, area: Rect, buf: &mut Buffer) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut spans = vec![Span::styled("Replies:", dim)];
+    for setting in [
+        ReplySettings::Everyone,
+        ReplySettings::Following,
+        ReplySettings::MentionedUsers,
+    ] {
+        let style = if setting == picked {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            dim
+        };
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(format!(" {} ", setting.label()), style));
+    }
+    spans.push(Span::styled("  Ctrl-R", dim));
+    Paragraph::new(Line::from(spans)).render(area, buf);
+}
+
+/// The draft with a cursor after it, keeping the cursor line in view once
+/// the draft outgrows `area`.
+fn render_editor(text: &str, area: Rect, buf: &mut Buffer) {
+    let rows = editor_rows(&format!("{text}\u{2588}"), area.width);
+    let skip = rows.len().saturating_sub(area.height as usize);
+    for (i, row) in rows[skip..].iter().enumerate() {
+        buf.set_string(area.x, area.y + i as u16, row, Style::default());
+    }
+}
+
+/// Each post of the thread under a header with its position and length,
+/// scrolled to the last one, where the typing happens.
+fn render_thread_cards(posts: &[String], area: Rect, buf: &mut Buffer) {
+    let header = Style::default().fg(Color::Cyan);
+    let mut lines = Vec::new();
+    for (i, post) in posts.iter().enumerate() {
+        let count = post_len(post);
+        let label = if posts.len() == 1 {
+            format!("── fits in one post · {count}/{POST_LIMIT}")
+        } else {
+            format!("── {}/{} · {count}/{POST_LIMIT}", i + 1, posts.len())
+        };
+        lines.push(Line::styled(label, header));
+        for row in editor_rows(post, area.width) {
+            lines.push(Line::raw(row));
+        }
+    }
+    let skip = lines.len().saturating_sub(area.height as usize);
+    Paragraph::new(lines.split_off(skip)).render(area, buf);
+}
+
+/// The suggested replies, the chosen one highlighted.
+fn render_drafts(drafts: &ReplyDrafts, area: Rect, buf: &mut Buffer) {
+    if drafts.loading {
+        let style = Style::default().fg(Color::DarkGray);
+        buf.set_string(area.x, area.y, "Drafting replies…", style);
+        return;
+    }
+    let mut lines = Vec::new();
+    for (i, draft) in drafts.drafts.iter().enumerate() {
+        let selected = i == drafts.selected;
+        let style = if selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let marker = if selected { "▸ " } else { "  " };
+        for (row_index, row) in editor_rows(draft, area.width.saturating_sub(2))
+            .into_iter()
+            .enumerate()
+        {
+            let prefix = if row_index == 0 { marker } else { "  " };
+            lines.push(Line::styled(format!("{prefix}{row}"), style));
+        }
+        lines.push(Line::default());
+    }
+    Paragraph::new(lines).render(area, buf);
+}
+
+/// Hard-wrap `text` at `width` columns, keeping every space the user typed so
+/// the cursor lands where the next character will go.
+fn editor_rows(text: &str, width: u16) -> Vec<String> {
+    let width = usize::from(width);
+    let mut rows = Vec::new();
+    for line in text.split('\n') {
+        let mut row = String::new();
+        let mut cols = 0;
+        for c in line.chars() {
+            let w = c.width().unwrap_or(0);
+            if cols + w > width && !row.is_empty() {
+                rows.push(std::mem::take(&mut row));
+                cols = 0;
+            }
+            row.push(c);
+            cols += w;
+        }
+        rows.push(row);
+    }
+    rows
+}
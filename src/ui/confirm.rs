@@ -0,0 +1,86 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+
+use crate::app::ConfirmDialog;
+use crate::ui::text::wrap_to_width;
+
+/// A centered Yes/No popup shown before a destructive action.
+pub struct ConfirmPopup<'a> {
+    dialog: &'a ConfirmDialog,
+}
+
+impl<'a> ConfirmPopup<'a> {
+    pub fn new(dialog: &'a ConfirmDialog) -> Self {
+        Self { dialog }
+    }
+}
+
+impl Widget for ConfirmPopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = 50u16.min(area.width.saturating_sub(4));
+        let inner_width = width.saturating_sub(2) as usize;
+        let message_lines = wrap_to_width(&self.dialog.message, inner_width);
+
+        // Borders, message, blank line, checkbox, blank line, buttons.
+        let height = (message_lines.len().max(1) as u16 + 6).min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let panel = Rect::new(x, y, width, height);
+
+        Clear.render(panel, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {}? ", self.dialog.title))
+            .title_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .border_style(Style::default().fg(Color::Red));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+
+        // Buttons and the checkbox matter more than the message.
+        if inner.height < 3 {
+            return;
+        }
+        let buttons_area = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
+        let checkbox_area = Rect::new(inner.x, inner.y + inner.height - 3, inner.width, 1);
+        let message_area = Rect::new(inner.x, inner.y, inner.width, inner.height - 3);
+
+        Paragraph::new(
+            message_lines
+                .into_iter()
+                .map(Line::from)
+                .collect::<Vec<_>>(),
+        )
+        .render(message_area, buf);
+
+        let check = if self.dialog.dont_ask_again {
+            "[x]"
+        } else {
+            "[ ]"
+        };
+        Paragraph::new(Line::from(Span::styled(
+            format!(" {check} Don't ask again (d)"),
+            Style::default().fg(Color::DarkGray),
+        )))
+        .render(checkbox_area, buf);
+
+        let button = |label: &'static str, selected: bool| {
+            let style = if selected {
+                Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Span::styled(label, style)
+        };
+        Paragraph::new(Line::from(vec![
+            button(" Yes (y) ", self.dialog.yes_selected),
+            Span::raw("  "),
+            button(" No (n) ", !self.dialog.yes_selected),
+        ]))
+        .centered()
+        .render(buttons_area, buf);
+    }
+}
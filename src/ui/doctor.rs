@@ -0,0 +1,86 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap};
+
+use crate::doctor::{Check, CheckStatus};
+
+/// Centered popup with the `:doctor` report.
+pub struct DoctorPopup<'a> {
+    checks: &'a [Check],
+}
+
+impl<'a> DoctorPopup<'a> {
+    pub fn new(checks: &'a [Check]) -> Self {
+        Self { checks }
+    }
+}
+
+impl Widget for DoctorPopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = 80u16.min(area.width.saturating_sub(4));
+        let name_width = self.checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+
+        let mut lines = Vec::new();
+        for check in self.checks {
+            let color = match check.status {
+                CheckStatus::Pass => Color::Green,
+                CheckStatus::Skip => Color::DarkGray,
+                CheckStatus::Warn => Color::Yellow,
+                CheckStatus::Fail => Color::Red,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("[{}] ", check.status.label()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:name_width$}  ", check.name),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(check.detail.as_str()),
+            ]));
+            if let Some(hint) = &check.hint
+                && check.status != CheckStatus::Pass
+            {
+                lines.push(Line::from(Span::styled(
+                    format!("       {hint}"),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+
+        // Borders, the report, a blank line and the dismiss hint.
+        let height = (lines.len() as u16 + 4).min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let panel = Rect::new(x, y, width, height);
+
+        Clear.render(panel, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Doctor ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+
+        if inner.height < 2 {
+            return;
+        }
+        let report_area = Rect::new(inner.x, inner.y, inner.width, inner.height - 1);
+        let hint_area = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .render(report_area, buf);
+        Paragraph::new(Line::from(Span::styled(
+            " Press Esc or Enter to dismiss ",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )))
+        .render(hint_area, buf);
+    }
+}
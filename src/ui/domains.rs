@@ -0,0 +1,101 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::App;
+use crate::ui::text::truncate_for_width;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+/// Widest the domain column gets before names are truncated.
+const MAX_DOMAIN_WIDTH: usize = 32;
+
+/// Domains linked from a view's tweets, most linked first, with a bar for
+/// each one's share of the linking tweets.
+pub struct DomainsView<'a> {
+    app: &'a App,
+}
+
+impl<'a> DomainsView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for DomainsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(report) = &self.app.domain_report else {
+            return;
+        };
+        let block = panel_block(
+            &format!(
+                "Domains · {} ({} of {} tweets link out)",
+                report.source, report.linking, report.scanned
+            ),
+            area.width < COMPACT_WIDTH,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        if report.domains.is_empty() {
+            buf.set_string(
+                inner.x + 1,
+                inner.y,
+                "No links to other sites",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        let domain_width = report
+            .domains
+            .iter()
+            .map(|d| d.domain.width())
+            .max()
+            .unwrap_or(0)
+            .min(MAX_DOMAIN_WIDTH);
+        // Rank, domain, count and percentage, plus the highlight symbol.
+        let bar_width = usize::from(inner.width).saturating_sub(domain_width + 22);
+        let top = report.domains[0].tweets.max(1);
+
+        let items: Vec<ListItem> = report
+            .domains
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                let share = d.tweets * 100 / report.linking.max(1);
+                let bar = "█".repeat(d.tweets * bar_width / top);
+                let domain = truncate_for_width(&d.domain, domain_width);
+                let pad = domain_width.saturating_sub(domain.width());
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:>3}  ", i + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::raw(format!("{domain}{}  ", " ".repeat(pad))),
+                    Span::styled(
+                        format!("{:>4} {share:>3}%  ", d.tweets),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(bar, Style::default().fg(Color::Cyan)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(self.app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
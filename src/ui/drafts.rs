@@ -0,0 +1,84 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+
+use crate::app::App;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+use crate::ui::tweet::format_time_ago;
+
+/// Composer drafts closed without posting, most recently saved first.
+pub struct DraftsView<'a> {
+    app: &'a App,
+}
+
+impl<'a> DraftsView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for DraftsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let drafts = self.app.drafts.entries();
+        let block = panel_block(
+            &format!("Drafts ({})", drafts.len()),
+            area.width < COMPACT_WIDTH,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        if drafts.is_empty() {
+            buf.set_string(
+                inner.x + 1,
+                inner.y,
+                "No drafts. Closing the composer with Esc saves one.",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        let items: Vec<ListItem> = drafts
+            .iter()
+            .map(|draft| {
+                let kind = match (&draft.reply_to, draft.thread) {
+                    (_, true) => "thread",
+                    (Some(_), false) => "reply ",
+                    (None, false) => "post  ",
+                };
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:>6}  ", format_time_ago(draft.saved_at)),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{kind}  "), Style::default().fg(Color::Yellow)),
+                ];
+                if let Some(username) = &draft.reply_to_username {
+                    spans.push(Span::styled(
+                        format!("@{username} "),
+                        Style::default().fg(Color::Cyan),
+                    ));
+                }
+                spans.push(Span::raw(
+                    draft.text.split_whitespace().collect::<Vec<_>>().join(" "),
+                ));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(self.app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
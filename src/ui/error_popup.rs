@@ -7,11 +7,21 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap};
 /// A dismissible centered popup overlay that displays the full API error message.
 pub struct ErrorPopup<'a> {
     text: &'a str,
+    retryable: bool,
 }
 
 impl<'a> ErrorPopup<'a> {
     pub fn new(text: &'a str) -> Self {
-        Self { text }
+        Self {
+            text,
+            retryable: false,
+        }
+    }
+
+    /// Offer `r` to retry the failed request.
+    pub fn retryable(mut self, retryable: bool) -> Self {
+        self.retryable = retryable;
+        self
     }
 }
 
@@ -66,8 +76,13 @@ impl Widget for ErrorPopup<'_> {
         let paragraph = Paragraph::new(self.text).wrap(Wrap { trim: true });
         paragraph.render(text_area, buf);
 
+        let hint = if self.retryable {
+            " Press r to retry, Esc or Enter to dismiss "
+        } else {
+            " Press Esc or Enter to dismiss "
+        };
         let hint = Line::from(Span::styled(
-            " Press Esc or Enter to dismiss ",
+            hint,
             Style::default()
                 .fg(Color::DarkGray)
                 .add_modifier(Modifier::ITALIC),
@@ -0,0 +1,29 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+
+use crate::app::App;
+use crate::event::StreamStatus;
+use crate::ui::timeline::TimelineView;
+
+/// Filtered stream view: tweets matching the stream rules, newest first,
+/// with the connection's state in the title.
+pub struct FilteredStreamView<'a> {
+    pub app: &'a App,
+}
+
+impl<'a> FilteredStreamView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for FilteredStreamView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = format!("Stream · {}", self.app.stream_status.label());
+        let connecting = self.app.stream_status == StreamStatus::Connecting;
+        TimelineView::new(&title, &self.app.filtered_stream.tweets, self.app)
+            .loading(connecting && self.app.filtered_stream.tweets.is_empty())
+            .render(area, buf);
+    }
+}
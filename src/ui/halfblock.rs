@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::Widget;
+
+/// A decoded RGB image, row-major.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl RgbImage {
+    fn pixel(&self, x: u32, y: u32) -> [u8; 3] {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// The most common color, bucketed coarsely so near-identical shades
+    /// count together; returns the mean of the winning bucket.
+    pub fn dominant_color(&self) -> Option<[u8; 3]> {
+        let bucket = |[r, g, b]: [u8; 3]| (r >> 5, g >> 5, b >> 5);
+        let mut counts: HashMap<(u8, u8, u8), (u32, [u32; 3])> = HashMap::new();
+        for &p in &self.pixels {
+            let (n, sum) = counts.entry(bucket(p)).or_default();
+            *n += 1;
+            for (acc, c) in sum.iter_mut().zip(p) {
+                *acc += u32::from(c);
+            }
+        }
+        let (n, sum) = counts.into_values().max_by_key(|(n, _)| *n)?;
+        Some(sum.map(|c| (c / n) as u8))
+    }
+}
+
+/// Image preview drawn with `▀` half-block cells: each cell shows two
+/// vertically stacked pixels, the upper as foreground and the lower as
+/// background. This is the fallback when the terminal has no graphics
+/// protocol (Linux console, tmux without passthrough).
+pub struct HalfBlockImage<'a> {
+    image: &'a RgbImage,
+    truecolor: bool,
+    cover: bool,
+}
+
+impl<'a> HalfBlockImage<'a> {
+    pub fn new(image: &'a RgbImage) -> Self {
+        Self {
+            image,
+            truecolor: true,
+            cover: false,
+        }
+    }
+
+    /// Fill the whole area, cropping the image's edges, instead of fitting
+    /// it inside with empty margins.
+    pub fn cover(mut self, cover: bool) -> Self {
+        self.cover = cover;
+        self
+    }
+
+    /// Emit 24-bit colors; otherwise colors are mapped onto the xterm
+    /// 256-color palette.
+    pub fn truecolor(mut self, truecolor: bool) -> Self {
+        self.truecolor = truecolor;
+        self
+    }
+
+    fn color(&self, [r, g, b]: [u8; 3]) -> Color {
+        if self.truecolor {
+            Color::Rgb(r, g, b)
+        } else {
+            Color::Indexed(rgb_to_ansi256(r, g, b))
+        }
+    }
+}
+
+impl Widget for HalfBlockImage<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (src_w, src_h) = (self.image.width, self.image.height);
+        if area.is_empty() || src_w == 0 || src_h == 0 {
+            return;
+        }
+
+        let max_w = u32::from(area.width);
+        let max_h = u32::from(area.height) * 2;
+
+        // Work in pixel space (two pixels per cell row). `cover` crops the
+        // source to the area's aspect ratio; otherwise the image is fitted
+        // inside, never upscaled.
+        let (src, dst_w, dst_h) = if self.cover {
+            let crop_w = src_w.min(src_h * max_w / max_h).max(1);
+            let crop_h = src_h.min(src_w * max_h / max_w).max(1);
+            let src = Crop {
+                x: (src_w - crop_w) / 2,
+                y: (src_h - crop_h) / 2,
+                width: crop_w,
+                height: crop_h,
+            };
+            (src, max_w, max_h)
+        } else {
+            let scale = (max_w as f64 / src_w as f64)
+                .min(max_h as f64 / src_h as f64)
+                .min(1.0);
+            let dst_w = ((src_w as f64 * scale).round() as u32).clamp(1, max_w);
+            let dst_h = ((src_h as f64 * scale).round() as u32).clamp(1, max_h);
+            let src = Crop {
+                x: 0,
+                y: 0,
+                width: src_w,
+                height: src_h,
+            };
+            (src, dst_w, dst_h)
+        };
+
+        let x_offset = (max_w - dst_w) / 2;
+        for row in 0..dst_h.div_ceil(2) {
+            for col in 0..dst_w {
+                let top = box_average(self.image, &src, col, row * 2, dst_w, dst_h);
+                let cell = &mut buf[(area.x + (x_offset + col) as u16, area.y + row as u16)];
+                cell.set_symbol("\u{2580}").set_fg(self.color(top));
+                if row * 2 + 1 < dst_h {
+                    let bottom = box_average(self.image, &src, col, row * 2 + 1, dst_w, dst_h);
+                    cell.set_bg(self.color(bottom));
+                }
+            }
+        }
+    }
+}
+
+/// Region of the source image being rendered.
+struct Crop {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// Average of the source pixels (within `src`) covered by destination pixel
+/// `(x, y)` when scaling to `dst_w`×`dst_h`.
+fn box_average(image: &RgbImage, src: &Crop, x: u32, y: u32, dst_w: u32, dst_h: u32) -> [u8; 3] {
+    let x0 = src.x + x * src.width / dst_w;
+    let x1 = (src.x + (x + 1) * src.width / dst_w).max(x0 + 1);
+    let y0 = src.y + y * src.height / dst_h;
+    let y1 = (src.y + (y + 1) * src.height / dst_h).max(y0 + 1);
+
+    let mut sum = [0u32; 3];
+    for sy in y0..y1 {
+        for sx in x0..x1 {
+            let p = image.pixel(sx, sy);
+            for (acc, c) in sum.iter_mut().zip(p) {
+                *acc += u32::from(c);
+            }
+        }
+    }
+    let n = (x1 - x0) * (y1 - y0);
+    sum.map(|c| (c / n) as u8)
+}
+
+/// Nearest xterm 256-color index: the 6×6×6 cube or the grayscale ramp.
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| -> u8 {
+        if c < 48 {
+            0
+        } else if c < 115 {
+            1
+        } else {
+            (c - 35) / 40
+        }
+    };
+    if r == g && g == b {
+        return match r {
+            0..=7 => 16,
+            // 248 and up would step past the ramp's last index (255).
+            248..=255 => 231,
+            _ => 232 + (r - 8) / 10,
+        };
+    }
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_two_pixels_per_cell() {
+        let image = RgbImage {
+            width: 1,
+            height: 2,
+            pixels: vec![[255, 0, 0], [0, 0, 255]],
+        };
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        HalfBlockImage::new(&image).render(area, &mut buf);
+
+        let cell = &buf[(0, 0)];
+        assert_eq!(cell.symbol(), "\u{2580}");
+        assert_eq!(cell.fg, Color::Rgb(255, 0, 0));
+        assert_eq!(cell.bg, Color::Rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn cover_fills_area_and_dominant_color_wins() {
+        let mut pixels = vec![[10, 20, 200]; 12];
+        pixels[0] = [250, 250, 250];
+        let image = RgbImage {
+            width: 4,
+            height: 3,
+            pixels,
+        };
+        assert_eq!(image.dominant_color(), Some([10, 20, 200]));
+
+        let area = Rect::new(0, 0, 6, 1);
+        let mut buf = Buffer::empty(area);
+        HalfBlockImage::new(&image)
+            .cover(true)
+            .render(area, &mut buf);
+        assert!((0..6).all(|x| buf[(x, 0)].symbol() == "\u{2580}"));
+    }
+
+    #[test]
+    fn maps_to_256_color_palette() {
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(247, 247, 247), 255);
+        assert_eq!(rgb_to_ansi256(248, 248, 248), 231);
+    }
+}
@@ -0,0 +1,103 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget};
+
+use crate::app::PostingHeatmap;
+use crate::terminal::{TerminalCaps, UnicodeLevel};
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+/// Rows the heatmap needs: border, hour ruler, seven days, border.
+pub const HEATMAP_HEIGHT: u16 = 10;
+
+const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Lightest to darkest. The first shade is for hours with no tweets.
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+const ASCII_SHADES: [char; 5] = [' ', '.', ':', '+', '#'];
+
+/// Weekday × hour grid of when a user posts, shaded by tweet count.
+pub struct PostingHeatmapView<'a> {
+    heatmap: &'a PostingHeatmap,
+    caps: TerminalCaps,
+}
+
+impl<'a> PostingHeatmapView<'a> {
+    pub fn new(heatmap: &'a PostingHeatmap) -> Self {
+        Self {
+            heatmap,
+            caps: TerminalCaps::default(),
+        }
+    }
+
+    pub fn caps(mut self, caps: TerminalCaps) -> Self {
+        self.caps = caps;
+        self
+    }
+}
+
+impl Widget for PostingHeatmapView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let heatmap = self.heatmap;
+        let title = match heatmap.since {
+            Some(since) if heatmap.tweets > 0 => format!(
+                "Posting times · {} tweets since {} (local time)",
+                heatmap.tweets,
+                since.format("%Y-%m-%d")
+            ),
+            _ => "Posting times".to_string(),
+        };
+        let block = panel_block(&title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let message = if heatmap.loading {
+            Some("Loading…")
+        } else if let Some(error) = &heatmap.error {
+            Some(error.as_str())
+        } else if heatmap.tweets == 0 {
+            Some("No tweets to chart")
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            Paragraph::new(Span::styled(message, dim)).render(inner, buf);
+            return;
+        }
+
+        // Two columns per hour when there is room, so the grid isn't cramped.
+        let cell = if inner.width >= 4 + 48 { 2 } else { 1 };
+        let shades = if self.caps.unicode == UnicodeLevel::Ascii {
+            ASCII_SHADES
+        } else {
+            SHADES
+        };
+        let max = heatmap.max().max(1);
+
+        let mut ruler = String::from("    ");
+        for hour in (0..24).step_by(6) {
+            ruler.push_str(&format!("{hour:<width$}", width = 6 * cell));
+        }
+        let mut lines = vec![Line::from(Span::styled(ruler, dim))];
+        for (day, counts) in DAYS.iter().zip(&heatmap.counts) {
+            let row: String = counts
+                .iter()
+                .map(|&n| {
+                    let level = (n * 4).div_ceil(max) as usize;
+                    shades[level.min(4)]
+                })
+                .flat_map(|c| std::iter::repeat_n(c, cell))
+                .collect();
+            lines.push(Line::from(vec![
+                Span::styled(format!("{day} "), dim),
+                Span::styled(row, Style::default().fg(Color::Green)),
+            ]));
+        }
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
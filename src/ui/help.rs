@@ -1,10 +1,13 @@
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use crate::app::App;
+use crate::ui::timeline::render_scrollbar;
+
 /// Leading spaces before the key label.
 const KEY_INDENT: usize = 2;
 /// Width of the left-padded key label column.
@@ -12,39 +15,23 @@ const KEY_WIDTH: usize = 12;
 /// Column at which descriptions begin (and where continuation rows align).
 const DESC_COL: usize = KEY_INDENT + KEY_WIDTH;
 
-/// Help overlay showing keybindings.
-#[derive(Default)]
-pub struct HelpView;
+/// Help overlay showing keybindings, scrolled with j/k and PgUp/PgDn when
+/// they don't all fit.
+pub struct HelpView<'a> {
+    app: &'a App,
+}
 
-impl HelpView {
-    pub fn new() -> Self {
-        Self
+impl<'a> HelpView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
     }
 }
 
-impl Widget for HelpView {
+impl Widget for HelpView<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        // Center a panel that's 60 wide, 30 tall (or fit to area)
+        // Center a panel that's 60 wide and as tall as the bindings (or fit
+        // to area).
         let width = 60u16.min(area.width.saturating_sub(4));
-        let height = 36u16.min(area.height.saturating_sub(2));
-        let x = area.x + (area.width.saturating_sub(width)) / 2;
-        let y = area.y + (area.height.saturating_sub(height)) / 2;
-        let panel = Rect::new(x, y, width, height);
-
-        Clear.render(panel, buf);
-
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Help - Keybindings ")
-            .title_style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .border_style(Style::default().fg(Color::Cyan));
-
-        let inner = block.inner(panel);
-        block.render(panel, buf);
 
         let key_style = Style::default()
             .fg(Color::Yellow)
@@ -54,10 +41,11 @@ impl Widget for HelpView {
             .fg(Color::Cyan)
             .add_modifier(Modifier::BOLD);
 
-        // Width available for description text (after key column).
-        // Saturates to 1 if the panel is somehow narrower than DESC_COL,
-        // so wrap_text never receives 0 and produces at least one row.
-        let desc_width = (inner.width as usize).saturating_sub(DESC_COL).max(1);
+        // Width available for description text (after key column and the
+        // borders). Saturates to 1 if the panel is somehow narrower than
+        // DESC_COL, so wrap_text never receives 0 and produces at least
+        // one row.
+        let desc_width = (width as usize).saturating_sub(2 + DESC_COL).max(1);
 
         let mut bindings: Vec<Line<'static>> = Vec::new();
         let push_section = |b: &mut Vec<Line<'static>>, name: &str| {
@@ -71,18 +59,77 @@ impl Widget for HelpView {
         push_binding(&mut bindings, "j/Down", "Move down");
         push_binding(&mut bindings, "k/Up", "Move up");
         push_binding(&mut bindings, "Enter", "Open selected item");
-        push_binding(&mut bindings, "Esc/q", "Go back / close");
+        push_binding(
+            &mut bindings,
+            "Esc/q",
+            "Stop loading / dismiss errors / back",
+        );
         push_binding(&mut bindings, "n", "Load next page");
+        push_binding(&mut bindings, "p", "Load previous page");
+        push_binding(&mut bindings, "u", "Open author's profile");
+        push_binding(&mut bindings, "l", "Like/unlike tweet");
+        push_binding(&mut bindings, "b", "Bookmark (in Bookmarks: remove)");
+        push_binding(&mut bindings, "m", "Profile: mute/unmute user");
+        push_binding(&mut bindings, "X", "Profile: block/unblock user");
+        push_binding(&mut bindings, "L", "Profile: add user to one of your Lists");
+        push_binding(&mut bindings, "d", "List members: remove from List");
+        push_binding(&mut bindings, "H", "Profile: posting-times heatmap");
+        push_binding(&mut bindings, "t", "Repost/undo repost");
+        push_binding(&mut bindings, "R", "Who reposted the tweet");
+        push_binding(&mut bindings, "F", "Who liked the tweet");
+        push_binding(&mut bindings, "a", "Thread: preview reply author's posts");
+        push_binding(
+            &mut bindings,
+            "h",
+            "Thread: hide/unhide reply to your tweet",
+        );
         push_binding(&mut bindings, "r", "Refresh current view");
         push_binding(&mut bindings, "y", "Copy tweet URL");
         push_binding(&mut bindings, "o", "Open tweet in browser");
+        push_binding(&mut bindings, "e", "Expand/collapse partial error notice");
+        push_binding(&mut bindings, "s", "Search: sort by engagement/recency");
+        push_binding(
+            &mut bindings,
+            "z",
+            "Show/fold duplicates (×N) or author runs",
+        );
+        push_binding(&mut bindings, "Z", "Undo a post just sent (delete it)");
+        push_binding(
+            &mut bindings,
+            "Q<reg>",
+            "Record keys into a macro (Q stops)",
+        );
+        push_binding(&mut bindings, "&<reg>", "Play a recorded macro");
+        bindings.push(Line::from(""));
+
+        push_section(&mut bindings, "Selection");
+        push_binding(&mut bindings, "Space", "Mark/unmark tweet");
+        push_binding(&mut bindings, "v", "Visual mode (select a range)");
+        push_binding(
+            &mut bindings,
+            "B",
+            "Bookmark selection (Search: all if none)",
+        );
+        push_binding(&mut bindings, "U", "Remove selection from bookmarks");
+        push_binding(&mut bindings, "M", "Mute authors of selection");
+        push_binding(&mut bindings, "x", "Export selection as JSONL");
+        push_binding(&mut bindings, "|", "Pipe selection to a shell command");
+        push_binding(&mut bindings, "Esc", "Clear selection");
         bindings.push(Line::from(""));
 
+        // Leave out the views an app-only bearer token can't load.
+        let read_only = self.app.read_only();
         push_section(&mut bindings, "Views");
-        push_binding(&mut bindings, "1", "Following timeline");
-        push_binding(&mut bindings, "2", "Mentions");
-        push_binding(&mut bindings, "3", "Bookmarks");
+        if !read_only {
+            push_binding(&mut bindings, "1", "Following timeline");
+            push_binding(&mut bindings, "2", "Mentions");
+            push_binding(&mut bindings, "3", "Bookmarks");
+        }
         push_binding(&mut bindings, "4", "Search");
+        if !read_only {
+            push_binding(&mut bindings, "5", "Lists you own and follow");
+            push_binding(&mut bindings, "6", "Direct messages");
+        }
         push_binding(&mut bindings, "?", "This help screen");
         bindings.push(Line::from(""));
 
@@ -90,10 +137,56 @@ impl Widget for HelpView {
         push_binding(&mut bindings, ":", "Command mode");
         push_binding(&mut bindings, "/", "Search tweets");
         push_binding(&mut bindings, "@", "Look up user");
+        push_binding(&mut bindings, "c", "Compose a post (Ctrl-S sends)");
+        push_binding(&mut bindings, "Ctrl-G", "Draft replies (in the composer)");
+        push_binding(&mut bindings, "Ctrl-T", "Split the draft into a thread");
+        push_binding(
+            &mut bindings,
+            "Ctrl-R",
+            "Who may reply: all/following/mentioned",
+        );
+        push_binding(&mut bindings, "A", "Run X setup (when offline)");
         push_binding(&mut bindings, "Ctrl-C", "Quit");
         bindings.push(Line::from(""));
 
         push_section(&mut bindings, "Commands");
+        push_binding(&mut bindings, ":goto <id>", "Jump to a tweet by ID or URL");
+        push_binding(
+            &mut bindings,
+            ":local <query>",
+            "Search stored tweets offline",
+        );
+        push_binding(
+            &mut bindings,
+            ":history",
+            "Recently viewed threads/profiles",
+        );
+        push_binding(&mut bindings, ":drafts", "Unsent drafts (d deletes)");
+        push_binding(
+            &mut bindings,
+            ":rules [add|delete]",
+            "Filtered stream rules (d deletes)",
+        );
+        push_binding(
+            &mut bindings,
+            ":stream [stop]",
+            "Live tweets matching the rules",
+        );
+        push_binding(
+            &mut bindings,
+            ":domains [all]",
+            "Sites linked from view (all: store)",
+        );
+        push_binding(
+            &mut bindings,
+            ":tags",
+            "Hashtags/topics in view; Enter searches",
+        );
+        push_binding(
+            &mut bindings,
+            ":pipe <cmd>",
+            "Pipe selection to cmd as JSONL",
+        );
         push_binding(&mut bindings, ":auth", "Authenticate (X OAuth2 PKCE)");
         push_binding(&mut bindings, ":or-auth", "Authenticate (OpenRouter)");
         push_binding(&mut bindings, ":embeddings", "Select embedding model");
@@ -118,18 +211,105 @@ impl Widget for HelpView {
             ":topics",
             "Regenerate cluster topic labels via LLM",
         );
+        push_binding(
+            &mut bindings,
+            ":similar",
+            "Stored tweets like the selected one",
+        );
         push_binding(&mut bindings, ":refresh", "Refresh current view");
+        push_binding(&mut bindings, ":retry", "Retry the last failed request");
+        push_binding(&mut bindings, ":perf", "Toggle performance overlay");
+        push_binding(&mut bindings, ":cache stats", "Show local cache disk usage");
+        push_binding(&mut bindings, ":doctor", "Check setup and credentials");
+        push_binding(&mut bindings, ":usage", "Posts read this month vs. the cap");
+        push_binding(&mut bindings, ":mute <user>", "Mute an account (:unmute)");
+        push_binding(
+            &mut bindings,
+            ":block <user>",
+            "Block an account (:unblock)",
+        );
+        push_binding(
+            &mut bindings,
+            ":mute-thread",
+            "Hide this thread from Home/Mentions",
+        );
+        push_binding(&mut bindings, ":unmute-thread", "Show this thread again");
+        push_binding(
+            &mut bindings,
+            ":list create [name]",
+            "New List (name, description, private)",
+        );
+        push_binding(
+            &mut bindings,
+            ":list edit|rename <name>|delete",
+            "Change or delete the selected List",
+        );
+        push_binding(
+            &mut bindings,
+            ":dm [user]",
+            "Messages with a user or this profile",
+        );
+        push_binding(
+            &mut bindings,
+            ":set tick_rate_fps <n>",
+            "Change the UI tick rate",
+        );
+        push_binding(
+            &mut bindings,
+            ":set theme <name>",
+            "Switch palette (auto, default, basic16, high-contrast)",
+        );
+        push_binding(
+            &mut bindings,
+            ":set exclude_replies|exclude_retweets on|off|toggle",
+            "Leave replies/retweets out of timelines",
+        );
         push_binding(&mut bindings, ":quit", "Quit");
 
-        let paragraph = Paragraph::new(bindings);
+        let height = (bindings.len() as u16)
+            .saturating_add(2)
+            .min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let panel = Rect::new(x, y, width, height);
+
+        Clear.render(panel, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Help - Keybindings ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(panel);
+        block.render(panel, buf);
 
-        let [content_area] = Layout::vertical([Constraint::Min(0)]).areas(inner);
-        paragraph.render(content_area, buf);
+        let page = inner.height as usize;
+        let max_scroll = bindings.len().saturating_sub(page);
+        self.app.help_scroll_max.set(max_scroll);
+        self.app.help_page.set(page);
+        let scroll = self
+            .app
+            .view_stack
+            .last()
+            .map_or(0, |vs| vs.scroll_offset)
+            .min(max_scroll);
+
+        let paragraph = Paragraph::new(bindings).scroll((scroll as u16, 0));
+        paragraph.render(inner, buf);
+        if max_scroll > 0 {
+            render_scrollbar(panel, buf, max_scroll + 1, scroll);
+        }
     }
 }
 
 /// Build one or more `Line`s for a single binding, hanging-indenting any
 /// wrapped continuation rows so they align under the description column.
+/// A key too long for its column gets a row of its own.
 fn binding_lines(
     key: &str,
     desc: &str,
@@ -138,9 +318,22 @@ fn binding_lines(
     desc_width: usize,
 ) -> Vec<Line<'static>> {
     let chunks = wrap_text(desc, desc_width);
-    let mut lines = Vec::with_capacity(chunks.len().max(1));
+    let mut lines = Vec::with_capacity(chunks.len() + 1);
 
     let mut iter = chunks.into_iter();
+    if UnicodeWidthStr::width(key) >= KEY_WIDTH {
+        lines.push(Line::from(Span::styled(
+            format!("{:indent$}{key}", "", indent = KEY_INDENT),
+            key_style,
+        )));
+        for chunk in iter {
+            lines.push(Line::from(vec![
+                Span::raw(" ".repeat(DESC_COL)),
+                Span::styled(chunk, desc_style),
+            ]));
+        }
+        return lines;
+    }
     let first = iter.next().unwrap_or_default();
     lines.push(Line::from(vec![
         Span::styled(
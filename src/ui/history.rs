@@ -0,0 +1,75 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+
+use crate::app::App;
+use crate::store::HistoryTarget;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+use crate::ui::tweet::format_time_ago;
+
+/// Threads and profiles opened recently, newest first.
+pub struct HistoryView<'a> {
+    app: &'a App,
+}
+
+impl<'a> HistoryView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for HistoryView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let entries = self.app.history.entries();
+        let block = panel_block(
+            &format!("History ({})", entries.len()),
+            area.width < COMPACT_WIDTH,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        if entries.is_empty() {
+            buf.set_string(
+                inner.x + 1,
+                inner.y,
+                "Nothing opened yet",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        let items: Vec<ListItem> = entries
+            .iter()
+            .map(|entry| {
+                let kind = match entry.target {
+                    HistoryTarget::Thread { .. } => "thread ",
+                    HistoryTarget::Profile { .. } => "profile",
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:>6}  ", format_time_ago(entry.opened_at)),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(format!("{kind}  "), Style::default().fg(Color::Yellow)),
+                    Span::raw(entry.label.as_str()),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(self.app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
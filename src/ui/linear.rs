@@ -0,0 +1,198 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::Widget;
+
+use crate::api::types::{Tweet, User};
+use crate::app::App;
+use crate::ui::text::wrap_to_width;
+use crate::ui::timeline::compute_scroll_start;
+use crate::ui::tweet::attachments;
+
+/// Accessible rendering of a tweet list: plain lines with no borders,
+/// separators or decorative glyphs, so a screen reader reading the terminal
+/// top to bottom hears a sensible sequence.
+///
+/// ```text
+/// Following, tweet 2 of 20
+///
+///   @alice (Alice), 2 hours ago, reply:
+///   tweet text...
+///   12 likes, 3 reposts, 5 replies
+///
+/// > @bob (Bob), 5 hours ago:
+/// ```
+pub struct LinearTweetList<'a> {
+    title: &'a str,
+    tweets: &'a [Tweet],
+    selected_index: usize,
+    app: &'a App,
+}
+
+impl<'a> LinearTweetList<'a> {
+    pub fn new(title: &'a str, tweets: &'a [Tweet], selected_index: usize, app: &'a App) -> Self {
+        Self {
+            title,
+            tweets,
+            selected_index,
+            app,
+        }
+    }
+
+    fn tweet_lines(&self, tweet: &Tweet, width: usize) -> Vec<String> {
+        let author = tweet
+            .author_id
+            .as_ref()
+            .and_then(|id| self.app.lookup_user(id));
+        let mut header = describe_header(tweet, author);
+        if self.app.is_watched(tweet) {
+            header.insert_str(0, "Watched: ");
+        }
+        match self.app.duplicate_count(&tweet.id) {
+            0 => {}
+            1 => header.push_str(" (1 copy folded, z to show)"),
+            n => header.push_str(&format!(" ({n} copies folded, z to show)")),
+        }
+        match self.app.author_run_count(&tweet.id) {
+            0 => {}
+            n => header.push_str(&format!(" (posted {} times in a row, z to show)", n + 1)),
+        }
+        let mut lines = vec![header];
+        let text = tweet
+            .note_tweet
+            .as_ref()
+            .map(|nt| nt.text.as_str())
+            .unwrap_or(&tweet.text);
+        lines.extend(wrap_to_width(text, width));
+        for attachment in attachments(tweet, &self.app.included) {
+            lines.push(format!("[{}]", attachment.describe()));
+        }
+        if let Some(ref m) = tweet.public_metrics {
+            lines.push(format!(
+                "{} likes, {} reposts, {} replies",
+                m.like_count, m.retweet_count, m.reply_count
+            ));
+        }
+        lines
+    }
+}
+
+impl Widget for LinearTweetList<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width < 3 {
+            return;
+        }
+
+        let heading = if self.tweets.is_empty() {
+            format!("{}, no tweets", self.title)
+        } else {
+            format!(
+                "{}, tweet {} of {}",
+                self.title,
+                self.selected_index.min(self.tweets.len() - 1) + 1,
+                self.tweets.len()
+            )
+        };
+        buf.set_stringn(
+            area.x,
+            area.y,
+            &heading,
+            area.width as usize,
+            Style::default().add_modifier(Modifier::BOLD),
+        );
+
+        // Two columns for the "> " selection marker.
+        let width = (area.width - 2) as usize;
+        let blocks: Vec<Vec<String>> = self
+            .tweets
+            .iter()
+            .map(|t| self.tweet_lines(t, width))
+            .collect();
+        // Each block is followed by a blank line.
+        let heights: Vec<u16> = blocks.iter().map(|b| b.len() as u16 + 1).collect();
+
+        let top = area.y + 2;
+        let bottom = area.y + area.height;
+        let start = compute_scroll_start(&heights, self.selected_index, bottom.saturating_sub(top));
+
+        let mut y = top;
+        for (i, block) in blocks.iter().enumerate().skip(start) {
+            let selected = i == self.selected_index;
+            for (j, line) in block.iter().enumerate() {
+                if y >= bottom {
+                    return;
+                }
+                let marker = if selected && j == 0 { "> " } else { "  " };
+                let style = if selected {
+                    Style::default().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                buf.set_string(area.x, y, marker, style);
+                buf.set_stringn(area.x + 2, y, line, width, style);
+                y += 1;
+            }
+            y += 1;
+        }
+    }
+}
+
+/// "@alice (Alice), 2 hours ago, reply:" — the author, age and kind of tweet in
+/// words rather than glyphs.
+fn describe_header(tweet: &Tweet, author: Option<&User>) -> String {
+    let mut header = match author {
+        Some(u) => {
+            let mut about = u.name.clone();
+            if let Some(verification) = u.verification() {
+                about.push_str(", ");
+                about.push_str(verification.label());
+            }
+            if u.is_protected() {
+                about.push_str(", protected");
+            }
+            format!("@{} ({about})", u.username)
+        }
+        None => format!("@{}", tweet.author_id.as_deref().unwrap_or("unknown")),
+    };
+    if let Some(created) = tweet.created_at {
+        header.push_str(&format!(", {}", describe_age(created)));
+    }
+    for rt in tweet.referenced_tweets.iter().flatten() {
+        let kind = match rt.type_.as_str() {
+            "retweeted" => "repost",
+            "replied_to" => "reply",
+            "quoted" => "quote",
+            _ => continue,
+        };
+        header.push_str(", ");
+        header.push_str(kind);
+    }
+    if let Some(ref withheld) = tweet.withheld {
+        header.push_str(", ");
+        header.push_str(&withheld.describe());
+    }
+    header.push(':');
+    header
+}
+
+fn describe_age(dt: chrono::DateTime<chrono::Utc>) -> String {
+    let diff = chrono::Utc::now().signed_duration_since(dt);
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {unit} ago")
+        } else {
+            format!("{n} {unit}s ago")
+        }
+    };
+    if diff.num_minutes() < 1 {
+        "just now".to_string()
+    } else if diff.num_hours() < 1 {
+        plural(diff.num_minutes(), "minute")
+    } else if diff.num_days() < 1 {
+        plural(diff.num_hours(), "hour")
+    } else if diff.num_days() < 30 {
+        plural(diff.num_days(), "day")
+    } else {
+        dt.format("%B %-d").to_string()
+    }
+}
@@ -0,0 +1,94 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+
+use crate::app::{ListForm, ListFormField};
+
+/// A centered form for a List's name, description and visibility.
+pub struct ListFormPopup<'a> {
+    form: &'a ListForm,
+}
+
+impl<'a> ListFormPopup<'a> {
+    pub fn new(form: &'a ListForm) -> Self {
+        Self { form }
+    }
+}
+
+impl Widget for ListFormPopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = 56u16.min(area.width.saturating_sub(4));
+        let height = 7u16.min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let panel = Rect::new(x, y, width, height);
+
+        Clear.render(panel, buf);
+
+        let title = if self.form.list_id.is_some() {
+            " Edit List "
+        } else {
+            " New List "
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+        if inner.height < 2 || inner.width == 0 {
+            return;
+        }
+
+        let label_width = 14;
+        let value_width = usize::from(inner.width).saturating_sub(label_width + 1);
+        let private = if self.form.private { "[x]" } else { "[ ]" };
+        let rows = [
+            (ListFormField::Name, "Name", self.form.name.as_str()),
+            (
+                ListFormField::Description,
+                "Description",
+                self.form.description.as_str(),
+            ),
+            (ListFormField::Private, "Private", private),
+        ];
+        let mut lines: Vec<Line> = rows
+            .into_iter()
+            .map(|(field, label, value)| {
+                let focused = field == self.form.focus;
+                let mut value = value.to_string();
+                if focused && field != ListFormField::Private {
+                    value.push('\u{2588}');
+                }
+                // Long descriptions scroll so the end being typed stays in view.
+                let skip = value.chars().count().saturating_sub(value_width);
+                let value: String = value.chars().skip(skip).collect();
+                let label_style = if focused {
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let marker = if focused { "▸ " } else { "  " };
+                Line::from(vec![
+                    Span::styled(format!("{marker}{label:<12}"), label_style),
+                    Span::raw(value),
+                ])
+            })
+            .collect();
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            "Tab next · Space private · Enter save · Esc cancel",
+            Style::default().fg(Color::DarkGray),
+        ));
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
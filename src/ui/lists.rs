@@ -0,0 +1,101 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+
+use crate::app::App;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+use crate::ui::tweet::format_count;
+
+/// The Lists you own, then the ones you follow. Enter shows the selected
+/// one's members, or while picking a List for `L`, adds the account to it.
+/// `o` opens the selected one on x.com.
+pub struct ListsView<'a> {
+    app: &'a App,
+}
+
+impl<'a> ListsView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for ListsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let title = match &app.list_pick_user {
+            Some(user) => format!("Add @{} to which List? · Enter adds", user.username),
+            None => format!(
+                "Lists · {} owned · {} followed",
+                app.owned_lists.len(),
+                app.followed_lists.len()
+            ),
+        };
+        let block = panel_block(&title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let message = if app.lists_loading {
+            Some("Loading…")
+        } else if app.owned_lists.is_empty() && app.followed_lists.is_empty() {
+            Some("No lists")
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            buf.set_string(inner.x + 1, inner.y, message, dim);
+            return;
+        }
+
+        let items: Vec<ListItem> = app
+            .lists()
+            .enumerate()
+            .map(|(i, list)| {
+                let relation = if i < app.owned_lists.len() {
+                    "owned"
+                } else {
+                    "followed"
+                };
+                let mut spans = vec![
+                    Span::styled(format!("{relation:<10}"), dim),
+                    Span::styled(list.name.as_str(), Style::default().fg(Color::Cyan)),
+                ];
+                if list.private == Some(true) {
+                    spans.push(Span::styled(
+                        " [private]",
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                if let Some(members) = list.member_count {
+                    spans.push(Span::styled(
+                        format!("  {} members", format_count(members)),
+                        dim,
+                    ));
+                }
+                if let Some(followers) = list.follower_count {
+                    spans.push(Span::styled(
+                        format!(" · {} followers", format_count(followers)),
+                        dim,
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
@@ -0,0 +1,238 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, Paragraph, StatefulWidget, Widget};
+use unicode_width::UnicodeWidthStr;
+
+use crate::api::types::DmEvent;
+use crate::app::App;
+use crate::ui::text::truncate_for_width;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+use crate::ui::tweet::format_time_ago;
+
+/// Your DM conversations, one row per conversation with its latest
+/// message. Enter opens a one-to-one conversation; `o` opens the selected
+/// one on x.com.
+pub struct MessagesView<'a> {
+    app: &'a App,
+}
+
+impl<'a> MessagesView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for MessagesView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let inbox = app.dm_inbox();
+        let title = format!("Messages · {} conversations", inbox.len());
+        let block = panel_block(&title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let message = if app.dm_loading {
+            Some("Loading…")
+        } else if inbox.is_empty() {
+            Some("No messages")
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            buf.set_string(inner.x + 1, inner.y, message, dim);
+            return;
+        }
+
+        // Room for the highlight symbol and the name column.
+        let text_width = (inner.width as usize).saturating_sub(2 + 18 + 6);
+        let items: Vec<ListItem> = inbox
+            .iter()
+            .map(|event| {
+                let conversation_id = event.dm_conversation_id.as_deref().unwrap_or_default();
+                let name = match app.dm_participant(conversation_id) {
+                    Some(id) => user_label(app, id),
+                    None => "group".to_string(),
+                };
+                let mine = event.sender_id.is_some() && event.sender_id == app.dm_user_id;
+                let mut text = if mine {
+                    "You: ".to_string()
+                } else {
+                    String::new()
+                };
+                text.push_str(&event_text(app, event).replace('\n', " "));
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:<18}", truncate_for_width(&name, 17)),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(truncate_for_width(&text, text_width)),
+                ];
+                if let Some(at) = event.created_at {
+                    spans.push(Span::styled(format!("  {}", format_time_ago(at)), dim));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
+
+/// One conversation as a chat: your messages on the right, theirs on the
+/// left, with people joining or leaving centered between them. The newest
+/// message is at the bottom.
+pub struct DmConversationView<'a> {
+    app: &'a App,
+    participant_id: &'a str,
+}
+
+impl<'a> DmConversationView<'a> {
+    pub fn new(app: &'a App, participant_id: &'a str) -> Self {
+        Self {
+            app,
+            participant_id,
+        }
+    }
+}
+
+impl Widget for DmConversationView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let title = format!(
+            "Messages with {} · {}",
+            user_label(app, self.participant_id),
+            app.dm_conversation.len()
+        );
+        let block = panel_block(&title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let message = if app.dm_conversation_loading {
+            Some("Loading…")
+        } else if app.dm_conversation.is_empty() {
+            Some("No messages yet")
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            buf.set_string(inner.x + 1, inner.y, message, dim);
+            return;
+        }
+
+        // Messages take up to two thirds of the width, so the sides stay
+        // apart.
+        let bubble_width = (inner.width as usize * 2 / 3).max(10);
+        let selected = app.selected_index();
+        let mut lines: Vec<Line> = Vec::new();
+        let mut selected_range = None;
+        for (i, event) in app.dm_conversation.iter().enumerate() {
+            let start = lines.len();
+            if event.event_type != "MessageCreate" {
+                let style = dim.add_modifier(Modifier::ITALIC);
+                lines.push(Line::styled(event_text(app, event), style).centered());
+            } else {
+                let mine = event.sender_id.as_deref() != Some(self.participant_id);
+                let mut style = Style::default().fg(if mine { Color::Cyan } else { Color::White });
+                if i == selected {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                let text = event.text.as_deref().unwrap_or_default();
+                for row in wrap(text, bubble_width) {
+                    let line = Line::styled(row, style);
+                    lines.push(if mine { line.right_aligned() } else { line });
+                }
+                if let Some(at) = event.created_at {
+                    let line = Line::styled(format_time_ago(at), dim);
+                    lines.push(if mine { line.right_aligned() } else { line });
+                }
+            }
+            if i == selected {
+                selected_range = Some((start, lines.len()));
+            }
+            lines.push(Line::default());
+        }
+        lines.pop();
+
+        // Keep the bottom in view unless the selection is further up.
+        let height = inner.height as usize;
+        let (selected_start, selected_end) = selected_range.unwrap_or((lines.len(), lines.len()));
+        let end = if lines.len() - selected_start <= height {
+            lines.len()
+        } else {
+            selected_end
+        };
+        let start = end.saturating_sub(height);
+        // A short conversation sits at the bottom, as in a chat.
+        let shown = (end - start) as u16;
+        let area = Rect {
+            y: inner.y + inner.height - shown,
+            height: shown,
+            ..inner
+        };
+        Paragraph::new(lines[start..end].to_vec()).render(area, buf);
+    }
+}
+
+/// `@username` if the account is known, otherwise its ID.
+fn user_label(app: &App, user_id: &str) -> String {
+    match app.lookup_user(user_id) {
+        Some(user) => format!("@{}", user.username),
+        None => user_id.to_string(),
+    }
+}
+
+/// A message's text, or what happened for a participant event.
+fn event_text(app: &App, event: &DmEvent) -> String {
+    let verb = match event.event_type.as_str() {
+        "ParticipantsJoin" => "joined",
+        "ParticipantsLeave" => "left",
+        _ => return event.text.clone().unwrap_or_default(),
+    };
+    let who: Vec<String> = event
+        .participant_ids
+        .iter()
+        .flatten()
+        .map(|id| user_label(app, id))
+        .collect();
+    format!("{} {verb}", who.join(", "))
+}
+
+/// Break `text` into rows of at most `width` columns at spaces, keeping
+/// its own line breaks. Words wider than a row are cut short.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    for paragraph in text.lines() {
+        let mut row = String::new();
+        for word in paragraph.split_whitespace() {
+            let word = truncate_for_width(word, width);
+            if !row.is_empty() && row.width() + 1 + word.width() > width {
+                rows.push(std::mem::take(&mut row));
+            }
+            if !row.is_empty() {
+                row.push(' ');
+            }
+            row.push_str(&word);
+        }
+        rows.push(row);
+    }
+    rows
+}
@@ -0,0 +1,175 @@
+use chrono::TimeDelta;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Axis, Chart, Dataset, GraphType, Widget};
+
+use crate::store::MetricsSample;
+use crate::terminal::{TerminalCaps, UnicodeLevel};
+use crate::ui::tweet::format_count;
+
+/// Rows for the legend plus the chart and its time axis.
+const CHART_HEIGHT: u16 = 8;
+
+/// How a tweet's likes, reposts and impressions grew across the fetches
+/// in the local store. Each line is scaled to its own peak so a slow
+/// like count still shows a shape next to thousands of impressions; the
+/// legend gives the actual numbers.
+pub struct MetricsChart<'a> {
+    samples: &'a [MetricsSample],
+    caps: TerminalCaps,
+}
+
+impl<'a> MetricsChart<'a> {
+    /// `None` unless there are at least two samples to draw a trend from.
+    pub fn new(samples: &'a [MetricsSample]) -> Option<Self> {
+        (samples.len() >= 2).then_some(Self {
+            samples,
+            caps: TerminalCaps::default(),
+        })
+    }
+
+    pub fn caps(mut self, caps: TerminalCaps) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    /// Rows the chart needs. ASCII terminals only get the legend.
+    pub fn height(&self) -> u16 {
+        if self.caps.unicode == UnicodeLevel::Ascii {
+            1
+        } else {
+            CHART_HEIGHT
+        }
+    }
+}
+
+/// One charted count: its label, colour and value at each sample.
+struct Series {
+    label: &'static str,
+    color: Color,
+    values: Vec<u64>,
+}
+
+impl Series {
+    /// "likes 120 (+40)": the latest value and the change since the first.
+    fn legend(&self) -> String {
+        let first = self.values.first().copied().unwrap_or_default();
+        let last = self.values.last().copied().unwrap_or_default();
+        let change = if last >= first {
+            format!("+{}", format_count(last - first))
+        } else {
+            format!("-{}", format_count(first - last))
+        };
+        format!("{} {} ({change})", self.label, format_count(last))
+    }
+
+    /// Points with each value as a percentage of the series' peak.
+    fn points(&self, times: &[f64]) -> Vec<(f64, f64)> {
+        let peak = self.values.iter().copied().max().unwrap_or_default().max(1) as f64;
+        times
+            .iter()
+            .zip(&self.values)
+            .map(|(&t, &v)| (t, v as f64 / peak * 100.0))
+            .collect()
+    }
+}
+
+/// "45m", "6h", "2d 6h": how long the samples span.
+fn format_span(span: TimeDelta) -> String {
+    let (days, hours) = (span.num_days(), span.num_hours() % 24);
+    if days > 0 && hours > 0 {
+        format!("{days}d {hours}h")
+    } else if days > 0 {
+        format!("{days}d")
+    } else if hours > 0 {
+        format!("{hours}h")
+    } else {
+        format!("{}m", span.num_minutes())
+    }
+}
+
+impl Widget for MetricsChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 2 || area.height == 0 {
+            return;
+        }
+        let samples = self.samples;
+        let mut series = vec![
+            Series {
+                label: "likes",
+                color: Color::Red,
+                values: samples.iter().map(|s| s.likes).collect(),
+            },
+            Series {
+                label: "reposts",
+                color: Color::Green,
+                values: samples.iter().map(|s| s.retweets).collect(),
+            },
+        ];
+        // Impressions are only reported for your own tweets.
+        if samples.iter().all(|s| s.impressions.is_some()) {
+            series.push(Series {
+                label: "views",
+                color: Color::Cyan,
+                values: samples.iter().filter_map(|s| s.impressions).collect(),
+            });
+        }
+
+        let start = samples[0].at;
+        let span = samples[samples.len() - 1].at - start;
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut legend = vec![Span::styled(
+            format!("{} fetches over {}: ", samples.len(), format_span(span)),
+            dim,
+        )];
+        for (i, s) in series.iter().enumerate() {
+            if i > 0 {
+                legend.push(Span::styled(" \u{00B7} ", dim));
+            }
+            legend.push(Span::styled(s.legend(), Style::default().fg(s.color)));
+        }
+        buf.set_line(area.x, area.y, &Line::from(legend), area.width);
+        if area.height < 3 || self.caps.unicode == UnicodeLevel::Ascii {
+            return;
+        }
+
+        let times: Vec<f64> = samples
+            .iter()
+            .map(|s| (s.at - start).num_seconds() as f64)
+            .collect();
+        let points: Vec<Vec<(f64, f64)>> = series.iter().map(|s| s.points(&times)).collect();
+        let marker = if self.caps.unicode == UnicodeLevel::Full {
+            Marker::Braille
+        } else {
+            Marker::HalfBlock
+        };
+        let datasets = series
+            .iter()
+            .zip(&points)
+            .map(|(s, points)| {
+                Dataset::default()
+                    .marker(marker)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(s.color))
+                    .data(points)
+            })
+            .collect();
+
+        let chart = Chart::new(datasets)
+            .x_axis(
+                Axis::default()
+                    .style(dim)
+                    .bounds([0.0, (span.num_seconds() as f64).max(1.0)])
+                    .labels([
+                        Span::styled("first", dim),
+                        Span::styled(format!("+{}", format_span(span)), dim),
+                    ]),
+            )
+            .y_axis(Axis::default().style(dim).bounds([0.0, 100.0]));
+        let chart_area = Rect::new(area.x, area.y + 1, area.width, area.height - 1);
+        chart.render(chart_area, buf);
+    }
+}
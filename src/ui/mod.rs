@@ -1,49 +1,104 @@
+pub mod account_state;
+pub mod author_preview;
 pub mod bookmarks;
 pub mod cluster;
 pub mod command_bar;
+pub mod compose;
+pub mod confirm;
+pub mod doctor;
+pub mod domains;
+pub mod drafts;
 pub mod error_popup;
+pub mod filtered_stream;
+pub mod halfblock;
+pub mod heatmap;
 pub mod help;
 pub mod hf_models;
+pub mod history;
 pub mod input;
+pub mod linear;
+pub mod list_form;
+pub mod lists;
+pub mod messages;
+pub mod metrics;
 pub mod models;
+pub mod offline_banner;
+pub mod perf;
 pub mod search;
 pub mod skeleton;
+pub mod splash;
 pub mod status_bar;
+pub mod stream_rules;
+pub mod tags;
 pub mod text;
+pub mod theme;
 pub mod thread;
 pub mod timeline;
+pub mod toasts;
 pub mod tweet;
+pub mod usage;
 pub mod user;
+pub mod users;
 
-use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout};
+#[cfg(test)]
+mod snapshot_tests;
+
+use std::time::Instant;
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::{Frame, Terminal};
+use unicode_width::UnicodeWidthStr;
 
 use crate::app::{App, AppMode};
 use crate::event::ViewKind;
 
+use account_state::AccountStateView;
+use author_preview::AuthorPreviewView;
 use bookmarks::BookmarksView;
 use cluster::ClusterView;
 use command_bar::CommandBar;
+use compose::ComposePopup;
+use confirm::ConfirmPopup;
+use doctor::DoctorPopup;
+use domains::DomainsView;
+use drafts::DraftsView;
 use error_popup::ErrorPopup;
+use filtered_stream::FilteredStreamView;
+use heatmap::{HEATMAP_HEIGHT, PostingHeatmapView};
 use help::HelpView;
+use history::HistoryView;
+use list_form::ListFormPopup;
+use lists::ListsView;
+use messages::{DmConversationView, MessagesView};
 use models::ModelsView;
+use offline_banner::OfflineBanner;
+use perf::PerfOverlay;
 use search::SearchView;
+use splash::SplashView;
 use status_bar::StatusBar;
+use stream_rules::StreamRulesView;
+use tags::TagsView;
 use thread::ThreadView;
 use timeline::TimelineView;
+use toasts::ToastStack;
+use usage::UsagePopup;
 use user::UserProfileView;
+use users::UserListView;
 
 pub fn draw(frame: &mut Frame, app: &App) {
     let area = frame.area();
 
     // Layout: main content + status bar + optional command bar
-    let bottom_height = if app.mode != AppMode::Normal { 2 } else { 1 };
+    let has_input = matches!(app.mode, AppMode::Command | AppMode::Search);
+    let bottom_height = if has_input { 2 } else { 1 };
 
     let [main_area, bottom_area] =
         Layout::vertical([Constraint::Min(1), Constraint::Length(bottom_height)]).areas(area);
 
     // Split bottom into status bar and optional command bar
-    if app.mode != AppMode::Normal {
+    if has_input {
         let [status_area, cmd_area] =
             Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(bottom_area);
         frame.render_widget(StatusBar::new(app), status_area);
@@ -52,80 +107,324 @@ pub fn draw(frame: &mut Frame, app: &App) {
         frame.render_widget(StatusBar::new(app), bottom_area);
     }
 
+    // Degraded mode: reserve a banner row explaining the missing credentials.
+    let main_area = if app.api_client.is_none() {
+        let [banner_area, rest] =
+            Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(main_area);
+        frame.render_widget(OfflineBanner::new(app), banner_area);
+        rest
+    } else {
+        main_area
+    };
+
     // Render the current view
+    let view_started = Instant::now();
+    draw_view(frame, app, main_area);
+
+    app.perf
+        .record_layout(view_label(app.current_view()), view_started.elapsed());
+
+    if app.perf_overlay {
+        frame.render_widget(PerfOverlay::new(&app.perf), main_area);
+    }
+
+    // Accessible mode puts the latest toast in the status line instead.
+    if !app.config.accessible && !app.toasts.is_empty() {
+        frame.render_widget(ToastStack::new(&app.toasts), main_area);
+    }
+
+    if app.mode == AppMode::Compose {
+        frame.render_widget(
+            ComposePopup::new(&app.compose_input)
+                .reply_to(app.compose_reply_to.as_ref())
+                .drafts(app.reply_drafts.as_ref())
+                .thread(app.compose_thread)
+                .reply_settings(app.compose_reply_settings),
+            frame.area(),
+        );
+    }
+
+    if app.mode == AppMode::ListForm
+        && let Some(ref form) = app.list_form
+    {
+        frame.render_widget(ListFormPopup::new(form), frame.area());
+    }
+
+    if let Some(ref dialog) = app.confirm {
+        frame.render_widget(ConfirmPopup::new(dialog), frame.area());
+    }
+
+    if let Some(ref checks) = app.doctor_report {
+        frame.render_widget(DoctorPopup::new(checks), frame.area());
+    }
+
+    if let Some(ref report) = app.usage_report {
+        frame.render_widget(
+            UsagePopup::new(report).accessible(app.config.accessible),
+            frame.area(),
+        );
+    }
+
+    // Error detail popup overlay (renders on top of everything)
+    if let Some(ref detail) = app.error_detail {
+        frame.render_widget(
+            ErrorPopup::new(detail).retryable(app.retry_request.is_some()),
+            frame.area(),
+        );
+    }
+
+    app.palette.apply(frame.buffer_mut());
+}
+
+/// Render the current view (without status bar, banners or overlays) into `area`.
+pub fn draw_view(frame: &mut Frame, app: &App, area: Rect) {
+    // The splash stands in for an empty timeline; cached tweets restored
+    // before the first fetch answers are shown instead.
+    let splash_timeline = match app.current_view() {
+        Some(ViewKind::Home) => Some(&app.home_timeline),
+        Some(ViewKind::Mentions) => Some(&app.mentions),
+        Some(ViewKind::Bookmarks) => Some(&app.bookmarks),
+        _ => None,
+    };
+    if let Some(splash) = &app.splash
+        && splash_timeline.is_some_and(|tl| tl.tweets.is_empty())
+    {
+        frame.render_widget(SplashView::new(app, splash), area);
+        return;
+    }
+    if let Some(account) = &app.unavailable_account
+        && app.current_view() == Some(&account.view)
+    {
+        let title = match &account.view {
+            ViewKind::UserTimeline(user_id) => format!("Timeline: {user_id}"),
+            ViewKind::UserProfile(name) => format!("@{name}"),
+            _ => String::new(),
+        };
+        frame.render_widget(AccountStateView::new(&title, account), area);
+        return;
+    }
     match app.current_view() {
         Some(ViewKind::Home) => {
             frame.render_widget(
                 TimelineView::new("Following", &app.home_timeline.tweets, app)
-                    .loading(app.home_timeline.loading),
-                main_area,
+                    .loading(app.home_timeline.loading)
+                    .partial_errors(&app.home_timeline.partial_errors),
+                area,
             );
         }
         Some(ViewKind::Mentions) => {
             frame.render_widget(
                 TimelineView::new("Mentions", &app.mentions.tweets, app)
-                    .loading(app.mentions.loading),
-                main_area,
+                    .loading(app.mentions.loading)
+                    .partial_errors(&app.mentions.partial_errors),
+                area,
             );
         }
         Some(ViewKind::Bookmarks) => {
-            frame.render_widget(BookmarksView::new(app), main_area);
+            frame.render_widget(BookmarksView::new(app), area);
         }
         Some(ViewKind::Search) => {
-            frame.render_widget(SearchView::new(app), main_area);
+            frame.render_widget(SearchView::new(app), area);
         }
         Some(ViewKind::UserTimeline(user_id)) => {
             let title = format!("Timeline: {user_id}");
             frame.render_widget(
                 TimelineView::new(&title, &app.viewed_user_timeline.tweets, app)
-                    .loading(app.viewed_user_timeline.loading),
-                main_area,
+                    .loading(app.viewed_user_timeline.loading)
+                    .partial_errors(&app.viewed_user_timeline.partial_errors),
+                area,
             );
         }
         Some(ViewKind::Thread(conv_id)) => {
-            let _ = conv_id; // conv_id is part of the ViewKind, thread data is in app state
+            let preview = app
+                .author_preview
+                .as_ref()
+                .filter(|p| &p.conversation_id == conv_id);
+            let area = match preview {
+                Some(preview) => {
+                    // Side by side when there's room, otherwise stacked.
+                    let [thread_area, preview_area] = if area.width >= 100 {
+                        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                            .areas(area)
+                    } else {
+                        Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)])
+                            .areas(area)
+                    };
+                    frame.render_widget(AuthorPreviewView::new(app, preview), preview_area);
+                    thread_area
+                }
+                None => area,
+            };
             frame.render_widget(
                 ThreadView::new(app.thread_root.as_ref(), &app.thread_tweets, app),
-                main_area,
+                area,
             );
         }
         Some(ViewKind::UserProfile(_)) => {
             if let Some(ref user) = app.viewed_user {
-                frame.render_widget(UserProfileView::new(user), main_area);
+                let banner = app.viewed_user_banner.as_ref().map(|(_, image)| image);
+                let heatmap = app
+                    .posting_heatmap
+                    .as_ref()
+                    .filter(|h| h.user_id == user.id);
+                let area = match heatmap {
+                    Some(heatmap) => {
+                        let [profile_area, heatmap_area] = Layout::vertical([
+                            Constraint::Min(0),
+                            Constraint::Length(HEATMAP_HEIGHT),
+                        ])
+                        .areas(area);
+                        frame.render_widget(
+                            PostingHeatmapView::new(heatmap).caps(app.term_caps),
+                            heatmap_area,
+                        );
+                        profile_area
+                    }
+                    None => area,
+                };
+                frame.render_widget(
+                    UserProfileView::new(user)
+                        .banner(banner)
+                        .caps(app.term_caps)
+                        .muted(app.muted_users.contains(&user.id))
+                        .blocked(app.blocked_users.contains(&user.id)),
+                    area,
+                );
             } else {
                 frame.render_widget(
                     TimelineView::new("User Profile", &[], app).loading(true),
-                    main_area,
+                    area,
                 );
             }
         }
         Some(ViewKind::OpenRouterModels) | Some(ViewKind::TextModels) => {
-            frame.render_widget(ModelsView::new(app), main_area);
+            frame.render_widget(ModelsView::new(app), area);
         }
         Some(ViewKind::HuggingFaceModels) => {
-            frame.render_widget(hf_models::HfModelsView::new(app), main_area);
+            frame.render_widget(hf_models::HfModelsView::new(app), area);
         }
         Some(ViewKind::Cluster) => {
-            frame.render_widget(ClusterView::new(app), main_area);
+            frame.render_widget(ClusterView::new(app), area);
+        }
+        Some(ViewKind::History) => {
+            frame.render_widget(HistoryView::new(app), area);
+        }
+        Some(ViewKind::Drafts) => {
+            frame.render_widget(DraftsView::new(app), area);
+        }
+        Some(ViewKind::Domains) => {
+            frame.render_widget(DomainsView::new(app), area);
+        }
+        Some(ViewKind::Tags) => {
+            frame.render_widget(TagsView::new(app), area);
+        }
+        Some(ViewKind::Lists) => {
+            frame.render_widget(ListsView::new(app), area);
+        }
+        Some(ViewKind::ListMembers(_)) => {
+            let name = app.members_list().map_or("List", |l| l.name.as_str());
+            let title = format!("{name} · {} members", app.list_members.len());
+            frame.render_widget(
+                UserListView::new(title, &app.list_members, app.selected_index())
+                    .loading(app.list_members_loading)
+                    .unicode(app.term_caps.unicode),
+                area,
+            );
+        }
+        Some(ViewKind::TweetUsers { kind, .. }) => {
+            let title = format!("{} · {} accounts", kind.title(), app.tweet_users.len());
+            frame.render_widget(
+                UserListView::new(title, &app.tweet_users, app.selected_index())
+                    .loading(app.tweet_users_loading)
+                    .unicode(app.term_caps.unicode),
+                area,
+            );
+        }
+        Some(ViewKind::StreamRules) => {
+            frame.render_widget(StreamRulesView::new(app), area);
+        }
+        Some(ViewKind::FilteredStream) => {
+            frame.render_widget(FilteredStreamView::new(app), area);
+        }
+        Some(ViewKind::Messages) => {
+            frame.render_widget(MessagesView::new(app), area);
+        }
+        Some(ViewKind::DmConversation(participant_id)) => {
+            frame.render_widget(DmConversationView::new(app, participant_id), area);
         }
         Some(ViewKind::Help) => {
             // Render the view underneath first, then overlay help.
-            render_previous_view(frame, app, main_area);
-            frame.render_widget(HelpView::new(), main_area);
+            render_previous_view(frame, app, area);
+            frame.render_widget(HelpView::new(app), area);
         }
         None => {
-            frame.render_widget(TimelineView::new("xplorertui", &[], app), main_area);
+            frame.render_widget(TimelineView::new("xplorertui", &[], app), area);
         }
     }
+}
 
-    // Error detail popup overlay (renders on top of everything)
-    if let Some(ref detail) = app.error_detail {
-        frame.render_widget(ErrorPopup::new(detail), frame.area());
+/// Render the current view into an off-screen buffer and return it as plain
+/// text, one line per row with trailing spaces trimmed.
+pub fn render_to_text(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .expect("test backend never fails to initialize");
+    terminal
+        .draw(|frame| draw_view(frame, app, frame.area()))
+        .expect("test backend never fails to draw");
+    buffer_to_text(terminal.backend().buffer())
+}
+
+/// Cell symbols row by row, skipping the cells hidden under wide characters.
+fn buffer_to_text(buf: &Buffer) -> String {
+    let mut text = String::new();
+    for y in 0..buf.area.height {
+        let mut line = String::new();
+        let mut skip = 0;
+        for x in 0..buf.area.width {
+            let symbol = buf[(x, y)].symbol();
+            if skip == 0 {
+                line.push_str(symbol);
+            }
+            skip = symbol.width().max(skip).saturating_sub(1);
+        }
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Short static name for a view, used to key per-view render cost and to
+/// name exports.
+pub(crate) fn view_label(view: Option<&ViewKind>) -> &'static str {
+    match view {
+        Some(ViewKind::Home) => "home",
+        Some(ViewKind::UserTimeline(_)) => "timeline",
+        Some(ViewKind::Thread(_)) => "thread",
+        Some(ViewKind::UserProfile(_)) => "profile",
+        Some(ViewKind::Search) => "search",
+        Some(ViewKind::Mentions) => "mentions",
+        Some(ViewKind::Bookmarks) => "bookmarks",
+        Some(ViewKind::OpenRouterModels | ViewKind::TextModels) => "models",
+        Some(ViewKind::Cluster) => "cluster",
+        Some(ViewKind::HuggingFaceModels) => "hf-models",
+        Some(ViewKind::History) => "history",
+        Some(ViewKind::Drafts) => "drafts",
+        Some(ViewKind::Domains) => "domains",
+        Some(ViewKind::Tags) => "tags",
+        Some(ViewKind::Lists) => "lists",
+        Some(ViewKind::ListMembers(_)) => "list-members",
+        Some(ViewKind::TweetUsers { kind, .. }) => kind.slug(),
+        Some(ViewKind::StreamRules) => "stream-rules",
+        Some(ViewKind::FilteredStream) => "stream",
+        Some(ViewKind::Messages) => "messages",
+        Some(ViewKind::DmConversation(_)) => "dm",
+        Some(ViewKind::Help) => "help",
+        None => "none",
     }
 }
 
 /// Render the view underneath the current one (for overlay views like Help).
-fn render_previous_view(frame: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+fn render_previous_view(frame: &mut Frame, app: &App, area: Rect) {
     if app.view_stack.len() < 2 {
         return;
     }
@@ -159,11 +458,15 @@ mod tests {
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
 
+    use unicode_width::UnicodeWidthStr;
+
     use super::draw;
-    use crate::api::types::Tweet;
+    use crate::api::types::{ApiError, Tweet, User};
     use crate::app::App;
     use crate::auth::credentials::CredentialSet;
-    use crate::config::AppConfig;
+    use crate::config::{AppConfig, StatusSegment};
+    use crate::event::ViewKind;
+    use crate::ui::halfblock::RgbImage;
 
     fn make_tweet(id: &str) -> Tweet {
         Tweet {
@@ -180,6 +483,10 @@ mod tests {
             referenced_tweets: None,
             attachments: None,
             note_tweet: None,
+            source: None,
+            reply_settings: None,
+            withheld: None,
+            context_annotations: None,
         }
     }
 
@@ -206,10 +513,11 @@ mod tests {
         let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
         app.home_timeline.tweets = vec![make_tweet("0"), make_tweet("1"), make_tweet("2")];
 
-        // Height 11 -> main area 10 -> timeline inner height 8 after borders.
+        // Height 12 -> offline banner 1 + main area 10 -> timeline inner
+        // height 8 after borders.
         // With 1-line tweet text, each card+separator is height 4, so items 0+1
         // exactly fill the viewport and selecting item 2 exercises the edge case.
-        let backend = TestBackend::new(80, 11);
+        let backend = TestBackend::new(80, 12);
         let mut terminal = Terminal::new(backend).expect("terminal should initialize");
 
         for selected in 0..app.home_timeline.tweets.len() {
@@ -229,4 +537,189 @@ mod tests {
             "expected bottom-selected tweet text in rendered buffer, got:\n{lines}"
         );
     }
+
+    #[tokio::test]
+    async fn render_to_text_omits_chrome_and_wide_char_padding() {
+        let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
+        let mut tweet = make_tweet("0");
+        tweet.text = "wide 日本 text".into();
+        app.home_timeline.tweets = vec![tweet];
+
+        let text = super::render_to_text(&app, 80, 6);
+        assert_eq!(text.lines().count(), 6);
+        let line = text.lines().nth(2).unwrap();
+        assert!(line.starts_with("\u{2502} wide 日本 text "), "got:\n{text}");
+        assert_eq!(line.width(), 80, "wide chars must not add padding cells");
+        assert!(!text.contains("OFFLINE"), "got:\n{text}");
+        assert!(text.lines().all(|l| !l.ends_with(' ')));
+    }
+
+    #[tokio::test]
+    async fn draw_thread_scrolls_to_selected_reply() {
+        let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
+        app.thread_root = Some(make_tweet("root"));
+        app.thread_tweets = (0..10).map(|i| make_tweet(&i.to_string())).collect();
+        app.push_view(ViewKind::Thread("root".into()));
+        app.view_stack
+            .last_mut()
+            .expect("thread view was pushed")
+            .selected_index = 9;
+
+        let backend = TestBackend::new(80, 16);
+        let mut terminal = Terminal::new(backend).expect("terminal should initialize");
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+
+        let lines = buffer_lines(&terminal).join("\n");
+        assert!(
+            lines.contains("tweet 9"),
+            "expected last reply to be scrolled into view, got:\n{lines}"
+        );
+        assert!(
+            !lines.contains("tweet 0"),
+            "expected first reply to be scrolled out of view, got:\n{lines}"
+        );
+    }
+
+    #[tokio::test]
+    async fn draw_loading_timeline_renders_card_skeletons() {
+        let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
+        app.home_timeline.loading = true;
+
+        let backend = TestBackend::new(80, 16);
+        let mut terminal = Terminal::new(backend).expect("terminal should initialize");
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+
+        let lines = buffer_lines(&terminal).join("\n");
+        assert!(!lines.contains("Loading..."), "got:\n{lines}");
+        assert!(
+            lines.contains("\u{2500}\u{2500}\u{2500}"),
+            "expected skeleton card separators, got:\n{lines}"
+        );
+    }
+
+    #[tokio::test]
+    async fn draw_timeline_shows_collapsible_partial_error_notice() {
+        let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
+        app.home_timeline.tweets = vec![make_tweet("0")];
+        app.home_timeline.partial_errors = vec![ApiError {
+            title: Some("Not Found Error".into()),
+            detail: Some("Could not find tweet with id: [42].".into()),
+            type_: None,
+            status: None,
+            resource_type: Some("tweet".into()),
+            resource_id: Some("42".into()),
+            parameter: None,
+        }];
+
+        let backend = TestBackend::new(80, 16);
+        let mut terminal = Terminal::new(backend).expect("terminal should initialize");
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+        let collapsed = buffer_lines(&terminal).join("\n");
+        assert!(collapsed.contains("1 item could not be loaded"));
+        assert!(!collapsed.contains("Could not find tweet"));
+
+        app.partial_errors_expanded = true;
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+        let expanded = buffer_lines(&terminal).join("\n");
+        assert!(
+            expanded.contains("Not Found Error: Could not find tweet with id: [42]."),
+            "got:\n{expanded}"
+        );
+        assert!(expanded.contains("tweet 0"));
+    }
+
+    #[tokio::test]
+    async fn draw_profile_renders_banner_strip() {
+        let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
+        let banner_url = "https://pbs.twimg.com/profile_banners/1/1".to_string();
+        app.viewed_user = Some(User {
+            id: "1".into(),
+            username: "alice".into(),
+            name: "Alice".into(),
+            description: None,
+            created_at: None,
+            verified: None,
+            verified_type: None,
+            protected: None,
+            profile_image_url: None,
+            profile_banner_url: Some(banner_url.clone()),
+            url: None,
+            location: None,
+            pinned_tweet_id: None,
+            public_metrics: None,
+            withheld: None,
+        });
+        app.viewed_user_banner = Some((
+            banner_url,
+            RgbImage {
+                width: 3,
+                height: 1,
+                pixels: vec![[200, 30, 30]; 3],
+            },
+        ));
+        app.push_view(ViewKind::UserProfile("alice".into()));
+
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).expect("terminal should initialize");
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+
+        let lines = buffer_lines(&terminal);
+        // Row 0 is the offline banner, row 1 the profile border.
+        assert!(
+            lines[2].contains("\u{2580}\u{2580}\u{2580}"),
+            "got:\n{lines:?}"
+        );
+        assert!(lines.iter().any(|l| l.contains("Alice")));
+    }
+
+    #[tokio::test]
+    async fn draw_accessible_timeline_is_linear_text() {
+        let config = AppConfig {
+            accessible: true,
+            ..AppConfig::default()
+        };
+        let mut app = App::new(config, None, CredentialSet::default());
+        app.home_timeline.tweets = vec![make_tweet("0"), make_tweet("1")];
+
+        let backend = TestBackend::new(60, 12);
+        let mut terminal = Terminal::new(backend).expect("terminal should initialize");
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+
+        let lines = buffer_lines(&terminal).join("\n");
+        assert!(lines.contains("Following, tweet 1 of 2"), "got:\n{lines}");
+        assert!(lines.contains("> @unknown:"), "got:\n{lines}");
+        assert!(!lines.contains('\u{2500}') && !lines.contains('\u{2502}'));
+    }
+
+    #[tokio::test]
+    async fn status_bar_right_aligns_configured_segments() {
+        let mut config = AppConfig::default();
+        config.status_bar.left = vec![StatusSegment::View].into();
+        config.status_bar.right = vec![StatusSegment::Unread].into();
+        let mut app = App::new(config, None, CredentialSet::default());
+        app.home_timeline.tweets = vec![make_tweet("9"), make_tweet("10"), make_tweet("11")];
+        app.home_timeline.seen_id = Some("9".into());
+
+        let backend = TestBackend::new(40, 6);
+        let mut terminal = Terminal::new(backend).expect("terminal should initialize");
+        terminal
+            .draw(|frame| draw(frame, &app))
+            .expect("draw should complete");
+
+        let lines = buffer_lines(&terminal);
+        let status = lines.last().unwrap();
+        assert_eq!(status, &format!("Following{:>31}", "Following 2 "));
+    }
 }
@@ -0,0 +1,57 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+use crate::app::App;
+use crate::event::ViewKind;
+use crate::ui::text::truncate_for_width;
+
+/// One-line banner shown above the main view when there is no API client,
+/// explaining why and how to fix it.
+pub struct OfflineBanner<'a> {
+    pub app: &'a App,
+}
+
+impl<'a> OfflineBanner<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for OfflineBanner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+
+        let bg_style = Style::default().bg(Color::Yellow).fg(Color::Black);
+        for x in area.x..area.x + area.width {
+            buf[(x, area.y)].set_style(bg_style);
+        }
+
+        let cached_at = match self.app.current_view() {
+            Some(ViewKind::Home) => self.app.home_timeline.cached_at,
+            Some(ViewKind::Mentions) => self.app.mentions.cached_at,
+            Some(ViewKind::Bookmarks) => self.app.bookmarks.cached_at,
+            _ => None,
+        };
+        let detail = match cached_at {
+            Some(at) => format!(
+                "No X credentials. Press A to run setup. Showing cached data from {}",
+                at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
+            ),
+            None => "No X credentials. Press A to run setup (or :auth).".to_string(),
+        };
+
+        let label = " OFFLINE ";
+        let available = (area.width as usize).saturating_sub(label.len() + 1);
+        let line = Line::from(vec![
+            Span::styled(label, bg_style.add_modifier(Modifier::BOLD)),
+            Span::styled(" ", bg_style),
+            Span::styled(truncate_for_width(&detail, available), bg_style),
+        ]);
+        buf.set_line(area.x, area.y, &line, area.width);
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+
+use crate::perf::PerfStats;
+
+/// Small top-right overlay with frame time, last request latency, and
+/// per-view render cost (toggled with `:perf`).
+pub struct PerfOverlay<'a> {
+    perf: &'a PerfStats,
+}
+
+impl<'a> PerfOverlay<'a> {
+    pub fn new(perf: &'a PerfStats) -> Self {
+        Self { perf }
+    }
+}
+
+fn fmt_ms(d: Duration) -> String {
+    format!("{:.2}ms", d.as_secs_f64() * 1000.0)
+}
+
+impl Widget for PerfOverlay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let label_style = Style::default().fg(Color::DarkGray);
+        let value_style = Style::default().fg(Color::White);
+        let row = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{label:<10}"), label_style),
+                Span::styled(value, value_style),
+            ])
+        };
+
+        let mut lines = vec![
+            row("frame", fmt_ms(self.perf.last_frame())),
+            row("avg", fmt_ms(self.perf.avg_frame())),
+        ];
+        match self.perf.last_request() {
+            Some(req) => {
+                lines.push(row("request", fmt_ms(req.latency)));
+                lines.push(row("", req.label));
+            }
+            None => lines.push(row("request", "-".into())),
+        }
+        for (view, cost) in self.perf.layout() {
+            lines.push(row(view, fmt_ms(cost)));
+        }
+
+        let width = 36u16.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let panel = Rect::new(area.x + area.width - width, area.y, width, height);
+
+        Clear.render(panel, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Perf ")
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::DarkGray));
+        Paragraph::new(lines).block(block).render(panel, buf);
+    }
+}
@@ -18,14 +18,20 @@ impl<'a> SearchView<'a> {
 
 impl Widget for SearchView<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let title = if self.app.search_query.is_empty() {
+        let mut title = if self.app.search_query.is_empty() {
             "Search (press / to search)".to_string()
+        } else if self.app.search_local {
+            format!("Local search: {} · offline results", self.app.search_query)
         } else {
             format!("Search: {}", self.app.search_query)
         };
+        if self.app.search_by_engagement && !self.app.search_query.is_empty() {
+            title.push_str(" · by engagement");
+        }
 
         TimelineView::new(&title, &self.app.search_results.tweets, self.app)
             .loading(self.app.search_results.loading)
+            .partial_errors(&self.app.search_results.partial_errors)
             .render(area, buf);
     }
 }
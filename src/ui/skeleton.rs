@@ -12,34 +12,73 @@ use tui_skeleton::{AnimationMode, SkeletonBlock, SkeletonList};
 const SKELETON_BASE: tui_skeleton::Color = tui_skeleton::Color::DarkGray;
 const SKELETON_HIGHLIGHT: tui_skeleton::Color = tui_skeleton::Color::Gray;
 
-/// Render a skeleton list resembling tweet cards in the given area.
-///
-/// Used by `TimelineView` when loading and the debounce threshold has passed.
-pub fn render_timeline_skeleton(elapsed_ms: u64, title: &str, area: Rect, buf: &mut Buffer) {
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .title(format!(" {} ", title))
-        .title_style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )
-        .border_style(Style::default().fg(Color::DarkGray));
+/// Height of one placeholder card: header, two text rows, metrics, separator.
+const CARD_SKELETON_HEIGHT: u16 = 5;
 
-    let inner = block.inner(area);
-    block.render(area, buf);
+/// Relative widths (percent) of the second text row, cycled per card so the
+/// placeholders have the ragged edge of real tweet text.
+const TEXT_ROW_WIDTHS: [u16; 4] = [65, 85, 45, 75];
 
-    if inner.width < 4 || inner.height < 2 {
+/// Render placeholder tweet cards (author bar, text bars, metrics bar) into
+/// the inner area of a timeline, mirroring the `TweetCard` layout so nothing
+/// jumps when the first page arrives.
+///
+/// `elapsed_ms` is `None` inside the loading debounce window, in which case
+/// the bars are drawn as a static frame instead of animating.
+pub fn render_tweet_skeletons(elapsed_ms: Option<u64>, inner: Rect, buf: &mut Buffer) {
+    if inner.width < 4 || inner.height == 0 {
         return;
     }
 
-    let skeleton = SkeletonList::new(elapsed_ms)
-        .mode(AnimationMode::Noise)
-        .base(SKELETON_BASE)
-        .highlight(SKELETON_HIGHLIGHT)
-        .items((inner.height / 3).max(1));
-
-    skeleton.render(inner, buf);
+    let x = inner.x + 1; // same 1-col left margin as TweetCard
+    let width = inner.width.saturating_sub(1);
+    let bottom = inner.y + inner.height;
+    let bar = |area: Rect, buf: &mut Buffer| {
+        if area.y < bottom && area.width > 0 {
+            SkeletonBlock::new(elapsed_ms.unwrap_or(0))
+                .mode(if elapsed_ms.is_some() {
+                    AnimationMode::Breathe
+                } else {
+                    AnimationMode::Noise
+                })
+                .base(SKELETON_BASE)
+                .highlight(SKELETON_HIGHLIGHT)
+                .render(area, buf);
+        }
+    };
+
+    let mut y = inner.y;
+    let mut card = 0usize;
+    while y < bottom {
+        // Header: @username bar + display name bar.
+        bar(Rect::new(x, y, width.min(14), 1), buf);
+        if width > 26 {
+            bar(Rect::new(x + 15, y, 10, 1), buf);
+        }
+        // Text rows.
+        bar(Rect::new(x, y + 1, width, 1), buf);
+        let second = width * TEXT_ROW_WIDTHS[card % TEXT_ROW_WIDTHS.len()] / 100;
+        bar(Rect::new(x, y + 2, second.max(1), 1), buf);
+        // Metrics: three short counters.
+        for i in 0..3u16 {
+            let mx = x + i * 7;
+            if mx + 5 <= x + width {
+                bar(Rect::new(mx, y + 3, 5, 1), buf);
+            }
+        }
+        // Separator, matching the real timeline.
+        if y + 4 < bottom {
+            buf.set_string(
+                x,
+                y + 4,
+                "\u{2500}".repeat(width as usize),
+                Style::default().fg(Color::DarkGray),
+            );
+        }
+
+        y = y.saturating_add(CARD_SKELETON_HEIGHT);
+        card += 1;
+    }
 }
 
 /// Render a skeleton list for model loading views (OpenRouter, HuggingFace).
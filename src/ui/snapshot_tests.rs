@@ -0,0 +1,794 @@
+//! Snapshot tests for every view, rendered through [`draw`] into a
+//! `TestBackend` and compared against `snapshots/*.snap` with insta.
+//!
+//! Fixture app states are fully deterministic: no timestamps (cards would
+//! show relative ages), fixed terminal capabilities, and no credentials, so
+//! the offline banner is always present. After an intentional rendering
+//! change, review and accept the new output with `cargo insta review` (or
+//! rerun with `INSTA_UPDATE=always`).
+
+use crossterm::event::{KeyCode, KeyEvent};
+use insta::assert_snapshot;
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+
+use super::draw;
+use crate::api::types::{
+    Attachments, Includes, Media, NoteTweet, PublicMetrics, ReferencedTweet, ReplySettings,
+    StreamRule, Tweet, User, Withheld,
+};
+use crate::app::{App, AppMode, ListForm, ListFormField, ReplyDrafts, ReplyTarget};
+use crate::auth::credentials::CredentialSet;
+use crate::config::AppConfig;
+use crate::domains::{DomainCount, DomainReport};
+use crate::embeddings::cluster::ClusterResult;
+use crate::event::{StreamStatus, TweetUsersKind, ViewKind};
+use crate::huggingface::types::HfModel;
+use crate::openrouter::types::Model;
+use crate::store::Draft;
+use crate::tags::{TagCount, TagReport};
+use crate::terminal::{TerminalCaps, UnicodeLevel};
+use crate::ui::theme::Palette;
+
+const LONG_NOTE: &str = "Long-form posts arrive as note_tweet, with the short text \
+    truncated. This one goes on for a while so the card has to wrap it across many \
+    lines and the timeline has to budget the height correctly.\n\nIt also has a second \
+    paragraph after a blank line, which should survive wrapping, and a final sentence \
+    that is long enough to need its own wrapped line at eighty columns.";
+
+const EMOJI_TEXT: &str = "Shipping day 🚀🦀🎉 family 👨\u{200d}👩\u{200d}👧 skin 👍🏽 \
+    flags 🇯🇵🇧🇷 wide 日本語のテキスト and back to ASCII";
+
+fn user(id: &str, username: &str, name: &str) -> User {
+    User {
+        id: id.into(),
+        username: username.into(),
+        name: name.into(),
+        description: None,
+        created_at: None,
+        verified: None,
+        verified_type: None,
+        protected: None,
+        profile_image_url: None,
+        profile_banner_url: None,
+        url: None,
+        location: None,
+        pinned_tweet_id: None,
+        public_metrics: None,
+        withheld: None,
+    }
+}
+
+fn tweet(id: &str, author_id: &str, text: &str) -> Tweet {
+    Tweet {
+        id: id.into(),
+        text: text.into(),
+        author_id: Some(author_id.into()),
+        created_at: None,
+        conversation_id: Some("100".into()),
+        in_reply_to_user_id: None,
+        lang: None,
+        edit_history_tweet_ids: None,
+        public_metrics: Some(PublicMetrics {
+            like_count: 1_234,
+            retweet_count: 56,
+            reply_count: 7,
+            quote_count: 0,
+            bookmark_count: None,
+            impression_count: None,
+        }),
+        entities: None,
+        referenced_tweets: None,
+        attachments: None,
+        note_tweet: None,
+        source: None,
+        reply_settings: None,
+        withheld: None,
+        context_annotations: None,
+    }
+}
+
+fn timeline_tweets() -> Vec<Tweet> {
+    let mut reply = tweet("102", "2", "@alice agreed, ship it");
+    reply.referenced_tweets = Some(vec![ReferencedTweet {
+        type_: "replied_to".into(),
+        id: "101".into(),
+    }]);
+    let mut long = tweet(
+        "103",
+        "1",
+        "Long-form posts arrive as note_tweet, with the…",
+    );
+    long.note_tweet = Some(NoteTweet {
+        text: LONG_NOTE.into(),
+        entities: None,
+    });
+    let mut with_media = tweet("101", "1", "Plain tweet with a few words");
+    with_media.attachments = Some(Attachments {
+        media_keys: Some(vec!["3_1".into(), "3_2".into()]),
+        poll_ids: None,
+    });
+    long.referenced_tweets = Some(vec![ReferencedTweet {
+        type_: "quoted".into(),
+        id: "999".into(),
+    }]);
+    let mut withheld = tweet("104", "2", EMOJI_TEXT);
+    withheld.withheld = Some(Withheld {
+        copyright: None,
+        country_codes: vec!["DE".into(), "FR".into()],
+        scope: Some("tweet".into()),
+    });
+    vec![with_media, reply, long, withheld]
+}
+
+/// An app with every view's data filled in, independent of the host terminal.
+fn fixture_app() -> App {
+    let mut app = App::new(AppConfig::default(), None, CredentialSet::default());
+    app.term_caps = TerminalCaps::default();
+    app.palette = Palette::Default;
+    let mut bob = user("2", "bob", "Bob 🦀");
+    bob.verified_type = Some("business".into());
+    bob.protected = Some(true);
+    app.users_cache.insert(user("1", "alice", "Alice"));
+    app.users_cache.insert(bob);
+
+    let tweets = timeline_tweets();
+    // 3_2 and the tweet 103 quotes are missing, as if deleted.
+    app.included.add(&Includes {
+        media: Some(vec![Media {
+            media_key: "3_1".into(),
+            type_: "photo".into(),
+            url: None,
+            preview_image_url: None,
+            width: None,
+            height: None,
+            alt_text: None,
+        }]),
+        ..Includes::default()
+    });
+    app.home_timeline.tweets = tweets.clone();
+    app.mentions.tweets = tweets[1..2].to_vec();
+    app.bookmarks.tweets = tweets[2..].to_vec();
+    app.search_query = "rust".into();
+    app.search_results.tweets = tweets.clone();
+    app.viewed_user_timeline.tweets = tweets.clone();
+    app.filtered_stream.tweets = tweets[..2].to_vec();
+    app.stream_status = StreamStatus::Connected;
+    app.thread_root = Some(tweets[0].clone());
+    app.thread_tweets = tweets[1..].to_vec();
+
+    let mut alice = user("1", "alice", "Alice");
+    alice.description = Some("Writes Rust 🦀 and long bios that need to wrap".into());
+    alice.location = Some("Lisbon".into());
+    alice.verified_type = Some("blue".into());
+    app.viewed_user = Some(alice);
+
+    app.openrouter_models = vec![Model {
+        id: "openai/text-embedding-3-small".into(),
+        name: Some("Text Embedding 3 Small".into()),
+        description: None,
+        pricing: None,
+        context_length: Some(8192),
+        architecture: None,
+    }];
+    app.text_models = app.openrouter_models.clone();
+    app.hf_models = vec![HfModel {
+        id: "mlx-community/Qwen3-4B-4bit".into(),
+        downloads: 12_345,
+        likes: 67,
+        library_name: Some("mlx".into()),
+        pipeline_tag: Some("text-generation".into()),
+        tags: vec!["4-bit".into()],
+    }];
+    app.cluster_result = Some(ClusterResult {
+        points: vec![(0.0, 0.0), (0.1, 0.2), (5.0, 5.0), (5.2, 4.9)],
+        labels: vec![0, 0, 1, 1],
+        tweet_texts: tweets.iter().map(|t| t.text.clone()).collect(),
+        tweet_ids: tweets.iter().map(|t| t.id.clone()).collect(),
+        conversation_ids: vec![None; 4],
+        author_ids: tweets.iter().map(|t| t.author_id.clone()).collect(),
+        cluster_topics: vec!["Shipping".into(), "Long posts".into()],
+    });
+    app.tag_report = Some(TagReport {
+        source: "Following".into(),
+        scanned: 40,
+        tags: vec![
+            TagCount {
+                label: "#rustlang".into(),
+                domain: None,
+                query: "#rustlang".into(),
+                tweets: 9,
+            },
+            TagCount {
+                label: "Rust".into(),
+                domain: Some("Programming Language".into()),
+                query: "context:66.1".into(),
+                tweets: 7,
+            },
+        ],
+    });
+    app.owned_lists = vec![
+        serde_json::from_value(serde_json::json!({
+            "id": "1", "name": "Rustaceans", "private": true,
+            "member_count": 12, "follower_count": 0,
+        }))
+        .unwrap(),
+    ];
+    app.followed_lists = vec![
+        serde_json::from_value(serde_json::json!({
+            "id": "2", "name": "Terminal UI folks",
+            "member_count": 48, "follower_count": 1_530,
+        }))
+        .unwrap(),
+    ];
+    app.list_members = serde_json::from_value(serde_json::json!([
+        {
+            "id": "7", "username": "alice", "name": "Alice",
+            "public_metrics": {
+                "followers_count": 2_400, "following_count": 180,
+                "tweet_count": 9_000, "listed_count": 30,
+            },
+        },
+        { "id": "8", "username": "bob", "name": "Bob", "protected": true },
+    ]))
+    .unwrap();
+    app.tweet_users = app.list_members.clone();
+    let saved_at = chrono::Utc::now() - chrono::Duration::hours(2);
+    for draft in [
+        Draft {
+            id: "1".into(),
+            text: "Half a thought about\nterminal charts".into(),
+            reply_to: None,
+            reply_to_username: None,
+            thread: false,
+            reply_settings: ReplySettings::Everyone,
+            saved_at,
+        },
+        Draft {
+            id: "2".into(),
+            text: "Agreed, braille makes a big difference".into(),
+            reply_to: Some("100".into()),
+            reply_to_username: Some("alice".into()),
+            thread: false,
+            reply_settings: ReplySettings::Everyone,
+            saved_at,
+        },
+    ] {
+        app.drafts.save(draft).unwrap();
+    }
+    app.stream_rules = vec![
+        StreamRule {
+            id: "1001".into(),
+            value: "ratatui lang:en -is:retweet".into(),
+            tag: Some("ratatui".into()),
+        },
+        StreamRule {
+            id: "1002".into(),
+            value: "from:alice".into(),
+            tag: None,
+        },
+    ];
+    app.dm_user_id = Some("9".into());
+    app.dm_events = serde_json::from_value(serde_json::json!([
+        {
+            "id": "13", "event_type": "MessageCreate", "sender_id": "9",
+            "dm_conversation_id": "2-9", "text": "Yes, see you there",
+        },
+        {
+            "id": "12", "event_type": "ParticipantsJoin", "participant_ids": ["1"],
+            "dm_conversation_id": "1500",
+        },
+        {
+            "id": "11", "event_type": "MessageCreate", "sender_id": "2",
+            "dm_conversation_id": "2-9", "text": "Coming to the meetup?",
+        },
+    ]))
+    .unwrap();
+    app.dm_conversation = serde_json::from_value(serde_json::json!([
+        {
+            "id": "11", "event_type": "MessageCreate", "sender_id": "2",
+            "text": "Coming to the meetup on Thursday? There will be a talk on \
+                     terminal UIs and plenty of pizza afterwards.",
+        },
+        {
+            "id": "13", "event_type": "MessageCreate", "sender_id": "9",
+            "text": "Yes, see you there",
+        },
+    ]))
+    .unwrap();
+    app.domain_report = Some(DomainReport {
+        source: "Following".into(),
+        scanned: 40,
+        linking: 12,
+        domains: [
+            ("github.com", 6),
+            ("example.com", 4),
+            ("blog.rust-lang.org", 2),
+        ]
+        .into_iter()
+        .map(|(domain, tweets)| DomainCount {
+            domain: domain.into(),
+            tweets,
+        })
+        .collect(),
+    });
+    app
+}
+
+fn with_view(view: ViewKind) -> App {
+    let mut app = fixture_app();
+    if view != ViewKind::Home {
+        app.push_view(view);
+    }
+    app.toasts.clear();
+    app
+}
+
+fn render(app: &App, width: u16, height: u16) -> Terminal<TestBackend> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| draw(frame, app)).unwrap();
+    terminal
+}
+
+fn all_views() -> Vec<ViewKind> {
+    vec![
+        ViewKind::Home,
+        ViewKind::UserTimeline("1".into()),
+        ViewKind::Thread("100".into()),
+        ViewKind::UserProfile("alice".into()),
+        ViewKind::Search,
+        ViewKind::Mentions,
+        ViewKind::Bookmarks,
+        ViewKind::OpenRouterModels,
+        ViewKind::TextModels,
+        ViewKind::Cluster,
+        ViewKind::HuggingFaceModels,
+        ViewKind::History,
+        ViewKind::Drafts,
+        ViewKind::StreamRules,
+        ViewKind::FilteredStream,
+        ViewKind::Domains,
+        ViewKind::Tags,
+        ViewKind::Lists,
+        ViewKind::ListMembers("1".into()),
+        ViewKind::TweetUsers {
+            tweet_id: "100".into(),
+            kind: TweetUsersKind::RetweetedBy,
+        },
+        ViewKind::TweetUsers {
+            tweet_id: "100".into(),
+            kind: TweetUsersKind::LikingUsers,
+        },
+        ViewKind::Messages,
+        ViewKind::DmConversation("2".into()),
+        ViewKind::Help,
+    ]
+}
+
+#[tokio::test]
+async fn home() {
+    assert_snapshot!(render(&with_view(ViewKind::Home), 80, 30).backend());
+}
+
+#[tokio::test]
+async fn home_with_watched_tweet() {
+    let mut app = with_view(ViewKind::Home);
+    app.config.watch.keywords = vec!["ship it".into()];
+    assert_snapshot!(render(&app, 80, 12).backend());
+}
+
+#[tokio::test]
+async fn user_timeline() {
+    let app = with_view(ViewKind::UserTimeline("1".into()));
+    assert_snapshot!(render(&app, 80, 30).backend());
+}
+
+#[tokio::test]
+async fn thread() {
+    let mut app = with_view(ViewKind::Thread("100".into()));
+    let root = app.thread_root.as_mut().unwrap();
+    root.source = Some("Typefully".into());
+    root.reply_settings = Some("following".into());
+    assert_snapshot!(render(&app, 80, 30).backend());
+}
+
+#[tokio::test]
+async fn thread_with_metrics_trend() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    let mut app = with_view(ViewKind::Thread("100".into()));
+    let mut root = app.thread_root.clone().unwrap();
+    let start = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+    for (hour, likes, retweets) in [(0, 10, 1), (2, 40, 3), (5, 90, 8), (30, 120, 12)] {
+        root.public_metrics = Some(PublicMetrics {
+            like_count: likes,
+            retweet_count: retweets,
+            reply_count: 0,
+            quote_count: 0,
+            bookmark_count: None,
+            impression_count: None,
+        });
+        app.metrics_history
+            .record([&root], start + Duration::hours(hour));
+    }
+    assert_snapshot!(render(&app, 80, 30).backend());
+}
+
+#[tokio::test]
+async fn user_profile() {
+    let app = with_view(ViewKind::UserProfile("alice".into()));
+    assert_snapshot!(render(&app, 80, 20).backend());
+}
+
+#[tokio::test]
+async fn search() {
+    assert_snapshot!(render(&with_view(ViewKind::Search), 80, 30).backend());
+}
+
+#[tokio::test]
+async fn search_with_batch_selection() {
+    let mut app = with_view(ViewKind::Search);
+    app.marked_tweets.insert("101".into());
+    app.view_stack.last_mut().unwrap().selected_index = 2;
+    app.visual_anchor = Some(3);
+    app.mode = AppMode::Visual;
+    assert_snapshot!(render(&app, 80, 30).backend());
+}
+
+#[tokio::test]
+async fn confirm_dialog() {
+    let mut app = with_view(ViewKind::Home);
+    app.marked_tweets.insert("101".into());
+    app.marked_tweets.insert("102".into());
+    app.handle_key_event(KeyEvent::from(KeyCode::Char('M')));
+    assert_snapshot!(render(&app, 80, 24).backend());
+}
+
+#[tokio::test]
+async fn startup_splash() {
+    use crate::api::types::Usage;
+    use crate::app::StartupSplash;
+    use crate::auth::AuthMethod;
+
+    let mut app = with_view(ViewKind::Home);
+    app.home_timeline.tweets.clear();
+    app.current_user = Some(user("1", "alice", "Alice Example"));
+    app.splash = Some(StartupSplash {
+        auth_method: Some(AuthMethod::OAuth2Pkce),
+        token_expires_at: None,
+        account_loaded: true,
+        usage: Some(Usage {
+            project_cap: Some(10_000),
+            project_usage: Some(8_123),
+            cap_reset_day: Some(14),
+        }),
+    });
+    assert_snapshot!(render(&app, 80, 24).backend());
+
+    // Cached tweets restored before the first fetch answers replace it.
+    app.home_timeline.tweets = timeline_tweets();
+    let cached = render(&app, 80, 24);
+    assert_eq!(
+        format!("{:?}", cached.backend()),
+        format!("{:?}", render(&with_view(ViewKind::Home), 80, 24).backend())
+    );
+}
+
+#[tokio::test]
+async fn thread_with_author_preview() {
+    use crate::app::AuthorPreview;
+
+    let mut app = with_view(ViewKind::Thread("100".into()));
+    app.author_preview = Some(AuthorPreview {
+        conversation_id: "100".into(),
+        user_id: "2".into(),
+        tweets: vec![
+            tweet("201", "2", "Shipped a new crate today"),
+            tweet("202", "2", "Two lines\nof text"),
+        ],
+        loading: false,
+        error: None,
+    });
+    assert_snapshot!(render(&app, 110, 24).backend());
+}
+
+#[tokio::test]
+async fn suspended_profile() {
+    use crate::api::error::AccountState;
+    use crate::app::UnavailableAccount;
+
+    let mut app = with_view(ViewKind::UserProfile("spam".into()));
+    app.unavailable_account = Some(UnavailableAccount {
+        view: ViewKind::UserProfile("spam".into()),
+        state: AccountState::Suspended,
+        detail: "Forbidden: User has been suspended: [spam].".into(),
+    });
+    assert_snapshot!(render(&app, 80, 16).backend());
+}
+
+#[tokio::test]
+async fn profile_with_posting_heatmap() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::app::PostingHeatmap;
+
+    let mut app = with_view(ViewKind::UserProfile("alice".into()));
+    // Mornings on weekdays, a few late nights at the weekend.
+    let monday = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+    let mut times = Vec::new();
+    for day in 0..5 {
+        for (hour, n) in [(0, 4), (1, 2), (3, 1)] {
+            times.extend(std::iter::repeat_n(
+                monday + Duration::days(day) + Duration::hours(hour),
+                n,
+            ));
+        }
+    }
+    times.extend([monday + Duration::days(5) + Duration::hours(14); 2]);
+    let mut heatmap = PostingHeatmap {
+        user_id: "1".into(),
+        ..PostingHeatmap::default()
+    };
+    heatmap.count(&times, &Utc);
+    app.posting_heatmap = Some(heatmap);
+    assert_snapshot!(render(&app, 80, 30).backend());
+}
+
+#[tokio::test]
+async fn composer() {
+    let mut app = with_view(ViewKind::Home);
+    app.mode = AppMode::Compose;
+    app.compose_input = "Trying the new composer.\n\nA second paragraph long enough to wrap \
+        inside the popup at eighty columns."
+        .into();
+    app.compose_reply_settings = ReplySettings::Following;
+    assert_snapshot!(render(&app, 80, 24).backend());
+}
+
+#[tokio::test]
+async fn composer_thread() {
+    let mut app = with_view(ViewKind::Home);
+    app.mode = AppMode::Compose;
+    app.compose_thread = true;
+    app.compose_input = "Some notes on rendering charts in a terminal. ".repeat(7);
+    assert_snapshot!(render(&app, 80, 24).backend());
+}
+
+#[tokio::test]
+async fn composer_reply_drafts() {
+    let mut app = with_view(ViewKind::Home);
+    app.mode = AppMode::Compose;
+    app.reply_drafts = Some(ReplyDrafts {
+        target: ReplyTarget {
+            tweet_id: "1".into(),
+            username: Some("alice".into()),
+        },
+        drafts: vec![
+            "Congrats on shipping this!".into(),
+            "The charts look great. Does it handle terminals without braille \
+             characters too?"
+                .into(),
+            "Bookmarked for the weekend.".into(),
+        ],
+        selected: 1,
+        loading: false,
+    });
+    assert_snapshot!(render(&app, 80, 24).backend());
+}
+
+#[tokio::test]
+async fn list_form() {
+    let mut app = with_view(ViewKind::Lists);
+    app.mode = AppMode::ListForm;
+    app.list_form = Some(ListForm {
+        list_id: None,
+        name: "Rustaceans".into(),
+        description: "People who write Rust".into(),
+        private: true,
+        focus: ListFormField::Description,
+    });
+    assert_snapshot!(render(&app, 80, 16).backend());
+}
+
+#[tokio::test]
+async fn toasts() {
+    let mut app = with_view(ViewKind::Home);
+    app.toasts
+        .error("Failed to load mentions: 503 upstream unavailable");
+    app.toasts
+        .info("Copied tweet URL", std::time::Duration::from_secs(60));
+    assert_snapshot!(render(&app, 80, 16).backend());
+}
+
+#[tokio::test]
+async fn doctor_report() {
+    use crate::doctor::{Check, CheckStatus};
+
+    let check = |name, status, detail: &str, hint: Option<&str>| Check {
+        name,
+        status,
+        detail: detail.into(),
+        hint: hint.map(Into::into),
+    };
+    let mut app = with_view(ViewKind::Home);
+    app.doctor_report = Some(vec![
+        check(
+            "Config",
+            CheckStatus::Pass,
+            "~/.config/xplorertui/config.toml",
+            None,
+        ),
+        check(
+            "OAuth 2.0 tokens",
+            CheckStatus::Fail,
+            "expired, no refresh token",
+            Some("run `xplorertui auth` to sign in again"),
+        ),
+        check("OpenRouter key", CheckStatus::Skip, "not configured", None),
+    ]);
+    assert_snapshot!(render(&app, 80, 16).backend());
+}
+
+#[tokio::test]
+async fn usage_report() {
+    use crate::api::types::Usage;
+    use crate::app::UsageReport;
+
+    let mut app = with_view(ViewKind::Home);
+    app.usage_report = Some(UsageReport {
+        usage: Usage {
+            project_cap: Some(10_000),
+            project_usage: Some(8_123),
+            cap_reset_day: Some(19),
+        },
+        checked_on: chrono::NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+    });
+    assert_snapshot!(render(&app, 80, 16).backend());
+}
+
+#[tokio::test]
+async fn mentions() {
+    assert_snapshot!(render(&with_view(ViewKind::Mentions), 80, 12).backend());
+}
+
+#[tokio::test]
+async fn bookmarks() {
+    assert_snapshot!(render(&with_view(ViewKind::Bookmarks), 80, 24).backend());
+}
+
+#[tokio::test]
+async fn openrouter_models() {
+    let app = with_view(ViewKind::OpenRouterModels);
+    assert_snapshot!(render(&app, 80, 12).backend());
+}
+
+#[tokio::test]
+async fn text_models() {
+    assert_snapshot!(render(&with_view(ViewKind::TextModels), 80, 12).backend());
+}
+
+#[tokio::test]
+async fn cluster() {
+    assert_snapshot!(render(&with_view(ViewKind::Cluster), 100, 30).backend());
+}
+
+#[tokio::test]
+async fn domains() {
+    assert_snapshot!(render(&with_view(ViewKind::Domains), 80, 12).backend());
+}
+
+#[tokio::test]
+async fn tags() {
+    assert_snapshot!(render(&with_view(ViewKind::Tags), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn lists() {
+    assert_snapshot!(render(&with_view(ViewKind::Lists), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn list_members() {
+    assert_snapshot!(render(&with_view(ViewKind::ListMembers("1".into())), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn list_members_without_unicode() {
+    let mut app = with_view(ViewKind::ListMembers("1".into()));
+    app.term_caps.unicode = UnicodeLevel::Ascii;
+    assert_snapshot!(render(&app, 80, 8).backend());
+}
+
+#[tokio::test]
+async fn drafts() {
+    assert_snapshot!(render(&with_view(ViewKind::Drafts), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn filtered_stream() {
+    assert_snapshot!(render(&with_view(ViewKind::FilteredStream), 80, 16).backend());
+}
+
+#[tokio::test]
+async fn stream_rules() {
+    assert_snapshot!(render(&with_view(ViewKind::StreamRules), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn reposted_by() {
+    let view = ViewKind::TweetUsers {
+        tweet_id: "100".into(),
+        kind: TweetUsersKind::RetweetedBy,
+    };
+    assert_snapshot!(render(&with_view(view), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn liked_by() {
+    let view = ViewKind::TweetUsers {
+        tweet_id: "100".into(),
+        kind: TweetUsersKind::LikingUsers,
+    };
+    assert_snapshot!(render(&with_view(view), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn messages() {
+    assert_snapshot!(render(&with_view(ViewKind::Messages), 80, 8).backend());
+}
+
+#[tokio::test]
+async fn dm_conversation() {
+    let mut app = with_view(ViewKind::DmConversation("2".into()));
+    app.view_stack.last_mut().unwrap().selected_index = 1;
+    assert_snapshot!(render(&app, 80, 12).backend());
+}
+
+#[tokio::test]
+async fn hugging_face_models() {
+    let app = with_view(ViewKind::HuggingFaceModels);
+    assert_snapshot!(render(&app, 80, 12).backend());
+}
+
+#[tokio::test]
+async fn help() {
+    assert_snapshot!(render(&with_view(ViewKind::Help), 80, 50).backend());
+}
+
+#[tokio::test]
+async fn help_scrolled_to_the_end() {
+    let mut app = with_view(ViewKind::Help);
+    render(&app, 80, 24);
+    for _ in 0..20 {
+        app.handle_key_event(KeyEvent::from(KeyCode::PageDown));
+    }
+    assert_snapshot!(render(&app, 80, 24).backend());
+}
+
+#[tokio::test]
+async fn accessible_home() {
+    let mut app = with_view(ViewKind::Home);
+    app.config.accessible = true;
+    assert_snapshot!(render(&app, 80, 24).backend());
+}
+
+#[tokio::test]
+async fn narrow_home() {
+    assert_snapshot!(render(&with_view(ViewKind::Home), 24, 30).backend());
+}
+
+/// A 60-column tmux split: borderless panels and compact cards.
+#[tokio::test]
+async fn compact_thread() {
+    assert_snapshot!(render(&with_view(ViewKind::Thread("100".into())), 60, 24).backend());
+}
+
+#[tokio::test]
+async fn degenerate_sizes_do_not_panic() {
+    for view in all_views() {
+        let app = with_view(view);
+        for (width, height) in [(0, 0), (0, 20), (80, 0), (1, 1), (1, 20), (3, 3), (80, 2)] {
+            render(&app, width, height);
+        }
+    }
+}
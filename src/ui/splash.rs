@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget, Wrap};
+
+use crate::api::types::Usage;
+use crate::app::{App, StartupSplash};
+use crate::auth::AuthMethod;
+use crate::ui::status_bar::view_name;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+const TIPS: [(&str, &str); 4] = [
+    ("?", "all keybindings"),
+    ("Enter / u", "open a thread / the author's profile"),
+    (":history", "threads and profiles opened recently"),
+    (":doctor", "check the setup if something looks off"),
+];
+
+/// Shown in place of the first timeline while it loads: who is signed in,
+/// how, and how much of the monthly post cap is used.
+pub struct SplashView<'a> {
+    app: &'a App,
+    splash: &'a StartupSplash,
+}
+
+impl<'a> SplashView<'a> {
+    pub fn new(app: &'a App, splash: &'a StartupSplash) -> Self {
+        Self { app, splash }
+    }
+}
+
+impl Widget for SplashView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = panel_block(
+            concat!("xplorertui ", env!("CARGO_PKG_VERSION")),
+            area.width < COMPACT_WIDTH,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let label = Style::default().fg(Color::DarkGray);
+        let pending = || Span::styled("checking…", label);
+        let row = |name: &'static str, value: Span<'static>| {
+            Line::from(vec![Span::styled(format!(" {name:<9}"), label), value])
+        };
+
+        let account = match &self.app.current_user {
+            Some(user) => Span::styled(
+                format!("@{} ({})", user.username, user.name),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None if self.splash.account_loaded => Span::raw("unknown"),
+            None => pending(),
+        };
+        let method = self.splash.auth_method.map_or("none", AuthMethod::label);
+        let token = match (self.splash.auth_method, self.splash.token_expires_at) {
+            (Some(AuthMethod::OAuth2Pkce), Some(at)) => token_expiry(at, Utc::now()),
+            (Some(AuthMethod::OAuth2Pkce), None) => Span::raw("no expiry recorded"),
+            _ => Span::raw("doesn't expire"),
+        };
+        let usage = match &self.splash.usage {
+            Some(usage) => usage_span(usage),
+            None if self.splash.account_loaded => {
+                Span::styled("unavailable (needs an app bearer token)", label)
+            }
+            None => pending(),
+        };
+
+        let mut lines = vec![
+            Line::from(""),
+            row("Account", account),
+            row("Auth", Span::raw(method)),
+            row("Token", token),
+            row("Usage", usage),
+            Line::from(""),
+            Line::from(Span::styled(
+                " Tips",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+        ];
+        for (key, what) in TIPS {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {key:<11}"), Style::default().fg(Color::Yellow)),
+                Span::raw(what),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(" Loading {}…", view_name(self.app)),
+            label.add_modifier(Modifier::ITALIC),
+        )));
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .render(inner, buf);
+    }
+}
+
+fn token_expiry(at: DateTime<Utc>, now: DateTime<Utc>) -> Span<'static> {
+    let left = at - now;
+    if left <= chrono::Duration::zero() {
+        return Span::styled(
+            "expired, refreshed on the next request",
+            Style::default().fg(Color::Yellow),
+        );
+    }
+    let (hours, minutes) = (left.num_hours(), left.num_minutes() % 60);
+    let text = if hours > 0 {
+        format!("expires in {hours}h {minutes}m")
+    } else {
+        format!("expires in {minutes}m")
+    };
+    Span::raw(text)
+}
+
+fn usage_span(usage: &Usage) -> Span<'static> {
+    let used = usage.project_usage.unwrap_or(0);
+    let reset = usage
+        .cap_reset_day
+        .map(|day| format!(", resets on day {day}"))
+        .unwrap_or_default();
+    let (Some(cap), Some(percent)) = (usage.project_cap, usage.percent_used()) else {
+        return Span::raw(format!("{used} posts read this month{reset}"));
+    };
+    let color = match percent {
+        90.. => Color::Red,
+        75.. => Color::Yellow,
+        _ => Color::Green,
+    };
+    Span::styled(
+        format!("{used} / {cap} posts this month ({percent}%){reset}"),
+        Style::default().fg(color),
+    )
+}
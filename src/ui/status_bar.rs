@@ -4,10 +4,15 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Widget;
 
+use crate::api::RateLimitInfo;
 use crate::app::{App, AppMode};
+use crate::config::StatusSegment;
 use crate::event::ViewKind;
 use crate::ui::text::truncate_for_width;
 
+/// What an app-only bearer token leaves out.
+pub const READ_ONLY: &str = "read-only: no Following, Mentions, Bookmarks, Lists or posting";
+
 /// Bottom status bar showing mode, current view, and status messages.
 pub struct StatusBar<'a> {
     pub app: &'a App,
@@ -31,64 +36,37 @@ impl Widget for StatusBar<'_> {
             buf[(x, area.y)].set_style(bg_style);
         }
 
-        let mut spans = Vec::new();
-
-        // Mode indicator
-        let mode_str = match self.app.mode {
-            AppMode::Normal => " NORMAL ",
-            AppMode::Command => " COMMAND ",
-            AppMode::Search => " SEARCH ",
-        };
-        let mode_style = Style::default()
-            .bg(match self.app.mode {
-                AppMode::Normal => Color::Blue,
-                AppMode::Command => Color::Magenta,
-                AppMode::Search => Color::Yellow,
-            })
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD);
-        spans.push(Span::styled(mode_str, mode_style));
-        spans.push(Span::raw(" "));
+        let segments = &self.app.config.status_bar;
+        let mut spans: Vec<Span> = segments
+            .left
+            .0
+            .iter()
+            .flat_map(|&segment| self.segment(segment))
+            .collect();
+        let right: Vec<Span> = segments
+            .right
+            .0
+            .iter()
+            .flat_map(|&segment| self.segment(segment))
+            .collect();
 
-        // Current view
-        let view_name = match self.app.current_view() {
-            Some(ViewKind::Home) => "Following".to_string(),
-            Some(ViewKind::UserTimeline(id)) => format!("Timeline: {id}"),
-            Some(ViewKind::Thread(id)) => format!("Thread: {id}"),
-            Some(ViewKind::UserProfile(name)) => format!("@{name}"),
-            Some(ViewKind::Search) => {
-                if self.app.search_query.is_empty() {
-                    "Search".to_string()
-                } else {
-                    format!("Search: {}", self.app.search_query)
-                }
-            }
-            Some(ViewKind::Mentions) => "Mentions".to_string(),
-            Some(ViewKind::Bookmarks) => "Bookmarks".to_string(),
-            Some(ViewKind::OpenRouterModels) => "Embedding Models".to_string(),
-            Some(ViewKind::TextModels) => "Text Models".to_string(),
-            Some(ViewKind::Cluster) => match self.app.cluster_source {
-                Some(src) => format!("Clusters ({src})"),
-                None => "Clusters".to_string(),
-            },
-            Some(ViewKind::HuggingFaceModels) => "HuggingFace Models".to_string(),
-            Some(ViewKind::Help) => "Help".to_string(),
-            None => "xplorertui".to_string(),
-        };
-        spans.push(Span::styled(view_name, bg_style));
-
-        // Loading indicator
-        if self.app.loading {
-            spans.push(Span::styled(
-                " [loading...]",
-                Style::default().bg(Color::DarkGray).fg(Color::Yellow),
-            ));
+        let width = area.width as usize;
+        let left_width: usize = spans.iter().map(|s| s.width()).sum();
+        let mut right_width: usize = right.iter().map(|s| s.width()).sum();
+        // The left side wins when both don't fit.
+        if left_width + right_width > width {
+            right_width = 0;
         }
+        let mut available = width.saturating_sub(left_width + right_width);
 
-        // Status message (right-aligned)
-        if let Some(ref msg) = self.app.status_message {
-            let left_width: usize = spans.iter().map(|s| s.width()).sum();
-            let available = (area.width as usize).saturating_sub(left_width);
+        // In accessible mode the latest toast goes in the status line
+        // (right-aligned), where screen readers look for it.
+        let toast = self
+            .app
+            .toasts
+            .latest()
+            .filter(|_| self.app.config.accessible);
+        if let Some(msg) = toast.map(|t| &t.text) {
             let display = truncate_for_width(msg, available);
             let display_width = Span::raw(&display).width();
             let padding = available.saturating_sub(display_width);
@@ -99,9 +77,201 @@ impl Widget for StatusBar<'_> {
                 display,
                 Style::default().bg(Color::DarkGray).fg(Color::Cyan),
             ));
+            available = 0;
+        }
+
+        if right_width > 0 {
+            if available > 0 {
+                spans.push(Span::styled(" ".repeat(available), bg_style));
+            }
+            spans.extend(right);
         }
 
         let line = Line::from(spans);
         buf.set_line(area.x, area.y, &line, area.width);
     }
 }
+
+impl StatusBar<'_> {
+    /// Spans for one configured segment; empty when it has nothing to show.
+    fn segment(&self, segment: StatusSegment) -> Vec<Span<'static>> {
+        let bar = |text: String, fg: Color| {
+            Span::styled(text, Style::default().bg(Color::DarkGray).fg(fg))
+        };
+        match segment {
+            StatusSegment::Mode => {
+                let mode_str = match self.app.mode {
+                    AppMode::Normal => " NORMAL ",
+                    AppMode::Command => " COMMAND ",
+                    AppMode::Search => " SEARCH ",
+                    AppMode::Visual => " VISUAL ",
+                    AppMode::Confirm => " CONFIRM ",
+                    AppMode::Compose => " COMPOSE ",
+                    AppMode::ListForm => " LIST ",
+                };
+                let mode_style = Style::default()
+                    .bg(match self.app.mode {
+                        AppMode::Normal => Color::Blue,
+                        AppMode::Command => Color::Magenta,
+                        AppMode::Search => Color::Yellow,
+                        AppMode::Visual => Color::Green,
+                        AppMode::Confirm => Color::Red,
+                        AppMode::Compose | AppMode::ListForm => Color::Cyan,
+                    })
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD);
+                let mut spans = vec![Span::styled(mode_str, mode_style), Span::raw(" ")];
+                if let Some(register) = self.app.recording_macro() {
+                    spans.push(bar(format!("recording @{register} "), Color::Red));
+                }
+                spans
+            }
+            // Named profile, so it's clear which account is in use, and
+            // what an app-only token can't do
+            StatusSegment::Account => {
+                let mut spans = Vec::new();
+                if let Some(profile) = crate::config::profile() {
+                    spans.push(bar(format!("[{profile}] "), Color::Magenta));
+                }
+                if self.app.read_only() {
+                    spans.push(bar(format!("[{READ_ONLY}] "), Color::Yellow));
+                }
+                spans
+            }
+            StatusSegment::View => vec![bar(view_name(self.app), Color::White)],
+            StatusSegment::Spinner if self.app.loading => {
+                let text = if self.app.config.accessible {
+                    " [loading...]".to_string()
+                } else {
+                    let frame = self.app.skeleton_elapsed_ms_immediate() / 80;
+                    let glyph = SPINNER[frame as usize % SPINNER.len()];
+                    format!(" {glyph} loading")
+                };
+                vec![bar(text, Color::Yellow)]
+            }
+            StatusSegment::Selection => match self.app.batch_selection().len() {
+                0 => Vec::new(),
+                selected => vec![bar(format!(" [{selected} selected]"), Color::Green)],
+            },
+            StatusSegment::Progress => match self.app.bulk_run {
+                Some(run) => {
+                    let label = run.action.progress_label();
+                    let text = match run.rate_limited_until {
+                        Some(until) => format!(
+                            " [{label} {}/{}, rate limited until {}]",
+                            run.done,
+                            run.total,
+                            until.with_timezone(&chrono::Local).format("%H:%M:%S"),
+                        ),
+                        None => format!(" [{label} {}/{}]", run.done, run.total),
+                    };
+                    vec![bar(text, Color::Yellow)]
+                }
+                None => Vec::new(),
+            },
+            StatusSegment::Unread => {
+                let counts: Vec<String> = [
+                    ("Following", self.app.home_timeline.unread()),
+                    ("Mentions", self.app.mentions.unread()),
+                ]
+                .into_iter()
+                .filter(|(_, n)| *n > 0)
+                .map(|(name, n)| format!("{name} {n}"))
+                .collect();
+                if counts.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![bar(format!(" {} ", counts.join(" · ")), Color::Cyan)]
+                }
+            }
+            StatusSegment::RateLimit => {
+                let Some(client) = &self.app.api_client else {
+                    return Vec::new();
+                };
+                match client.rate_limit() {
+                    RateLimitInfo {
+                        remaining: Some(remaining),
+                        limit,
+                        reset_at,
+                    } => {
+                        let mut text = match limit {
+                            Some(limit) => format!(" API {remaining}/{limit}"),
+                            None => format!(" API {remaining} left"),
+                        };
+                        if remaining == 0
+                            && let Some(reset_at) = reset_at
+                        {
+                            let reset = reset_at.with_timezone(&chrono::Local);
+                            text.push_str(&format!(" until {}", reset.format("%H:%M")));
+                        }
+                        text.push(' ');
+                        let low = limit.is_some_and(|limit| remaining.saturating_mul(10) <= limit);
+                        let fg = match remaining {
+                            0 => Color::Red,
+                            _ if low => Color::Yellow,
+                            _ => Color::Gray,
+                        };
+                        vec![bar(text, fg)]
+                    }
+                    _ => Vec::new(),
+                }
+            }
+            StatusSegment::Clock => {
+                let now = chrono::Local::now();
+                vec![bar(format!(" {} ", now.format("%H:%M")), Color::White)]
+            }
+            StatusSegment::Spinner => Vec::new(),
+        }
+    }
+}
+
+/// Braille frames for the loading spinner.
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Human-readable name of the current view, shown in the status bar and
+/// announced on view changes in accessible mode.
+pub fn view_name(app: &App) -> String {
+    match app.current_view() {
+        Some(ViewKind::Home) => "Following".to_string(),
+        Some(ViewKind::UserTimeline(id)) => format!("Timeline: {id}"),
+        Some(ViewKind::Thread(id)) => format!("Thread: {id}"),
+        Some(ViewKind::UserProfile(name)) => format!("@{name}"),
+        Some(ViewKind::Search) => {
+            if app.search_query.is_empty() {
+                "Search".to_string()
+            } else if app.search_local {
+                format!("Local search: {}", app.search_query)
+            } else {
+                format!("Search: {}", app.search_query)
+            }
+        }
+        Some(ViewKind::Mentions) => "Mentions".to_string(),
+        Some(ViewKind::Bookmarks) => "Bookmarks".to_string(),
+        Some(ViewKind::OpenRouterModels) => "Embedding Models".to_string(),
+        Some(ViewKind::TextModels) => "Text Models".to_string(),
+        Some(ViewKind::Cluster) => match app.cluster_source {
+            Some(src) => format!("Clusters ({src})"),
+            None => "Clusters".to_string(),
+        },
+        Some(ViewKind::HuggingFaceModels) => "HuggingFace Models".to_string(),
+        Some(ViewKind::History) => "History".to_string(),
+        Some(ViewKind::Drafts) => "Drafts".to_string(),
+        Some(ViewKind::Domains) => "Domains".to_string(),
+        Some(ViewKind::Tags) => "Tags".to_string(),
+        Some(ViewKind::Lists) => "Lists".to_string(),
+        Some(ViewKind::ListMembers(_)) => match app.members_list() {
+            Some(list) => format!("List: {}", list.name),
+            None => "List members".to_string(),
+        },
+        Some(ViewKind::TweetUsers { kind, .. }) => kind.title().to_string(),
+        Some(ViewKind::StreamRules) => "Stream rules".to_string(),
+        Some(ViewKind::FilteredStream) => "Stream".to_string(),
+        Some(ViewKind::Messages) => "Messages".to_string(),
+        Some(ViewKind::DmConversation(id)) => match app.lookup_user(id) {
+            Some(user) => format!("Messages: @{}", user.username),
+            None => "Messages".to_string(),
+        },
+        Some(ViewKind::Help) => "Help".to_string(),
+        None => "xplorertui".to_string(),
+    }
+}
@@ -0,0 +1,79 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+
+use crate::app::App;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+/// The filtered stream's rules, with their tags and IDs.
+pub struct StreamRulesView<'a> {
+    app: &'a App,
+}
+
+impl<'a> StreamRulesView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for StreamRulesView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let rules = &self.app.stream_rules;
+        let block = panel_block(
+            &format!("Stream rules ({})", rules.len()),
+            area.width < COMPACT_WIDTH,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        if rules.is_empty() {
+            let message = if self.app.stream_rules_loading {
+                "Loading rules…"
+            } else {
+                "No rules. Add one with :rules add <rule> [--tag <name>]"
+            };
+            buf.set_string(
+                inner.x + 1,
+                inner.y,
+                message,
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        let items: Vec<ListItem> = rules
+            .iter()
+            .map(|rule| {
+                let mut spans = Vec::new();
+                if let Some(tag) = &rule.tag {
+                    spans.push(Span::styled(
+                        format!("[{tag}] "),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                spans.push(Span::raw(rule.value.as_str()));
+                spans.push(Span::styled(
+                    format!("  {}", rule.id),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(self.app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
@@ -0,0 +1,90 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+
+use crate::app::App;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+/// Hashtags and topics in a view's tweets, most frequent first. Enter
+/// searches for the selected one.
+pub struct TagsView<'a> {
+    app: &'a App,
+}
+
+impl<'a> TagsView<'a> {
+    pub fn new(app: &'a App) -> Self {
+        Self { app }
+    }
+}
+
+impl Widget for TagsView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(report) = &self.app.tag_report else {
+            return;
+        };
+        let block = panel_block(
+            &format!("Tags · {} ({} tweets)", report.source, report.scanned),
+            area.width < COMPACT_WIDTH,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        if report.tags.is_empty() {
+            buf.set_string(
+                inner.x + 1,
+                inner.y,
+                "No hashtags or topics",
+                Style::default().fg(Color::DarkGray),
+            );
+            return;
+        }
+
+        let items: Vec<ListItem> = report
+            .tags
+            .iter()
+            .enumerate()
+            .map(|(i, tag)| {
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:>3}  ", i + 1),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{:>4}  ", tag.tweets),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                ];
+                match &tag.domain {
+                    None => spans.push(Span::styled(
+                        tag.label.as_str(),
+                        Style::default().fg(Color::Cyan),
+                    )),
+                    Some(domain) => {
+                        spans.push(Span::raw(tag.label.as_str()));
+                        spans.push(Span::styled(
+                            format!("  {domain}"),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(self.app.selected_index()));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}
@@ -1,4 +1,5 @@
-use unicode_width::UnicodeWidthChar;
+use unicode_bidi::{BidiInfo, Level};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 const ELLIPSIS: char = '…';
 
@@ -40,10 +41,90 @@ pub fn truncate_for_width(s: &str, max_cols: usize) -> String {
     out
 }
 
+/// Word-wrap `text` to `width` display columns, so wide (e.g. CJK)
+/// characters take the two columns they fill. A word wider than a line is
+/// broken between characters.
+pub fn wrap_to_width(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut current = String::new();
+        let mut cols = 0;
+        for word in paragraph.split_whitespace() {
+            let word_cols = word.width();
+            if !current.is_empty() && cols + 1 + word_cols > width {
+                lines.push(std::mem::take(&mut current));
+                cols = 0;
+            }
+            if !current.is_empty() {
+                current.push(' ');
+                cols += 1;
+            }
+            for c in word.chars() {
+                let w = c.width().unwrap_or(0);
+                if cols + w > width && !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                    cols = 0;
+                }
+                current.push(c);
+                cols += w;
+            }
+        }
+        if !current.is_empty() {
+            lines.push(current);
+        }
+    }
+    lines
+}
+
+/// Whether a paragraph's base direction is right-to-left, judged by its
+/// first strong character (the Unicode P2/P3 rule).
+pub fn is_rtl_paragraph(paragraph: &str) -> bool {
+    unicode_bidi::get_base_direction(paragraph) == unicode_bidi::Direction::Rtl
+}
+
+/// Left-to-right isolate / pop directional isolate.
+const LRI: char = '\u{2066}';
+const PDI: char = '\u{2069}';
+
+/// Reorder one already-wrapped line from logical to visual order, so mixed
+/// RTL text (Arabic, Hebrew) and LTR runs display in the right sequence.
+/// `rtl` is the base direction of the paragraph the line came from. Pure-LTR
+/// lines are returned unchanged.
+///
+/// Mentions, hashtags, cashtags and URLs are isolated as LTR first so their
+/// leading sigil stays attached (`@bob`, not `bob@`) inside RTL text.
+pub fn bidi_visual_line(line: &str, rtl: bool) -> String {
+    let level = if rtl { Level::rtl() } else { Level::ltr() };
+    if !BidiInfo::new(line, Some(level)).has_rtl() {
+        return line.to_string();
+    }
+
+    let mut isolated = String::with_capacity(line.len() + 16);
+    for (i, word) in line.split(' ').enumerate() {
+        if i > 0 {
+            isolated.push(' ');
+        }
+        if word.starts_with(['@', '#', '$']) || word.contains("://") {
+            isolated.push(LRI);
+            isolated.push_str(word);
+            isolated.push(PDI);
+        } else {
+            isolated.push_str(word);
+        }
+    }
+
+    let info = BidiInfo::new(&isolated, Some(level));
+    info.paragraphs
+        .iter()
+        .map(|para| info.reorder_line(para, para.range.clone()))
+        .collect::<String>()
+        .replace([LRI, PDI], "")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use unicode_width::UnicodeWidthStr;
 
     #[test]
     fn empty_max_returns_empty() {
@@ -114,4 +195,27 @@ mod tests {
         let out = truncate_for_width(s, 4);
         assert!(out.width() <= 4);
     }
+
+    #[test]
+    fn bidi_reorders_rtl_runs_and_keeps_ltr_runs() {
+        // Logical "שלום @bob" in an RTL paragraph displays as "@bob םולש".
+        assert!(is_rtl_paragraph("שלום @bob"));
+        assert_eq!(bidi_visual_line("שלום @bob", true), "@bob םולש");
+        // Hebrew inside an LTR paragraph only flips the Hebrew run.
+        assert!(!is_rtl_paragraph("hi שלום"));
+        assert_eq!(bidi_visual_line("hi שלום", false), "hi םולש");
+        assert_eq!(bidi_visual_line("plain ascii", false), "plain ascii");
+        assert_eq!(
+            bidi_visual_line("مرحبا #rust https://x.y", true),
+            "https://x.y #rust ابحرم"
+        );
+    }
+
+    #[test]
+    fn lines_wrap_by_display_width() {
+        assert_eq!(wrap_to_width("one two three", 7), ["one two", "three"]);
+        let lines = wrap_to_width("日本語のテキストです ok", 8);
+        assert_eq!(lines, ["日本語の", "テキスト", "です ok"]);
+        assert!(lines.iter().all(|l| l.width() <= 8));
+    }
 }
@@ -0,0 +1,127 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::terminal::TerminalCaps;
+
+/// Theme requested in config (`theme = "..."`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeChoice {
+    /// The default palette on truecolor terminals, basic-16 otherwise.
+    #[default]
+    Auto,
+    Default,
+    Basic16,
+    HighContrast,
+}
+
+impl ThemeChoice {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "auto" => Some(Self::Auto),
+            "default" => Some(Self::Default),
+            "basic16" | "16" => Some(Self::Basic16),
+            "high-contrast" | "hc" => Some(Self::HighContrast),
+            _ => None,
+        }
+    }
+
+    /// Resolve `Auto` against the terminal's capabilities.
+    pub fn resolve(self, caps: &TerminalCaps) -> Palette {
+        match self {
+            Self::Auto if caps.truecolor => Palette::Default,
+            Self::Auto => Palette::Basic16,
+            Self::Default => Palette::Default,
+            Self::Basic16 => Palette::Basic16,
+            Self::HighContrast => Palette::HighContrast,
+        }
+    }
+}
+
+/// Color palette applied to the finished frame.
+///
+/// Widgets draw with the default named colors; other palettes remap those
+/// after rendering, so views don't each need to know about themes. RGB and
+/// indexed colors (e.g. image previews) are left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    Default,
+    /// For 16-color terminals, where `DarkGray` ("bright black") is often
+    /// indistinguishable from the background.
+    Basic16,
+    /// Bright foregrounds on black, for low vision or washed-out displays.
+    HighContrast,
+}
+
+impl Palette {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Basic16 => "basic16",
+            Self::HighContrast => "high-contrast",
+        }
+    }
+
+    fn fg(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::Default, c) => c,
+            (Self::Basic16, Color::DarkGray) => Color::Gray,
+            (Self::Basic16, c) => c,
+            (Self::HighContrast, Color::DarkGray | Color::Gray) => Color::White,
+            (Self::HighContrast, Color::Cyan) => Color::LightCyan,
+            (Self::HighContrast, Color::Blue) => Color::LightBlue,
+            (Self::HighContrast, Color::Red) => Color::LightRed,
+            (Self::HighContrast, Color::Green) => Color::LightGreen,
+            (Self::HighContrast, Color::Yellow) => Color::LightYellow,
+            (Self::HighContrast, Color::Magenta) => Color::LightMagenta,
+            (Self::HighContrast, c) => c,
+        }
+    }
+
+    fn bg(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::Default, c) => c,
+            (Self::Basic16, Color::DarkGray) => Color::Blue,
+            (Self::Basic16, c) => c,
+            (Self::HighContrast, Color::DarkGray) => Color::Black,
+            (Self::HighContrast, c) => c,
+        }
+    }
+
+    /// Remap every cell of a rendered frame.
+    pub fn apply(self, buf: &mut Buffer) {
+        if self == Self::Default {
+            return;
+        }
+        for cell in &mut buf.content {
+            cell.fg = self.fg(cell.fg);
+            cell.bg = self.bg(cell.bg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn auto_picks_basic16_without_truecolor_and_remaps_dark_gray() {
+        let caps = TerminalCaps::default();
+        assert_eq!(ThemeChoice::Auto.resolve(&caps), Palette::Basic16);
+        let truecolor = TerminalCaps {
+            truecolor: true,
+            ..caps
+        };
+        assert_eq!(ThemeChoice::Auto.resolve(&truecolor), Palette::Default);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf.set_string(0, 0, "a", Style::default().fg(Color::DarkGray));
+        buf.set_string(1, 0, "b", Style::default().fg(Color::Rgb(1, 2, 3)));
+        Palette::HighContrast.apply(&mut buf);
+        assert_eq!(buf[(0, 0)].fg, Color::White);
+        assert_eq!(buf[(1, 0)].fg, Color::Rgb(1, 2, 3));
+    }
+}
@@ -1,11 +1,18 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, Widget};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
 
 use crate::api::types::Tweet;
 use crate::app::App;
-use crate::ui::tweet::{TweetCard, tweet_card_height};
+use crate::stats::TextStats;
+use crate::ui::linear::LinearTweetList;
+use crate::ui::metrics::MetricsChart;
+use crate::ui::timeline::{
+    COMPACT_WIDTH, compute_scroll_start, panel_block, render_batch_mark, render_partial_errors,
+    render_scrollbar, render_watch_gutter,
+};
+use crate::ui::tweet::{TweetCard, tweet_card_height, tweet_metadata};
 
 /// Thread/conversation view: root tweet at top, replies below.
 pub struct ThreadView<'a> {
@@ -24,29 +31,62 @@ impl<'a> ThreadView<'a> {
             app,
         }
     }
+
+    /// "Thread · 12 replies · ~2 min read". The root's own reply count wins
+    /// when it's more than were loaded; reading time covers what is loaded.
+    fn title(&self) -> String {
+        if self.root.is_none() && self.replies.is_empty() {
+            return "Thread".to_string();
+        }
+        let direct = self
+            .root
+            .and_then(|root| root.public_metrics.as_ref())
+            .map_or(0, |m| m.reply_count as usize);
+        let replies = direct.max(self.replies.len());
+        let stats = TextStats::of_tweets(self.root.into_iter().chain(self.replies));
+        let s = if replies == 1 { "y" } else { "ies" };
+        format!(
+            "Thread \u{00B7} {replies} repl{s} \u{00B7} {}",
+            stats.reading_time()
+        )
+    }
 }
 
 impl Widget for ThreadView<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(" Thread ")
-            .title_style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .border_style(Style::default().fg(Color::DarkGray));
+        if self.app.config.accessible {
+            // The root is announced in the heading; replies follow linearly.
+            let title = match self.root {
+                Some(root) => match tweet_metadata(root) {
+                    Some(meta) => format!("Thread replying to: {} ({meta})", root.text),
+                    None => format!("Thread replying to: {}", root.text),
+                },
+                None => "Thread".to_string(),
+            };
+            LinearTweetList::new(&title, self.replies, self.selected_index, self.app)
+                .render(area, buf);
+            return;
+        }
+
+        let compact = area.width < COMPACT_WIDTH;
+        let block = panel_block(&self.title(), compact);
 
         let inner = block.inner(area);
         block.render(area, buf);
 
-        let content_width = inner.width.saturating_sub(1);
-        let mut y = inner.y;
+        let content_width = inner.width.saturating_sub(1 + u16::from(compact));
+        let layout = &self.app.config.card_layout;
+        let mut y = inner.y
+            + render_partial_errors(
+                &self.app.thread_partial_errors,
+                self.app.partial_errors_expanded,
+                inner,
+                buf,
+            );
 
         // Render root tweet (if available)
         if let Some(root) = self.root {
-            let root_h = tweet_card_height(root, content_width);
+            let root_h = tweet_card_height(root, content_width, layout, None);
             let remaining = (inner.y + inner.height).saturating_sub(y);
             let render_h = root_h.min(remaining);
 
@@ -56,10 +96,46 @@ impl Widget for ThreadView<'_> {
                     .author_id
                     .as_ref()
                     .and_then(|id| self.app.lookup_user(id));
-                TweetCard::new(root, author).render(root_area, buf);
+                TweetCard::new(root, author)
+                    .compact(compact)
+                    .included(&self.app.included)
+                    .layout(layout)
+                    .liked(self.app.liked_tweets.contains(&root.id))
+                    .retweeted(self.app.retweeted_tweets.contains(&root.id))
+                    .unicode(self.app.term_caps.unicode)
+                    .render(root_area, buf);
+                if self.app.is_watched(root) {
+                    render_watch_gutter(inner.x, y, render_h, buf);
+                }
                 y += render_h;
             }
 
+            // Details the cards leave out
+            let meta = match tweet_metadata(root) {
+                Some(meta) => format!("{meta} \u{00B7} {}", TextStats::of_tweet(root)),
+                None => TextStats::of_tweet(root).to_string(),
+            };
+            if y < inner.y + inner.height {
+                buf.set_stringn(
+                    inner.x + 1,
+                    y,
+                    &meta,
+                    content_width as usize,
+                    Style::default().fg(Color::DarkGray),
+                );
+                y += 1;
+            }
+
+            // How its metrics moved across refreshes, when it fits whole
+            if let Some(chart) = MetricsChart::new(self.app.metrics_history.get(&root.id)) {
+                let chart = chart.caps(self.app.term_caps);
+                let chart_h = chart.height();
+                if y + chart_h <= inner.y + inner.height {
+                    chart.render(Rect::new(inner.x + 1, y, content_width, chart_h), buf);
+                    y += chart_h;
+                }
+            }
+
             // Separator
             if y < inner.y + inner.height {
                 let sep = "\u{2550}".repeat(content_width as usize);
@@ -80,13 +156,32 @@ impl Widget for ThreadView<'_> {
             return;
         }
 
-        // Render replies
-        for (i, tweet) in self.replies.iter().enumerate() {
+        // Render replies, scrolled so the selected reply stays visible below
+        // the pinned root tweet.
+        let reply_width = content_width.saturating_sub(2); // indent replies
+        let preview = self
+            .replies
+            .get(self.selected_index)
+            .and_then(|t| self.app.link_preview(t));
+        let card_height = |i: usize, t: &Tweet| {
+            let preview = preview.as_ref().filter(|_| i == self.selected_index);
+            tweet_card_height(t, reply_width, layout, preview)
+        };
+        let heights: Vec<u16> = self
+            .replies
+            .iter()
+            .enumerate()
+            .map(|(i, t)| card_height(i, t) + 1)
+            .collect();
+        let available = (inner.y + inner.height).saturating_sub(y);
+        let scroll_start = compute_scroll_start(&heights, self.selected_index, available);
+
+        for (i, tweet) in self.replies.iter().enumerate().skip(scroll_start) {
             if y >= inner.y + inner.height {
                 break;
             }
 
-            let card_h = tweet_card_height(tweet, content_width.saturating_sub(2)); // indent replies
+            let card_h = card_height(i, tweet);
             let remaining = (inner.y + inner.height).saturating_sub(y);
             let render_h = card_h.min(remaining);
 
@@ -99,15 +194,29 @@ impl Widget for ThreadView<'_> {
                     Style::default().fg(Color::DarkGray),
                 );
 
-                let reply_area =
-                    Rect::new(inner.x + 3, y, content_width.saturating_sub(2), render_h);
+                let reply_area = Rect::new(inner.x + 3, y, reply_width, render_h);
                 let author = tweet
                     .author_id
                     .as_ref()
                     .and_then(|id| self.app.lookup_user(id));
+                let selected = i == self.selected_index;
                 TweetCard::new(tweet, author)
-                    .selected(i == self.selected_index)
+                    .selected(selected)
+                    .compact(compact)
+                    .included(&self.app.included)
+                    .link_preview(preview.as_ref().filter(|_| selected))
+                    .layout(layout)
+                    .liked(self.app.liked_tweets.contains(&tweet.id))
+                    .retweeted(self.app.retweeted_tweets.contains(&tweet.id))
+                    .hidden(self.app.hidden_replies.contains(&tweet.id))
+                    .unicode(self.app.term_caps.unicode)
                     .render(reply_area, buf);
+                if self.app.is_watched(tweet) {
+                    render_watch_gutter(inner.x + 2, y, render_h, buf);
+                }
+                if self.app.is_batch_selected(i, &tweet.id) {
+                    render_batch_mark(inner.x + 2, y, buf);
+                }
 
                 y += render_h;
             }
@@ -125,5 +234,10 @@ impl Widget for ThreadView<'_> {
                 y += 1;
             }
         }
+
+        let total_height: u16 = heights.iter().fold(0u16, |acc, h| acc.saturating_add(*h));
+        if scroll_start > 0 || total_height > available {
+            render_scrollbar(area, buf, self.replies.len(), self.selected_index);
+        }
     }
 }
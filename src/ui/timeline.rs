@@ -1,13 +1,21 @@
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
+use ratatui::layout::{Margin, Rect};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, Widget};
+use ratatui::widgets::{
+    Block, Borders, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, Widget,
+};
 
-use crate::api::types::Tweet;
+use crate::api::types::{ApiError, Tweet};
 use crate::app::App;
-use crate::ui::skeleton::render_timeline_skeleton;
+use crate::ui::linear::LinearTweetList;
+use crate::ui::skeleton::render_tweet_skeletons;
+use crate::ui::text::truncate_for_width;
 use crate::ui::tweet::{TweetCard, tweet_card_height};
 
+/// Panels narrower than this drop their borders and show compact cards,
+/// e.g. in a 60-column tmux split.
+pub(crate) const COMPACT_WIDTH: u16 = 64;
+
 /// A scrollable list of tweets with selection highlight.
 ///
 /// Used by home timeline, mentions, bookmarks, search results, and user timeline.
@@ -17,6 +25,7 @@ pub struct TimelineView<'a> {
     pub selected_index: usize,
     pub app: &'a App,
     pub loading: bool,
+    pub partial_errors: &'a [ApiError],
 }
 
 impl<'a> TimelineView<'a> {
@@ -27,6 +36,7 @@ impl<'a> TimelineView<'a> {
             selected_index: app.selected_index(),
             app,
             loading: false,
+            partial_errors: &[],
         }
     }
 
@@ -34,38 +44,48 @@ impl<'a> TimelineView<'a> {
         self.loading = loading;
         self
     }
+
+    pub fn partial_errors(mut self, errors: &'a [ApiError]) -> Self {
+        self.partial_errors = errors;
+        self
+    }
 }
 
 impl Widget for TimelineView<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(format!(" {} ", self.title))
-            .title_style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .border_style(Style::default().fg(Color::DarkGray));
-
-        let inner = block.inner(area);
+        if self.app.config.accessible {
+            let title = if self.loading && self.tweets.is_empty() {
+                format!("{}, loading", self.title)
+            } else {
+                self.title.to_string()
+            };
+            LinearTweetList::new(&title, self.tweets, self.selected_index, self.app)
+                .render(area, buf);
+            return;
+        }
+
+        let compact = area.width < COMPACT_WIDTH;
+        let block = panel_block(self.title, compact);
+
+        let mut inner = block.inner(area);
         block.render(area, buf);
 
+        let notice_h = render_partial_errors(
+            self.partial_errors,
+            self.app.partial_errors_expanded,
+            inner,
+            buf,
+        );
+        inner.y += notice_h;
+        inner.height -= notice_h;
+
         if self.tweets.is_empty() {
             if self.loading {
-                // Show animated skeleton once debounce threshold (200ms) passes.
-                // During the debounce window, show simple "Loading..." text so
-                // the user always sees feedback — even on fast responses.
-                if let Some(elapsed_ms) = self.app.skeleton_elapsed_ms() {
-                    render_timeline_skeleton(elapsed_ms, self.title, area, buf);
-                } else {
-                    buf.set_string(
-                        inner.x + 1,
-                        inner.y,
-                        "Loading...",
-                        Style::default().fg(Color::DarkGray),
-                    );
-                }
+                // Placeholder cards render immediately so the layout is in
+                // place before data arrives; they only start animating once
+                // the debounce threshold (200ms) passes, so fast responses
+                // don't flicker.
+                render_tweet_skeletons(self.app.skeleton_elapsed_ms(), inner, buf);
                 return;
             }
             buf.set_string(
@@ -77,14 +97,27 @@ impl Widget for TimelineView<'_> {
             return;
         }
 
-        let content_width = inner.width.saturating_sub(1); // 1 char left margin
+        // 1 char left margin, plus the scrollbar column when there's no border.
+        let content_width = inner.width.saturating_sub(1 + u16::from(compact));
         let available_height = inner.height;
 
+        // Only the selected tweet shows its link preview.
+        let preview = self
+            .tweets
+            .get(self.selected_index)
+            .and_then(|t| self.app.link_preview(t));
+
+        let layout = &self.app.config.card_layout;
+
         // Pre-compute heights for each tweet card (including separator).
         let heights: Vec<u16> = self
             .tweets
             .iter()
-            .map(|t| tweet_card_height(t, content_width) + 1)
+            .enumerate()
+            .map(|(i, t)| {
+                let preview = preview.as_ref().filter(|_| i == self.selected_index);
+                tweet_card_height(t, content_width, layout, preview) + 1
+            })
             .collect();
 
         // Find the scroll start: the first tweet index such that the selected
@@ -107,10 +140,27 @@ impl Widget for TimelineView<'_> {
                 .as_ref()
                 .and_then(|id| self.app.lookup_user(id));
 
+            let selected = tweet_idx == self.selected_index;
             TweetCard::new(tweet, author)
-                .selected(tweet_idx == self.selected_index)
+                .selected(selected)
+                .compact(compact)
+                .included(&self.app.included)
+                .link_preview(preview.as_ref().filter(|_| selected))
+                .layout(layout)
+                .duplicates(self.app.duplicate_count(&tweet.id))
+                .author_run(self.app.author_run_count(&tweet.id))
+                .liked(self.app.liked_tweets.contains(&tweet.id))
+                .retweeted(self.app.retweeted_tweets.contains(&tweet.id))
+                .unicode(self.app.term_caps.unicode)
                 .render(tweet_area, buf);
 
+            if self.app.is_watched(tweet) {
+                render_watch_gutter(inner.x, y, render_h.saturating_sub(1), buf);
+            }
+            if self.app.is_batch_selected(tweet_idx, &tweet.id) && render_h > 0 {
+                render_batch_mark(inner.x, y, buf);
+            }
+
             y += render_h;
 
             // Draw separator line
@@ -126,12 +176,127 @@ impl Widget for TimelineView<'_> {
 
             tweet_idx += 1;
         }
+
+        let total_height: u16 = heights.iter().fold(0u16, |acc, h| acc.saturating_add(*h));
+        if scroll_start > 0 || total_height > available_height {
+            render_scrollbar(area, buf, self.tweets.len(), self.selected_index);
+        }
+    }
+}
+
+/// Gutter indicator for a tweet in the batch selection (marked or inside
+/// the visual range).
+pub(crate) fn render_batch_mark(x: u16, y: u16, buf: &mut Buffer) {
+    buf.set_string(
+        x,
+        y,
+        "\u{2713}",
+        Style::default()
+            .fg(Color::Green)
+            .add_modifier(Modifier::BOLD),
+    );
+}
+
+/// The titled block around a tweet list. Compact panels keep the title but
+/// drop the border, leaving the columns to the tweets.
+pub(crate) fn panel_block(title: &str, compact: bool) -> Block<'static> {
+    Block::default()
+        .borders(if compact { Borders::NONE } else { Borders::ALL })
+        .title(format!(" {title} "))
+        .title_style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .border_style(Style::default().fg(Color::DarkGray))
+}
+
+/// Gutter bar alongside a tweet matching a `[watch]` rule. Drawn before the
+/// batch mark, which takes the first row when both apply.
+pub(crate) fn render_watch_gutter(x: u16, y: u16, height: u16, buf: &mut Buffer) {
+    for row in y..y + height {
+        buf.set_string(x, row, "\u{258E}", Style::default().fg(Color::Magenta));
+    }
+}
+
+/// Maximum number of individual errors listed when the notice is expanded.
+const MAX_LISTED_ERRORS: usize = 5;
+
+/// Render the collapsible notice for partial API errors at the top of
+/// `inner`, returning how many rows it used (0 when there are no errors).
+pub(crate) fn render_partial_errors(
+    errors: &[ApiError],
+    expanded: bool,
+    inner: Rect,
+    buf: &mut Buffer,
+) -> u16 {
+    if errors.is_empty() || inner.height < 2 {
+        return 0;
+    }
+
+    let style = Style::default().fg(Color::Yellow);
+    let width = inner.width.saturating_sub(1) as usize;
+    let noun = if errors.len() == 1 { "item" } else { "items" };
+    let hint = if expanded { "hide" } else { "show" };
+    let header = format!(
+        "\u{26A0} {} {noun} could not be loaded (e to {hint} details)",
+        errors.len()
+    );
+
+    let mut lines = vec![header];
+    if expanded {
+        lines.extend(
+            errors
+                .iter()
+                .take(MAX_LISTED_ERRORS)
+                .map(|e| format!("  \u{2022} {}", e.summary())),
+        );
+        if errors.len() > MAX_LISTED_ERRORS {
+            lines.push(format!(
+                "  \u{2026} and {} more",
+                errors.len() - MAX_LISTED_ERRORS
+            ));
+        }
+    }
+
+    // Always leave at least one row for content below the notice.
+    let rows = (lines.len() as u16).min(inner.height - 1);
+    for (i, line) in lines.iter().take(rows as usize).enumerate() {
+        buf.set_string(
+            inner.x + 1,
+            inner.y + i as u16,
+            truncate_for_width(line, width),
+            style,
+        );
+    }
+    rows
+}
+
+/// Draw a vertical scrollbar over the right border of a bordered `area`,
+/// positioned at `position` among `total` loaded items.
+pub(crate) fn render_scrollbar(area: Rect, buf: &mut Buffer, total: usize, position: usize) {
+    if total <= 1 {
+        return;
     }
+    let mut state = ScrollbarState::new(total).position(position.min(total - 1));
+    Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .track_style(Style::default().fg(Color::DarkGray))
+        .thumb_style(Style::default().fg(Color::Cyan))
+        .render(
+            area.inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            buf,
+            &mut state,
+        );
 }
 
 /// Find the smallest scroll start index so that the selected item fits
 /// within the available height.
-fn compute_scroll_start(heights: &[u16], selected: usize, available: u16) -> usize {
+pub(crate) fn compute_scroll_start(heights: &[u16], selected: usize, available: u16) -> usize {
     if heights.is_empty() {
         return 0;
     }
@@ -0,0 +1,51 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+use crate::app::{ToastLevel, Toasts};
+use crate::ui::text::truncate_for_width;
+
+/// Notifications stacked in the bottom-right corner, newest at the bottom.
+pub struct ToastStack<'a> {
+    toasts: &'a Toasts,
+}
+
+impl<'a> ToastStack<'a> {
+    pub fn new(toasts: &'a Toasts) -> Self {
+        Self { toasts }
+    }
+}
+
+impl Widget for ToastStack<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Keep clear of the right border and the bottom edge of the view.
+        let max_width = (area.width.saturating_sub(2) as usize).min(60);
+        if max_width < 4 || area.height < 2 {
+            return;
+        }
+        let mut y = area.y + area.height - 1;
+        for toast in self.toasts.iter().rev() {
+            if y <= area.y {
+                break;
+            }
+            y -= 1;
+            let style = match toast.level {
+                ToastLevel::Info => Style::default().bg(Color::DarkGray).fg(Color::Cyan),
+                ToastLevel::Error => Style::default()
+                    .bg(Color::Red)
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            };
+            let text = format!(
+                " {} ",
+                truncate_for_width(&toast.text, max_width.saturating_sub(2))
+            );
+            let span = Span::styled(text, style);
+            let width = span.width() as u16;
+            let x = area.x + area.width.saturating_sub(width + 1);
+            buf.set_line(x, y, &Line::from(span), width);
+        }
+    }
+}
@@ -1,29 +1,72 @@
+use std::sync::LazyLock;
+
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Widget;
+use unicode_width::UnicodeWidthStr;
 
 use crate::api::types::{Tweet, User};
+use crate::app::IncludedContent;
+use crate::config::{CardElement, CardLayout, CardRow};
+use crate::link_preview::LinkPreview;
+use crate::terminal::UnicodeLevel;
+use crate::ui::text::{bidi_visual_line, is_rtl_paragraph, truncate_for_width};
+use crate::ui::user::user_badges;
 
-/// Renders a single tweet as a compact card (2-4 lines).
+/// Renders a single tweet as a compact card.
 ///
-/// Layout:
-///   @username · 2h ago                   [RT] [Reply]
+/// Rows follow the configured [`CardLayout`]; the default is:
+///   @username Name · 2h [RT] [Reply]
 ///   Tweet text (may wrap) ...
-///   ♥ 12  🔁 3  💬 5  🔖 1
+///   ▎ link preview (selected tweet, when enabled)
+///   ♥ 12  🔁 3  💬 5
 pub struct TweetCard<'a> {
     pub tweet: &'a Tweet,
     pub author: Option<&'a User>,
     pub selected: bool,
+    pub link_preview: Option<&'a LinkPreview>,
+    pub layout: &'a CardLayout,
+    /// Narrow-terminal variant: no display name, tighter metrics.
+    pub compact: bool,
+    /// Resolves attached media and quoted tweets; without it they aren't
+    /// shown.
+    pub included: Option<&'a IncludedContent>,
+    /// Copies folded into this tweet, shown as a `×N` badge.
+    pub duplicates: usize,
+    /// Tweets of its author's run folded into this one, shown as
+    /// "posted N times".
+    pub author_run: usize,
+    /// Liked by the signed-in user this session; the heart is drawn bold.
+    pub liked: bool,
+    /// Reposted by the signed-in user this session, shown as `[RT'd]`.
+    pub retweeted: bool,
+    /// A reply hidden by the signed-in user this session, shown as
+    /// `[hidden]`.
+    pub hidden: bool,
+    /// What the terminal can draw, for the author's badges.
+    pub unicode: UnicodeLevel,
 }
 
+static DEFAULT_LAYOUT: LazyLock<CardLayout> = LazyLock::new(CardLayout::default);
+
 impl<'a> TweetCard<'a> {
     pub fn new(tweet: &'a Tweet, author: Option<&'a User>) -> Self {
         Self {
             tweet,
             author,
             selected: false,
+            link_preview: None,
+            layout: &DEFAULT_LAYOUT,
+            compact: false,
+            included: None,
+            duplicates: 0,
+            author_run: 0,
+            liked: false,
+            retweeted: false,
+            hidden: false,
+            unicode: UnicodeLevel::Full,
         }
     }
 
@@ -31,179 +74,496 @@ impl<'a> TweetCard<'a> {
         self.selected = selected;
         self
     }
-}
 
-impl Widget for TweetCard<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.height == 0 || area.width == 0 {
-            return;
-        }
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.compact = compact;
+        self
+    }
 
-        let highlight_style = if self.selected {
-            Style::default().fg(Color::Cyan)
-        } else {
-            Style::default()
-        };
+    pub fn included(mut self, included: &'a IncludedContent) -> Self {
+        self.included = Some(included);
+        self
+    }
 
-        let mut y = area.y;
+    /// Show a link preview card in the layout's `preview` row. Pass the same
+    /// preview to [`tweet_card_height`].
+    pub fn link_preview(mut self, preview: Option<&'a LinkPreview>) -> Self {
+        self.link_preview = preview;
+        self
+    }
+
+    pub fn layout(mut self, layout: &'a CardLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn duplicates(mut self, duplicates: usize) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+
+    pub fn author_run(mut self, author_run: usize) -> Self {
+        self.author_run = author_run;
+        self
+    }
+
+    pub fn liked(mut self, liked: bool) -> Self {
+        self.liked = liked;
+        self
+    }
+
+    pub fn retweeted(mut self, retweeted: bool) -> Self {
+        self.retweeted = retweeted;
+        self
+    }
 
-        // -- Line 1: author info + indicators --
-        let username = self
-            .author
-            .map(|u| format!("@{}", u.username))
-            .or_else(|| self.tweet.author_id.clone().map(|id| format!("@{id}")))
-            .unwrap_or_else(|| "@unknown".into());
-
-        let time_ago = self
-            .tweet
-            .created_at
-            .map(format_time_ago)
-            .unwrap_or_default();
-
-        let mut header_spans = vec![Span::styled(
-            &username,
-            highlight_style.add_modifier(Modifier::BOLD),
-        )];
-
-        if let Some(name) = self.author.map(|u| &u.name) {
-            header_spans.push(Span::raw(" "));
-            header_spans.push(Span::styled(
-                name.as_str(),
-                Style::default().fg(Color::DarkGray),
-            ));
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    pub fn unicode(mut self, unicode: UnicodeLevel) -> Self {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Spans for one inline element; empty when the tweet has nothing to show.
+    fn element_spans(&self, element: CardElement, first: bool) -> Vec<Span<'a>> {
+        let dim = Style::default().fg(Color::DarkGray);
+        match element {
+            CardElement::Author => {
+                let highlight_style = if self.selected {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default()
+                };
+                let username = self
+                    .author
+                    .map(|u| format!("@{}", u.username))
+                    .or_else(|| self.tweet.author_id.as_ref().map(|id| format!("@{id}")))
+                    .unwrap_or_else(|| "@unknown".into());
+                let mut spans = vec![Span::styled(
+                    username,
+                    highlight_style.add_modifier(Modifier::BOLD),
+                )];
+                if let Some(author) = self.author {
+                    spans.extend(user_badges(author, self.unicode));
+                }
+                spans
+            }
+            CardElement::Name if self.compact => vec![],
+            CardElement::Name => self
+                .author
+                .map(|u| vec![Span::styled(u.name.as_str(), dim)])
+                .unwrap_or_default(),
+            CardElement::Time => match self.tweet.created_at.map(format_time_ago) {
+                Some(ago) if first => vec![Span::styled(ago, dim)],
+                Some(ago) => vec![Span::styled(format!("\u{00B7} {ago}"), dim)],
+                None => vec![],
+            },
+            CardElement::Indicators => (self.duplicates > 0)
+                .then(|| {
+                    Span::styled(
+                        format!("\u{00D7}{}", self.duplicates + 1),
+                        Style::default()
+                            .fg(Color::Magenta)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                })
+                .into_iter()
+                .chain((self.author_run > 0).then(|| {
+                    Span::styled(
+                        format!("posted {} times", self.author_run + 1),
+                        Style::default().fg(Color::Magenta),
+                    )
+                }))
+                .chain(
+                    self.tweet
+                        .referenced_tweets
+                        .iter()
+                        .flatten()
+                        .filter_map(|rt| match rt.type_.as_str() {
+                            "retweeted" => {
+                                Some(Span::styled("[RT]", Style::default().fg(Color::Green)))
+                            }
+                            "replied_to" => {
+                                Some(Span::styled("[Reply]", Style::default().fg(Color::Blue)))
+                            }
+                            // Missing quotes get a placeholder below instead.
+                            "quoted"
+                                if self
+                                    .included
+                                    .is_some_and(|inc| !inc.tweets.contains_key(&rt.id)) =>
+                            {
+                                None
+                            }
+                            "quoted" => {
+                                Some(Span::styled("[Quote]", Style::default().fg(Color::Yellow)))
+                            }
+                            _ => None,
+                        }),
+                )
+                .chain(self.retweeted.then(|| {
+                    Span::styled(
+                        "[RT'd]",
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                }))
+                .chain(
+                    self.hidden
+                        .then(|| Span::styled("[hidden]", Style::default().fg(Color::Red))),
+                )
+                .chain(self.included.into_iter().flat_map(|included| {
+                    attachments(self.tweet, included)
+                        .into_iter()
+                        .map(|attachment| match attachment {
+                            Attachment::Media(kind) => Span::styled(format!("[{kind}]"), dim),
+                            missing => Span::styled(
+                                format!("[{}]", missing.describe()),
+                                Style::default().fg(Color::Yellow),
+                            ),
+                        })
+                }))
+                .chain(self.tweet.withheld.as_ref().map(|w| {
+                    Span::styled(
+                        format!("[{}]", w.describe()),
+                        Style::default().fg(Color::Red),
+                    )
+                }))
+                .collect(),
+            CardElement::Source => self
+                .tweet
+                .source
+                .as_ref()
+                .map(|src| vec![Span::styled(format!("via {src}"), dim)])
+                .unwrap_or_default(),
+            CardElement::Metrics => match self.tweet.public_metrics {
+                Some(ref metrics) if self.compact => vec![
+                    Span::styled(
+                        format!("\u{2665}{}", format_count(metrics.like_count)),
+                        self.like_style(),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("\u{21BB}{}", format_count(metrics.retweet_count)),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("\u{1F4AC}{}", format_count(metrics.reply_count)),
+                        Style::default().fg(Color::Blue),
+                    ),
+                ],
+                Some(ref metrics) => vec![
+                    Span::styled(
+                        format!("\u{2665} {}", format_count(metrics.like_count)),
+                        self.like_style(),
+                    ),
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("\u{21BB} {}", format_count(metrics.retweet_count)),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("\u{1F4AC} {}", format_count(metrics.reply_count)),
+                        Style::default().fg(Color::Blue),
+                    ),
+                ],
+                None => vec![],
+            },
         }
+    }
 
-        if !time_ago.is_empty() {
-            header_spans.push(Span::styled(
-                format!(" · {time_ago}"),
-                Style::default().fg(Color::DarkGray),
-            ));
+    fn like_style(&self) -> Style {
+        let style = Style::default().fg(Color::Red);
+        if self.liked {
+            style.add_modifier(Modifier::BOLD)
+        } else {
+            style
         }
+    }
 
-        // Indicators for RT/reply
-        if let Some(ref refs) = self.tweet.referenced_tweets {
-            for rt in refs {
-                match rt.type_.as_str() {
-                    "retweeted" => {
-                        header_spans.push(Span::styled(" [RT]", Style::default().fg(Color::Green)));
-                    }
-                    "replied_to" => {
-                        header_spans
-                            .push(Span::styled(" [Reply]", Style::default().fg(Color::Blue)));
-                    }
-                    "quoted" => {
-                        header_spans
-                            .push(Span::styled(" [Quote]", Style::default().fg(Color::Yellow)));
+    /// One inline row, with elements separated by a space. Multi-span
+    /// elements (metrics) carry their own internal spacing; indicators are
+    /// each treated as separate elements.
+    fn inline_line(&self, elements: &[CardElement]) -> Line<'a> {
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        for &element in elements {
+            let element_spans = self.element_spans(element, spans.is_empty());
+            if element_spans.is_empty() {
+                continue;
+            }
+            if element == CardElement::Indicators {
+                for span in element_spans {
+                    if !spans.is_empty() {
+                        spans.push(Span::raw(" "));
                     }
-                    _ => {}
+                    spans.push(span);
                 }
+                continue;
+            }
+            if !spans.is_empty() {
+                spans.push(Span::raw(" "));
             }
+            spans.extend(element_spans);
         }
+        Line::from(spans)
+    }
+}
 
-        let header_line = Line::from(header_spans);
-        buf.set_line(area.x, y, &header_line, area.width);
-        y += 1;
-
-        if y >= area.y + area.height {
+impl Widget for TweetCard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 {
             return;
         }
 
-        // -- Line 2+: tweet text (wrapped) --
-        let text = self
-            .tweet
-            .note_tweet
-            .as_ref()
-            .map(|nt| nt.text.as_str())
-            .unwrap_or(&self.tweet.text);
-
         let width = area.width as usize;
-        let max_text_lines = (area.height - (y - area.y) - 1).max(1) as usize; // Reserve 1 line for metrics
+        let bottom = area.y + area.height;
+        let rows = &self.layout.rows;
+        let preview_h = link_preview_height(self.link_preview);
+        let row_height = |row: &CardRow| match row {
+            CardRow::Text => 0,
+            CardRow::Preview => preview_h,
+            CardRow::Inline(_) => 1,
+        };
 
-        for (i, line_text) in wrap_text(text, width).into_iter().enumerate() {
-            if i >= max_text_lines || y >= area.y + area.height {
-                break;
+        let mut y = area.y;
+        for (i, row) in rows.iter().enumerate() {
+            if y >= bottom {
+                return;
+            }
+            match row {
+                CardRow::Inline(elements) => {
+                    buf.set_line(area.x, y, &self.inline_line(elements), area.width);
+                    y += 1;
+                }
+                CardRow::Text => {
+                    let text = self
+                        .tweet
+                        .note_tweet
+                        .as_ref()
+                        .map(|nt| nt.text.as_str())
+                        .unwrap_or(&self.tweet.text);
+                    // Keep room for the rows after the text.
+                    let reserved: u16 = rows[i + 1..].iter().map(row_height).sum();
+                    let max_text_lines = (bottom - y).saturating_sub(reserved).max(1) as usize;
+                    let text_style = if self.selected {
+                        Style::default().fg(Color::White)
+                    } else {
+                        Style::default()
+                    };
+                    for line in wrap_text(text, width).into_iter().take(max_text_lines) {
+                        if y >= bottom {
+                            return;
+                        }
+                        let x = if line.rtl {
+                            area.x + area.width.saturating_sub(line.text.width() as u16)
+                        } else {
+                            area.x
+                        };
+                        buf.set_stringn(x, y, &line.text, width, text_style);
+                        y += 1;
+                    }
+                }
+                CardRow::Preview => {
+                    let Some(preview) = self.link_preview else {
+                        continue;
+                    };
+                    for line in preview_lines(preview, width.saturating_sub(2)) {
+                        if y >= bottom {
+                            return;
+                        }
+                        buf.set_string(area.x, y, "\u{258E}", Style::default().fg(Color::DarkGray));
+                        buf.set_line(area.x + 2, y, &line, area.width.saturating_sub(2));
+                        y += 1;
+                    }
+                }
             }
-            let text_style = if self.selected {
-                Style::default().fg(Color::White)
-            } else {
-                Style::default()
-            };
-            buf.set_string(area.x, y, &line_text, text_style);
-            y += 1;
         }
+    }
+}
 
-        if y >= area.y + area.height {
-            return;
-        }
+/// Something a tweet carries besides its text, as far as `includes` could
+/// resolve it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Attachment {
+    /// Resolved media, by type: `photo`, `video` or `GIF`.
+    Media(&'static str),
+    /// A media key missing from `includes`.
+    MissingMedia(String),
+    /// A quoted tweet ID missing from `includes`.
+    MissingQuote(String),
+}
 
-        // -- Last line: metrics --
-        if let Some(ref metrics) = self.tweet.public_metrics {
-            let metrics_line = Line::from(vec![
-                Span::styled(
-                    format!("\u{2665} {}", format_count(metrics.like_count)),
-                    Style::default().fg(Color::Red),
-                ),
-                Span::raw("  "),
-                Span::styled(
-                    format!("\u{21BB} {}", format_count(metrics.retweet_count)),
-                    Style::default().fg(Color::Green),
-                ),
-                Span::raw("  "),
-                Span::styled(
-                    format!("\u{1F4AC} {}", format_count(metrics.reply_count)),
-                    Style::default().fg(Color::Blue),
-                ),
-            ]);
-            buf.set_line(area.x, y, &metrics_line, area.width);
+impl Attachment {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Media(kind) => (*kind).to_string(),
+            Self::MissingMedia(key) => format!("media unavailable: {key}"),
+            Self::MissingQuote(id) => format!("quoted tweet unavailable: {id}"),
         }
     }
 }
 
-/// Height in lines needed for a tweet card.
-pub fn tweet_card_height(tweet: &Tweet, width: u16) -> u16 {
-    let text = tweet
-        .note_tweet
-        .as_ref()
-        .map(|nt| nt.text.as_str())
-        .unwrap_or(&tweet.text);
-    let text_lines = wrap_text(text, width as usize).len() as u16;
-    // header + text + metrics
-    1 + text_lines + 1
+/// The tweet's media, plus placeholders for media and quoted tweets that
+/// `included` has no record of (e.g. deleted, protected, or requested
+/// without the expansion).
+pub fn attachments(tweet: &Tweet, included: &IncludedContent) -> Vec<Attachment> {
+    let media = tweet
+        .attachments
+        .iter()
+        .flat_map(|a| a.media_keys.iter().flatten())
+        .map(|key| match included.media.get(key) {
+            Some(media) => Attachment::Media(match media.type_.as_str() {
+                "video" => "video",
+                "animated_gif" => "GIF",
+                _ => "photo",
+            }),
+            None => Attachment::MissingMedia(key.clone()),
+        });
+    let quotes = tweet
+        .referenced_tweets
+        .iter()
+        .flatten()
+        .filter(|rt| rt.type_ == "quoted" && !included.tweets.contains_key(&rt.id))
+        .map(|rt| Attachment::MissingQuote(rt.id.clone()));
+    media.chain(quotes).collect()
+}
+
+/// Posting client and reply restrictions, e.g. "via Typefully · replies:
+/// accounts they follow", for the detail view. `None` when the tweet has
+/// neither, or only says anyone may reply.
+pub fn tweet_metadata(tweet: &Tweet) -> Option<String> {
+    let source = tweet.source.as_ref().map(|src| format!("via {src}"));
+    let replies = tweet
+        .reply_settings
+        .as_deref()
+        .and_then(|setting| {
+            Some(match setting {
+                "everyone" => return None,
+                "mentionedUsers" => "mentioned accounts",
+                "following" => "accounts they follow",
+                "subscribers" => "subscribers",
+                "verified" => "verified accounts",
+                other => other,
+            })
+        })
+        .map(|who| format!("replies: {who}"));
+    let parts: Vec<String> = source.into_iter().chain(replies).collect();
+    (!parts.is_empty()).then(|| parts.join(" \u{00B7} "))
+}
+
+/// Lines of a link preview card: site, title, and description if any.
+fn preview_lines(preview: &LinkPreview, width: usize) -> Vec<Line<'static>> {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::with_capacity(3);
+    if let Some(ref site) = preview.site {
+        lines.push(Line::styled(truncate_for_width(site, width), dim));
+    }
+    lines.push(Line::styled(
+        truncate_for_width(&preview.title, width),
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+    if let Some(ref desc) = preview.description {
+        lines.push(Line::styled(truncate_for_width(desc, width), dim));
+    }
+    lines
+}
+
+/// Extra lines a link preview card adds to a tweet card.
+fn link_preview_height(preview: Option<&LinkPreview>) -> u16 {
+    preview.map_or(0, |p| {
+        1 + u16::from(p.site.is_some()) + u16::from(p.description.is_some())
+    })
+}
+
+/// Height in lines needed for a tweet card laid out with `layout`,
+/// including `preview` when the layout has a row for it.
+pub fn tweet_card_height(
+    tweet: &Tweet,
+    width: u16,
+    layout: &CardLayout,
+    preview: Option<&LinkPreview>,
+) -> u16 {
+    layout
+        .rows
+        .iter()
+        .map(|row| match row {
+            CardRow::Text => {
+                let text = tweet
+                    .note_tweet
+                    .as_ref()
+                    .map(|nt| nt.text.as_str())
+                    .unwrap_or(&tweet.text);
+                wrap_text(text, width as usize).len() as u16
+            }
+            CardRow::Preview => link_preview_height(preview),
+            CardRow::Inline(_) => 1,
+        })
+        .sum()
 }
 
-fn wrap_text(text: &str, width: usize) -> Vec<String> {
+/// A wrapped line of tweet text, already in visual (display) order.
+struct WrappedLine {
+    text: String,
+    /// The line belongs to a right-to-left paragraph and should be
+    /// right-aligned.
+    rtl: bool,
+}
+
+/// Word-wrap `text` to `width` columns, then reorder each line for display
+/// so RTL scripts and mixed-direction text read correctly.
+fn wrap_text(text: &str, width: usize) -> Vec<WrappedLine> {
     if width == 0 {
         return vec![];
     }
     let mut lines = Vec::new();
     for paragraph in text.lines() {
         if paragraph.is_empty() {
-            lines.push(String::new());
+            lines.push(WrappedLine {
+                text: String::new(),
+                rtl: false,
+            });
             continue;
         }
+        // Wrap in logical order first so each line holds the right words,
+        // then reorder line by line using the paragraph's base direction.
+        let rtl = is_rtl_paragraph(paragraph);
+        let mut push = |line: &str| {
+            lines.push(WrappedLine {
+                text: bidi_visual_line(line, rtl),
+                rtl,
+            });
+        };
         let mut current = String::new();
         for word in paragraph.split_whitespace() {
             if current.is_empty() {
                 current = word.to_string();
-            } else if current.len() + 1 + word.len() <= width {
+            } else if current.width() + 1 + word.width() <= width {
                 current.push(' ');
                 current.push_str(word);
             } else {
-                lines.push(current);
+                push(&current);
                 current = word.to_string();
             }
         }
         if !current.is_empty() {
-            lines.push(current);
+            push(&current);
         }
     }
     if lines.is_empty() {
-        lines.push(String::new());
+        lines.push(WrappedLine {
+            text: String::new(),
+            rtl: false,
+        });
     }
     lines
 }
 
-fn format_time_ago(dt: chrono::DateTime<chrono::Utc>) -> String {
+pub(crate) fn format_time_ago(dt: chrono::DateTime<chrono::Utc>) -> String {
     let now = chrono::Utc::now();
     let diff = now.signed_duration_since(dt);
 
@@ -220,7 +580,7 @@ fn format_time_ago(dt: chrono::DateTime<chrono::Utc>) -> String {
     }
 }
 
-fn format_count(n: u64) -> String {
+pub(crate) fn format_count(n: u64) -> String {
     if n >= 1_000_000 {
         format!("{:.1}M", n as f64 / 1_000_000.0)
     } else if n >= 1_000 {
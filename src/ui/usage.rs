@@ -0,0 +1,136 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Widget};
+
+use crate::app::UsageReport;
+
+/// Width of the consumption bar, in cells.
+const BAR_WIDTH: u64 = 40;
+
+/// Centered popup with the `:usage` report: posts read this month against
+/// the project's cap, and when the count resets.
+pub struct UsagePopup<'a> {
+    report: &'a UsageReport,
+    accessible: bool,
+}
+
+impl<'a> UsagePopup<'a> {
+    pub fn new(report: &'a UsageReport) -> Self {
+        Self {
+            report,
+            accessible: false,
+        }
+    }
+
+    /// Leave out the bar, which screen readers can't make sense of.
+    pub fn accessible(mut self, accessible: bool) -> Self {
+        self.accessible = accessible;
+        self
+    }
+}
+
+impl Widget for UsagePopup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let usage = &self.report.usage;
+        let label = |text: &'static str| {
+            Span::styled(
+                format!(" {text:<12}"),
+                Style::default().add_modifier(Modifier::BOLD),
+            )
+        };
+        let used = usage.project_usage.unwrap_or(0);
+
+        let mut lines = vec![Line::from("")];
+        match (usage.project_cap, usage.percent_used()) {
+            (Some(cap), Some(percent)) => {
+                let color = match percent {
+                    90.. => Color::Red,
+                    75.. => Color::Yellow,
+                    _ => Color::Green,
+                };
+                lines.push(Line::from(vec![
+                    label("Posts read"),
+                    Span::raw(format!("{} of {}  ", grouped(used), grouped(cap))),
+                    Span::styled(format!("{percent}%"), Style::default().fg(color)),
+                ]));
+                if !self.accessible {
+                    let filled = (used.min(cap) * BAR_WIDTH / cap) as usize;
+                    lines.push(Line::from(vec![
+                        Span::raw(" ".repeat(13)),
+                        Span::styled("█".repeat(filled), Style::default().fg(color)),
+                        Span::styled(
+                            "░".repeat(BAR_WIDTH as usize - filled),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ]));
+                }
+                lines.push(Line::from(vec![
+                    label("Remaining"),
+                    Span::raw(grouped(cap.saturating_sub(used))),
+                ]));
+            }
+            _ => lines.push(Line::from(vec![
+                label("Posts read"),
+                Span::raw(format!("{} (no cap reported)", grouped(used))),
+            ])),
+        }
+        let reset = match usage.next_reset(self.report.checked_on) {
+            Some(date) => {
+                let days = (date - self.report.checked_on).num_days();
+                format!(
+                    "{} (in {days} day{})",
+                    date.format("%a %-d %b"),
+                    if days == 1 { "" } else { "s" }
+                )
+            }
+            None => "unknown".to_string(),
+        };
+        lines.push(Line::from(vec![label("Resets"), Span::raw(reset)]));
+
+        // Borders, the report, a blank line and the dismiss hint.
+        let width = 60u16.min(area.width.saturating_sub(4));
+        let height = (lines.len() as u16 + 4).min(area.height.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(width)) / 2;
+        let y = area.y + (area.height.saturating_sub(height)) / 2;
+        let panel = Rect::new(x, y, width, height);
+
+        Clear.render(panel, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Usage this month ")
+            .title_style(Style::default().add_modifier(Modifier::BOLD));
+        let inner = block.inner(panel);
+        block.render(panel, buf);
+
+        if inner.height < 2 {
+            return;
+        }
+        let report_area = Rect::new(inner.x, inner.y, inner.width, inner.height - 1);
+        let hint_area = Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1);
+
+        Paragraph::new(lines).render(report_area, buf);
+        Paragraph::new(Line::from(Span::styled(
+            " Press Esc or Enter to dismiss ",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )))
+        .render(hint_area, buf);
+    }
+}
+
+/// `1234567` as `1,234,567`.
+fn grouped(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
@@ -4,34 +4,102 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Widget};
 
-use crate::api::types::User;
+use crate::api::types::{User, Verification};
+use crate::terminal::{TerminalCaps, UnicodeLevel};
+use crate::ui::halfblock::{HalfBlockImage, RgbImage};
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+
+/// Rows given to the profile banner strip.
+const BANNER_HEIGHT: u16 = 4;
 
 /// User profile view showing stats, bio, and info.
 pub struct UserProfileView<'a> {
     pub user: &'a User,
+    banner: Option<&'a RgbImage>,
+    caps: TerminalCaps,
+    /// Muted from this client this session.
+    muted: bool,
+    /// Blocked from this client this session.
+    blocked: bool,
 }
 
 impl<'a> UserProfileView<'a> {
     pub fn new(user: &'a User) -> Self {
-        Self { user }
+        Self {
+            user,
+            banner: None,
+            caps: TerminalCaps::default(),
+            muted: false,
+            blocked: false,
+        }
+    }
+
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
+    pub fn blocked(mut self, blocked: bool) -> Self {
+        self.blocked = blocked;
+        self
+    }
+
+    pub fn banner(mut self, banner: Option<&'a RgbImage>) -> Self {
+        self.banner = banner;
+        self
+    }
+
+    pub fn caps(mut self, caps: TerminalCaps) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    /// Draw the banner strip: half-block pixels where the terminal can show
+    /// them, otherwise a band of the banner's dominant color.
+    fn render_banner(&self, image: &RgbImage, area: Rect, buf: &mut Buffer) {
+        if self.caps.unicode != UnicodeLevel::Ascii {
+            HalfBlockImage::new(image)
+                .cover(true)
+                .truecolor(self.caps.truecolor)
+                .render(area, buf);
+            return;
+        }
+        let Some([r, g, b]) = image.dominant_color() else {
+            return;
+        };
+        buf.set_style(area, Style::default().bg(Color::Rgb(r, g, b)));
+        let label = format!(" banner #{r:02x}{g:02x}{b:02x} ");
+        buf.set_string(
+            area.x + 1,
+            area.y + area.height / 2,
+            label,
+            Style::default().fg(Color::White).bg(Color::Black),
+        );
     }
 }
 
 impl Widget for UserProfileView<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(format!(" @{} ", self.user.username))
-            .title_style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .border_style(Style::default().fg(Color::DarkGray));
-
-        let inner = block.inner(area);
+        let block = panel_block(
+            &format!("@{}", self.user.username),
+            area.width < COMPACT_WIDTH,
+        );
+
+        let mut inner = block.inner(area);
         block.render(area, buf);
 
+        if self.user.profile_banner_url.is_some() && inner.height > BANNER_HEIGHT * 3 {
+            let [banner_area, rest] =
+                Layout::vertical([Constraint::Length(BANNER_HEIGHT), Constraint::Min(1)])
+                    .areas(inner);
+            // Until the banner arrives the strip stays blank, so the layout
+            // doesn't jump when it loads.
+            if let Some(image) = self.banner {
+                self.render_banner(image, banner_area, buf);
+            }
+            inner = rest;
+        }
+
         let [info_area, bio_area] =
             Layout::vertical([Constraint::Length(8), Constraint::Min(1)]).areas(inner);
 
@@ -42,14 +110,25 @@ impl Widget for UserProfileView<'_> {
         let name_style = Style::default()
             .fg(Color::White)
             .add_modifier(Modifier::BOLD);
-        lines.push(Line::from(vec![
-            Span::styled(&self.user.name, name_style),
-            if self.user.verified.unwrap_or(false) {
-                Span::styled(" \u{2713}", Style::default().fg(Color::Blue))
-            } else {
-                Span::raw("")
-            },
-        ]));
+        let mut name_line = vec![Span::styled(self.user.name.as_str(), name_style)];
+        name_line.extend(user_badges(self.user, self.caps.unicode));
+        name_line.push(match self.user.withheld {
+            Some(ref withheld) => Span::styled(
+                format!(" [{}]", withheld.describe()),
+                Style::default().fg(Color::Red),
+            ),
+            None => Span::raw(""),
+        });
+        if self.muted {
+            name_line.push(Span::styled(
+                " [muted]",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if self.blocked {
+            name_line.push(Span::styled(" [blocked]", Style::default().fg(Color::Red)));
+        }
+        lines.push(Line::from(name_line));
 
         lines.push(Line::from(Span::styled(
             format!("@{}", self.user.username),
@@ -142,3 +221,31 @@ fn format_count(n: u64) -> String {
         n.to_string()
     }
 }
+
+/// Verification check (blue, gold or gray by account type) and a lock for
+/// protected accounts, each led by a space, for after a name or handle.
+/// Terminals that can't draw them get `[v]` and `[locked]`.
+pub fn user_badges(user: &User, unicode: UnicodeLevel) -> Vec<Span<'static>> {
+    let mut badges = Vec::new();
+    if let Some(verification) = user.verification() {
+        let color = match verification {
+            Verification::Blue => Color::Blue,
+            Verification::Business => Color::Yellow,
+            Verification::Government => Color::Gray,
+        };
+        let check = match unicode {
+            UnicodeLevel::Ascii => " [v]",
+            _ => " \u{2713}",
+        };
+        badges.push(Span::styled(check, Style::default().fg(color)));
+    }
+    if user.is_protected() {
+        // The lock is an emoji, which limited fonts don't have.
+        let lock = match unicode {
+            UnicodeLevel::Full => " \u{1F512}",
+            _ => " [locked]",
+        };
+        badges.push(Span::styled(lock, Style::default().fg(Color::DarkGray)));
+    }
+    badges
+}
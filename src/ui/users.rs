@@ -0,0 +1,100 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{List, ListItem, ListState, StatefulWidget, Widget};
+
+use crate::api::types::User;
+use crate::terminal::UnicodeLevel;
+use crate::ui::timeline::{COMPACT_WIDTH, panel_block};
+use crate::ui::tweet::format_count;
+use crate::ui::user::user_badges;
+
+/// A titled list of accounts, one per row. Enter opens the selected
+/// profile.
+pub struct UserListView<'a> {
+    title: String,
+    users: &'a [User],
+    selected: usize,
+    loading: bool,
+    unicode: UnicodeLevel,
+}
+
+impl<'a> UserListView<'a> {
+    pub fn new(title: impl Into<String>, users: &'a [User], selected: usize) -> Self {
+        Self {
+            title: title.into(),
+            users,
+            selected,
+            loading: false,
+            unicode: UnicodeLevel::Full,
+        }
+    }
+
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    pub fn unicode(mut self, unicode: UnicodeLevel) -> Self {
+        self.unicode = unicode;
+        self
+    }
+}
+
+impl Widget for UserListView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = panel_block(&self.title, area.width < COMPACT_WIDTH);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.width < 2 || inner.height == 0 {
+            return;
+        }
+
+        let dim = Style::default().fg(Color::DarkGray);
+        let message = if self.loading {
+            Some("Loading…")
+        } else if self.users.is_empty() {
+            Some("No accounts")
+        } else {
+            None
+        };
+        if let Some(message) = message {
+            buf.set_string(inner.x + 1, inner.y, message, dim);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .users
+            .iter()
+            .map(|user| {
+                let mut spans = vec![
+                    Span::styled(
+                        format!("@{}", user.username),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(format!("  {}", user.name)),
+                ];
+                spans.extend(user_badges(user, self.unicode));
+                if let Some(metrics) = &user.public_metrics {
+                    spans.push(Span::styled(
+                        format!("  {} followers", format_count(metrics.followers_count)),
+                        dim,
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(list, inner, buf, &mut state);
+    }
+}